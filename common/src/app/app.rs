@@ -0,0 +1,427 @@
+use crate::{
+    app::{
+        gl_trace,
+        window_config::{ContextApi, GlProfile, WindowConfig},
+    },
+    asset::{AssetManager, AssetResolver},
+    capabilities::{Capabilities, Features},
+    debug_output,
+    framebuffer::RenderTarget,
+    gl,
+    input::{InputMap, QUIT, SCREENSHOT},
+    recording::FrameRecorder,
+    screenshot::{save_screenshot, save_timestamped_screenshot},
+    state_cache,
+    texture::AsyncTextureLoader,
+    time::{Clock, TimeSource},
+};
+use glad::LoadReport;
+use glfw::Context;
+use std::sync::mpsc::Receiver;
+
+// frame rate written into recordings that follow the wall clock
+const DEFAULT_RECORD_FPS: u32 = 60;
+
+// everything a tutorial needs to talk to the window and OpenGL
+pub struct Window {
+    pub source: glfw::Window,
+    pub gl: gl::Gl,
+    pub glfw: glfw::Glfw,
+    pub assets: AssetResolver,
+    // shared handles to shaders, textures and meshes, loading the same file
+    // twice hands out the first one again
+    pub asset_manager: AssetManager,
+    // call `load`/`load_into` freely, decoded images are uploaded before each `update`
+    pub textures: AsyncTextureLoader,
+    // size in pixels, differs from the window size on HiDPI displays
+    pub framebuffer_size: (i32, i32),
+    // ticked before each frame's updates, pause or scale time through it
+    pub clock: Clock,
+    // updated before each frame's updates, bind the app's own actions in `init`
+    pub input: InputMap,
+    // what the window was created with, e.g. `config.clear_color`
+    pub config: WindowConfig,
+    // what the context turned out to be, check limits and extensions here
+    pub capabilities: Capabilities,
+    // which GL functions the driver had, see `gl.is_loaded` for single ones
+    pub load_report: LoadReport,
+    // what gets drawn to instead of the window when running headless
+    pub render_target: Option<RenderTarget>,
+}
+
+impl Window {
+    // width / height of the framebuffer, for perspective projections
+    pub fn aspect_ratio(&self) -> f32 {
+        let (width, height) = self.framebuffer_size;
+        width as f32 / height.max(1) as f32
+    }
+
+    // the window's framebuffer, or the offscreen target when headless
+    // call this after drawing into a framebuffer of your own
+    pub fn bind_default_framebuffer(&mut self) {
+        let (width, height) = self.framebuffer_size;
+        match &self.render_target {
+            Some(target) => target.bind(&mut self.gl),
+            None => RenderTarget::unbind(&mut self.gl, width, height),
+        }
+    }
+}
+
+// What's drawn in the window. `run` owns the window, GL context and render loop
+// and calls these every frame: fixed_update and update, then render, on_event
+// for each window event, and shutdown once the scene is done.
+// The `quit` action (Escape) closes the window and `screenshot` (F12) saves the
+// frame for every scene.
+pub trait Scene {
+    // advance the simulation by `dt` seconds and poll input
+    fn update(&mut self, _window: &mut Window, _dt: f32) {}
+
+    // called `clock.fixed_steps()` times before `update` when the config has a
+    // fixed timestep, `dt` is always that step
+    // render with `window.clock.alpha()` to interpolate between the last two steps
+    fn fixed_update(&mut self, _window: &mut Window, _dt: f32) {}
+
+    fn render(&mut self, window: &mut Window);
+
+    fn on_event(&mut self, _window: &mut Window, _event: &glfw::WindowEvent) {}
+
+    // the framebuffer changed size, the viewport already covers it
+    // recreate anything drawn at the old size here, e.g. `RenderTarget::resize`
+    fn on_resize(&mut self, _window: &mut Window, _width: i32, _height: i32) {}
+
+    // de-allocate all GL resources, the window may carry on with another scene
+    fn shutdown(&mut self, _window: &mut Window) {}
+}
+
+// A tutorial chapter, a scene that can create itself in a fresh window.
+pub trait App: Scene {
+    // create GL resources, the context is current and the viewport is set
+    fn init(window: &mut Window) -> Self
+    where
+        Self: Sized;
+}
+
+pub fn run<A: App>(config: WindowConfig) {
+    run_with(config, A::init);
+}
+
+// like `run`, for scenes that need more than the window to be created, e.g. a
+// `SceneSwitcher`
+pub fn run_with<S: Scene, F: FnOnce(&mut Window) -> S>(config: WindowConfig, init: F) {
+    // anything logged goes to stderr, GL driver messages included
+    // RUST_LOG overrides the levels, e.g. RUST_LOG=gl=warn
+    let _ = env_logger::Builder::from_env(
+        env_logger::Env::default().default_filter_or("warn,gl=trace"),
+    )
+    .try_init();
+
+    // glfw: initialize and configure
+    // ------------------------------
+    // errors are logged, a window that can't be created panics below instead
+    // of inside glfw's callback
+    let mut glfw = glfw::init(glfw::LOG_ERRORS).unwrap();
+    let (major, minor) = config.gl_version;
+    glfw.window_hint(glfw::WindowHint::ContextVersion(major, minor));
+    glfw.window_hint(glfw::WindowHint::OpenGlProfile(match config.gl_profile {
+        GlProfile::Core => glfw::OpenGlProfileHint::Core,
+        GlProfile::Compat => glfw::OpenGlProfileHint::Compat,
+        GlProfile::Any => glfw::OpenGlProfileHint::Any,
+    }));
+    #[cfg(target_os = "macos")]
+    glfw.window_hint(glfw::WindowHint::OpenGlForwardCompat(
+        config.gl_profile == GlProfile::Core,
+    ));
+    glfw.window_hint(glfw::WindowHint::OpenGlDebugContext(config.debug));
+    glfw.window_hint(glfw::WindowHint::Samples(
+        (config.samples > 0).then_some(config.samples),
+    ));
+    glfw.window_hint(glfw::WindowHint::SRgbCapable(config.srgb));
+    glfw.window_hint(glfw::WindowHint::Visible(!config.headless));
+    glfw.window_hint(glfw::WindowHint::ContextCreationApi(
+        match config.context_api {
+            ContextApi::Native => glfw::ContextCreationApi::Native,
+            ContextApi::Egl => glfw::ContextCreationApi::Egl,
+            ContextApi::OsMesa => glfw::ContextCreationApi::OsMesa,
+        },
+    ));
+
+    // glfw window creation
+    // --------------------
+    let (mut source, events) = create_window(&mut glfw, &config);
+
+    source.make_current();
+    source.set_key_polling(true);
+    source.set_mouse_button_polling(true);
+    source.set_cursor_pos_polling(true);
+    source.set_scroll_polling(true);
+    source.set_framebuffer_size_polling(true);
+
+    glfw.set_swap_interval(match config.swap_interval {
+        interval if interval < 0 => glfw::SwapInterval::Adaptive,
+        0 => glfw::SwapInterval::None,
+        interval => glfw::SwapInterval::Sync(interval as u32),
+    });
+
+    // glad: load all OpenGL function pointers
+    // ---------------------------------------
+    let (mut gl, load_report) =
+        gl::load(|symbol| glfw.get_proc_address_raw(symbol) as *const std::os::raw::c_void);
+    // from the first call on, so a replay sets up the same context state
+    if let Some(path) = &config.trace {
+        gl_trace::start(path);
+    }
+    // some context creators leave an error behind (e.g. headless contexts
+    // without a default framebuffer), don't blame the first real call for it
+    unsafe { while gl.GetError() != gl::NO_ERROR {} }
+    if config.samples > 0 {
+        state_cache::enable(&mut gl, gl::MULTISAMPLE);
+    }
+    if config.srgb {
+        state_cache::enable(&mut gl, gl::FRAMEBUFFER_SRGB);
+    }
+    let capabilities = Capabilities::query(&mut gl);
+    // the bindings may be newer than the context, only what it should have counts
+    let load_report = load_report.for_context(capabilities.gl_version);
+    if !load_report.is_complete() {
+        // calling one of them panics, but many tutorials never will
+        log::warn!("{}", load_report);
+    }
+    debug_output::detect(&capabilities);
+    log::info!(
+        "GL {}.{} features: {}",
+        capabilities.gl_version.0,
+        capabilities.gl_version.1,
+        Features::detect(&capabilities)
+    );
+    if config.debug && !debug_output::install(&mut gl, config.debug_filter.clone()) {
+        log::warn!("no debug messages, the context has no KHR_debug or isn't a debug context");
+    }
+
+    // headless contexts may not have a default framebuffer at all, so
+    // everything is drawn into an offscreen one of the configured size
+    let render_target = config.headless.then(|| {
+        let (width, height) = (config.width as i32, config.height as i32);
+        let target = RenderTarget::new(&mut gl, width, height);
+        target.bind(&mut gl);
+        target
+    });
+
+    // the framebuffer can be larger than the window on HiDPI displays
+    let framebuffer_size = match &render_target {
+        Some(target) => (target.width, target.height),
+        None => source.get_framebuffer_size(),
+    };
+    set_viewport_size(&mut gl, framebuffer_size.0, framebuffer_size.1);
+    gl_trace::default_framebuffer(
+        render_target.as_ref().map_or(0, |target| target.fbo),
+        framebuffer_size.0,
+        framebuffer_size.1,
+    );
+
+    let assets = config
+        .asset_roots
+        .iter()
+        .fold(AssetResolver::default(), |assets, root| {
+            assets.with_root(root.clone())
+        });
+
+    let mut input = InputMap::default();
+    if let Some(path) = &config.input_config {
+        let loaded = InputMap::load(&assets, path)
+            .unwrap_or_else(|err| panic!("failed to load {}: {}", path.display(), err));
+        input.merge(loaded.config);
+    }
+
+    // establish an owner for these objects
+    let mut window = Window {
+        source,
+        gl,
+        glfw,
+        textures: AsyncTextureLoader::new(assets.clone()),
+        asset_manager: AssetManager::new(assets.clone()),
+        assets,
+        framebuffer_size,
+        clock: match config.fixed_timestep {
+            Some(step) => Clock::new().with_fixed_timestep(step),
+            None => Clock::new(),
+        }
+        .with_source(time_source(&config)),
+        input,
+        config,
+        capabilities,
+        load_report,
+        render_target,
+    };
+
+    let mut app = init(&mut window);
+    if window.config.headless {
+        // headless runs produce images, don't let them show placeholder textures
+        window
+            .textures
+            .finish(&mut window.gl)
+            .expect("failed to load texture");
+    }
+
+    let mut recorder = window.config.record.clone().map(|path| {
+        let (width, height) = window.framebuffer_size;
+        let fps = window.config.record_fps.unwrap_or(DEFAULT_RECORD_FPS);
+        FrameRecorder::new(&mut window.gl, &path, width, height, fps)
+            .unwrap_or_else(|err| panic!("failed to start recording: {}", err))
+    });
+
+    // render loop
+    // -----------
+    while !window.source.should_close() {
+        let dt = window.clock.advance();
+
+        // input
+        // -----
+        window.input.update(&window.source, &window.glfw);
+        if window.input.pressed(QUIT) {
+            window.source.set_should_close(true);
+        }
+
+        // upload textures that finished decoding in the background
+        window
+            .textures
+            .poll(&mut window.gl)
+            .expect("failed to load texture");
+
+        if let Some(step) = window.clock.fixed_timestep() {
+            for _ in 0..window.clock.fixed_steps() {
+                app.fixed_update(&mut window, step);
+            }
+        }
+        app.update(&mut window, dt);
+
+        // render
+        // ------
+        window.bind_default_framebuffer();
+        app.render(&mut window);
+
+        if let Some(recorder) = &mut recorder {
+            let framebuffer = window.render_target.as_ref().map_or(0, |target| target.fbo);
+            let (width, height) = window.framebuffer_size;
+            recorder.capture(&mut window.gl, framebuffer, width, height);
+        }
+
+        let last_frame = window.config.frames == Some(window.clock.frame());
+        if last_frame {
+            window.source.set_should_close(true);
+            if let Some(path) = window.config.output.clone() {
+                save_screenshot(&mut window, &path)
+                    .unwrap_or_else(|err| panic!("failed to write {}: {}", path.display(), err));
+            }
+        }
+        if window.input.pressed(SCREENSHOT) {
+            let dir = window.config.screenshot_dir.clone();
+            match save_timestamped_screenshot(&mut window, dir) {
+                Ok(path) => log::info!("saved {}", path.display()),
+                Err(err) => log::error!("failed to save screenshot: {}", err),
+            }
+        }
+
+        // glfw: swap buffers and poll IO events (keys pressed/released, mouse moved etc.)
+        if window.render_target.is_none() {
+            window.source.swap_buffers();
+        }
+        gl_trace::frame();
+        // textures still streaming in are safe to drop, the loader skips them
+        window.asset_manager.collect_garbage(&mut window.gl);
+        window.glfw.poll_events();
+        for (_, event) in glfw::flush_messages(&events) {
+            if let (glfw::WindowEvent::FramebufferSize(width, height), None) =
+                (&event, &window.render_target)
+            {
+                let (width, height) = (*width, *height);
+                window.framebuffer_size = (width, height);
+                set_viewport_size(&mut window.gl, width, height);
+                gl_trace::default_framebuffer(0, width, height);
+                app.on_resize(&mut window, width, height);
+            }
+            window.input.handle_event(&event);
+            app.on_event(&mut window, &event);
+        }
+    }
+
+    if let Some(recorder) = recorder {
+        let path = window.config.record.clone().unwrap_or_default();
+        match recorder.finish(&mut window.gl) {
+            Ok(frames) => log::info!("recorded {} frames to {}", frames, path.display()),
+            Err(err) => log::error!("recording failed: {}", err),
+        }
+    }
+
+    app.shutdown(&mut window);
+    // whatever the app still held a handle to
+    drop(app);
+    window
+        .textures
+        .finish(&mut window.gl)
+        .expect("failed to load texture");
+    window.asset_manager.collect_garbage(&mut window.gl);
+    log::info!("{}", state_cache::stats(&window.gl));
+
+    if let Some(path) = &window.config.trace {
+        match gl_trace::stop() {
+            Ok(calls) => log::info!("traced {} calls to {}", calls, path.display()),
+            Err(err) => log::error!("trace failed: {}", err),
+        }
+    }
+
+    // glfw: terminate, clearing all previously allocated GLFW resources.
+    // ------------------------------------------------------------------
+    // dropped when out of scope
+}
+
+// a recording at a fixed frame rate moves time on by exactly one frame per
+// frame, however long rendering it took
+fn time_source(config: &WindowConfig) -> TimeSource {
+    match (config.record_fps, &config.time_source) {
+        (Some(fps), TimeSource::Real) if config.record.is_some() => TimeSource::Fixed {
+            start: 0.0,
+            step: 1.0 / fps as f64,
+        },
+        (_, source) => source.clone(),
+    }
+}
+
+// windowed, or fullscreen on the configured monitor at its current video mode
+fn create_window(
+    glfw: &mut glfw::Glfw,
+    config: &WindowConfig,
+) -> (glfw::Window, Receiver<(f64, glfw::WindowEvent)>) {
+    let window = if config.fullscreen {
+        glfw.with_connected_monitors(|glfw, monitors| {
+            // glfw lists the primary monitor first
+            let monitor = monitors
+                .get(config.monitor.unwrap_or(0))
+                .expect("no such monitor");
+            let (width, height) = monitor
+                .get_video_mode()
+                .map_or((config.width, config.height), |mode| {
+                    (mode.width, mode.height)
+                });
+            glfw.create_window(
+                width,
+                height,
+                &config.title,
+                glfw::WindowMode::FullScreen(monitor),
+            )
+        })
+    } else {
+        glfw.create_window(
+            config.width,
+            config.height,
+            &config.title,
+            glfw::WindowMode::Windowed,
+        )
+    };
+    window.expect("failed to create GLFW window, see the GLFW error above")
+}
+
+// glfw: whenever the window size changed (by OS or user resize) this callback function executes
+// ---------------------------------------------------------------------------------------------
+fn set_viewport_size(gl: &mut gl::Gl, width: i32, height: i32) {
+    state_cache::viewport(gl, 0, 0, width, height);
+}
@@ -0,0 +1,42 @@
+// `--trace`, glad's call tracing when common is built with the gl-trace feature
+// Without it there's nothing to record with, the functions do nothing and a
+// trace that was asked for anyway stops the program.
+
+use crate::gl::types::GLuint;
+use std::{io, path::Path};
+
+#[cfg(feature = "gl-trace")]
+pub fn start(path: &Path) {
+    glad::trace::start(path)
+        .unwrap_or_else(|err| panic!("failed to start trace {}: {}", path.display(), err));
+}
+
+#[cfg(not(feature = "gl-trace"))]
+pub fn start(path: &Path) {
+    panic!(
+        "can't write {}, --trace needs the gl-trace feature",
+        path.display()
+    );
+}
+
+// what the scene draws to as the window, called again after every resize
+pub fn default_framebuffer(_framebuffer: GLuint, _width: i32, _height: i32) {
+    #[cfg(feature = "gl-trace")]
+    glad::trace::default_framebuffer(_framebuffer, _width, _height);
+}
+
+pub fn frame() {
+    #[cfg(feature = "gl-trace")]
+    glad::trace::frame();
+}
+
+// how many calls were traced
+#[cfg(feature = "gl-trace")]
+pub fn stop() -> io::Result<usize> {
+    glad::trace::stop()
+}
+
+#[cfg(not(feature = "gl-trace"))]
+pub fn stop() -> io::Result<usize> {
+    Ok(0)
+}
@@ -0,0 +1,9 @@
+#[allow(clippy::module_inception)]
+pub mod app;
+mod gl_trace;
+pub mod scene_switcher;
+pub mod window_config;
+
+pub use app::*;
+pub use scene_switcher::*;
+pub use window_config::*;
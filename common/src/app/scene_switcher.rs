@@ -0,0 +1,145 @@
+use crate::{
+    app::app::{App, Scene, Window},
+    chapter::Chapter,
+    gl, state_cache,
+};
+use glfw::Key;
+
+// actions for cycling through the chapters, bound to Page Down/Page Up unless
+// the input config already has them
+pub const NEXT_SCENE: &str = "next_scene";
+pub const PREVIOUS_SCENE: &str = "previous_scene";
+
+// texture units the switcher unbinds between scenes
+const TEXTURE_UNITS: u32 = 16;
+
+// create chapter `A` inside a running window, for `Chapter::scene`
+pub fn boxed_scene<A: App + 'static>(window: &mut Window) -> Box<dyn Scene> {
+    Box::new(A::init(window))
+}
+
+// Runs one chapter at a time in a single window, the next/previous scene
+// actions shut the current one down and create its neighbour.
+//   app::run_with(config, |window| SceneSwitcher::new(window, CHAPTERS, 0));
+pub struct SceneSwitcher {
+    chapters: &'static [Chapter],
+    current: usize,
+    scene: Box<dyn Scene>,
+}
+
+impl SceneSwitcher {
+    // start with `chapters[start]`
+    pub fn new(window: &mut Window, chapters: &'static [Chapter], start: usize) -> Self {
+        assert!(!chapters.is_empty(), "no chapters to switch between");
+        if !window.input.config.actions.contains_key(NEXT_SCENE) {
+            window.input.bind(NEXT_SCENE, Key::PageDown);
+        }
+        if !window.input.config.actions.contains_key(PREVIOUS_SCENE) {
+            window.input.bind(PREVIOUS_SCENE, Key::PageUp);
+        }
+
+        let current = start.min(chapters.len() - 1);
+        let scene = create(window, &chapters[current], current, chapters.len());
+        SceneSwitcher {
+            chapters,
+            current,
+            scene,
+        }
+    }
+
+    pub fn current(&self) -> &'static Chapter {
+        &self.chapters[self.current]
+    }
+
+    // shut the running chapter down and start `chapters[index]`
+    pub fn switch_to(&mut self, window: &mut Window, index: usize) {
+        let index = index.min(self.chapters.len() - 1);
+        // queued images go into the outgoing scene's textures, upload them while
+        // those still exist rather than into deleted or reused names later
+        window
+            .textures
+            .finish(&mut window.gl)
+            .expect("failed to load texture");
+        self.scene.shutdown(window);
+        reset_state(window);
+        self.current = index;
+        // the outgoing scene is dropped once its successor exists, textures
+        // both load through `asset_manager` are handed over instead of reloaded
+        self.scene = create(window, &self.chapters[index], index, self.chapters.len());
+    }
+
+    // wraps around after the last chapter
+    pub fn next(&mut self, window: &mut Window) {
+        self.switch_to(window, (self.current + 1) % self.chapters.len());
+    }
+
+    pub fn previous(&mut self, window: &mut Window) {
+        let count = self.chapters.len();
+        self.switch_to(window, (self.current + count - 1) % count);
+    }
+}
+
+impl Scene for SceneSwitcher {
+    fn update(&mut self, window: &mut Window, dt: f32) {
+        if window.input.pressed(NEXT_SCENE) {
+            self.next(window);
+        } else if window.input.pressed(PREVIOUS_SCENE) {
+            self.previous(window);
+        }
+        self.scene.update(window, dt);
+    }
+
+    fn fixed_update(&mut self, window: &mut Window, dt: f32) {
+        self.scene.fixed_update(window, dt);
+    }
+
+    fn render(&mut self, window: &mut Window) {
+        self.scene.render(window);
+    }
+
+    fn on_event(&mut self, window: &mut Window, event: &glfw::WindowEvent) {
+        self.scene.on_event(window, event);
+    }
+
+    fn on_resize(&mut self, window: &mut Window, width: i32, height: i32) {
+        self.scene.on_resize(window, width, height);
+    }
+
+    fn shutdown(&mut self, window: &mut Window) {
+        self.scene.shutdown(window);
+    }
+}
+
+fn create(window: &mut Window, chapter: &Chapter, index: usize, count: usize) -> Box<dyn Scene> {
+    window.source.set_title(&format!(
+        "{} ({}/{}) - Page Up/Page Down to switch",
+        chapter.title,
+        index + 1,
+        count
+    ));
+    window.bind_default_framebuffer();
+    let scene = (chapter.scene)(window);
+    if window.config.headless {
+        // same as `run`, headless frames shouldn't show placeholder textures
+        window
+            .textures
+            .finish(&mut window.gl)
+            .expect("failed to load texture");
+    }
+    scene
+}
+
+// chapters assume a fresh context, put back what they commonly change
+fn reset_state(window: &mut Window) {
+    let gl = &mut window.gl;
+    state_cache::use_program(gl, 0);
+    state_cache::bind_vertex_array(gl, 0);
+    state_cache::bind_buffer(gl, gl::ARRAY_BUFFER, 0);
+    for unit in (0..TEXTURE_UNITS).rev() {
+        state_cache::active_texture(gl, gl::TEXTURE0 + unit);
+        state_cache::bind_texture(gl, gl::TEXTURE_2D, 0);
+    }
+    unsafe { gl.PolygonMode(gl::FRONT_AND_BACK, gl::FILL) };
+    state_cache::disable(gl, gl::DEPTH_TEST);
+    state_cache::disable(gl, gl::BLEND);
+}
@@ -0,0 +1,503 @@
+use crate::{
+    debug_output::{DebugFilter, Severity},
+    time::TimeSource,
+};
+use serde::{Deserialize, Serialize};
+use std::{env, error::Error, fmt, fs, io, path::PathBuf, process};
+
+pub const USAGE: &str = "\
+options:
+  --config <file>        read settings from a TOML file, flags after it override it
+  --title <text>         window title
+  --size <width>x<height>
+  --width <pixels>
+  --height <pixels>
+  --fullscreen           use the monitor's current video mode
+  --monitor <index>      monitor for --fullscreen, 0 is the primary monitor
+  --vsync                same as --swap-interval 1
+  --no-vsync             same as --swap-interval 0
+  --swap-interval <n>    wait for n screen refreshes per frame, -1 for adaptive vsync
+  --samples <n>          MSAA samples, 0 turns multisampling off
+  --gl <major>.<minor>   OpenGL version to request
+  --profile <core|compat|any>
+  --debug                request a debug context and log the driver's messages
+  --debug-severity <notification|low|medium|high>
+                         least severe message to log, implies --debug
+  --debug-ignore <id>,<id>,...
+                         message IDs not to log, implies --debug
+  --debug-backtrace      log a backtrace with high severity messages, implies --debug
+  --srgb                 sRGB framebuffer
+  --clear-color <r>,<g>,<b>[,<a>]
+  --headless             no visible window, render into an offscreen framebuffer
+  --context-api <native|egl|osmesa>
+                         library creating the context, osmesa runs without a display
+  --frames <n>           close after n frames
+  --output <file.png>    save the last frame before closing
+  --time <seconds>       start at this time and keep it, for reproducible frames
+  --time-step <seconds>  advance time by exactly this much per frame
+  --timestamps <t0>,<t1>,...
+                         the time of each frame, the last one repeats
+  --record <path>        record every frame, into a .y4m file or a directory of PNGs
+  --record-fps <n>       frame rate of the recording, time then advances by exactly
+                         1/n seconds per frame instead of following the wall clock
+  --screenshot-dir <dir> where the screenshot key (F12) saves frames
+  --trace <file>         record every GL call, needs the gl-trace feature
+  --help                 print this and exit";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GlProfile {
+    Core,
+    Compat,
+    // needed for versions before 3.2
+    Any,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ContextApi {
+    // GLX, WGL or NSGL, needs a display
+    Native,
+    Egl,
+    // Mesa's software renderer, glfw has to be built for it
+    OsMesa,
+}
+
+// how the window and its GL context are created
+// every field can be set from a TOML file or the command line, see `from_args`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WindowConfig {
+    pub title: String,
+    pub width: u32,
+    pub height: u32,
+    // fullscreen windows take the size of the monitor's current video mode
+    pub fullscreen: bool,
+    // index into the connected monitors, None for the primary one
+    pub monitor: Option<usize>,
+    // screen refreshes to wait for on each swap, 0 is no vsync, -1 is adaptive
+    pub swap_interval: i32,
+    // MSAA samples, 0 for none
+    pub samples: u32,
+    // requested context version
+    pub gl_version: (u32, u32),
+    pub gl_profile: GlProfile,
+    // log KHR_debug messages, see `debug_output::install`
+    pub debug: bool,
+    pub debug_filter: DebugFilter,
+    pub srgb: bool,
+    // what tutorials clear the screen to
+    pub clear_color: [f32; 4],
+    // searched for shaders and textures after the default asset roots
+    pub asset_roots: Vec<PathBuf>,
+    // seconds per `App::fixed_update`, None to only use `App::update`
+    pub fixed_timestep: Option<f32>,
+    // TOML file with input bindings, added to the default ones, see `InputConfig`
+    pub input_config: Option<PathBuf>,
+    // hide the window and draw into an offscreen `RenderTarget` of `width` x `height`
+    pub headless: bool,
+    pub context_api: ContextApi,
+    // close after this many frames
+    pub frames: Option<u64>,
+    // PNG the last frame is written to
+    pub output: Option<PathBuf>,
+    // where `Window::clock` gets each frame's time from
+    pub time_source: TimeSource,
+    // `.y4m` file or PNG directory every frame is recorded to, see `FrameRecorder`
+    pub record: Option<PathBuf>,
+    // frames per second of the recording, with the real time source this also
+    // makes each frame advance time by exactly 1 / fps
+    pub record_fps: Option<u32>,
+    // where the screenshot action saves frames
+    pub screenshot_dir: PathBuf,
+    // file every GL call is recorded to, for gl_replay and gl_trace_dump
+    pub trace: Option<PathBuf>,
+}
+
+impl Default for WindowConfig {
+    fn default() -> Self {
+        WindowConfig {
+            title: String::from("Learn OpenGL"),
+            width: 800,
+            height: 600,
+            fullscreen: false,
+            monitor: None,
+            swap_interval: 1,
+            samples: 0,
+            gl_version: (3, 3),
+            gl_profile: GlProfile::Core,
+            debug: false,
+            debug_filter: DebugFilter::default(),
+            srgb: false,
+            clear_color: [0.2, 0.3, 0.3, 1.0],
+            asset_roots: vec![],
+            fixed_timestep: None,
+            input_config: None,
+            headless: false,
+            context_api: ContextApi::Native,
+            frames: None,
+            output: None,
+            time_source: TimeSource::Real,
+            record: None,
+            record_fps: None,
+            screenshot_dir: PathBuf::from("."),
+            trace: None,
+        }
+    }
+}
+
+impl WindowConfig {
+    // the defaults overridden by this process' command line, exits with a usage
+    // message when the flags are invalid
+    pub fn from_args() -> Self {
+        WindowConfig::from_arg_list(env::args().skip(1))
+    }
+
+    // same as `from_args` for a binary that takes arguments of its own first
+    pub fn from_arg_list<I: IntoIterator<Item = String>>(args: I) -> Self {
+        match WindowConfig::default().parse_args(args) {
+            Ok(config) => config,
+            Err(ConfigError::Help) => {
+                println!("{}", USAGE);
+                process::exit(0);
+            }
+            Err(err) => {
+                eprintln!("{}\n\n{}", err, USAGE);
+                process::exit(2);
+            }
+        }
+    }
+
+    // apply `args` (without the program name) on top of this config, in order
+    pub fn parse_args<I: IntoIterator<Item = String>>(
+        mut self,
+        args: I,
+    ) -> Result<WindowConfig, ConfigError> {
+        let mut args = args.into_iter();
+        while let Some(flag) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| ConfigError::MissingValue(flag.clone()))
+            };
+            match flag.as_str() {
+                "--config" => {
+                    let config = WindowConfig::load(value()?)?;
+                    // a config file doesn't know where this binary keeps its assets
+                    let asset_roots = self.asset_roots;
+                    let input_config = self.input_config;
+                    self = config;
+                    self.asset_roots.splice(0..0, asset_roots);
+                    self.input_config = self.input_config.or(input_config);
+                }
+                "--title" => self.title = value()?,
+                "--size" => {
+                    let size = value()?;
+                    let (width, height) = size
+                        .split_once('x')
+                        .ok_or_else(|| ConfigError::invalid(&flag, &size))?;
+                    self.width = parse(&flag, width)?;
+                    self.height = parse(&flag, height)?;
+                }
+                "--width" => self.width = parse(&flag, &value()?)?,
+                "--height" => self.height = parse(&flag, &value()?)?,
+                "--fullscreen" => self.fullscreen = true,
+                "--monitor" => self.monitor = Some(parse(&flag, &value()?)?),
+                "--vsync" => self.swap_interval = 1,
+                "--no-vsync" => self.swap_interval = 0,
+                "--swap-interval" => self.swap_interval = parse(&flag, &value()?)?,
+                "--samples" => self.samples = parse(&flag, &value()?)?,
+                "--gl" => {
+                    let version = value()?;
+                    let (major, minor) = version
+                        .split_once('.')
+                        .ok_or_else(|| ConfigError::invalid(&flag, &version))?;
+                    self.gl_version = (parse(&flag, major)?, parse(&flag, minor)?);
+                }
+                "--profile" => {
+                    let profile = value()?;
+                    self.gl_profile = match profile.as_str() {
+                        "core" => GlProfile::Core,
+                        "compat" => GlProfile::Compat,
+                        "any" => GlProfile::Any,
+                        _ => return Err(ConfigError::invalid(&flag, &profile)),
+                    };
+                }
+                "--debug" => self.debug = true,
+                "--debug-severity" => {
+                    let severity = value()?;
+                    self.debug_filter.min_severity = match severity.as_str() {
+                        "notification" => Severity::Notification,
+                        "low" => Severity::Low,
+                        "medium" => Severity::Medium,
+                        "high" => Severity::High,
+                        _ => return Err(ConfigError::invalid(&flag, &severity)),
+                    };
+                    self.debug = true;
+                }
+                "--debug-ignore" => {
+                    let ids = value()?;
+                    self.debug_filter.ignore_ids = ids
+                        .split(',')
+                        .map(|id| parse(&flag, id))
+                        .collect::<Result<_, _>>()?;
+                    self.debug = true;
+                }
+                "--debug-backtrace" => {
+                    self.debug_filter.backtrace = true;
+                    self.debug = true;
+                }
+                "--srgb" => self.srgb = true,
+                "--clear-color" => {
+                    let color = value()?;
+                    let channels = color
+                        .split(',')
+                        .map(|c| parse(&flag, c))
+                        .collect::<Result<Vec<f32>, _>>()?;
+                    self.clear_color = match channels[..] {
+                        [r, g, b] => [r, g, b, 1.0],
+                        [r, g, b, a] => [r, g, b, a],
+                        _ => return Err(ConfigError::invalid(&flag, &color)),
+                    };
+                }
+                "--headless" => self.headless = true,
+                "--context-api" => {
+                    let api = value()?;
+                    self.context_api = match api.as_str() {
+                        "native" => ContextApi::Native,
+                        "egl" => ContextApi::Egl,
+                        "osmesa" => ContextApi::OsMesa,
+                        _ => return Err(ConfigError::invalid(&flag, &api)),
+                    };
+                }
+                "--frames" => self.frames = Some(parse(&flag, &value()?)?),
+                "--output" => self.output = Some(PathBuf::from(value()?)),
+                "--time" => {
+                    let start = parse(&flag, &value()?)?;
+                    self.time_source = match self.time_source {
+                        TimeSource::Fixed { step, .. } => TimeSource::Fixed { start, step },
+                        _ => TimeSource::Fixed { start, step: 0.0 },
+                    };
+                }
+                "--time-step" => {
+                    let step = parse(&flag, &value()?)?;
+                    self.time_source = match self.time_source {
+                        TimeSource::Fixed { start, .. } => TimeSource::Fixed { start, step },
+                        _ => TimeSource::Fixed { start: 0.0, step },
+                    };
+                }
+                "--timestamps" => {
+                    let times = value()?;
+                    self.time_source = TimeSource::Scripted(
+                        times
+                            .split(',')
+                            .map(|t| parse(&flag, t))
+                            .collect::<Result<_, _>>()?,
+                    );
+                }
+                "--record" => self.record = Some(PathBuf::from(value()?)),
+                "--record-fps" => {
+                    let fps = value()?;
+                    match parse(&flag, &fps)? {
+                        0 => return Err(ConfigError::invalid(&flag, &fps)),
+                        fps => self.record_fps = Some(fps),
+                    }
+                }
+                "--screenshot-dir" => self.screenshot_dir = PathBuf::from(value()?),
+                "--trace" => self.trace = Some(PathBuf::from(value()?)),
+                "--help" | "-h" => return Err(ConfigError::Help),
+                _ => return Err(ConfigError::UnknownFlag(flag)),
+            }
+        }
+        Ok(self)
+    }
+
+    // read a TOML file, missing fields keep their defaults
+    // Example:
+    //   width = 1280
+    //   height = 720
+    //   samples = 4
+    //   gl_version = [4, 6]
+    pub fn load<P: Into<PathBuf>>(path: P) -> Result<WindowConfig, ConfigError> {
+        let path = path.into();
+        let text = fs::read_to_string(&path).map_err(|err| ConfigError::Io(path.clone(), err))?;
+        toml::from_str(&text).map_err(|err| ConfigError::Parse(path, err))
+    }
+
+    // usually the package directory of the calling binary:
+    //   WindowConfig::from_args().with_asset_root(env!("CARGO_MANIFEST_DIR"))
+    pub fn with_asset_root<P: Into<PathBuf>>(mut self, root: P) -> Self {
+        self.asset_roots.push(root.into());
+        self
+    }
+
+    pub fn with_input_config<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.input_config = Some(path.into());
+        self
+    }
+
+    pub fn with_fixed_timestep(mut self, step: f32) -> Self {
+        self.fixed_timestep = Some(step);
+        self
+    }
+}
+
+fn parse<T: std::str::FromStr>(flag: &str, value: &str) -> Result<T, ConfigError> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| ConfigError::invalid(flag, value))
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    // --help was passed, not really an error
+    Help,
+    UnknownFlag(String),
+    MissingValue(String),
+    InvalidValue { flag: String, value: String },
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, toml::de::Error),
+}
+
+impl ConfigError {
+    fn invalid(flag: &str, value: &str) -> Self {
+        ConfigError::InvalidValue {
+            flag: flag.to_string(),
+            value: value.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Help => write!(f, "help requested"),
+            ConfigError::UnknownFlag(flag) => write!(f, "unknown option {}", flag),
+            ConfigError::MissingValue(flag) => write!(f, "{} needs a value", flag),
+            ConfigError::InvalidValue { flag, value } => {
+                write!(f, "invalid value for {}: {}", flag, value)
+            }
+            ConfigError::Io(path, err) => write!(f, "failed to read {}: {}", path.display(), err),
+            ConfigError::Parse(path, err) => {
+                write!(f, "invalid config {}: {}", path.display(), err)
+            }
+        }
+    }
+}
+
+impl Error for ConfigError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_args(args: &[&str]) -> Result<WindowConfig, ConfigError> {
+        WindowConfig::default().parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    fn error(args: &[&str]) -> String {
+        match parse_args(args) {
+            Ok(config) => panic!("{:?} parsed to {:?}", args, config),
+            Err(err) => err.to_string(),
+        }
+    }
+
+    #[test]
+    fn sizes_and_versions_are_split() {
+        let config = parse_args(&["--size", "1280x720", "--gl", "4.6"]).unwrap();
+        assert_eq!((config.width, config.height), (1280, 720));
+        assert_eq!(config.gl_version, (4, 6));
+
+        // later flags win
+        let config = parse_args(&["--size", "1280x720", "--width", "640"]).unwrap();
+        assert_eq!((config.width, config.height), (640, 720));
+    }
+
+    #[test]
+    fn time_and_time_step_combine_in_either_order() {
+        let fixed = TimeSource::Fixed {
+            start: 2.0,
+            step: 0.5,
+        };
+        let config = parse_args(&["--time", "2", "--time-step", "0.5"]).unwrap();
+        assert_eq!(config.time_source, fixed);
+        let config = parse_args(&["--time-step", "0.5", "--time", "2"]).unwrap();
+        assert_eq!(config.time_source, fixed);
+
+        let config = parse_args(&["--time", "2"]).unwrap();
+        assert_eq!(
+            config.time_source,
+            TimeSource::Fixed {
+                start: 2.0,
+                step: 0.0
+            }
+        );
+        let config = parse_args(&["--time-step", "0.5"]).unwrap();
+        assert_eq!(
+            config.time_source,
+            TimeSource::Fixed {
+                start: 0.0,
+                step: 0.5
+            }
+        );
+
+        // timestamps replace both
+        let config = parse_args(&["--time", "2", "--timestamps", "1,3"]).unwrap();
+        assert_eq!(config.time_source, TimeSource::Scripted(vec![1.0, 3.0]));
+    }
+
+    #[test]
+    fn flags_after_config_override_it() {
+        let dir = std::env::temp_dir().join(format!("common-window-config-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("window.toml");
+        fs::write(
+            &path,
+            "width = 1280\nheight = 720\nsamples = 4\ngl_version = [4, 6]\n",
+        )
+        .unwrap();
+        let path = path.to_str().unwrap();
+
+        let config = parse_args(&["--samples", "8", "--config", path, "--height", "100"]).unwrap();
+        // the file replaces flags before it, flags after it win
+        assert_eq!(config.samples, 4);
+        assert_eq!((config.width, config.height), (1280, 100));
+        assert_eq!(config.gl_version, (4, 6));
+        // and fields it doesn't set are defaults
+        assert_eq!(config.title, WindowConfig::default().title);
+
+        // the binary's asset roots and input config are kept
+        let config = WindowConfig::default()
+            .with_asset_root("assets")
+            .with_input_config("input.toml")
+            .parse_args(["--config".to_string(), path.to_string()])
+            .unwrap();
+        assert_eq!(config.asset_roots, [PathBuf::from("assets")]);
+        assert_eq!(config.input_config, Some(PathBuf::from("input.toml")));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn bad_values_name_the_flag() {
+        assert_eq!(error(&["--size", "1280"]), "invalid value for --size: 1280");
+        assert_eq!(error(&["--size", "wx720"]), "invalid value for --size: w");
+        assert_eq!(error(&["--gl", "4"]), "invalid value for --gl: 4");
+        assert_eq!(
+            error(&["--profile", "es"]),
+            "invalid value for --profile: es"
+        );
+        assert_eq!(
+            error(&["--clear-color", "1,0"]),
+            "invalid value for --clear-color: 1,0"
+        );
+        assert_eq!(
+            error(&["--record-fps", "0"]),
+            "invalid value for --record-fps: 0"
+        );
+        assert_eq!(error(&["--time"]), "--time needs a value");
+        assert_eq!(error(&["--fast"]), "unknown option --fast");
+        assert!(error(&["--config", "/nonexistent/window.toml"])
+            .starts_with("failed to read /nonexistent/window.toml: "));
+        assert!(matches!(parse_args(&["--help"]), Err(ConfigError::Help)));
+    }
+}
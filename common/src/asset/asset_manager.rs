@@ -0,0 +1,184 @@
+use crate::{
+    asset::{
+        asset_resolver::{AssetError, AssetResolver},
+        handle::{Asset, Garbage, GlResource, Handle},
+    },
+    gl,
+    mesh::Mesh,
+    shader::{Shader, ShaderBuilder},
+    texture::{AsyncTextureLoader, Texture, TextureBuilder, TextureParams},
+};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fs,
+    hash::Hash,
+    path::{Path, PathBuf},
+    rc::{Rc, Weak},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct ShaderKey {
+    vertex: PathBuf,
+    fragment: PathBuf,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct TextureKey {
+    path: PathBuf,
+    params: TextureParams,
+}
+
+// Loads shaders, textures and meshes once and hands out shared handles.
+// Requests are de-duplicated by canonical file path plus load parameters,
+// so asking twice for `resources/container.jpg` decodes and uploads it once.
+//
+// A file the resolver can't find is an `AssetError`, with every path it
+// tried. Dropping the last handle queues its GL objects for deletion, call
+// `collect_garbage` once per frame (or before exit) to delete them on the GL thread.
+pub struct AssetManager {
+    assets: AssetResolver,
+    shaders: HashMap<ShaderKey, Weak<Asset<Shader>>>,
+    textures: HashMap<TextureKey, Weak<Asset<Texture>>>,
+    meshes: HashMap<String, Weak<Asset<Mesh>>>,
+    garbage: Garbage,
+}
+
+impl AssetManager {
+    pub fn new(assets: AssetResolver) -> Self {
+        AssetManager {
+            assets,
+            shaders: HashMap::new(),
+            textures: HashMap::new(),
+            meshes: HashMap::new(),
+            garbage: Rc::new(RefCell::new(vec![])),
+        }
+    }
+
+    pub fn assets(&self) -> &AssetResolver {
+        &self.assets
+    }
+
+    pub fn shader<P: AsRef<Path>>(
+        &mut self,
+        gl: &mut gl::Gl,
+        vertex_path: P,
+        fragment_path: P,
+    ) -> Result<Handle<Shader>, AssetError> {
+        let key = ShaderKey {
+            vertex: self.canonical_path(vertex_path)?,
+            fragment: self.canonical_path(fragment_path)?,
+        };
+        let assets = self.assets.clone();
+        Ok(get_or_insert(
+            &mut self.shaders,
+            &self.garbage,
+            key.clone(),
+            || ShaderBuilder::new(assets).build(gl, &key.vertex, &key.fragment),
+        ))
+    }
+
+    pub fn texture<P: AsRef<Path>>(
+        &mut self,
+        gl: &mut gl::Gl,
+        path: P,
+        params: TextureParams,
+    ) -> Result<Handle<Texture>, AssetError> {
+        let key = TextureKey {
+            path: self.canonical_path(path)?,
+            params,
+        };
+        let builder = TextureBuilder::new(self.assets.clone()).params(params);
+        Ok(get_or_insert(
+            &mut self.textures,
+            &self.garbage,
+            key.clone(),
+            || builder.build(gl, &key.path),
+        ))
+    }
+
+    // like `texture`, but the image is decoded on `loader`'s workers and a
+    // placeholder is shown until `loader.poll` uploads it
+    pub fn texture_async<P: AsRef<Path>>(
+        &mut self,
+        gl: &mut gl::Gl,
+        loader: &mut AsyncTextureLoader,
+        path: P,
+        params: TextureParams,
+    ) -> Result<Handle<Texture>, AssetError> {
+        let key = TextureKey {
+            path: self.canonical_path(path)?,
+            params,
+        };
+        Ok(get_or_insert(
+            &mut self.textures,
+            &self.garbage,
+            key.clone(),
+            || loader.load(gl, &key.path, params),
+        ))
+    }
+
+    // meshes are usually generated rather than loaded, so they are keyed by name
+    // `create` only runs when no live mesh with that name exists
+    pub fn mesh<F>(&mut self, gl: &mut gl::Gl, name: &str, create: F) -> Handle<Mesh>
+    where
+        F: FnOnce(&mut gl::Gl) -> Mesh,
+    {
+        get_or_insert(&mut self.meshes, &self.garbage, name.to_string(), || {
+            create(gl)
+        })
+    }
+
+    // number of assets that still have at least one handle
+    pub fn len(&self) -> usize {
+        live(&self.shaders) + live(&self.textures) + live(&self.meshes)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // delete the GL objects of every asset whose last handle has been dropped
+    // returns the number of GL objects deleted
+    pub fn collect_garbage(&mut self, gl: &mut gl::Gl) -> usize {
+        self.shaders.retain(|_, asset| asset.strong_count() > 0);
+        self.textures.retain(|_, asset| asset.strong_count() > 0);
+        self.meshes.retain(|_, asset| asset.strong_count() > 0);
+
+        let objects: Vec<_> = self.garbage.borrow_mut().drain(..).collect();
+        let count = objects.len();
+        // deleting through `gl` also makes the state cache forget the names
+        for object in objects {
+            object.delete(gl);
+        }
+        count
+    }
+
+    fn canonical_path<P: AsRef<Path>>(&self, path: P) -> Result<PathBuf, AssetError> {
+        let path = self.assets.resolve(path)?;
+        Ok(fs::canonicalize(&path).unwrap_or(path))
+    }
+}
+
+fn get_or_insert<K, T, F>(
+    cache: &mut HashMap<K, Weak<Asset<T>>>,
+    garbage: &Garbage,
+    key: K,
+    load: F,
+) -> Handle<T>
+where
+    K: Eq + Hash,
+    T: GlResource,
+    F: FnOnce() -> T,
+{
+    if let Some(asset) = cache.get(&key).and_then(Weak::upgrade) {
+        return Handle { asset };
+    }
+    let asset = Rc::new(Asset::new(load(), Rc::clone(garbage)));
+    cache.insert(key, Rc::downgrade(&asset));
+    Handle { asset }
+}
+
+fn live<K, T: GlResource>(cache: &HashMap<K, Weak<Asset<T>>>) -> usize {
+    cache.values().filter(|a| a.strong_count() > 0).count()
+}
@@ -0,0 +1,130 @@
+use std::{
+    env,
+    error::Error,
+    fmt, io,
+    path::{Path, PathBuf},
+};
+
+// colon (or semicolon on windows) separated list of extra directories to search for assets
+pub const ASSET_PATH_ENV: &str = "LEARN_OPENGL_ASSETS";
+
+// Finds asset files (shaders, images) without depending on the working directory.
+// Relative paths are tried against each root in order, the first existing file wins:
+//   1. every directory listed in LEARN_OPENGL_ASSETS
+//   2. the directory containing the running executable
+//   3. CARGO_MANIFEST_DIR, set by `cargo run`
+//   4. any root added with `with_root`
+//   5. the current working directory
+#[derive(Clone, Debug)]
+pub struct AssetResolver {
+    roots: Vec<PathBuf>,
+    search_cwd: bool,
+}
+
+impl Default for AssetResolver {
+    fn default() -> Self {
+        let mut roots = vec![];
+
+        if let Some(paths) = env::var_os(ASSET_PATH_ENV) {
+            roots.extend(env::split_paths(&paths).filter(|p| !p.as_os_str().is_empty()));
+        }
+        if let Some(dir) = env::current_exe()
+            .ok()
+            .and_then(|exe| exe.parent().map(Path::to_path_buf))
+        {
+            roots.push(dir);
+        }
+        if let Some(dir) = env::var_os("CARGO_MANIFEST_DIR") {
+            roots.push(PathBuf::from(dir));
+        }
+
+        AssetResolver {
+            roots,
+            search_cwd: true,
+        }
+    }
+}
+
+impl AssetResolver {
+    // a resolver that only searches the given roots
+    pub fn new<I, P>(roots: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<PathBuf>,
+    {
+        AssetResolver {
+            roots: roots.into_iter().map(Into::into).collect(),
+            search_cwd: false,
+        }
+    }
+
+    // add a search root, usually the package directory of the calling binary:
+    //   AssetResolver::default().with_root(env!("CARGO_MANIFEST_DIR"))
+    pub fn with_root<P: Into<PathBuf>>(mut self, root: P) -> Self {
+        let root = root.into();
+        if !self.roots.contains(&root) {
+            self.roots.push(root);
+        }
+        self
+    }
+
+    pub fn roots(&self) -> &[PathBuf] {
+        &self.roots
+    }
+
+    // return the first existing file for `path`, or every candidate that was tried
+    pub fn resolve<P: AsRef<Path>>(&self, path: P) -> Result<PathBuf, AssetError> {
+        let path = path.as_ref();
+        let candidates: Vec<PathBuf> = if path.is_absolute() {
+            vec![path.to_path_buf()]
+        } else {
+            let mut candidates: Vec<PathBuf> = self.roots.iter().map(|r| r.join(path)).collect();
+            if self.search_cwd {
+                if let Ok(cwd) = env::current_dir() {
+                    let candidate = cwd.join(path);
+                    if !candidates.contains(&candidate) {
+                        candidates.push(candidate);
+                    }
+                }
+            }
+            candidates
+        };
+
+        match candidates.iter().find(|c| c.is_file()) {
+            Some(found) => Ok(found.clone()),
+            None => Err(AssetError {
+                path: path.to_path_buf(),
+                tried: candidates,
+            }),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct AssetError {
+    pub path: PathBuf,
+    pub tried: Vec<PathBuf>,
+}
+
+impl fmt::Display for AssetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "asset not found: {}", self.path.display())?;
+        if self.tried.is_empty() {
+            write!(f, " (no search roots configured, set {})", ASSET_PATH_ENV)?;
+        } else {
+            write!(f, "\ntried:")?;
+            for candidate in &self.tried {
+                write!(f, "\n  {}", candidate.display())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Error for AssetError {}
+
+impl From<AssetError> for io::Error {
+    fn from(err: AssetError) -> Self {
+        io::Error::new(io::ErrorKind::NotFound, err)
+    }
+}
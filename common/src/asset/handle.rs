@@ -0,0 +1,141 @@
+use crate::{
+    buffer::StorageBuffer,
+    framebuffer::RenderTarget,
+    gl::{self, types::*},
+    mesh::Mesh,
+    shader::Shader,
+    texture::Texture,
+};
+use std::{cell::RefCell, fmt, ops::Deref, rc::Rc};
+
+// a GL object name waiting to be deleted
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GlObject {
+    Program(GLuint),
+    Texture(GLuint),
+    VertexArray(GLuint),
+    Buffer(GLuint),
+    Framebuffer(GLuint),
+    Renderbuffer(GLuint),
+}
+
+impl GlObject {
+    pub fn delete(self, gl: &mut gl::Gl) {
+        unsafe {
+            match self {
+                GlObject::Program(id) => gl.DeleteProgram(id),
+                GlObject::Texture(id) => gl.DeleteTextures(1, &id),
+                GlObject::VertexArray(id) => gl.DeleteVertexArrays(1, &id),
+                GlObject::Buffer(id) => gl.DeleteBuffers(1, &id),
+                GlObject::Framebuffer(id) => gl.DeleteFramebuffers(1, &id),
+                GlObject::Renderbuffer(id) => gl.DeleteRenderbuffers(1, &id),
+            }
+        }
+    }
+}
+
+// anything the asset manager can hand out, it must know which GL objects it owns
+pub trait GlResource {
+    fn gl_objects(&self) -> Vec<GlObject>;
+}
+
+impl GlResource for StorageBuffer {
+    fn gl_objects(&self) -> Vec<GlObject> {
+        vec![GlObject::Buffer(self.id)]
+    }
+}
+
+impl GlResource for Shader {
+    fn gl_objects(&self) -> Vec<GlObject> {
+        vec![GlObject::Program(self.id)]
+    }
+}
+
+impl GlResource for Texture {
+    fn gl_objects(&self) -> Vec<GlObject> {
+        vec![GlObject::Texture(self.id)]
+    }
+}
+
+impl GlResource for Mesh {
+    fn gl_objects(&self) -> Vec<GlObject> {
+        let mut objects = vec![GlObject::VertexArray(self.vao), GlObject::Buffer(self.vbo)];
+        if self.ebo != 0 {
+            objects.push(GlObject::Buffer(self.ebo));
+        }
+        objects
+    }
+}
+
+impl GlResource for RenderTarget {
+    fn gl_objects(&self) -> Vec<GlObject> {
+        vec![
+            GlObject::Framebuffer(self.fbo),
+            GlObject::Texture(self.color),
+            GlObject::Renderbuffer(self.depth_stencil),
+        ]
+    }
+}
+
+// objects whose last handle was dropped, deleted by AssetManager::collect_garbage
+pub(crate) type Garbage = Rc<RefCell<Vec<GlObject>>>;
+
+pub(crate) struct Asset<T: GlResource> {
+    pub(crate) value: T,
+    garbage: Garbage,
+}
+
+impl<T: GlResource> Asset<T> {
+    pub(crate) fn new(value: T, garbage: Garbage) -> Self {
+        Asset { value, garbage }
+    }
+}
+
+// dropping needs the GL context, so only queue the names here
+impl<T: GlResource> Drop for Asset<T> {
+    fn drop(&mut self) {
+        self.garbage.borrow_mut().extend(self.value.gl_objects());
+    }
+}
+
+// shared, reference counted access to a loaded asset
+// cloning is cheap, the GL objects are freed once every clone is gone
+pub struct Handle<T: GlResource> {
+    pub(crate) asset: Rc<Asset<T>>,
+}
+
+impl<T: GlResource> Handle<T> {
+    pub fn ref_count(&self) -> usize {
+        Rc::strong_count(&self.asset)
+    }
+
+    // true when both handles point at the same loaded asset
+    pub fn ptr_eq(&self, other: &Handle<T>) -> bool {
+        Rc::ptr_eq(&self.asset, &other.asset)
+    }
+}
+
+impl<T: GlResource> Clone for Handle<T> {
+    fn clone(&self) -> Self {
+        Handle {
+            asset: Rc::clone(&self.asset),
+        }
+    }
+}
+
+impl<T: GlResource> Deref for Handle<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.asset.value
+    }
+}
+
+impl<T: GlResource> fmt::Debug for Handle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Handle")
+            .field("objects", &self.asset.value.gl_objects())
+            .field("ref_count", &self.ref_count())
+            .finish()
+    }
+}
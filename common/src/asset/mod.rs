@@ -0,0 +1,7 @@
+pub mod asset_manager;
+pub mod asset_resolver;
+pub mod handle;
+
+pub use asset_manager::*;
+pub use asset_resolver::*;
+pub use handle::*;
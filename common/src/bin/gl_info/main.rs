@@ -0,0 +1,44 @@
+use common::app::{self, Scene, Window, WindowConfig, USAGE};
+use std::env;
+
+const GL_INFO_USAGE: &str = "\
+usage: gl_info [--json] [window options...]
+
+  --json        print the report as JSON, e.g. to attach to a bug report
+  window options pick the context to report on, the window stays hidden";
+
+// nothing to draw, the report is printed before the first frame
+struct Report;
+
+impl Scene for Report {
+    fn render(&mut self, _window: &mut Window) {}
+}
+
+fn main() {
+    let mut json = false;
+    let mut args = vec![];
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--json" => json = true,
+            "--help" | "-h" => {
+                println!("{}\n\n{}", GL_INFO_USAGE, USAGE);
+                return;
+            }
+            _ => args.push(arg),
+        }
+    }
+
+    let mut config = WindowConfig::from_arg_list(args);
+    config.title = String::from("gl_info");
+    config.headless = true;
+    app::run_with(config, |window| {
+        if json {
+            println!("{}", window.capabilities.to_json());
+        } else {
+            print!("{}", window.capabilities);
+            println!("\n{}", window.load_report);
+        }
+        window.source.set_should_close(true);
+        Report
+    });
+}
@@ -0,0 +1,118 @@
+use common::app::{self, Scene, Window, WindowConfig, USAGE};
+use glad::{
+    gl33,
+    replay::Replayer,
+    trace::{Event, TraceError, TraceReader},
+};
+use std::{env, fs::File, io::BufReader, os::raw::c_void, path::Path, process};
+
+const GL_REPLAY_USAGE: &str = "\
+usage: gl_replay <trace> [window options...]
+
+  plays back a trace recorded with --trace, one traced frame per frame
+  the window takes the traced size and closes after the last frame,
+  --headless --frames 1 --output frame.png renders the first one to a file";
+
+// plays the trace's calls up to the next frame marker every frame
+struct Replay {
+    reader: TraceReader<BufReader<File>>,
+    replayer: Replayer,
+    // replays go through gl33 whichever bindings common is built on
+    gl: gl33::Gl,
+    frames: u64,
+}
+
+impl Replay {
+    // false at the end of the trace
+    fn play_frame(&mut self) -> bool {
+        loop {
+            match self.reader.next_event() {
+                Ok(Some(Event::Frame)) => {
+                    self.frames += 1;
+                    return true;
+                }
+                Ok(Some(event)) => unsafe { self.replayer.replay(&self.gl, &event) },
+                Ok(None) => return false,
+                Err(err) => {
+                    eprintln!("stopped replaying: {}", err);
+                    return false;
+                }
+            }
+        }
+    }
+}
+
+impl Scene for Replay {
+    fn render(&mut self, window: &mut Window) {
+        if !self.play_frame() {
+            window.source.set_should_close(true);
+        }
+    }
+
+    // whatever the traced program did after its last frame, e.g. deleting things
+    fn shutdown(&mut self, _window: &mut Window) {
+        while self.play_frame() {}
+        println!(
+            "replayed {} calls in {} frames",
+            self.replayer.calls, self.frames
+        );
+        for (function, count) in &self.replayer.skipped {
+            println!("skipped gl{} {} times", function, count);
+        }
+    }
+}
+
+// frames and the size of the traced window, from a first pass over the trace
+fn scan(path: &Path) -> Result<(u64, Option<(i32, i32)>), TraceError> {
+    let mut frames = 0;
+    let mut size = None;
+    for event in TraceReader::open(path)? {
+        match event? {
+            Event::Frame => frames += 1,
+            Event::DefaultFramebuffer { width, height, .. } if size.is_none() => {
+                size = Some((width, height))
+            }
+            _ => {}
+        }
+    }
+    Ok((frames, size))
+}
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let path = match args.next() {
+        Some(arg) if arg == "--help" || arg == "-h" => {
+            println!("{}\n\n{}", GL_REPLAY_USAGE, USAGE);
+            return;
+        }
+        Some(path) => path,
+        None => {
+            eprintln!("{}", GL_REPLAY_USAGE);
+            process::exit(2);
+        }
+    };
+
+    let (frames, size) = scan(Path::new(&path)).unwrap_or_else(|err| {
+        eprintln!("failed to read {}: {}", path, err);
+        process::exit(1);
+    });
+    let mut config = WindowConfig::default();
+    if let Some((width, height)) = size {
+        config.width = width as u32;
+        config.height = height as u32;
+    }
+    config.frames = (frames > 0).then_some(frames);
+    // flags win over what the trace says
+    let mut config = config.parse_args(args).unwrap_or_else(|err| {
+        eprintln!("{}\n\n{}", err, USAGE);
+        process::exit(2);
+    });
+    config.title = format!("gl_replay {}", path);
+
+    app::run_with(config, |window| Replay {
+        reader: TraceReader::open(&path).expect("failed to open trace"),
+        replayer: Replayer::new(window.render_target.as_ref().map_or(0, |target| target.fbo)),
+        gl: gl33::load(|symbol| window.glfw.get_proc_address_raw(symbol) as *const c_void).0,
+        frames: 0,
+    });
+}
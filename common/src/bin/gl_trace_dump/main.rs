@@ -0,0 +1,59 @@
+use glad::trace::{Event, TraceReader};
+use std::{collections::BTreeSet, env, process};
+
+const GL_TRACE_DUMP_USAGE: &str = "\
+usage: gl_trace_dump <trace>
+
+  prints every call of a trace recorded with --trace, one per line";
+
+fn main() {
+    let path = match env::args().nth(1) {
+        Some(arg) if arg == "--help" || arg == "-h" => {
+            println!("{}", GL_TRACE_DUMP_USAGE);
+            return;
+        }
+        Some(path) => path,
+        None => {
+            eprintln!("{}", GL_TRACE_DUMP_USAGE);
+            process::exit(2);
+        }
+    };
+    let reader = TraceReader::open(&path).unwrap_or_else(|err| {
+        eprintln!("failed to read {}: {}", path, err);
+        process::exit(1);
+    });
+
+    let (mut calls, mut frames) = (0, 0);
+    let mut functions = BTreeSet::new();
+    for event in reader {
+        match event {
+            Ok(Event::Call(call)) => {
+                calls += 1;
+                println!("{}", call);
+                functions.insert(call.function);
+            }
+            Ok(Event::Frame) => {
+                frames += 1;
+                println!("-- frame {} --", frames);
+            }
+            Ok(Event::DefaultFramebuffer {
+                framebuffer,
+                width,
+                height,
+            }) => println!(
+                "-- default framebuffer {}, {}x{} --",
+                framebuffer, width, height
+            ),
+            Err(err) => {
+                eprintln!("failed to read {}: {}", path, err);
+                process::exit(1);
+            }
+        }
+    }
+    println!(
+        "{} calls to {} functions in {} frames",
+        calls,
+        functions.len(),
+        frames
+    );
+}
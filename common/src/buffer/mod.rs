@@ -0,0 +1,3 @@
+pub mod storage_buffer;
+
+pub use storage_buffer::*;
@@ -0,0 +1,77 @@
+use crate::{
+    capabilities::Features,
+    gl::{self, types::*},
+    state_cache,
+};
+use std::mem;
+
+// a shader storage buffer, what compute shaders read and write, GL 4.3
+// Example: one float per invocation, doubled by the shader
+//   let buffer = StorageBuffer::new(gl, &values);
+//   buffer.bind(gl, 0);
+//   shader.dispatch(gl, [values.len() as u32, 1, 1]);
+//   unsafe { gl.MemoryBarrier(gl::BUFFER_UPDATE_BARRIER_BIT) };
+//   let doubled: Vec<f32> = buffer.read(gl);
+pub struct StorageBuffer {
+    pub id: GLuint,
+    // bytes
+    pub size: usize,
+}
+
+impl StorageBuffer {
+    pub fn new<T: Copy>(gl: &mut gl::Gl, data: &[T]) -> StorageBuffer {
+        let features = Features::current();
+        assert!(
+            features.storage_buffers,
+            "storage buffers need a GL 4.3 context, run with --gl 4.3 or newer"
+        );
+        let size = mem::size_of_val(data);
+        let mut id = 0;
+        unsafe {
+            if features.direct_state_access {
+                gl.CreateBuffers(1, &mut id);
+                gl.NamedBufferData(
+                    id,
+                    size as GLsizeiptr,
+                    data.as_ptr() as *const GLvoid,
+                    gl::DYNAMIC_COPY,
+                );
+            } else {
+                gl.GenBuffers(1, &mut id);
+                state_cache::bind_buffer(gl, gl::SHADER_STORAGE_BUFFER, id);
+                gl.BufferData(
+                    gl::SHADER_STORAGE_BUFFER,
+                    size as GLsizeiptr,
+                    data.as_ptr() as *const GLvoid,
+                    gl::DYNAMIC_COPY,
+                );
+            }
+        }
+        StorageBuffer { id, size }
+    }
+
+    // to `layout (std430, binding = index) buffer` blocks
+    pub fn bind(&self, gl: &mut gl::Gl, index: GLuint) {
+        state_cache::bind_buffer_base(gl, gl::SHADER_STORAGE_BUFFER, index, self.id);
+    }
+
+    // the whole buffer as `T`s, after a glMemoryBarrier for shader writes
+    pub fn read<T: Copy + Default>(&self, gl: &mut gl::Gl) -> Vec<T> {
+        let mut data = vec![T::default(); self.size / mem::size_of::<T>().max(1)];
+        let size = mem::size_of_val(data.as_slice()) as GLsizeiptr;
+        unsafe {
+            if Features::current().direct_state_access {
+                gl.GetNamedBufferSubData(self.id, 0, size, data.as_mut_ptr() as *mut GLvoid);
+            } else {
+                state_cache::bind_buffer(gl, gl::SHADER_STORAGE_BUFFER, self.id);
+                gl.GetBufferSubData(
+                    gl::SHADER_STORAGE_BUFFER,
+                    0,
+                    size,
+                    data.as_mut_ptr() as *mut GLvoid,
+                );
+            }
+        }
+        data
+    }
+}
@@ -0,0 +1,281 @@
+use crate::gl::{self, types::*};
+use serde::{Deserialize, Serialize};
+use std::{cell::Cell, ffi::CStr, fmt};
+
+// What the current context is and what it can do, queried once at startup and
+// kept in `Window::capabilities`. Serializes to JSON for bug reports, see the
+// `gl_info` binary.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Capabilities {
+    pub vendor: String,
+    pub renderer: String,
+    // the full version string, e.g. "4.5 (Core Profile) Mesa 23.2.1"
+    pub version: String,
+    pub shading_language_version: String,
+    // parsed from MAJOR_VERSION/MINOR_VERSION
+    pub gl_version: (u32, u32),
+    pub core_profile: bool,
+    pub debug_context: bool,
+    pub extensions: Vec<String>,
+    pub limits: Limits,
+    // missing from reports written before it was added
+    #[serde(default)]
+    pub features: Features,
+}
+
+// the limits tutorials are most likely to run into, all as reported by GL
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Limits {
+    pub max_texture_size: i32,
+    pub max_3d_texture_size: i32,
+    pub max_cube_map_texture_size: i32,
+    pub max_array_texture_layers: i32,
+    pub max_renderbuffer_size: i32,
+    pub max_viewport_dims: (i32, i32),
+    // texture units a fragment shader can sample from
+    pub max_texture_image_units: i32,
+    // texture units across all shader stages, what `ActiveTexture` accepts
+    pub max_combined_texture_image_units: i32,
+    pub max_vertex_attribs: i32,
+    pub max_vertex_uniform_components: i32,
+    pub max_fragment_uniform_components: i32,
+    // bytes
+    pub max_uniform_block_size: i32,
+    pub max_uniform_buffer_bindings: i32,
+    pub max_samples: i32,
+    pub max_color_attachments: i32,
+    pub max_draw_buffers: i32,
+    // None without anisotropic filtering (GL 4.6 or the EXT/ARB extension)
+    pub max_anisotropy: Option<f32>,
+}
+
+// the GL 4.x paths common takes when the context is new enough, by version
+// only: drivers list the ARB extensions on 3.3 contexts too, but 3.3 is what
+// the tutorials are written and the golden images are rendered against
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Features {
+    // 4.5, glCreate*/glNamed*/glTexture* instead of binding to edit
+    pub direct_state_access: bool,
+    // 4.3, compute shaders and glDispatchCompute
+    pub compute_shaders: bool,
+    // 4.3, shader storage buffers
+    pub storage_buffers: bool,
+}
+
+thread_local! {
+    // per thread like the context they were detected on
+    static CURRENT: Cell<Features> = Cell::new(Features::default());
+}
+
+impl Features {
+    pub fn for_version(gl_version: (u32, u32)) -> Features {
+        Features {
+            direct_state_access: gl_version >= (4, 5),
+            compute_shaders: gl_version >= (4, 3),
+            storage_buffers: gl_version >= (4, 3),
+        }
+    }
+
+    // what the wrappers in common check, none until `detect`
+    pub fn current() -> Features {
+        CURRENT.with(Cell::get)
+    }
+
+    // called once after loading the GL functions, on the thread the context is
+    // current on, `app::run` does
+    pub fn detect(capabilities: &Capabilities) -> Features {
+        let features = capabilities.features;
+        CURRENT.with(|current| current.set(features));
+        features
+    }
+}
+
+impl fmt::Display for Features {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names = [
+            (self.direct_state_access, "direct state access"),
+            (self.compute_shaders, "compute shaders"),
+            (self.storage_buffers, "storage buffers"),
+        ];
+        let names: Vec<&str> = names
+            .iter()
+            .filter(|(on, _)| *on)
+            .map(|(_, name)| *name)
+            .collect();
+        if names.is_empty() {
+            write!(f, "none")
+        } else {
+            write!(f, "{}", names.join(", "))
+        }
+    }
+}
+
+impl Capabilities {
+    pub fn query(gl: &mut gl::Gl) -> Capabilities {
+        let (mut major, mut minor, mut mask, mut flags) = (0, 0, 0, 0);
+        unsafe {
+            gl.GetIntegerv(gl::MAJOR_VERSION, &mut major);
+            gl.GetIntegerv(gl::MINOR_VERSION, &mut minor);
+            gl.GetIntegerv(gl::CONTEXT_PROFILE_MASK, &mut mask);
+            gl.GetIntegerv(gl::CONTEXT_FLAGS, &mut flags);
+        }
+        let gl_version = (major.max(0) as u32, minor.max(0) as u32);
+        let extensions = extensions(gl);
+
+        let anisotropic = gl_version >= (4, 6)
+            || extensions.iter().any(|name| {
+                name == "GL_EXT_texture_filter_anisotropic"
+                    || name == "GL_ARB_texture_filter_anisotropic"
+            });
+        let limits = Limits {
+            max_texture_size: integer(gl, gl::MAX_TEXTURE_SIZE),
+            max_3d_texture_size: integer(gl, gl::MAX_3D_TEXTURE_SIZE),
+            max_cube_map_texture_size: integer(gl, gl::MAX_CUBE_MAP_TEXTURE_SIZE),
+            max_array_texture_layers: integer(gl, gl::MAX_ARRAY_TEXTURE_LAYERS),
+            max_renderbuffer_size: integer(gl, gl::MAX_RENDERBUFFER_SIZE),
+            max_viewport_dims: {
+                let mut dims = [0; 2];
+                unsafe { gl.GetIntegerv(gl::MAX_VIEWPORT_DIMS, dims.as_mut_ptr()) };
+                (dims[0], dims[1])
+            },
+            max_texture_image_units: integer(gl, gl::MAX_TEXTURE_IMAGE_UNITS),
+            max_combined_texture_image_units: integer(gl, gl::MAX_COMBINED_TEXTURE_IMAGE_UNITS),
+            max_vertex_attribs: integer(gl, gl::MAX_VERTEX_ATTRIBS),
+            max_vertex_uniform_components: integer(gl, gl::MAX_VERTEX_UNIFORM_COMPONENTS),
+            max_fragment_uniform_components: integer(gl, gl::MAX_FRAGMENT_UNIFORM_COMPONENTS),
+            max_uniform_block_size: integer(gl, gl::MAX_UNIFORM_BLOCK_SIZE),
+            max_uniform_buffer_bindings: integer(gl, gl::MAX_UNIFORM_BUFFER_BINDINGS),
+            max_samples: integer(gl, gl::MAX_SAMPLES),
+            max_color_attachments: integer(gl, gl::MAX_COLOR_ATTACHMENTS),
+            max_draw_buffers: integer(gl, gl::MAX_DRAW_BUFFERS),
+            max_anisotropy: anisotropic.then(|| {
+                let mut value = 0.0;
+                unsafe { gl.GetFloatv(gl::MAX_TEXTURE_MAX_ANISOTROPY, &mut value) };
+                value
+            }),
+        };
+
+        Capabilities {
+            vendor: string(gl, gl::VENDOR),
+            renderer: string(gl, gl::RENDERER),
+            version: string(gl, gl::VERSION),
+            shading_language_version: string(gl, gl::SHADING_LANGUAGE_VERSION),
+            gl_version,
+            core_profile: mask as GLuint & gl::CONTEXT_CORE_PROFILE_BIT != 0,
+            debug_context: flags as GLuint & gl::CONTEXT_FLAG_DEBUG_BIT != 0,
+            extensions,
+            limits,
+            features: Features::for_version(gl_version),
+        }
+    }
+
+    // e.g. has_extension("GL_ARB_texture_filter_anisotropic")
+    pub fn has_extension(&self, name: &str) -> bool {
+        self.extensions.iter().any(|extension| extension == name)
+    }
+
+    pub fn at_least(&self, major: u32, minor: u32) -> bool {
+        self.gl_version >= (major, minor)
+    }
+
+    pub fn to_json(&self) -> String {
+        // only strings and numbers, can't fail
+        serde_json::to_string_pretty(self).unwrap()
+    }
+}
+
+// the human readable report, extensions last since there are hundreds
+impl fmt::Display for Capabilities {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "vendor:    {}", self.vendor)?;
+        writeln!(f, "renderer:  {}", self.renderer)?;
+        writeln!(f, "version:   {}", self.version)?;
+        writeln!(f, "GLSL:      {}", self.shading_language_version)?;
+        writeln!(
+            f,
+            "context:   {}.{} {}{}",
+            self.gl_version.0,
+            self.gl_version.1,
+            if self.core_profile {
+                "core"
+            } else {
+                "compatibility"
+            },
+            if self.debug_context { ", debug" } else { "" }
+        )?;
+        writeln!(f, "features:  {}", self.features)?;
+
+        let limits = &self.limits;
+        let (width, height) = limits.max_viewport_dims;
+        writeln!(f, "\nlimits")?;
+        row(f, "texture size", limits.max_texture_size)?;
+        row(f, "3D texture size", limits.max_3d_texture_size)?;
+        row(f, "cube map size", limits.max_cube_map_texture_size)?;
+        row(f, "array texture layers", limits.max_array_texture_layers)?;
+        row(f, "renderbuffer size", limits.max_renderbuffer_size)?;
+        row(f, "viewport", format!("{}x{}", width, height))?;
+        row(f, "fragment texture units", limits.max_texture_image_units)?;
+        row(f, "texture units", limits.max_combined_texture_image_units)?;
+        row(f, "vertex attributes", limits.max_vertex_attribs)?;
+        row(
+            f,
+            "vertex uniform components",
+            limits.max_vertex_uniform_components,
+        )?;
+        row(
+            f,
+            "fragment uniform components",
+            limits.max_fragment_uniform_components,
+        )?;
+        row(f, "uniform block bytes", limits.max_uniform_block_size)?;
+        row(
+            f,
+            "uniform buffer bindings",
+            limits.max_uniform_buffer_bindings,
+        )?;
+        row(f, "MSAA samples", limits.max_samples)?;
+        row(f, "color attachments", limits.max_color_attachments)?;
+        row(f, "draw buffers", limits.max_draw_buffers)?;
+        match limits.max_anisotropy {
+            Some(anisotropy) => row(f, "anisotropy", anisotropy)?,
+            None => row(f, "anisotropy", "unsupported")?,
+        }
+
+        writeln!(f, "\n{} extensions", self.extensions.len())?;
+        for extension in &self.extensions {
+            writeln!(f, "  {}", extension)?;
+        }
+        Ok(())
+    }
+}
+
+fn row(f: &mut fmt::Formatter<'_>, name: &str, value: impl fmt::Display) -> fmt::Result {
+    writeln!(f, "  {:<28} {}", name, value)
+}
+
+fn integer(gl: &mut gl::Gl, name: GLenum) -> i32 {
+    let mut value = 0;
+    unsafe { gl.GetIntegerv(name, &mut value) };
+    value
+}
+
+fn string(gl: &mut gl::Gl, name: GLenum) -> String {
+    unsafe { c_string(gl.GetString(name)) }
+}
+
+fn extensions(gl: &mut gl::Gl) -> Vec<String> {
+    let count = integer(gl, gl::NUM_EXTENSIONS).max(0) as GLuint;
+    (0..count)
+        .map(|i| unsafe { c_string(gl.GetStringi(gl::EXTENSIONS, i)) })
+        .collect()
+}
+
+// GL returns null for names it doesn't know
+unsafe fn c_string(string: *const GLubyte) -> String {
+    if string.is_null() {
+        return String::new();
+    }
+    CStr::from_ptr(string as *const _)
+        .to_string_lossy()
+        .into_owned()
+}
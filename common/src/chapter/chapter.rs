@@ -0,0 +1,42 @@
+use crate::app::{Scene, Window};
+
+// what a tutorial binary is about, each package lists its chapters in reading
+// order so the launcher, the scene switcher and the package README can be
+// generated from one place
+#[derive(Clone, Copy, Debug)]
+pub struct Chapter {
+    // binary target name, `cargo run --bin <name>`
+    pub name: &'static str,
+    pub title: &'static str,
+    // LearnOpenGL page the chapter belongs to, e.g. "Hello Triangle"
+    pub section: &'static str,
+    pub url: &'static str,
+    // one line on what's on screen
+    pub description: &'static str,
+    // create the chapter in a running window, usually `boxed_scene::<App>`
+    pub scene: fn(&mut Window) -> Box<dyn Scene>,
+}
+
+// the chapters grouped by section, sections in order of their first chapter
+pub fn sections(chapters: &[Chapter]) -> Vec<(&'static str, Vec<&Chapter>)> {
+    let mut sections: Vec<(&'static str, Vec<&Chapter>)> = vec![];
+    for chapter in chapters {
+        match sections
+            .iter_mut()
+            .find(|(section, _)| *section == chapter.section)
+        {
+            Some((_, group)) => group.push(chapter),
+            None => sections.push((chapter.section, vec![chapter])),
+        }
+    }
+    sections
+}
+
+// look a chapter up by binary name or by its 1-based number in `chapters`
+pub fn find_chapter<'a>(chapters: &'a [Chapter], query: &str) -> Option<&'a Chapter> {
+    let query = query.trim();
+    match query.parse::<usize>() {
+        Ok(number) => number.checked_sub(1).and_then(|i| chapters.get(i)),
+        Err(_) => chapters.iter().find(|chapter| chapter.name == query),
+    }
+}
@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+pub mod chapter;
+pub mod readme;
+
+pub use chapter::*;
+pub use readme::*;
@@ -0,0 +1,41 @@
+use crate::chapter::chapter::{sections, Chapter};
+use std::fmt::Write;
+
+// the package README: one table per section, followed by the chapters that
+// haven't been ported yet
+pub fn readme(package: &str, chapters: &[Chapter], in_progress: &[&str]) -> String {
+    let mut out = String::new();
+    // writing to a String can't fail
+    let _ = writeln!(out, "# Tutorial Order and Binary Target Name\n");
+    let _ = writeln!(
+        out,
+        "<!-- generated by `cargo run -p {0} --bin launcher -- --readme > {0}/README.md`, edit the chapter list instead -->\n",
+        package
+    );
+    let _ = writeln!(
+        out,
+        "Run `cargo run -p {0} --bin launcher` to pick a chapter from a list, or `cargo run -p {0} --bin switcher` to page through all of them in one window.",
+        package
+    );
+
+    for (section, group) in sections(chapters) {
+        let _ = writeln!(out, "\n## {}\n", section);
+        let _ = writeln!(out, "| Chapter | Description | Command |");
+        let _ = writeln!(out, "| --- | --- | --- |");
+        for chapter in group {
+            let _ = writeln!(
+                out,
+                "| [{}]({}) | {} | `cargo run -p {} --bin {}` |",
+                chapter.title, chapter.url, chapter.description, package, chapter.name
+            );
+        }
+    }
+
+    if !in_progress.is_empty() {
+        let _ = writeln!(out, "\n## In Progress\n");
+        for title in in_progress {
+            let _ = writeln!(out, "- {}", title);
+        }
+    }
+    out
+}
@@ -0,0 +1,72 @@
+use crate::{
+    debug_output::debug_output::is_supported,
+    gl::{self, types::*},
+};
+use std::ops::{Deref, DerefMut};
+
+// A named group of GL calls, shows up as a nested region in RenderDoc and
+// apitrace and wraps the driver messages logged inside it. Popped when dropped,
+// use the guard in place of `gl` until then:
+//   let mut gl = DebugGroup::push(&mut window.gl, "shadow pass");
+//   gl.DrawArrays(gl::TRIANGLES, 0, 3);
+pub struct DebugGroup<'a> {
+    gl: &'a mut gl::Gl,
+    pushed: bool,
+}
+
+impl<'a> DebugGroup<'a> {
+    pub fn push(gl: &'a mut gl::Gl, name: &str) -> DebugGroup<'a> {
+        let pushed = is_supported();
+        if pushed {
+            unsafe {
+                gl.PushDebugGroup(
+                    gl::DEBUG_SOURCE_APPLICATION,
+                    0,
+                    name.len() as GLsizei,
+                    name.as_ptr() as *const GLchar,
+                );
+            }
+        }
+        DebugGroup { gl, pushed }
+    }
+}
+
+impl Deref for DebugGroup<'_> {
+    type Target = gl::Gl;
+
+    fn deref(&self) -> &gl::Gl {
+        self.gl
+    }
+}
+
+impl DerefMut for DebugGroup<'_> {
+    fn deref_mut(&mut self) -> &mut gl::Gl {
+        self.gl
+    }
+}
+
+impl Drop for DebugGroup<'_> {
+    fn drop(&mut self) {
+        if self.pushed {
+            unsafe { self.gl.PopDebugGroup() };
+        }
+    }
+}
+
+// name a GL object for debuggers and driver messages, e.g.
+//   object_label(gl, gl::TEXTURE, texture, "container.jpg");
+// `identifier` is the kind of object: gl::BUFFER, gl::SHADER, gl::PROGRAM,
+// gl::VERTEX_ARRAY, gl::TEXTURE, gl::FRAMEBUFFER, gl::RENDERBUFFER, ...
+pub fn object_label(gl: &mut gl::Gl, identifier: GLenum, object: GLuint, label: &str) {
+    if !is_supported() {
+        return;
+    }
+    unsafe {
+        gl.ObjectLabel(
+            identifier,
+            object,
+            label.len() as GLsizei,
+            label.as_ptr() as *const GLchar,
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use crate::{
+    capabilities::Capabilities,
+    gl::{self, types::*},
+    state_cache,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    backtrace::Backtrace,
+    cell::{Cell, RefCell},
+    ffi::CStr,
+    os::raw::c_void,
+    ptr,
+};
+
+// log target of every driver message, e.g. RUST_LOG=gl=warn
+pub const LOG_TARGET: &str = "gl";
+
+// ordered from least to most important, so filters can compare them
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Notification,
+    Low,
+    Medium,
+    High,
+}
+
+impl Severity {
+    pub fn from_gl(severity: GLenum) -> Severity {
+        match severity {
+            gl::DEBUG_SEVERITY_HIGH => Severity::High,
+            gl::DEBUG_SEVERITY_MEDIUM => Severity::Medium,
+            gl::DEBUG_SEVERITY_LOW => Severity::Low,
+            _ => Severity::Notification,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Severity::Notification => "notification",
+            Severity::Low => "low",
+            Severity::Medium => "medium",
+            Severity::High => "high",
+        }
+    }
+
+    fn level(self) -> log::Level {
+        match self {
+            Severity::High => log::Level::Error,
+            Severity::Medium => log::Level::Warn,
+            Severity::Low => log::Level::Info,
+            Severity::Notification => log::Level::Debug,
+        }
+    }
+}
+
+pub fn source_name(source: GLenum) -> &'static str {
+    match source {
+        gl::DEBUG_SOURCE_API => "api",
+        gl::DEBUG_SOURCE_WINDOW_SYSTEM => "window system",
+        gl::DEBUG_SOURCE_SHADER_COMPILER => "shader compiler",
+        gl::DEBUG_SOURCE_THIRD_PARTY => "third party",
+        gl::DEBUG_SOURCE_APPLICATION => "application",
+        _ => "other",
+    }
+}
+
+pub fn type_name(type_: GLenum) -> &'static str {
+    match type_ {
+        gl::DEBUG_TYPE_ERROR => "error",
+        gl::DEBUG_TYPE_DEPRECATED_BEHAVIOR => "deprecated behavior",
+        gl::DEBUG_TYPE_UNDEFINED_BEHAVIOR => "undefined behavior",
+        gl::DEBUG_TYPE_PORTABILITY => "portability",
+        gl::DEBUG_TYPE_PERFORMANCE => "performance",
+        gl::DEBUG_TYPE_MARKER => "marker",
+        gl::DEBUG_TYPE_PUSH_GROUP => "push group",
+        gl::DEBUG_TYPE_POP_GROUP => "pop group",
+        _ => "other",
+    }
+}
+
+// which driver messages make it into the log
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DebugFilter {
+    // messages below this are dropped
+    pub min_severity: Severity,
+    // message IDs to drop whatever their severity, drivers repeat some a lot
+    pub ignore_ids: Vec<u32>,
+    // log where high severity messages came from, costs a lot per message
+    pub backtrace: bool,
+}
+
+impl Default for DebugFilter {
+    fn default() -> Self {
+        DebugFilter {
+            // notifications are mostly "buffer will use video memory" and the like
+            min_severity: Severity::Low,
+            ignore_ids: vec![],
+            backtrace: false,
+        }
+    }
+}
+
+impl DebugFilter {
+    pub fn allows(&self, id: u32, severity: Severity) -> bool {
+        severity >= self.min_severity && !self.ignore_ids.contains(&id)
+    }
+}
+
+// per thread like `Features`, messages are synchronous so the callback runs on
+// the thread of the context that sent them
+thread_local! {
+    // set by `install`, read by the callback which can't borrow the window
+    static FILTER: RefCell<Option<DebugFilter>> = const { RefCell::new(None) };
+    // KHR_debug functions are only loaded in 4.3+ contexts or with the extension
+    static SUPPORTED: Cell<bool> = const { Cell::new(false) };
+}
+
+// whether the current context has KHR_debug, `DebugGroup` and `object_label`
+// do nothing without it
+pub fn is_supported() -> bool {
+    SUPPORTED.get()
+}
+
+// look for KHR_debug, called once after loading the GL functions
+pub fn detect(capabilities: &Capabilities) -> bool {
+    let supported = capabilities.at_least(4, 3) || capabilities.has_extension("GL_KHR_debug");
+    SUPPORTED.set(supported);
+    supported
+}
+
+// route driver messages into the `log` crate, returns false when the context
+// can't report them (no KHR_debug, or not a debug context)
+// Messages are synchronous so a backtrace points at the call that caused them.
+pub fn install(gl: &mut gl::Gl, filter: DebugFilter) -> bool {
+    if !is_supported() {
+        return false;
+    }
+    unsafe {
+        let mut flags = 0;
+        gl.GetIntegerv(gl::CONTEXT_FLAGS, &mut flags);
+        if flags as GLuint & gl::CONTEXT_FLAG_DEBUG_BIT == 0 {
+            return false;
+        }
+    }
+    set_filter(filter);
+    state_cache::enable(gl, gl::DEBUG_OUTPUT);
+    state_cache::enable(gl, gl::DEBUG_OUTPUT_SYNCHRONOUS);
+    unsafe {
+        gl.DebugMessageCallback(callback, ptr::null());
+    }
+    true
+}
+
+// change what gets logged without reinstalling the callback
+pub fn set_filter(filter: DebugFilter) {
+    FILTER.set(Some(filter));
+}
+
+extern "system" fn callback(
+    source: GLenum,
+    type_: GLenum,
+    id: GLuint,
+    severity: GLenum,
+    length: GLsizei,
+    message: *const GLchar,
+    _user: *mut c_void,
+) {
+    let severity = Severity::from_gl(severity);
+    let backtrace = FILTER.with_borrow(|filter| match filter {
+        Some(filter) if !filter.allows(id, severity) => None,
+        Some(filter) => Some(filter.backtrace && severity == Severity::High),
+        None => Some(false),
+    });
+    let Some(backtrace) = backtrace else {
+        return;
+    };
+    if message.is_null() {
+        return;
+    }
+
+    // the length is optional, -1 means null terminated
+    let message = unsafe {
+        if length >= 0 {
+            String::from_utf8_lossy(std::slice::from_raw_parts(
+                message as *const u8,
+                length as usize,
+            ))
+        } else {
+            CStr::from_ptr(message).to_string_lossy()
+        }
+    };
+    let message = message.trim_end();
+
+    if backtrace {
+        log::log!(
+            target: LOG_TARGET,
+            severity.level(),
+            "{} {} {} ({}): {}\n{}",
+            source_name(source),
+            type_name(type_),
+            id,
+            severity.name(),
+            message,
+            Backtrace::force_capture()
+        );
+    } else {
+        log::log!(
+            target: LOG_TARGET,
+            severity.level(),
+            "{} {} {} ({}): {}",
+            source_name(source),
+            type_name(type_),
+            id,
+            severity.name(),
+            message
+        );
+    }
+}
@@ -0,0 +1,6 @@
+pub mod debug_group;
+#[allow(clippy::module_inception)]
+pub mod debug_output;
+
+pub use debug_group::*;
+pub use debug_output::*;
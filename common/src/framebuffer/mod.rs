@@ -0,0 +1,5 @@
+pub mod read_pixels;
+pub mod render_target;
+
+pub use read_pixels::*;
+pub use render_target::*;
@@ -0,0 +1,28 @@
+use crate::gl::{self, types::*};
+use image::{imageops, RgbaImage};
+
+// copy a rectangle of the bound read framebuffer into an image
+// GL's first row is the bottom one, the image is flipped so it reads top down
+pub fn read_pixels(gl: &mut gl::Gl, x: i32, y: i32, width: i32, height: i32) -> RgbaImage {
+    let (width, height) = (width.max(0) as u32, height.max(0) as u32);
+    let mut pixels = vec![0u8; (width * height * 4) as usize];
+    unsafe {
+        let mut alignment = 0;
+        gl.GetIntegerv(gl::PACK_ALIGNMENT, &mut alignment);
+        gl.PixelStorei(gl::PACK_ALIGNMENT, 1);
+        gl.ReadPixels(
+            x,
+            y,
+            width as GLsizei,
+            height as GLsizei,
+            gl::RGBA,
+            gl::UNSIGNED_BYTE,
+            pixels.as_mut_ptr() as *mut GLvoid,
+        );
+        gl.PixelStorei(gl::PACK_ALIGNMENT, alignment);
+    }
+
+    let mut image = RgbaImage::from_raw(width, height, pixels).expect("buffer matches image size");
+    imageops::flip_vertical_in_place(&mut image);
+    image
+}
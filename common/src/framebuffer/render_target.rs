@@ -0,0 +1,134 @@
+use crate::{
+    framebuffer::read_pixels::read_pixels,
+    gl::{self, types::*},
+    state_cache,
+};
+use image::RgbaImage;
+use std::ptr;
+
+// an offscreen framebuffer with a color texture and a depth/stencil renderbuffer
+// anything drawn at the window's size has to follow it, see `App::on_resize`
+pub struct RenderTarget {
+    pub fbo: GLuint,
+    // sample this after rendering into the target
+    pub color: GLuint,
+    pub depth_stencil: GLuint,
+    pub width: i32,
+    pub height: i32,
+}
+
+impl RenderTarget {
+    pub fn new(gl: &mut gl::Gl, width: i32, height: i32) -> RenderTarget {
+        let (mut fbo, mut color, mut depth_stencil) = (0, 0, 0);
+        unsafe {
+            gl.GenFramebuffers(1, &mut fbo);
+            gl.GenTextures(1, &mut color);
+            gl.GenRenderbuffers(1, &mut depth_stencil);
+        }
+
+        let mut target = RenderTarget {
+            fbo,
+            color,
+            depth_stencil,
+            width: 0,
+            height: 0,
+        };
+        target.resize(gl, width, height);
+        target
+    }
+
+    // reallocate the attachments, their contents are undefined afterwards
+    pub fn resize(&mut self, gl: &mut gl::Gl, width: i32, height: i32) {
+        // a minimized window reports 0x0, which GL does not accept for storage
+        let (width, height) = (width.max(1), height.max(1));
+        if (width, height) == (self.width, self.height) {
+            return;
+        }
+        self.width = width;
+        self.height = height;
+
+        unsafe {
+            let mut previous = 0;
+            gl.GetIntegerv(gl::FRAMEBUFFER_BINDING, &mut previous);
+
+            state_cache::bind_texture(gl, gl::TEXTURE_2D, self.color);
+            gl.TexImage2D(
+                gl::TEXTURE_2D,
+                0,
+                gl::RGBA8 as GLint,
+                width,
+                height,
+                0,
+                gl::RGBA,
+                gl::UNSIGNED_BYTE,
+                ptr::null(),
+            );
+            gl.TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, gl::LINEAR as GLint);
+            gl.TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, gl::LINEAR as GLint);
+            gl.TexParameteri(
+                gl::TEXTURE_2D,
+                gl::TEXTURE_WRAP_S,
+                gl::CLAMP_TO_EDGE as GLint,
+            );
+            gl.TexParameteri(
+                gl::TEXTURE_2D,
+                gl::TEXTURE_WRAP_T,
+                gl::CLAMP_TO_EDGE as GLint,
+            );
+            state_cache::bind_texture(gl, gl::TEXTURE_2D, 0);
+
+            gl.BindRenderbuffer(gl::RENDERBUFFER, self.depth_stencil);
+            gl.RenderbufferStorage(gl::RENDERBUFFER, gl::DEPTH24_STENCIL8, width, height);
+            gl.BindRenderbuffer(gl::RENDERBUFFER, 0);
+
+            gl.BindFramebuffer(gl::FRAMEBUFFER, self.fbo);
+            gl.FramebufferTexture2D(
+                gl::FRAMEBUFFER,
+                gl::COLOR_ATTACHMENT0,
+                gl::TEXTURE_2D,
+                self.color,
+                0,
+            );
+            gl.FramebufferRenderbuffer(
+                gl::FRAMEBUFFER,
+                gl::DEPTH_STENCIL_ATTACHMENT,
+                gl::RENDERBUFFER,
+                self.depth_stencil,
+            );
+            let status = gl.CheckFramebufferStatus(gl::FRAMEBUFFER);
+            gl.BindFramebuffer(gl::FRAMEBUFFER, previous as GLuint);
+
+            if status != gl::FRAMEBUFFER_COMPLETE {
+                panic!("framebuffer is not complete: 0x{:x}", status);
+            }
+        }
+    }
+
+    // draw into the target, the viewport is set to cover all of it
+    pub fn bind(&self, gl: &mut gl::Gl) {
+        unsafe {
+            gl.BindFramebuffer(gl::FRAMEBUFFER, self.fbo);
+        }
+        state_cache::viewport(gl, 0, 0, self.width, self.height);
+    }
+
+    // the color attachment, top row first
+    pub fn read_pixels(&self, gl: &mut gl::Gl) -> RgbaImage {
+        unsafe {
+            let mut previous = 0;
+            gl.GetIntegerv(gl::READ_FRAMEBUFFER_BINDING, &mut previous);
+            gl.BindFramebuffer(gl::READ_FRAMEBUFFER, self.fbo);
+            let image = read_pixels(gl, 0, 0, self.width, self.height);
+            gl.BindFramebuffer(gl::READ_FRAMEBUFFER, previous as GLuint);
+            image
+        }
+    }
+
+    // back to the default framebuffer, `width` and `height` are the window's framebuffer size
+    pub fn unbind(gl: &mut gl::Gl, width: i32, height: i32) {
+        unsafe {
+            gl.BindFramebuffer(gl::FRAMEBUFFER, 0);
+        }
+        state_cache::viewport(gl, 0, 0, width, height);
+    }
+}
@@ -0,0 +1,16 @@
+// the bindings everything here is written against: glad's generated `gl`
+// (GL 4.6 core) by default, the full gl33 or gl46 with their features. Each
+// loads the 4.x functions the context has, which paths common takes depends
+// on the context, see `capabilities::Features`.
+#[cfg(all(feature = "gl-slim", not(any(feature = "gl33", feature = "gl46"))))]
+pub(crate) use glad::gl as bindings;
+#[cfg(all(feature = "gl33", not(feature = "gl46")))]
+pub(crate) use glad::gl33 as bindings;
+#[cfg(feature = "gl46")]
+pub(crate) use glad::gl46 as bindings;
+#[cfg(not(any(feature = "gl-slim", feature = "gl33", feature = "gl46")))]
+compile_error!("common needs one of the gl-slim, gl33 or gl46 features");
+
+pub use bindings::*;
+// with the context's state cache, see `state_cache`
+pub use crate::state_cache::{load, Gl};
@@ -0,0 +1,247 @@
+use glfw::{GamepadAxis, GamepadButton, Key, MouseButton};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// Anything that is either down or up. Written in config files by name:
+//   keys use glfw's names ("Escape", "W", "Num1", "LeftShift", "KpEnter")
+//   mouse buttons are "MouseLeft", "MouseRight", "MouseMiddle" or "Mouse4" to "Mouse8"
+//   gamepad buttons are "GamepadA", "GamepadStart", "GamepadDpadUp", ...
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Button {
+    Key(Key),
+    Mouse(MouseButton),
+    Gamepad(GamepadButton),
+}
+
+// Anything with a value, read once per frame. Written in config files as
+// "MouseX", "MouseY", "ScrollX", "ScrollY" or "GamepadLeftX", "GamepadRightTrigger", ...
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Analog {
+    // cursor movement since the last frame, in screen coordinates
+    MouseX,
+    MouseY,
+    // scroll wheel steps since the last frame
+    ScrollX,
+    ScrollY,
+    // -1..1 for sticks, 0..1 for triggers
+    Gamepad(GamepadAxis),
+}
+
+// one input driving an axis, several bindings on the same axis are added up
+// in a config file:
+//   [[axes.mix]]
+//   negative = "Down"
+//   positive = "Up"
+//
+//   [[axes.mix]]
+//   analog = "ScrollY"
+//   scale = 0.1
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum AxisBinding {
+    // -1 while `negative` is held, 1 while `positive` is held
+    Buttons {
+        negative: Button,
+        positive: Button,
+    },
+    Analog {
+        analog: Analog,
+        #[serde(default = "default_scale")]
+        scale: f32,
+    },
+}
+
+fn default_scale() -> f32 {
+    1.0
+}
+
+impl AxisBinding {
+    pub fn buttons<N: Into<Button>, P: Into<Button>>(negative: N, positive: P) -> Self {
+        AxisBinding::Buttons {
+            negative: negative.into(),
+            positive: positive.into(),
+        }
+    }
+
+    pub fn analog(analog: Analog, scale: f32) -> Self {
+        AxisBinding::Analog { analog, scale }
+    }
+}
+
+impl From<Key> for Button {
+    fn from(key: Key) -> Self {
+        Button::Key(key)
+    }
+}
+
+impl From<MouseButton> for Button {
+    fn from(button: MouseButton) -> Self {
+        Button::Mouse(button)
+    }
+}
+
+impl From<GamepadButton> for Button {
+    fn from(button: GamepadButton) -> Self {
+        Button::Gamepad(button)
+    }
+}
+
+// Key::Unknown is left out, glfw can't poll it
+#[rustfmt::skip]
+const KEYS: [Key; 120] = [
+    Key::Space, Key::Apostrophe, Key::Comma, Key::Minus, Key::Period, Key::Slash, Key::Num0,
+    Key::Num1, Key::Num2, Key::Num3, Key::Num4, Key::Num5, Key::Num6, Key::Num7, Key::Num8,
+    Key::Num9, Key::Semicolon, Key::Equal, Key::A, Key::B, Key::C, Key::D, Key::E, Key::F,
+    Key::G, Key::H, Key::I, Key::J, Key::K, Key::L, Key::M, Key::N, Key::O, Key::P, Key::Q,
+    Key::R, Key::S, Key::T, Key::U, Key::V, Key::W, Key::X, Key::Y, Key::Z, Key::LeftBracket,
+    Key::Backslash, Key::RightBracket, Key::GraveAccent, Key::World1, Key::World2,
+    Key::Escape, Key::Enter, Key::Tab, Key::Backspace, Key::Insert, Key::Delete, Key::Right,
+    Key::Left, Key::Down, Key::Up, Key::PageUp, Key::PageDown, Key::Home, Key::End,
+    Key::CapsLock, Key::ScrollLock, Key::NumLock, Key::PrintScreen, Key::Pause, Key::F1,
+    Key::F2, Key::F3, Key::F4, Key::F5, Key::F6, Key::F7, Key::F8, Key::F9, Key::F10,
+    Key::F11, Key::F12, Key::F13, Key::F14, Key::F15, Key::F16, Key::F17, Key::F18, Key::F19,
+    Key::F20, Key::F21, Key::F22, Key::F23, Key::F24, Key::F25, Key::Kp0, Key::Kp1, Key::Kp2,
+    Key::Kp3, Key::Kp4, Key::Kp5, Key::Kp6, Key::Kp7, Key::Kp8, Key::Kp9, Key::KpDecimal,
+    Key::KpDivide, Key::KpMultiply, Key::KpSubtract, Key::KpAdd, Key::KpEnter, Key::KpEqual,
+    Key::LeftShift, Key::LeftControl, Key::LeftAlt, Key::LeftSuper, Key::RightShift,
+    Key::RightControl, Key::RightAlt, Key::RightSuper, Key::Menu,
+];
+
+const MOUSE_BUTTONS: [(MouseButton, &str); 8] = [
+    (MouseButton::Button1, "MouseLeft"),
+    (MouseButton::Button2, "MouseRight"),
+    (MouseButton::Button3, "MouseMiddle"),
+    (MouseButton::Button4, "Mouse4"),
+    (MouseButton::Button5, "Mouse5"),
+    (MouseButton::Button6, "Mouse6"),
+    (MouseButton::Button7, "Mouse7"),
+    (MouseButton::Button8, "Mouse8"),
+];
+
+pub(crate) const GAMEPAD_BUTTONS: [(GamepadButton, &str); 15] = [
+    (GamepadButton::ButtonA, "GamepadA"),
+    (GamepadButton::ButtonB, "GamepadB"),
+    (GamepadButton::ButtonX, "GamepadX"),
+    (GamepadButton::ButtonY, "GamepadY"),
+    (GamepadButton::ButtonLeftBumper, "GamepadLeftBumper"),
+    (GamepadButton::ButtonRightBumper, "GamepadRightBumper"),
+    (GamepadButton::ButtonBack, "GamepadBack"),
+    (GamepadButton::ButtonStart, "GamepadStart"),
+    (GamepadButton::ButtonGuide, "GamepadGuide"),
+    (GamepadButton::ButtonLeftThumb, "GamepadLeftThumb"),
+    (GamepadButton::ButtonRightThumb, "GamepadRightThumb"),
+    (GamepadButton::ButtonDpadUp, "GamepadDpadUp"),
+    (GamepadButton::ButtonDpadRight, "GamepadDpadRight"),
+    (GamepadButton::ButtonDpadDown, "GamepadDpadDown"),
+    (GamepadButton::ButtonDpadLeft, "GamepadDpadLeft"),
+];
+
+const GAMEPAD_AXES: [(GamepadAxis, &str); 6] = [
+    (GamepadAxis::AxisLeftX, "GamepadLeftX"),
+    (GamepadAxis::AxisLeftY, "GamepadLeftY"),
+    (GamepadAxis::AxisRightX, "GamepadRightX"),
+    (GamepadAxis::AxisRightY, "GamepadRightY"),
+    (GamepadAxis::AxisLeftTrigger, "GamepadLeftTrigger"),
+    (GamepadAxis::AxisRightTrigger, "GamepadRightTrigger"),
+];
+
+impl Button {
+    // names are matched ignoring case
+    pub fn from_name(name: &str) -> Option<Button> {
+        if let Some(key) = KEYS
+            .iter()
+            .find(|key| format!("{:?}", key).eq_ignore_ascii_case(name))
+        {
+            return Some(Button::Key(*key));
+        }
+        if let Some((button, _)) = MOUSE_BUTTONS
+            .iter()
+            .find(|(_, n)| n.eq_ignore_ascii_case(name))
+        {
+            return Some(Button::Mouse(*button));
+        }
+        GAMEPAD_BUTTONS
+            .iter()
+            .find(|(_, n)| n.eq_ignore_ascii_case(name))
+            .map(|(button, _)| Button::Gamepad(*button))
+    }
+}
+
+impl fmt::Display for Button {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Button::Key(key) => write!(f, "{:?}", key),
+            Button::Mouse(button) => {
+                let (_, name) = MOUSE_BUTTONS.iter().find(|(b, _)| b == button).unwrap();
+                write!(f, "{}", name)
+            }
+            Button::Gamepad(button) => {
+                let (_, name) = GAMEPAD_BUTTONS.iter().find(|(b, _)| b == button).unwrap();
+                write!(f, "{}", name)
+            }
+        }
+    }
+}
+
+impl TryFrom<String> for Button {
+    type Error = String;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        Button::from_name(&name).ok_or_else(|| format!("unknown button \"{}\"", name))
+    }
+}
+
+impl From<Button> for String {
+    fn from(button: Button) -> Self {
+        button.to_string()
+    }
+}
+
+impl Analog {
+    pub fn from_name(name: &str) -> Option<Analog> {
+        let analog = match name.to_ascii_lowercase().as_str() {
+            "mousex" => Analog::MouseX,
+            "mousey" => Analog::MouseY,
+            "scrollx" => Analog::ScrollX,
+            "scrolly" => Analog::ScrollY,
+            _ => {
+                return GAMEPAD_AXES
+                    .iter()
+                    .find(|(_, n)| n.eq_ignore_ascii_case(name))
+                    .map(|(axis, _)| Analog::Gamepad(*axis))
+            }
+        };
+        Some(analog)
+    }
+}
+
+impl fmt::Display for Analog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Analog::MouseX => write!(f, "MouseX"),
+            Analog::MouseY => write!(f, "MouseY"),
+            Analog::ScrollX => write!(f, "ScrollX"),
+            Analog::ScrollY => write!(f, "ScrollY"),
+            Analog::Gamepad(axis) => {
+                let (_, name) = GAMEPAD_AXES.iter().find(|(a, _)| a == axis).unwrap();
+                write!(f, "{}", name)
+            }
+        }
+    }
+}
+
+impl TryFrom<String> for Analog {
+    type Error = String;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        Analog::from_name(&name).ok_or_else(|| format!("unknown analog input \"{}\"", name))
+    }
+}
+
+impl From<Analog> for String {
+    fn from(analog: Analog) -> Self {
+        analog.to_string()
+    }
+}
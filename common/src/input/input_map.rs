@@ -0,0 +1,590 @@
+use crate::{
+    asset::{AssetError, AssetResolver},
+    input::binding::{Analog, AxisBinding, Button, GAMEPAD_BUTTONS},
+};
+use glfw::{Action, GamepadAxis, GamepadState, JoystickId, Key, WindowEvent};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    error::Error,
+    fmt, fs, io, mem,
+    path::Path,
+};
+
+// actions every app gets, handled by `run`
+// closes the window
+pub const QUIT: &str = "quit";
+// saves the frame to a timestamped PNG
+pub const SCREENSHOT: &str = "screenshot";
+
+// the bindings, kept apart from the per-frame state so they can be saved and loaded
+// Example input.toml:
+//   dead_zone = 0.2
+//
+//   [actions]
+//   quit = ["Escape", "GamepadBack"]
+//   pause = ["P"]
+//
+//   [[axes.mix]]
+//   negative = "Down"
+//   positive = "Up"
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InputConfig {
+    #[serde(default)]
+    pub actions: BTreeMap<String, Vec<Button>>,
+    #[serde(default)]
+    pub axes: BTreeMap<String, Vec<AxisBinding>>,
+    // gamepad axes closer to the center than this read as 0, DEFAULT_DEAD_ZONE
+    // when not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dead_zone: Option<f32>,
+}
+
+pub const DEFAULT_DEAD_ZONE: f32 = 0.15;
+
+impl InputConfig {
+    pub fn dead_zone(&self) -> f32 {
+        self.dead_zone.unwrap_or(DEFAULT_DEAD_ZONE)
+    }
+}
+
+impl Default for InputConfig {
+    fn default() -> Self {
+        let mut actions = BTreeMap::new();
+        actions.insert(String::from(QUIT), vec![Button::Key(Key::Escape)]);
+        actions.insert(String::from(SCREENSHOT), vec![Button::Key(Key::F12)]);
+        InputConfig {
+            actions,
+            axes: BTreeMap::new(),
+            dead_zone: None,
+        }
+    }
+}
+
+// Maps raw input to named actions and axes.
+//
+// `update` is called once at the start of every frame, which takes a snapshot
+// of every bound button, so `pressed`/`released` are true for exactly one frame.
+// Events have to be passed to `handle_event` for scrolling and rebinding, and
+// so keys and mouse buttons pressed and released within one frame still count.
+pub struct InputMap {
+    pub config: InputConfig,
+    down: BTreeSet<Button>,
+    previous: BTreeSet<Button>,
+    // pressed and released according to the events before this frame
+    presses: BTreeSet<Button>,
+    releases: BTreeSet<Button>,
+    // the same since the last `update`
+    pending_presses: BTreeSet<Button>,
+    pending_releases: BTreeSet<Button>,
+    cursor: Option<(f64, f64)>,
+    mouse_delta: (f32, f32),
+    scroll: (f32, f32),
+    // scrolled since the last `update`
+    pending_scroll: (f32, f32),
+    gamepad: Option<GamepadState>,
+    // by `GamepadAxis as usize`, 0 without a gamepad
+    gamepad_axes: [f32; 6],
+    // the action that the next pressed key or mouse button is bound to
+    rebinding: Option<String>,
+}
+
+impl Default for InputMap {
+    fn default() -> Self {
+        InputMap::new(InputConfig::default())
+    }
+}
+
+impl InputMap {
+    pub fn new(config: InputConfig) -> Self {
+        InputMap {
+            config,
+            down: BTreeSet::new(),
+            previous: BTreeSet::new(),
+            presses: BTreeSet::new(),
+            releases: BTreeSet::new(),
+            pending_presses: BTreeSet::new(),
+            pending_releases: BTreeSet::new(),
+            cursor: None,
+            mouse_delta: (0.0, 0.0),
+            scroll: (0.0, 0.0),
+            pending_scroll: (0.0, 0.0),
+            gamepad: None,
+            gamepad_axes: [0.0; 6],
+            rebinding: None,
+        }
+    }
+
+    // read a TOML config, see `InputConfig`
+    pub fn load<P: AsRef<Path>>(assets: &AssetResolver, path: P) -> Result<InputMap, InputError> {
+        let path = assets.resolve(path)?;
+        let config = toml::from_str(&fs::read_to_string(path)?)?;
+        Ok(InputMap::new(config))
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), InputError> {
+        fs::write(path, toml::to_string_pretty(&self.config)?)?;
+        Ok(())
+    }
+
+    // add the actions and axes of another config, replacing any with the same
+    // name, and its dead zone if it has one
+    pub fn merge(&mut self, config: InputConfig) {
+        self.config.actions.extend(config.actions);
+        self.config.axes.extend(config.axes);
+        if config.dead_zone.is_some() {
+            self.config.dead_zone = config.dead_zone;
+        }
+    }
+
+    pub fn bind<B: Into<Button>>(&mut self, action: &str, button: B) -> &mut Self {
+        self.config
+            .actions
+            .entry(action.to_string())
+            .or_default()
+            .push(button.into());
+        self
+    }
+
+    pub fn bind_axis(&mut self, axis: &str, binding: AxisBinding) -> &mut Self {
+        self.config
+            .axes
+            .entry(axis.to_string())
+            .or_default()
+            .push(binding);
+        self
+    }
+
+    // replace every binding of `action`
+    pub fn rebind(&mut self, action: &str, buttons: Vec<Button>) {
+        self.config.actions.insert(action.to_string(), buttons);
+    }
+
+    pub fn unbind(&mut self, action: &str) {
+        self.config.actions.remove(action);
+    }
+
+    // bind `action` to whichever key or mouse button is pressed next
+    pub fn rebind_next(&mut self, action: &str) {
+        self.rebinding = Some(action.to_string());
+    }
+
+    // the action waiting for `rebind_next` to finish
+    pub fn rebinding(&self) -> Option<&str> {
+        self.rebinding.as_deref()
+    }
+
+    pub fn cancel_rebind(&mut self) {
+        self.rebinding = None;
+    }
+
+    pub fn handle_event(&mut self, event: &WindowEvent) {
+        let (button, action) = match *event {
+            WindowEvent::Scroll(x, y) => {
+                self.pending_scroll.0 += x as f32;
+                self.pending_scroll.1 += y as f32;
+                return;
+            }
+            WindowEvent::Key(key, _, action, _) if key != Key::Unknown => {
+                (Button::Key(key), action)
+            }
+            WindowEvent::MouseButton(button, action, _) => (Button::Mouse(button), action),
+            _ => return,
+        };
+
+        match action {
+            Action::Press => match self.rebinding.take() {
+                Some(rebinding) => {
+                    self.rebind(&rebinding, vec![button]);
+                    // already held, so the new binding doesn't fire straight away
+                    self.down.insert(button);
+                }
+                None => {
+                    self.pending_presses.insert(button);
+                }
+            },
+            Action::Release => {
+                self.pending_releases.insert(button);
+            }
+            Action::Repeat => {}
+        }
+    }
+
+    // snapshot the input for this frame
+    pub fn update(&mut self, window: &glfw::Window, glfw: &glfw::Glfw) {
+        self.gamepad = (0..16)
+            .filter_map(JoystickId::from_i32)
+            .map(|id| glfw.get_joystick(id))
+            .find(|joystick| joystick.is_gamepad())
+            .and_then(|joystick| joystick.get_gamepad_state());
+        let gamepad = self.gamepad;
+        self.gamepad_axes = std::array::from_fn(|i| {
+            let axis = GamepadAxis::from_i32(i as i32);
+            gamepad
+                .zip(axis)
+                .map_or(0.0, |(state, axis)| state.get_axis(axis))
+        });
+
+        let cursor = window.get_cursor_pos();
+        self.next_frame(cursor, |button| is_down(window, gamepad, button));
+    }
+
+    // the rest of `update` with the window read through `is_down`, so tests
+    // can run frames without one
+    fn next_frame(&mut self, cursor: (f64, f64), is_down: impl Fn(Button) -> bool) {
+        self.previous = mem::take(&mut self.down);
+        let bound: BTreeSet<Button> = self
+            .config
+            .actions
+            .values()
+            .flatten()
+            .copied()
+            .chain(
+                self.config
+                    .axes
+                    .values()
+                    .flatten()
+                    .flat_map(|binding| match binding {
+                        AxisBinding::Buttons { negative, positive } => vec![*negative, *positive],
+                        AxisBinding::Analog { .. } => vec![],
+                    }),
+            )
+            .collect();
+        self.down = bound
+            .into_iter()
+            .filter(|&button| is_down(button))
+            .collect();
+        self.presses = mem::take(&mut self.pending_presses);
+        self.releases = mem::take(&mut self.pending_releases);
+
+        self.mouse_delta = match self.cursor {
+            Some(last) => ((cursor.0 - last.0) as f32, (cursor.1 - last.1) as f32),
+            None => (0.0, 0.0),
+        };
+        self.cursor = Some(cursor);
+        self.scroll = mem::take(&mut self.pending_scroll);
+    }
+
+    fn buttons(&self, action: &str) -> &[Button] {
+        self.config
+            .actions
+            .get(action)
+            .map_or(&[], |buttons| buttons.as_slice())
+    }
+
+    // went down this frame, or went down and up again since the last one
+    pub fn pressed(&self, action: &str) -> bool {
+        self.buttons(action).iter().any(|b| {
+            (self.down.contains(b) && !self.previous.contains(b)) || self.presses.contains(b)
+        })
+    }
+
+    // down this frame, whenever it went down
+    pub fn held(&self, action: &str) -> bool {
+        self.buttons(action).iter().any(|b| self.down.contains(b))
+    }
+
+    // went up this frame
+    pub fn released(&self, action: &str) -> bool {
+        self.buttons(action).iter().any(|b| {
+            (!self.down.contains(b) && self.previous.contains(b)) || self.releases.contains(b)
+        })
+    }
+
+    // the sum of every binding of `axis`
+    // buttons and gamepad axes give -1..1, mouse and scroll give the movement
+    // since the last frame times `scale`
+    pub fn axis(&self, axis: &str) -> f32 {
+        let Some(bindings) = self.config.axes.get(axis) else {
+            return 0.0;
+        };
+        bindings
+            .iter()
+            .map(|binding| match binding {
+                AxisBinding::Buttons { negative, positive } => {
+                    let held = |b: &Button| self.down.contains(b) as i32 as f32;
+                    held(positive) - held(negative)
+                }
+                AxisBinding::Analog { analog, scale } => self.analog(*analog) * scale,
+            })
+            .sum()
+    }
+
+    pub fn analog(&self, analog: Analog) -> f32 {
+        match analog {
+            Analog::MouseX => self.mouse_delta.0,
+            Analog::MouseY => self.mouse_delta.1,
+            Analog::ScrollX => self.scroll.0,
+            Analog::ScrollY => self.scroll.1,
+            Analog::Gamepad(axis) => {
+                let value = self.gamepad_axes[axis as usize];
+                if value.abs() < self.config.dead_zone() {
+                    0.0
+                } else {
+                    value
+                }
+            }
+        }
+    }
+
+    // every gamepad button down this frame, bound or not, for rebinding menus
+    pub fn gamepad_buttons_down(&self) -> Vec<Button> {
+        GAMEPAD_BUTTONS
+            .iter()
+            .filter(|(button, _)| {
+                self.gamepad
+                    .is_some_and(|state| state.get_button_state(*button) == Action::Press)
+            })
+            .map(|(button, _)| Button::Gamepad(*button))
+            .collect()
+    }
+}
+
+fn is_down(window: &glfw::Window, gamepad: Option<GamepadState>, button: Button) -> bool {
+    match button {
+        Button::Key(key) => window.get_key(key) != Action::Release,
+        Button::Mouse(button) => window.get_mouse_button(button) == Action::Press,
+        Button::Gamepad(button) => {
+            gamepad.is_some_and(|state| state.get_button_state(button) == Action::Press)
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Asset(AssetError),
+    Io(io::Error),
+    Parse(toml::de::Error),
+    Write(toml::ser::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Asset(err) => write!(f, "{}", err),
+            InputError::Io(err) => write!(f, "{}", err),
+            InputError::Parse(err) => write!(f, "invalid input config: {}", err),
+            InputError::Write(err) => write!(f, "failed to write input config: {}", err),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+impl From<AssetError> for InputError {
+    fn from(err: AssetError) -> Self {
+        InputError::Asset(err)
+    }
+}
+
+impl From<io::Error> for InputError {
+    fn from(err: io::Error) -> Self {
+        InputError::Io(err)
+    }
+}
+
+impl From<toml::de::Error> for InputError {
+    fn from(err: toml::de::Error) -> Self {
+        InputError::Parse(err)
+    }
+}
+
+impl From<toml::ser::Error> for InputError {
+    fn from(err: toml::ser::Error) -> Self {
+        InputError::Write(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glfw::{Modifiers, MouseButton};
+
+    fn key(key: Key, action: Action) -> WindowEvent {
+        WindowEvent::Key(key, 0, action, Modifiers::empty())
+    }
+
+    fn map() -> InputMap {
+        let mut map = InputMap::new(InputConfig::default());
+        map.bind("jump", Key::Space)
+            .bind("fire", MouseButton::Button1)
+            .bind_axis("move", AxisBinding::buttons(Key::A, Key::D))
+            .bind_axis("zoom", AxisBinding::analog(Analog::ScrollY, 0.5));
+        map
+    }
+
+    #[test]
+    fn held_buttons_are_pressed_for_one_frame() {
+        let mut map = map();
+        map.next_frame((0.0, 0.0), |b| b == Button::Key(Key::Space));
+        assert!(map.pressed("jump") && map.held("jump"));
+        map.next_frame((0.0, 0.0), |b| b == Button::Key(Key::Space));
+        assert!(!map.pressed("jump") && map.held("jump"));
+        map.next_frame((0.0, 0.0), |_| false);
+        assert!(map.released("jump") && !map.held("jump"));
+        map.next_frame((0.0, 0.0), |_| false);
+        assert!(!map.released("jump"));
+    }
+
+    #[test]
+    fn a_press_and_release_within_one_frame_is_not_lost() {
+        let mut map = map();
+        map.handle_event(&key(Key::Space, Action::Press));
+        map.handle_event(&key(Key::Space, Action::Release));
+        map.handle_event(&WindowEvent::MouseButton(
+            MouseButton::Button1,
+            Action::Press,
+            Modifiers::empty(),
+        ));
+        map.handle_event(&WindowEvent::MouseButton(
+            MouseButton::Button1,
+            Action::Release,
+            Modifiers::empty(),
+        ));
+        // polling sees neither of them down
+        map.next_frame((0.0, 0.0), |_| false);
+        assert!(map.pressed("jump") && map.released("jump") && !map.held("jump"));
+        assert!(map.pressed("fire") && map.released("fire"));
+        map.next_frame((0.0, 0.0), |_| false);
+        assert!(!map.pressed("jump") && !map.released("jump"));
+    }
+
+    #[test]
+    fn repeats_are_not_presses() {
+        let mut map = map();
+        map.handle_event(&key(Key::Space, Action::Repeat));
+        map.next_frame((0.0, 0.0), |_| false);
+        assert!(!map.pressed("jump"));
+    }
+
+    #[test]
+    fn scroll_and_cursor_are_per_frame() {
+        let mut map = map();
+        map.handle_event(&WindowEvent::Scroll(0.0, 1.0));
+        map.handle_event(&WindowEvent::Scroll(1.0, 2.0));
+        map.next_frame((10.0, 10.0), |_| false);
+        assert_eq!(map.analog(Analog::ScrollX), 1.0);
+        assert_eq!(map.axis("zoom"), 1.5);
+        // no movement on the first frame
+        assert_eq!(map.analog(Analog::MouseX), 0.0);
+
+        map.next_frame((13.0, 6.0), |_| false);
+        assert_eq!(map.axis("zoom"), 0.0);
+        assert_eq!(map.analog(Analog::MouseX), 3.0);
+        assert_eq!(map.analog(Analog::MouseY), -4.0);
+    }
+
+    #[test]
+    fn button_axes_add_up() {
+        let mut map = map();
+        map.bind_axis("move", AxisBinding::buttons(Key::Left, Key::Right));
+        map.next_frame((0.0, 0.0), |b| b == Button::Key(Key::D));
+        assert_eq!(map.axis("move"), 1.0);
+        map.next_frame((0.0, 0.0), |b| {
+            b == Button::Key(Key::D) || b == Button::Key(Key::A)
+        });
+        assert_eq!(map.axis("move"), 0.0);
+        map.next_frame((0.0, 0.0), |b| {
+            b == Button::Key(Key::A) || b == Button::Key(Key::Left)
+        });
+        assert_eq!(map.axis("move"), -2.0);
+        assert_eq!(map.axis("unbound"), 0.0);
+    }
+
+    #[test]
+    fn gamepad_axes_have_a_dead_zone() {
+        let mut map = map();
+        map.bind_axis(
+            "turn",
+            AxisBinding::analog(Analog::Gamepad(GamepadAxis::AxisLeftX), 2.0),
+        );
+        map.gamepad_axes[GamepadAxis::AxisLeftX as usize] = 0.1;
+        assert_eq!(map.axis("turn"), 0.0);
+        map.gamepad_axes[GamepadAxis::AxisLeftX as usize] = -0.5;
+        assert_eq!(map.axis("turn"), -1.0);
+
+        map.config.dead_zone = Some(0.6);
+        assert_eq!(map.axis("turn"), 0.0);
+    }
+
+    #[test]
+    fn the_next_press_rebinds() {
+        let mut map = map();
+        map.rebind_next("jump");
+        assert_eq!(map.rebinding(), Some("jump"));
+        map.handle_event(&key(Key::W, Action::Press));
+        assert_eq!(map.rebinding(), None);
+        assert_eq!(map.config.actions["jump"], [Button::Key(Key::W)]);
+
+        // still held from the rebind, so it doesn't fire
+        map.next_frame((0.0, 0.0), |b| b == Button::Key(Key::W));
+        assert!(!map.pressed("jump") && map.held("jump"));
+    }
+
+    #[test]
+    fn merge_keeps_the_dead_zone_unless_set() {
+        let mut map = map();
+        map.config.dead_zone = Some(0.3);
+
+        let mut config: InputConfig = toml::from_str("[actions]\njump = [\"W\"]").unwrap();
+        map.merge(config.clone());
+        assert_eq!(map.config.dead_zone(), 0.3);
+        assert_eq!(map.config.actions["jump"], [Button::Key(Key::W)]);
+        // untouched actions and axes stay
+        assert_eq!(
+            map.config.actions["fire"],
+            [Button::Mouse(MouseButton::Button1)]
+        );
+        assert!(map.config.axes.contains_key("move"));
+
+        config.dead_zone = Some(0.05);
+        map.merge(config);
+        assert_eq!(map.config.dead_zone(), 0.05);
+    }
+
+    #[test]
+    fn rebound_configs_load_back() {
+        let dir = std::env::temp_dir().join(format!("common-input-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let mut map = map();
+        map.rebind("jump", vec![Button::Key(Key::W), Button::Key(Key::Up)]);
+        map.save(dir.join("input.toml")).unwrap();
+
+        // no dead zone written unless one was set
+        let saved = fs::read_to_string(dir.join("input.toml")).unwrap();
+        assert!(!saved.contains("dead_zone"), "{}", saved);
+        let loaded = InputMap::load(&AssetResolver::new([&dir]), "input.toml").unwrap();
+        assert_eq!(loaded.config, map.config);
+        assert_eq!(loaded.config.dead_zone(), DEFAULT_DEAD_ZONE);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn configs_parse_from_toml() {
+        let config: InputConfig = toml::from_str(
+            r#"
+            dead_zone = 0.2
+
+            [actions]
+            quit = ["Escape", "GamepadBack"]
+
+            [[axes.mix]]
+            negative = "Down"
+            positive = "Up"
+
+            [[axes.mix]]
+            analog = "ScrollY"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.dead_zone, Some(0.2));
+        assert_eq!(
+            config.axes["mix"],
+            [
+                AxisBinding::buttons(Key::Down, Key::Up),
+                AxisBinding::analog(Analog::ScrollY, 1.0),
+            ]
+        );
+        assert!(toml::from_str::<InputConfig>("[actions]\nquit = [\"Nope\"]").is_err());
+    }
+}
@@ -0,0 +1,5 @@
+pub mod binding;
+pub mod input_map;
+
+pub use binding::*;
+pub use input_map::*;
@@ -1 +1,16 @@
+pub mod app;
+pub mod asset;
+pub mod buffer;
+pub mod capabilities;
+pub mod chapter;
+pub mod debug_output;
+pub mod framebuffer;
+pub mod gl;
+pub mod input;
+pub mod mesh;
+pub mod recording;
+pub mod screenshot;
 pub mod shader;
+pub mod state_cache;
+pub mod texture;
+pub mod time;
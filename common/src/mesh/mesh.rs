@@ -0,0 +1,156 @@
+use crate::{
+    capabilities::Features,
+    gl::{self, types::*},
+    state_cache,
+};
+use std::{mem, ptr};
+
+// a vertex array with its vertex buffer and optional element buffer
+pub struct Mesh {
+    pub vao: GLuint,
+    pub vbo: GLuint,
+    // 0 when the mesh is drawn without indices
+    pub ebo: GLuint,
+    pub count: GLsizei,
+}
+
+impl Mesh {
+    // `layout` is the number of floats in each vertex attribute, in location order
+    // Example: interleaved position and texture coordinates
+    //   Mesh::new(gl, &vertices, &indices, &[3, 2])
+    pub fn new(gl: &mut gl::Gl, vertices: &[f32], indices: &[u32], layout: &[usize]) -> Mesh {
+        let floats_per_vertex: usize = layout.iter().sum();
+        let stride = (floats_per_vertex * mem::size_of::<GLfloat>()) as GLsizei;
+
+        // (location, floats, offset in floats) of each attribute
+        let mut attributes = Vec::with_capacity(layout.len());
+        let mut offset = 0;
+        for (location, &size) in layout.iter().enumerate() {
+            attributes.push((location as GLuint, size, offset));
+            offset += size;
+        }
+        let (vao, vbo, ebo) = if Features::current().direct_state_access {
+            unsafe { create_named(gl, vertices, indices, &attributes, stride) }
+        } else {
+            unsafe { create_bound(gl, vertices, indices, &attributes, stride) }
+        };
+
+        let count = if indices.is_empty() {
+            vertices.len() / floats_per_vertex.max(1)
+        } else {
+            indices.len()
+        };
+
+        Mesh {
+            vao,
+            vbo,
+            ebo,
+            count: count as GLsizei,
+        }
+    }
+
+    pub fn draw(&self, gl: &mut gl::Gl) {
+        state_cache::bind_vertex_array(gl, self.vao);
+        unsafe {
+            if self.ebo != 0 {
+                gl.DrawElements(gl::TRIANGLES, self.count, gl::UNSIGNED_INT, ptr::null());
+            } else {
+                gl.DrawArrays(gl::TRIANGLES, 0, self.count);
+            }
+        }
+    }
+}
+
+// GL 3.3: bind each object to edit it, the vertex array is unbound afterwards
+unsafe fn create_bound(
+    gl: &mut gl::Gl,
+    vertices: &[f32],
+    indices: &[u32],
+    attributes: &[(GLuint, usize, usize)],
+    stride: GLsizei,
+) -> (GLuint, GLuint, GLuint) {
+    let (mut vao, mut vbo, mut ebo) = (0, 0, 0);
+    gl.GenVertexArrays(1, &mut vao);
+    gl.GenBuffers(1, &mut vbo);
+    state_cache::bind_vertex_array(gl, vao);
+
+    state_cache::bind_buffer(gl, gl::ARRAY_BUFFER, vbo);
+    gl.BufferData(
+        gl::ARRAY_BUFFER,
+        mem::size_of_val(vertices) as GLsizeiptr,
+        vertices.as_ptr() as *const GLvoid,
+        gl::STATIC_DRAW,
+    );
+
+    if !indices.is_empty() {
+        gl.GenBuffers(1, &mut ebo);
+        state_cache::bind_buffer(gl, gl::ELEMENT_ARRAY_BUFFER, ebo);
+        gl.BufferData(
+            gl::ELEMENT_ARRAY_BUFFER,
+            mem::size_of_val(indices) as GLsizeiptr,
+            indices.as_ptr() as *const GLvoid,
+            gl::STATIC_DRAW,
+        );
+    }
+
+    for &(location, size, offset) in attributes {
+        gl.VertexAttribPointer(
+            location,
+            size as GLint,
+            gl::FLOAT,
+            gl::FALSE,
+            stride,
+            (offset * mem::size_of::<GLfloat>()) as *const GLvoid,
+        );
+        gl.EnableVertexAttribArray(location);
+    }
+
+    state_cache::bind_vertex_array(gl, 0);
+    (vao, vbo, ebo)
+}
+
+// GL 4.5 direct state access: the same objects, nothing gets bound
+unsafe fn create_named(
+    gl: &mut gl::Gl,
+    vertices: &[f32],
+    indices: &[u32],
+    attributes: &[(GLuint, usize, usize)],
+    stride: GLsizei,
+) -> (GLuint, GLuint, GLuint) {
+    let (mut vao, mut vbo, mut ebo) = (0, 0, 0);
+    gl.CreateVertexArrays(1, &mut vao);
+    gl.CreateBuffers(1, &mut vbo);
+    gl.NamedBufferData(
+        vbo,
+        mem::size_of_val(vertices) as GLsizeiptr,
+        vertices.as_ptr() as *const GLvoid,
+        gl::STATIC_DRAW,
+    );
+    // every attribute reads from binding 0
+    gl.VertexArrayVertexBuffer(vao, 0, vbo, 0, stride);
+
+    if !indices.is_empty() {
+        gl.CreateBuffers(1, &mut ebo);
+        gl.NamedBufferData(
+            ebo,
+            mem::size_of_val(indices) as GLsizeiptr,
+            indices.as_ptr() as *const GLvoid,
+            gl::STATIC_DRAW,
+        );
+        gl.VertexArrayElementBuffer(vao, ebo);
+    }
+
+    for &(location, size, offset) in attributes {
+        gl.VertexArrayAttribFormat(
+            vao,
+            location,
+            size as GLint,
+            gl::FLOAT,
+            gl::FALSE,
+            (offset * mem::size_of::<GLfloat>()) as GLuint,
+        );
+        gl.VertexArrayAttribBinding(vao, location, 0);
+        gl.EnableVertexArrayAttrib(vao, location);
+    }
+    (vao, vbo, ebo)
+}
@@ -0,0 +1,207 @@
+use crate::{
+    gl::{self, types::*},
+    recording::frame_sink::{FrameSink, RecordError},
+};
+use image::{imageops, RgbaImage};
+use std::{
+    collections::VecDeque,
+    path::PathBuf,
+    ptr, slice,
+    sync::mpsc::{self, SyncSender},
+    thread::{self, JoinHandle},
+};
+
+// readbacks in flight, a frame is mapped this many frames after it was drawn,
+// by then the GPU is long done with it and mapping doesn't stall
+const PIXEL_BUFFERS: usize = 3;
+// frames waiting to be encoded before `capture` blocks, bounds the memory a
+// slow disk can take up
+const MAX_QUEUED: usize = 8;
+
+// Records every frame it's given into a `FrameSink`.
+//
+// `capture` only starts an asynchronous copy of the frame into a pixel buffer
+// object, frames are read back a few frames later and encoded on a background
+// thread, so recording costs little more than the copy. Call `finish` to get
+// the last frames out and the file closed.
+pub struct FrameRecorder {
+    pbos: Vec<GLuint>,
+    next: usize,
+    // buffers holding frames that haven't been read back yet, oldest first
+    in_flight: VecDeque<GLuint>,
+    width: i32,
+    height: i32,
+    frames: Option<SyncSender<RgbaImage>>,
+    writer: Option<JoinHandle<Result<u64, RecordError>>>,
+    warned_size: bool,
+}
+
+impl FrameRecorder {
+    // record `width` x `height` frames to `path`, see `FrameSink` for the formats
+    pub fn new<P: Into<PathBuf>>(
+        gl: &mut gl::Gl,
+        path: P,
+        width: i32,
+        height: i32,
+        fps: u32,
+    ) -> Result<FrameRecorder, RecordError> {
+        let (width, height) = (width.max(1), height.max(1));
+        let mut sink = FrameSink::create(path, width as u32, height as u32, fps)?;
+
+        let mut pbos = vec![0; PIXEL_BUFFERS];
+        unsafe {
+            let mut previous = 0;
+            gl.GetIntegerv(gl::PIXEL_PACK_BUFFER_BINDING, &mut previous);
+            gl.GenBuffers(pbos.len() as GLsizei, pbos.as_mut_ptr());
+            for &pbo in &pbos {
+                gl.BindBuffer(gl::PIXEL_PACK_BUFFER, pbo);
+                gl.BufferData(
+                    gl::PIXEL_PACK_BUFFER,
+                    (width * height * 4) as GLsizeiptr,
+                    ptr::null(),
+                    gl::STREAM_READ,
+                );
+            }
+            gl.BindBuffer(gl::PIXEL_PACK_BUFFER, previous as GLuint);
+        }
+
+        let (frames, received) = mpsc::sync_channel::<RgbaImage>(MAX_QUEUED);
+        let writer = thread::Builder::new()
+            .name(String::from("frame-recorder"))
+            .spawn(move || {
+                let mut written = 0;
+                for frame in received {
+                    sink.write(&frame)?;
+                    written += 1;
+                }
+                sink.finish()?;
+                Ok(written)
+            })
+            .expect("failed to spawn frame recorder thread");
+
+        Ok(FrameRecorder {
+            pbos,
+            next: 0,
+            in_flight: VecDeque::new(),
+            width,
+            height,
+            frames: Some(frames),
+            writer: Some(writer),
+            warned_size: false,
+        })
+    }
+
+    // queue the color buffer of `framebuffer` (0 for the window's back buffer),
+    // call it after drawing and before the buffers are swapped
+    // frames of another size than the recording are skipped
+    pub fn capture(&mut self, gl: &mut gl::Gl, framebuffer: GLuint, width: i32, height: i32) {
+        if (width, height) != (self.width, self.height) {
+            if !self.warned_size {
+                log::warn!(
+                    "recording is {}x{}, skipping {}x{} frames",
+                    self.width,
+                    self.height,
+                    width,
+                    height
+                );
+                self.warned_size = true;
+            }
+            return;
+        }
+
+        let pbo = self.pbos[self.next];
+        self.next = (self.next + 1) % self.pbos.len();
+        unsafe {
+            let (mut read_framebuffer, mut pack_buffer, mut alignment) = (0, 0, 0);
+            gl.GetIntegerv(gl::READ_FRAMEBUFFER_BINDING, &mut read_framebuffer);
+            gl.GetIntegerv(gl::PIXEL_PACK_BUFFER_BINDING, &mut pack_buffer);
+            gl.GetIntegerv(gl::PACK_ALIGNMENT, &mut alignment);
+
+            gl.BindFramebuffer(gl::READ_FRAMEBUFFER, framebuffer);
+            // the read buffer belongs to the default framebuffer, put it back too
+            let mut read_buffer = 0;
+            if framebuffer == 0 {
+                gl.GetIntegerv(gl::READ_BUFFER, &mut read_buffer);
+                gl.ReadBuffer(gl::BACK);
+            }
+            gl.PixelStorei(gl::PACK_ALIGNMENT, 1);
+            gl.BindBuffer(gl::PIXEL_PACK_BUFFER, pbo);
+            // with a pack buffer bound the pointer is an offset into it and
+            // the call returns before the copy is done
+            gl.ReadPixels(
+                0,
+                0,
+                self.width,
+                self.height,
+                gl::RGBA,
+                gl::UNSIGNED_BYTE,
+                ptr::null_mut(),
+            );
+            self.in_flight.push_back(pbo);
+
+            if self.in_flight.len() == self.pbos.len() {
+                let oldest = self.in_flight.pop_front().unwrap();
+                self.read_back(gl, oldest);
+            }
+
+            if framebuffer == 0 {
+                gl.ReadBuffer(read_buffer as GLenum);
+            }
+            gl.PixelStorei(gl::PACK_ALIGNMENT, alignment);
+            gl.BindBuffer(gl::PIXEL_PACK_BUFFER, pack_buffer as GLuint);
+            gl.BindFramebuffer(gl::READ_FRAMEBUFFER, read_framebuffer as GLuint);
+        }
+    }
+
+    // write the frames still in flight, wait for the encoder and free the
+    // buffers, returns the number of frames written
+    pub fn finish(mut self, gl: &mut gl::Gl) -> Result<u64, RecordError> {
+        unsafe {
+            let mut pack_buffer = 0;
+            gl.GetIntegerv(gl::PIXEL_PACK_BUFFER_BINDING, &mut pack_buffer);
+            while let Some(pbo) = self.in_flight.pop_front() {
+                self.read_back(gl, pbo);
+            }
+            gl.BindBuffer(gl::PIXEL_PACK_BUFFER, pack_buffer as GLuint);
+            gl.DeleteBuffers(self.pbos.len() as GLsizei, self.pbos.as_ptr());
+        }
+
+        // closing the channel lets the writer run out and exit
+        self.frames.take();
+        match self.writer.take().map(JoinHandle::join) {
+            Some(Ok(result)) => result,
+            Some(Err(_)) => panic!("frame recorder thread panicked"),
+            None => Ok(0),
+        }
+    }
+
+    // map `pbo` and send its frame to the writer, leaves `pbo` bound
+    unsafe fn read_back(&mut self, gl: &mut gl::Gl, pbo: GLuint) {
+        let size = (self.width * self.height * 4) as usize;
+        gl.BindBuffer(gl::PIXEL_PACK_BUFFER, pbo);
+        let mapped = gl.MapBufferRange(
+            gl::PIXEL_PACK_BUFFER,
+            0,
+            size as GLsizeiptr,
+            gl::MAP_READ_BIT,
+        ) as *const u8;
+        if mapped.is_null() {
+            log::error!("failed to map a recorded frame, skipping it");
+            return;
+        }
+        let pixels = slice::from_raw_parts(mapped, size).to_vec();
+        gl.UnmapBuffer(gl::PIXEL_PACK_BUFFER);
+
+        let mut frame = RgbaImage::from_raw(self.width as u32, self.height as u32, pixels)
+            .expect("buffer matches frame size");
+        // GL's first row is the bottom one
+        imageops::flip_vertical_in_place(&mut frame);
+
+        // a closed channel means the writer failed, `finish` reports why
+        if let Some(frames) = &self.frames {
+            if frames.send(frame).is_err() {
+                self.frames = None;
+            }
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use image::{ImageError, RgbaImage};
+use std::{
+    error::Error,
+    fmt, fs,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+// Where recorded frames end up, picked from the path by `FrameSink::create`:
+// a `.y4m` file gets an uncompressed YUV 4:4:4 stream, anything else is a
+// directory of numbered PNGs.
+//   ffmpeg -i recording.y4m recording.mp4
+//   ffmpeg -framerate 60 -i frames/frame_%06d.png recording.mp4
+pub enum FrameSink {
+    PngSequence {
+        dir: PathBuf,
+        next: u64,
+    },
+    Y4m {
+        path: PathBuf,
+        writer: BufWriter<File>,
+        width: u32,
+        height: u32,
+    },
+}
+
+impl FrameSink {
+    // frames of `width` x `height`, `fps` only ends up in the Y4M header
+    pub fn create<P: Into<PathBuf>>(
+        path: P,
+        width: u32,
+        height: u32,
+        fps: u32,
+    ) -> Result<FrameSink, RecordError> {
+        let path = path.into();
+        let is_y4m = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("y4m"));
+
+        if !is_y4m {
+            fs::create_dir_all(&path).map_err(|err| RecordError::Io(path.clone(), err))?;
+            return Ok(FrameSink::PngSequence { dir: path, next: 0 });
+        }
+
+        let file = File::create(&path).map_err(|err| RecordError::Io(path.clone(), err))?;
+        let mut writer = BufWriter::new(file);
+        // progressive, square pixels, no chroma subsampling
+        writeln!(
+            writer,
+            "YUV4MPEG2 W{} H{} F{}:1 Ip A1:1 C444",
+            width, height, fps
+        )
+        .map_err(|err| RecordError::Io(path.clone(), err))?;
+        Ok(FrameSink::Y4m {
+            path,
+            writer,
+            width,
+            height,
+        })
+    }
+
+    // append a frame, top row first
+    pub fn write(&mut self, frame: &RgbaImage) -> Result<(), RecordError> {
+        match self {
+            FrameSink::PngSequence { dir, next } => {
+                let path = dir.join(format!("frame_{:06}.png", next));
+                *next += 1;
+                frame
+                    .save_with_format(&path, image::ImageFormat::Png)
+                    .map_err(|err| RecordError::Image(path, err))
+            }
+            FrameSink::Y4m {
+                path,
+                writer,
+                width,
+                height,
+            } => {
+                if frame.dimensions() != (*width, *height) {
+                    return Err(RecordError::SizeChanged {
+                        expected: (*width, *height),
+                        actual: frame.dimensions(),
+                    });
+                }
+                write_y4m_frame(writer, frame).map_err(|err| RecordError::Io(path.clone(), err))
+            }
+        }
+    }
+
+    // flush what's buffered, the sink can't be written to afterwards
+    pub fn finish(self) -> Result<(), RecordError> {
+        match self {
+            FrameSink::PngSequence { .. } => Ok(()),
+            FrameSink::Y4m {
+                path, mut writer, ..
+            } => writer.flush().map_err(|err| RecordError::Io(path, err)),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        match self {
+            FrameSink::PngSequence { dir, .. } => dir,
+            FrameSink::Y4m { path, .. } => path,
+        }
+    }
+}
+
+// one FRAME header and the Y, Cb and Cr planes, BT.601 limited range
+fn write_y4m_frame<W: Write>(writer: &mut W, frame: &RgbaImage) -> io::Result<()> {
+    let pixels = frame.pixels().len();
+    let mut planes = vec![0u8; pixels * 3];
+    let (y_plane, chroma) = planes.split_at_mut(pixels);
+    let (u_plane, v_plane) = chroma.split_at_mut(pixels);
+
+    for (i, pixel) in frame.pixels().enumerate() {
+        let [r, g, b, _] = pixel.0.map(|c| c as i32);
+        y_plane[i] = (((66 * r + 129 * g + 25 * b + 128) >> 8) + 16) as u8;
+        u_plane[i] = (((-38 * r - 74 * g + 112 * b + 128) >> 8) + 128) as u8;
+        v_plane[i] = (((112 * r - 94 * g - 18 * b + 128) >> 8) + 128) as u8;
+    }
+
+    writer.write_all(b"FRAME\n")?;
+    writer.write_all(&planes)
+}
+
+#[derive(Debug)]
+pub enum RecordError {
+    Io(PathBuf, io::Error),
+    Image(PathBuf, ImageError),
+    // Y4M streams can't change size halfway through
+    SizeChanged {
+        expected: (u32, u32),
+        actual: (u32, u32),
+    },
+}
+
+impl fmt::Display for RecordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecordError::Io(path, err) => write!(f, "failed to write {}: {}", path.display(), err),
+            RecordError::Image(path, err) => {
+                write!(f, "failed to write {}: {}", path.display(), err)
+            }
+            RecordError::SizeChanged { expected, actual } => write!(
+                f,
+                "frame is {}x{}, the recording is {}x{}",
+                actual.0, actual.1, expected.0, expected.1
+            ),
+        }
+    }
+}
+
+impl Error for RecordError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    fn temp_dir(test: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("common-sink-{}-{}", test, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn y4m_frames_are_planar_bt601() {
+        // white, black, red, blue in the top row, so the planes are in row order
+        let colors = [
+            [255, 255, 255, 255],
+            [0, 0, 0, 255],
+            [255, 0, 0, 255],
+            [0, 0, 255, 255],
+        ];
+        let frame = RgbaImage::from_fn(2, 2, |x, y| Rgba(colors[(y * 2 + x) as usize]));
+        let mut written = vec![];
+        write_y4m_frame(&mut written, &frame).unwrap();
+
+        let (header, planes) = written.split_at(6);
+        assert_eq!(header, b"FRAME\n");
+        // Y, then all of Cb, then all of Cr, a sample per pixel
+        assert_eq!(planes[0..4], [235, 16, 82, 41]);
+        assert_eq!(planes[4..8], [128, 128, 90, 240]);
+        assert_eq!(planes[8..12], [128, 128, 240, 110]);
+    }
+
+    #[test]
+    fn y4m_files_start_with_a_stream_header() {
+        let dir = temp_dir("y4m");
+        let path = dir.join("recording.Y4M");
+        let mut sink = FrameSink::create(&path, 3, 2, 30).unwrap();
+        assert_eq!(sink.path(), path);
+        sink.write(&RgbaImage::new(3, 2)).unwrap();
+        sink.write(&RgbaImage::new(3, 2)).unwrap();
+        match sink.write(&RgbaImage::new(2, 2)) {
+            Err(RecordError::SizeChanged { expected, actual }) => {
+                assert_eq!((expected, actual), ((3, 2), (2, 2)))
+            }
+            _ => panic!("expected SizeChanged"),
+        }
+        sink.finish().unwrap();
+
+        let header = "YUV4MPEG2 W3 H2 F30:1 Ip A1:1 C444\n";
+        let written = fs::read(&path).unwrap();
+        assert!(written.starts_with(header.as_bytes()));
+        assert_eq!(written.len(), header.len() + 2 * (6 + 3 * 2 * 3));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn other_paths_are_numbered_pngs() {
+        let dir = temp_dir("png").join("frames");
+        let mut sink = FrameSink::create(&dir, 2, 2, 30).unwrap();
+        let frame = RgbaImage::from_pixel(2, 2, Rgba([1, 2, 3, 255]));
+        sink.write(&frame).unwrap();
+        sink.write(&frame).unwrap();
+        sink.finish().unwrap();
+
+        assert!(dir.join("frame_000000.png").is_file());
+        let second = image::open(dir.join("frame_000001.png")).unwrap();
+        assert_eq!(second.to_rgba8(), frame);
+        fs::remove_dir_all(dir.parent().unwrap()).unwrap();
+    }
+}
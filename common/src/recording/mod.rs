@@ -0,0 +1,5 @@
+pub mod frame_recorder;
+pub mod frame_sink;
+
+pub use frame_recorder::*;
+pub use frame_sink::*;
@@ -0,0 +1,3 @@
+pub mod screenshot_writer;
+
+pub use screenshot_writer::*;
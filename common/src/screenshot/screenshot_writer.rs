@@ -0,0 +1,91 @@
+use crate::{
+    app::Window,
+    framebuffer::read_pixels,
+    gl::{self, types::*},
+};
+use image::{ImageError, RgbaImage};
+use std::{
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+// the frame that was just rendered, top row first
+// call it after drawing and before the buffers are swapped, `run` does this for
+// the screenshot hotkey
+pub fn capture(window: &mut Window) -> RgbaImage {
+    if let Some(target) = &window.render_target {
+        return target.read_pixels(&mut window.gl);
+    }
+
+    let (width, height) = window.framebuffer_size;
+    unsafe {
+        let mut previous = 0;
+        window
+            .gl
+            .GetIntegerv(gl::READ_FRAMEBUFFER_BINDING, &mut previous);
+        window.gl.BindFramebuffer(gl::READ_FRAMEBUFFER, 0);
+        // the read buffer belongs to the default framebuffer, put it back too
+        let mut read_buffer = 0;
+        window.gl.GetIntegerv(gl::READ_BUFFER, &mut read_buffer);
+        window.gl.ReadBuffer(gl::BACK);
+        let image = read_pixels(&mut window.gl, 0, 0, width, height);
+        window.gl.ReadBuffer(read_buffer as GLenum);
+        window
+            .gl
+            .BindFramebuffer(gl::READ_FRAMEBUFFER, previous as GLuint);
+        image
+    }
+}
+
+// capture the frame and write it to `path` as a PNG
+pub fn save_screenshot<P: AsRef<Path>>(window: &mut Window, path: P) -> Result<(), ImageError> {
+    capture(window).save_with_format(path, image::ImageFormat::Png)
+}
+
+// capture the frame into `dir`, named after the current time
+// Example: screenshot_2024-03-09_17-05-42.123.png
+pub fn save_timestamped_screenshot<P: AsRef<Path>>(
+    window: &mut Window,
+    dir: P,
+) -> Result<PathBuf, ImageError> {
+    let path = dir.as_ref().join(timestamped_name("screenshot", "png"));
+    save_screenshot(window, &path)?;
+    Ok(path)
+}
+
+// `<prefix>_<UTC date>_<UTC time>.<extension>`
+pub fn timestamped_name(prefix: &str, extension: &str) -> String {
+    let since_epoch = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    let seconds = since_epoch.as_secs();
+    let (year, month, day) = civil_from_days((seconds / 86_400) as i64);
+    let time_of_day = seconds % 86_400;
+    format!(
+        "{}_{:04}-{:02}-{:02}_{:02}-{:02}-{:02}.{:03}.{}",
+        prefix,
+        year,
+        month,
+        day,
+        time_of_day / 3600,
+        time_of_day / 60 % 60,
+        time_of_day % 60,
+        since_epoch.subsec_millis(),
+        extension
+    )
+}
+
+// days since 1970-01-01 to a gregorian (year, month, day)
+// http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + (month <= 2) as i64;
+    (year, month, day)
+}
@@ -1,6 +1,6 @@
 use std::ffi::CStr;
 
-use glad::gl33::{self as gl};
+use crate::{gl, state_cache};
 
 pub struct Shader {
     pub id: gl::types::GLuint,
@@ -8,7 +8,14 @@ pub struct Shader {
 
 impl Shader {
     pub fn use_program(&self, gl: &mut gl::Gl) {
-        unsafe { gl.UseProgram(self.id) };
+        state_cache::use_program(gl, self.id);
+    }
+
+    // run a compute program on `groups` work groups, see `ShaderBuilder::build_compute`
+    // writes to storage buffers and images need a glMemoryBarrier before they're read
+    pub fn dispatch(&self, gl: &mut gl::Gl, groups: [u32; 3]) {
+        self.use_program(gl);
+        unsafe { gl.DispatchCompute(groups[0], groups[1], groups[2]) };
     }
 
     pub fn set_bool(&self, gl: &mut gl::Gl, name: &CStr, value: bool) {
@@ -61,27 +68,27 @@ impl Shader {
                 gl.GetUniformLocation(self.id, name.as_ptr()),
                 1,
                 gl::FALSE,
-                mat[0].as_ptr(),
+                mat.as_ptr() as *const f32,
             )
         }
     }
     pub fn set_mat3(&self, gl: &mut gl::Gl, name: &CStr, mat: [[f32; 3]; 3]) {
         unsafe {
-            gl.UniformMatrix2fv(
+            gl.UniformMatrix3fv(
                 gl.GetUniformLocation(self.id, name.as_ptr()),
                 1,
                 gl::FALSE,
-                mat[0].as_ptr(),
+                mat.as_ptr() as *const f32,
             )
         }
     }
     pub fn set_mat4(&self, gl: &mut gl::Gl, name: &CStr, mat: [[f32; 4]; 4]) {
         unsafe {
-            gl.UniformMatrix2fv(
+            gl.UniformMatrix4fv(
                 gl.GetUniformLocation(self.id, name.as_ptr()),
                 1,
                 gl::FALSE,
-                mat[0].as_ptr(),
+                mat.as_ptr() as *const f32,
             )
         }
     }
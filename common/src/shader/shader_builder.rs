@@ -1,41 +1,111 @@
-use crate::shader::shader::Shader;
+use crate::{
+    asset::AssetResolver,
+    capabilities::Features,
+    debug_output::object_label,
+    gl::{self, types::*},
+    shader::shader::Shader,
+};
 use core::ffi::CStr;
-use glad::gl33::{self as gl, types::*};
 use std::{
     ffi::CString,
     fs::{self},
     io::{self},
+    path::Path,
     ptr,
 };
 
 #[derive(Default)]
 pub struct ShaderBuilder {
     id: gl::types::GLuint,
+    assets: AssetResolver,
 }
 
 impl ShaderBuilder {
-    pub fn build(&mut self, gl: &mut gl::Gl, vertex_path: &str, fragment_path: &str) -> Shader {
+    // shader paths are looked up through `assets` instead of the working directory
+    pub fn new(assets: AssetResolver) -> Self {
+        ShaderBuilder { id: 0, assets }
+    }
+
+    pub fn build<P: AsRef<Path>>(
+        &mut self,
+        gl: &mut gl::Gl,
+        vertex_path: P,
+        fragment_path: P,
+    ) -> Shader {
         match self.new_shader(gl, vertex_path, fragment_path) {
             Ok(_) => Shader { id: self.id },
             Err(err) => {
-                panic!("{:?}", err);
+                panic!("{}", err);
+            }
+        }
+    }
+
+    // a program with a single compute shader, needs GL 4.3
+    pub fn build_compute<P: AsRef<Path>>(&mut self, gl: &mut gl::Gl, compute_path: P) -> Shader {
+        assert!(
+            Features::current().compute_shaders,
+            "compute shaders need a GL 4.3 context, run with --gl 4.3 or newer"
+        );
+        match self.new_compute_shader(gl, compute_path) {
+            Ok(_) => Shader { id: self.id },
+            Err(err) => {
+                panic!("{}", err);
+            }
+        }
+    }
+
+    fn new_compute_shader<P: AsRef<Path>>(
+        &mut self,
+        gl: &mut gl::Gl,
+        compute_path: P,
+    ) -> Result<(), io::Error> {
+        let label = compute_path.as_ref().display().to_string();
+
+        let compute_code = fs::read_to_string(self.assets.resolve(compute_path)?)?;
+        let compute_cstring = CString::new(compute_code)?;
+        let compute_ptr = compute_cstring.as_ptr();
+
+        unsafe {
+            let compute = gl.CreateShader(gl::COMPUTE_SHADER);
+            gl.ShaderSource(compute, 1, &compute_ptr, ptr::null());
+            gl.CompileShader(compute);
+            if let Err(e) = self.check_compile_errors(gl, compute, gl::COMPUTE_SHADER) {
+                panic!("COMPUTE::{}", e);
             }
+            let id = gl.CreateProgram();
+            gl.AttachShader(id, compute);
+            gl.LinkProgram(id);
+            if let Err(e) = self.check_compile_errors(gl, id, gl::PROGRAM) {
+                panic!("PROGRAM::{}", e);
+            }
+            object_label(gl, gl::PROGRAM, id, &label);
+            self.id = id;
+            gl.DeleteShader(compute);
         }
+
+        Ok(())
     }
 
-    fn new_shader(
+    fn new_shader<P: AsRef<Path>>(
         &mut self,
         gl: &mut gl::Gl,
-        vertex_path: &str,
-        fragment_path: &str,
+        vertex_path: P,
+        fragment_path: P,
     ) -> Result<(), io::Error> {
+        // names the program in debug messages and graphics debuggers
+        let label = format!(
+            "{} + {}",
+            vertex_path.as_ref().display(),
+            fragment_path.as_ref().display()
+        );
+
         // get vertex source file
-        let vertex_code = fs::read_to_string(vertex_path)?;
+        let vertex_code = fs::read_to_string(self.assets.resolve(vertex_path)?)?;
         let vertex_cstring = CString::new(vertex_code)?;
         let vertex_ptr = vertex_cstring.as_ptr();
 
         // get fragment source file
-        let fragment_code = fs::read_to_string(fragment_path)?;
+        let fragment_code = fs::read_to_string(self.assets.resolve(fragment_path)?)?;
         let fragment_cstring = CString::new(fragment_code)?;
         let fragment_ptr = fragment_cstring.as_ptr();
 
@@ -67,7 +137,10 @@ impl ShaderBuilder {
             gl.AttachShader(id, fragment);
             gl.LinkProgram(id);
             match self.check_compile_errors(gl, id, gl::PROGRAM) {
-                Ok(_) => self.id = id,
+                Ok(_) => {
+                    object_label(gl, gl::PROGRAM, id, &label);
+                    self.id = id;
+                }
                 Err(e) => {
                     panic!("PROGRAM::{}", e);
                 }
@@ -91,7 +164,7 @@ impl ShaderBuilder {
         let mut info_log: Vec<i8> = Vec::with_capacity(1024);
 
         match type_ {
-            gl::VERTEX_SHADER | gl::FRAGMENT_SHADER => unsafe {
+            gl::VERTEX_SHADER | gl::FRAGMENT_SHADER | gl::COMPUTE_SHADER => unsafe {
                 gl.GetShaderiv(shader, gl::COMPILE_STATUS, &mut success);
                 if success == gl::FALSE as GLint {
                     gl.GetShaderInfoLog(
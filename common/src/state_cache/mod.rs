@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+pub mod state_cache;
+
+pub use state_cache::*;
@@ -0,0 +1,492 @@
+use crate::gl::{self, bindings, types::*};
+use glad::LoadReport;
+use std::{cell::RefCell, collections::HashMap, fmt, ops::Deref, os::raw::c_void};
+
+// Skips GL calls that would set state to what it already is. Each function
+// here stands in for the GL function of the same name, e.g.
+//   state_cache::use_program(gl, shader.id);
+//   state_cache::active_texture(gl, gl::TEXTURE1);
+//   state_cache::bind_texture(gl, gl::TEXTURE_2D, texture);
+//
+// The cache belongs to the context: `gl::Gl` is the bindings' `Gl` together
+// with its cache, so a new context starts out with nothing known. Plain calls
+// of the same GL functions (`gl.BindTexture(..)`) always reach GL and update
+// the cache on the way, and so do the glDelete* calls of the objects it
+// tracks, so mixing both is fine. Only state changed some other way, e.g.
+// glBindTextures or a replayed trace, leaves it wrong: call `invalidate`
+// afterwards.
+
+// the bindings plus what the cache knows of their context, derefs to the
+// bindings for everything else
+pub struct Gl {
+    raw: bindings::Gl,
+    cache: RefCell<Cache>,
+}
+
+// `bindings::load` wrapped with an empty cache
+pub fn load<F>(loadfn: F) -> (Gl, LoadReport)
+where
+    F: FnMut(&'static str) -> *const c_void,
+{
+    let (raw, report) = bindings::load(loadfn);
+    let gl = Gl {
+        raw,
+        cache: RefCell::new(Cache::default()),
+    };
+    (gl, report)
+}
+
+impl Deref for Gl {
+    type Target = bindings::Gl;
+
+    fn deref(&self) -> &bindings::Gl {
+        &self.raw
+    }
+}
+
+// calls issued vs skipped since the context was created or `reset_stats`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StateStats {
+    pub issued: u64,
+    pub skipped: u64,
+}
+
+impl fmt::Display for StateStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let total = (self.issued + self.skipped).max(1);
+        write!(
+            f,
+            "{} state changes issued, {} redundant ones skipped ({:.0}%)",
+            self.issued,
+            self.skipped,
+            100.0 * self.skipped as f64 / total as f64
+        )
+    }
+}
+
+// None or a missing key means unknown
+#[derive(Default)]
+struct Cache {
+    program: Option<GLuint>,
+    vertex_array: Option<GLuint>,
+    // by target, except GL_ELEMENT_ARRAY_BUFFER
+    buffers: HashMap<GLenum, GLuint>,
+    // by (target, index), glBindBufferBase
+    indexed_buffers: HashMap<(GLenum, GLuint), GLuint>,
+    // the element buffer is part of the vertex array, by vertex array
+    element_buffers: HashMap<GLuint, GLuint>,
+    // GL_TEXTURE0 + unit
+    active_texture: Option<GLenum>,
+    // by (unit, target)
+    textures: HashMap<(GLenum, GLenum), GLuint>,
+    enabled: HashMap<GLenum, bool>,
+    blend_func: Option<(GLenum, GLenum)>,
+    depth_func: Option<GLenum>,
+    depth_mask: Option<bool>,
+    cull_face: Option<GLenum>,
+    viewport: Option<[GLint; 4]>,
+    stats: StateStats,
+}
+
+// what each call changes, true if it's new
+impl Cache {
+    fn use_program(&mut self, program: GLuint) -> bool {
+        replace(&mut self.program, program)
+    }
+
+    fn bind_vertex_array(&mut self, array: GLuint) -> bool {
+        replace(&mut self.vertex_array, array)
+    }
+
+    fn bind_buffer(&mut self, target: GLenum, buffer: GLuint) -> bool {
+        match (target, self.vertex_array) {
+            (gl::ELEMENT_ARRAY_BUFFER, Some(array)) => {
+                self.element_buffers.insert(array, buffer) != Some(buffer)
+            }
+            // no idea which vertex array it goes to
+            (gl::ELEMENT_ARRAY_BUFFER, None) => true,
+            _ => self.buffers.insert(target, buffer) != Some(buffer),
+        }
+    }
+
+    fn bind_buffer_base(&mut self, target: GLenum, index: GLuint, buffer: GLuint) -> bool {
+        let changed = self.indexed_buffers.insert((target, index), buffer) != Some(buffer);
+        if changed {
+            self.buffers.insert(target, buffer);
+        }
+        changed
+    }
+
+    fn bind_texture(&mut self, target: GLenum, texture: GLuint) -> bool {
+        match self.active_texture {
+            Some(unit) => self.textures.insert((unit, target), texture) != Some(texture),
+            None => {
+                // it could be any unit
+                self.textures.retain(|&(_, bound), _| bound != target);
+                true
+            }
+        }
+    }
+
+    fn bind_texture_unit(&mut self, unit: GLuint, target: GLenum, texture: GLuint) -> bool {
+        self.textures.insert((gl::TEXTURE0 + unit, target), texture) != Some(texture)
+    }
+
+    // deleted names can come back from the next glGen*/glCreate*, forget
+    // wherever they were bound
+    fn forget_buffers(&mut self, deleted: &[GLuint]) {
+        self.buffers.retain(|_, buffer| !deleted.contains(buffer));
+        self.indexed_buffers
+            .retain(|_, buffer| !deleted.contains(buffer));
+        self.element_buffers
+            .retain(|_, buffer| !deleted.contains(buffer));
+    }
+
+    fn forget_vertex_arrays(&mut self, deleted: &[GLuint]) {
+        if self
+            .vertex_array
+            .is_some_and(|array| deleted.contains(&array))
+        {
+            self.vertex_array = None;
+        }
+        self.element_buffers
+            .retain(|array, _| !deleted.contains(array));
+    }
+
+    fn forget_textures(&mut self, deleted: &[GLuint]) {
+        self.textures
+            .retain(|_, texture| !deleted.contains(texture));
+    }
+}
+
+// store `value`, true if it's new
+fn replace<T: PartialEq + Copy>(slot: &mut Option<T>, value: T) -> bool {
+    slot.replace(value) != Some(value)
+}
+
+// `n` names from a glDelete* call
+unsafe fn names<'a>(n: GLsizei, names: *const GLuint) -> &'a [GLuint] {
+    if names.is_null() || n <= 0 {
+        return &[];
+    }
+    std::slice::from_raw_parts(names, n as usize)
+}
+
+impl Gl {
+    // runs `call` when `update` says the state changed, GL is called outside the
+    // borrow in case it calls back into the cache (e.g. a debug callback)
+    fn cached(&self, update: impl FnOnce(&mut Cache) -> bool, call: impl FnOnce(&bindings::Gl)) {
+        let changed = {
+            let mut cache = self.cache.borrow_mut();
+            let changed = update(&mut cache);
+            match changed {
+                true => cache.stats.issued += 1,
+                false => cache.stats.skipped += 1,
+            }
+            changed
+        };
+        if changed {
+            call(&self.raw);
+        }
+    }
+
+    // for the plain calls below, which always go to GL
+    fn record(&self, update: impl FnOnce(&mut Cache)) {
+        update(&mut self.cache.borrow_mut());
+    }
+}
+
+// The plain GL calls of the state the cache tracks, same as the bindings' but
+// keeping the cache up to date. They shadow the bindings' through `Deref`, and
+// like them report the caller when a call fails with glad's debug feature.
+#[allow(non_snake_case, clippy::missing_safety_doc)]
+impl Gl {
+    #[track_caller]
+    pub unsafe fn UseProgram(&self, program: GLuint) {
+        self.record(|cache| {
+            cache.use_program(program);
+        });
+        self.raw.UseProgram(program)
+    }
+
+    #[track_caller]
+    pub unsafe fn BindVertexArray(&self, array: GLuint) {
+        self.record(|cache| {
+            cache.bind_vertex_array(array);
+        });
+        self.raw.BindVertexArray(array)
+    }
+
+    #[track_caller]
+    pub unsafe fn BindBuffer(&self, target: GLenum, buffer: GLuint) {
+        self.record(|cache| {
+            cache.bind_buffer(target, buffer);
+        });
+        self.raw.BindBuffer(target, buffer)
+    }
+
+    #[track_caller]
+    pub unsafe fn BindBufferBase(&self, target: GLenum, index: GLuint, buffer: GLuint) {
+        self.record(|cache| {
+            cache.bind_buffer_base(target, index, buffer);
+        });
+        self.raw.BindBufferBase(target, index, buffer)
+    }
+
+    #[track_caller]
+    pub unsafe fn BindBufferRange(
+        &self,
+        target: GLenum,
+        index: GLuint,
+        buffer: GLuint,
+        offset: GLintptr,
+        size: GLsizeiptr,
+    ) {
+        // the same buffer at another offset isn't the same binding
+        self.record(|cache| {
+            cache.indexed_buffers.remove(&(target, index));
+            cache.buffers.insert(target, buffer);
+        });
+        self.raw
+            .BindBufferRange(target, index, buffer, offset, size)
+    }
+
+    #[track_caller]
+    pub unsafe fn ActiveTexture(&self, texture: GLenum) {
+        self.record(|cache| {
+            cache.active_texture = Some(texture);
+        });
+        self.raw.ActiveTexture(texture)
+    }
+
+    #[track_caller]
+    pub unsafe fn BindTexture(&self, target: GLenum, texture: GLuint) {
+        self.record(|cache| {
+            cache.bind_texture(target, texture);
+        });
+        self.raw.BindTexture(target, texture)
+    }
+
+    #[track_caller]
+    pub unsafe fn BindTextureUnit(&self, unit: GLuint, texture: GLuint) {
+        // the target is the texture's, which only GL knows
+        self.record(|cache| {
+            let unit = gl::TEXTURE0 + unit;
+            cache.textures.retain(|&(bound, _), _| bound != unit);
+        });
+        self.raw.BindTextureUnit(unit, texture)
+    }
+
+    #[track_caller]
+    pub unsafe fn Enable(&self, cap: GLenum) {
+        self.record(|cache| {
+            cache.enabled.insert(cap, true);
+        });
+        self.raw.Enable(cap)
+    }
+
+    #[track_caller]
+    pub unsafe fn Disable(&self, cap: GLenum) {
+        self.record(|cache| {
+            cache.enabled.insert(cap, false);
+        });
+        self.raw.Disable(cap)
+    }
+
+    #[track_caller]
+    pub unsafe fn BlendFunc(&self, sfactor: GLenum, dfactor: GLenum) {
+        self.record(|cache| {
+            cache.blend_func = Some((sfactor, dfactor));
+        });
+        self.raw.BlendFunc(sfactor, dfactor)
+    }
+
+    #[track_caller]
+    pub unsafe fn BlendFuncSeparate(
+        &self,
+        sfactor_rgb: GLenum,
+        dfactor_rgb: GLenum,
+        sfactor_alpha: GLenum,
+        dfactor_alpha: GLenum,
+    ) {
+        self.record(|cache| {
+            let same = (sfactor_rgb, dfactor_rgb) == (sfactor_alpha, dfactor_alpha);
+            cache.blend_func = same.then_some((sfactor_rgb, dfactor_rgb));
+        });
+        self.raw
+            .BlendFuncSeparate(sfactor_rgb, dfactor_rgb, sfactor_alpha, dfactor_alpha)
+    }
+
+    #[track_caller]
+    pub unsafe fn DepthFunc(&self, func: GLenum) {
+        self.record(|cache| {
+            cache.depth_func = Some(func);
+        });
+        self.raw.DepthFunc(func)
+    }
+
+    #[track_caller]
+    pub unsafe fn DepthMask(&self, flag: GLboolean) {
+        self.record(|cache| {
+            cache.depth_mask = Some(flag != gl::FALSE);
+        });
+        self.raw.DepthMask(flag)
+    }
+
+    #[track_caller]
+    pub unsafe fn CullFace(&self, mode: GLenum) {
+        self.record(|cache| {
+            cache.cull_face = Some(mode);
+        });
+        self.raw.CullFace(mode)
+    }
+
+    #[track_caller]
+    pub unsafe fn Viewport(&self, x: GLint, y: GLint, width: GLsizei, height: GLsizei) {
+        self.record(|cache| {
+            cache.viewport = Some([x, y, width, height]);
+        });
+        self.raw.Viewport(x, y, width, height)
+    }
+
+    // a deleted program stays in use until another one is, nothing to forget
+    #[track_caller]
+    pub unsafe fn DeleteBuffers(&self, n: GLsizei, buffers: *const GLuint) {
+        self.record(|cache| cache.forget_buffers(names(n, buffers)));
+        self.raw.DeleteBuffers(n, buffers)
+    }
+
+    #[track_caller]
+    pub unsafe fn DeleteVertexArrays(&self, n: GLsizei, arrays: *const GLuint) {
+        self.record(|cache| cache.forget_vertex_arrays(names(n, arrays)));
+        self.raw.DeleteVertexArrays(n, arrays)
+    }
+
+    #[track_caller]
+    pub unsafe fn DeleteTextures(&self, n: GLsizei, textures: *const GLuint) {
+        self.record(|cache| cache.forget_textures(names(n, textures)));
+        self.raw.DeleteTextures(n, textures)
+    }
+}
+
+pub fn use_program(gl: &mut gl::Gl, program: GLuint) {
+    gl.cached(
+        |cache| cache.use_program(program),
+        |gl| unsafe { gl.UseProgram(program) },
+    );
+}
+
+pub fn bind_vertex_array(gl: &mut gl::Gl, array: GLuint) {
+    gl.cached(
+        |cache| cache.bind_vertex_array(array),
+        |gl| unsafe { gl.BindVertexArray(array) },
+    );
+}
+
+pub fn bind_buffer(gl: &mut gl::Gl, target: GLenum, buffer: GLuint) {
+    gl.cached(
+        |cache| cache.bind_buffer(target, buffer),
+        |gl| unsafe { gl.BindBuffer(target, buffer) },
+    );
+}
+
+// binds to `target` and to binding point `index` of it, like glBindBufferBase
+pub fn bind_buffer_base(gl: &mut gl::Gl, target: GLenum, index: GLuint, buffer: GLuint) {
+    gl.cached(
+        |cache| cache.bind_buffer_base(target, index, buffer),
+        |gl| unsafe { gl.BindBufferBase(target, index, buffer) },
+    );
+}
+
+// `texture` is GL_TEXTURE0 + unit, like glActiveTexture
+pub fn active_texture(gl: &mut gl::Gl, texture: GLenum) {
+    gl.cached(
+        |cache| replace(&mut cache.active_texture, texture),
+        |gl| unsafe { gl.ActiveTexture(texture) },
+    );
+}
+
+// binds to the active texture unit, like glBindTexture
+pub fn bind_texture(gl: &mut gl::Gl, target: GLenum, texture: GLuint) {
+    gl.cached(
+        |cache| cache.bind_texture(target, texture),
+        |gl| unsafe { gl.BindTexture(target, texture) },
+    );
+}
+
+// glBindTextureUnit, GL 4.5: binds to `unit` without making it the active one
+// `target` is the texture's, GL takes it from the texture itself
+pub fn bind_texture_unit(gl: &mut gl::Gl, unit: GLuint, target: GLenum, texture: GLuint) {
+    gl.cached(
+        |cache| cache.bind_texture_unit(unit, target, texture),
+        |gl| unsafe { gl.BindTextureUnit(unit, texture) },
+    );
+}
+
+pub fn enable(gl: &mut gl::Gl, cap: GLenum) {
+    gl.cached(
+        |cache| cache.enabled.insert(cap, true) != Some(true),
+        |gl| unsafe { gl.Enable(cap) },
+    );
+}
+
+pub fn disable(gl: &mut gl::Gl, cap: GLenum) {
+    gl.cached(
+        |cache| cache.enabled.insert(cap, false) != Some(false),
+        |gl| unsafe { gl.Disable(cap) },
+    );
+}
+
+pub fn blend_func(gl: &mut gl::Gl, sfactor: GLenum, dfactor: GLenum) {
+    gl.cached(
+        |cache| replace(&mut cache.blend_func, (sfactor, dfactor)),
+        |gl| unsafe { gl.BlendFunc(sfactor, dfactor) },
+    );
+}
+
+pub fn depth_func(gl: &mut gl::Gl, func: GLenum) {
+    gl.cached(
+        |cache| replace(&mut cache.depth_func, func),
+        |gl| unsafe { gl.DepthFunc(func) },
+    );
+}
+
+pub fn depth_mask(gl: &mut gl::Gl, flag: bool) {
+    gl.cached(
+        |cache| replace(&mut cache.depth_mask, flag),
+        |gl| unsafe { gl.DepthMask(flag as GLboolean) },
+    );
+}
+
+pub fn cull_face(gl: &mut gl::Gl, mode: GLenum) {
+    gl.cached(
+        |cache| replace(&mut cache.cull_face, mode),
+        |gl| unsafe { gl.CullFace(mode) },
+    );
+}
+
+pub fn viewport(gl: &mut gl::Gl, x: GLint, y: GLint, width: GLsizei, height: GLsizei) {
+    gl.cached(
+        |cache| replace(&mut cache.viewport, [x, y, width, height]),
+        |gl| unsafe { gl.Viewport(x, y, width, height) },
+    );
+}
+
+// forget all state, the next call of each function goes to GL again
+// the stats are kept
+pub fn invalidate(gl: &mut gl::Gl) {
+    let mut cache = gl.cache.borrow_mut();
+    let stats = cache.stats;
+    *cache = Cache {
+        stats,
+        ..Cache::default()
+    };
+}
+
+pub fn stats(gl: &gl::Gl) -> StateStats {
+    gl.cache.borrow().stats
+}
+
+pub fn reset_stats(gl: &mut gl::Gl) {
+    gl.cache.borrow_mut().stats = StateStats::default();
+}
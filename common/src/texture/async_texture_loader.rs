@@ -0,0 +1,231 @@
+use crate::{
+    asset::AssetResolver,
+    gl::{self, types::*},
+    texture::{
+        texture::Texture,
+        texture_builder::{upload_image, upload_image_into, TextureParams},
+        texture_loader::{load_image, TextureError},
+    },
+};
+use image::{DynamicImage, ImageError, Rgba, RgbaImage};
+use std::{
+    cell::Cell,
+    collections::HashMap,
+    path::{Path, PathBuf},
+    rc::Weak,
+    sync::{
+        mpsc::{self, Receiver, Sender},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+};
+
+struct Job {
+    ticket: u64,
+    path: PathBuf,
+}
+
+struct Decoded {
+    ticket: u64,
+    image: Result<DynamicImage, ImageError>,
+}
+
+// where a queued image goes, kept on the GL thread
+struct Target {
+    id: GLuint,
+    path: PathBuf,
+    params: TextureParams,
+    // dead once the texture is dropped, its name may be deleted or belong to
+    // another object by the time the image is decoded
+    size: Weak<Cell<(u32, u32)>>,
+}
+
+// Decodes images on a pool of worker threads and uploads them on the GL thread.
+//
+// `load`/`load_into` return straight away with a small checkerboard placeholder
+// in the texture, `poll` must be called from the frame loop to upload whatever
+// finished decoding. The texture name never changes, so it can be bound while
+// loading, and its size changes to the image's once uploaded. Images of
+// textures dropped in the meantime are thrown away.
+pub struct AsyncTextureLoader {
+    jobs: Option<Sender<Job>>,
+    decoded: Receiver<Decoded>,
+    workers: Vec<JoinHandle<()>>,
+    targets: HashMap<u64, Target>,
+    next_ticket: u64,
+}
+
+impl AsyncTextureLoader {
+    // one worker per core, capped at 4
+    pub fn new(assets: AssetResolver) -> Self {
+        let threads = thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1)
+            .min(4);
+        Self::with_threads(assets, threads)
+    }
+
+    pub fn with_threads(assets: AssetResolver, threads: usize) -> Self {
+        let (job_sender, job_receiver) = mpsc::channel::<Job>();
+        let (decoded_sender, decoded) = mpsc::channel();
+        let job_receiver = Arc::new(Mutex::new(job_receiver));
+
+        let workers = (0..threads.max(1))
+            .map(|i| {
+                let jobs = Arc::clone(&job_receiver);
+                let decoded = decoded_sender.clone();
+                let assets = assets.clone();
+                thread::Builder::new()
+                    .name(format!("texture-decoder-{}", i))
+                    .spawn(move || decode_worker(&assets, &jobs, &decoded))
+                    .expect("failed to spawn texture decoder thread")
+            })
+            .collect();
+
+        AsyncTextureLoader {
+            jobs: Some(job_sender),
+            decoded,
+            workers,
+            targets: HashMap::new(),
+            next_ticket: 0,
+        }
+    }
+
+    // create a texture with `params` and start decoding `path` into it
+    pub fn load<P: AsRef<Path>>(
+        &mut self,
+        gl: &mut gl::Gl,
+        path: P,
+        params: TextureParams,
+    ) -> Texture {
+        let texture = upload_image(gl, placeholder(), &params);
+        self.queue(&texture, path.as_ref(), params);
+        texture
+    }
+
+    // stream `path` into a texture whose sampling state was already set up
+    // only `flip_vertically` and `generate_mipmaps` of `params` are used
+    pub fn load_into<P: AsRef<Path>>(
+        &mut self,
+        gl: &mut gl::Gl,
+        texture: &Texture,
+        path: P,
+        params: TextureParams,
+    ) {
+        let placeholder = upload_image_into(gl, texture.id, placeholder(), &params);
+        texture.set_size(placeholder.width(), placeholder.height());
+        self.queue(texture, path.as_ref(), params);
+    }
+
+    // number of images queued or decoding that have not been uploaded yet
+    pub fn pending(&self) -> usize {
+        self.targets.len()
+    }
+
+    pub fn is_idle(&self) -> bool {
+        self.targets.is_empty()
+    }
+
+    // upload every image decoded since the last call, without blocking
+    // returns the number uploaded, or the first image that failed to load
+    pub fn poll(&mut self, gl: &mut gl::Gl) -> Result<usize, TextureError> {
+        let mut uploaded = 0;
+        while let Ok(decoded) = self.decoded.try_recv() {
+            if self.upload(gl, decoded)? {
+                uploaded += 1;
+            }
+        }
+        Ok(uploaded)
+    }
+
+    // block until every queued image is uploaded, e.g. before rendering a test frame
+    pub fn finish(&mut self, gl: &mut gl::Gl) -> Result<usize, TextureError> {
+        let mut uploaded = 0;
+        while !self.targets.is_empty() {
+            match self.decoded.recv() {
+                Ok(decoded) => {
+                    if self.upload(gl, decoded)? {
+                        uploaded += 1;
+                    }
+                }
+                Err(_) => break,
+            }
+        }
+        Ok(uploaded)
+    }
+
+    fn queue(&mut self, texture: &Texture, path: &Path, params: TextureParams) {
+        let Some(jobs) = &self.jobs else {
+            return;
+        };
+        let ticket = self.next_ticket;
+        self.next_ticket += 1;
+        let job = Job {
+            ticket,
+            path: path.to_path_buf(),
+        };
+        jobs.send(job).expect("texture decoder threads exited");
+        let target = Target {
+            id: texture.id,
+            path: path.to_path_buf(),
+            params,
+            size: texture.size_ref(),
+        };
+        self.targets.insert(ticket, target);
+    }
+
+    // false when the texture was dropped while its image was decoding
+    fn upload(&mut self, gl: &mut gl::Gl, decoded: Decoded) -> Result<bool, TextureError> {
+        let Some(target) = self.targets.remove(&decoded.ticket) else {
+            return Ok(false);
+        };
+        let Some(size) = target.size.upgrade() else {
+            return Ok(false);
+        };
+        let image = decoded.image.map_err(|source| TextureError {
+            path: target.path,
+            source,
+        })?;
+        let texture = upload_image_into(gl, target.id, image, &target.params);
+        size.set((texture.width(), texture.height()));
+        Ok(true)
+    }
+}
+
+impl Drop for AsyncTextureLoader {
+    fn drop(&mut self) {
+        // closing the job channel lets the workers run out and exit
+        self.jobs.take();
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+fn decode_worker(assets: &AssetResolver, jobs: &Mutex<Receiver<Job>>, decoded: &Sender<Decoded>) {
+    loop {
+        let job = match jobs.lock() {
+            Ok(receiver) => receiver.recv(),
+            Err(_) => return,
+        };
+        let Ok(job) = job else {
+            return;
+        };
+        let image = load_image(assets, &job.path);
+        let result = Decoded {
+            ticket: job.ticket,
+            image,
+        };
+        if decoded.send(result).is_err() {
+            return;
+        }
+    }
+}
+
+// 2x2 magenta and black checkerboard, hard to mistake for a real texture
+fn placeholder() -> DynamicImage {
+    let magenta = Rgba([255, 0, 255, 255]);
+    let black = Rgba([0, 0, 0, 255]);
+    let image = RgbaImage::from_fn(2, 2, |x, y| if (x + y) % 2 == 0 { magenta } else { black });
+    DynamicImage::ImageRgba8(image)
+}
@@ -0,0 +1,463 @@
+use crate::{
+    asset::{AssetError, AssetResolver},
+    gl,
+    texture::{
+        skyline_packer::SkylinePacker,
+        texture::Texture,
+        texture_builder::{upload_image, TextureParams},
+        texture_loader::load_image,
+    },
+};
+use image::{imageops, DynamicImage, ImageError, RgbaImage};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, error::Error, fmt, fs, io, path::Path};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AtlasConfig {
+    pub page_width: u32,
+    pub page_height: u32,
+    // transparent pixels left between neighbouring images
+    pub padding: u32,
+    // pixels of each image's edge repeated outwards, so linear filtering and
+    // mipmaps sample the image's own border instead of its neighbour
+    pub extrude: u32,
+}
+
+impl Default for AtlasConfig {
+    fn default() -> Self {
+        AtlasConfig {
+            page_width: 1024,
+            page_height: 1024,
+            padding: 2,
+            extrude: 1,
+        }
+    }
+}
+
+// texture coordinates of an image inside its page
+// v is measured from the page's first row, which is where GL puts t = 0 when
+// the page is uploaded without flipping
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UvRect {
+    pub min: [f32; 2],
+    pub max: [f32; 2],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AtlasRegion {
+    pub page: usize,
+    // pixel rectangle of the image, without padding or extrusion
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+    pub uv: UvRect,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AtlasPage {
+    pub width: u32,
+    pub height: u32,
+    // image file relative to the layout file, empty until the atlas is saved
+    pub file: String,
+}
+
+// everything needed to use a baked atlas, stored as JSON next to its page images
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AtlasLayout {
+    pub config: AtlasConfig,
+    pub pages: Vec<AtlasPage>,
+    pub regions: BTreeMap<String, AtlasRegion>,
+}
+
+// many small images packed into one or more pages
+pub struct Atlas {
+    pub layout: AtlasLayout,
+    pub pages: Vec<RgbaImage>,
+}
+
+impl Atlas {
+    pub fn region(&self, name: &str) -> Option<&AtlasRegion> {
+        self.layout.regions.get(name)
+    }
+
+    // one texture per page, `params.flip_vertically` must stay false for the uvs to match
+    pub fn upload(&self, gl: &mut gl::Gl, params: &TextureParams) -> Vec<Texture> {
+        self.pages
+            .iter()
+            .map(|page| upload_image(gl, DynamicImage::ImageRgba8(page.clone()), params))
+            .collect()
+    }
+
+    // write `<name>.json` and one `<name>_<page>.png` per page next to it
+    pub fn save<P: AsRef<Path>>(&mut self, layout_path: P) -> Result<(), AtlasError> {
+        let layout_path = layout_path.as_ref();
+        let dir = layout_path.parent().unwrap_or_else(|| Path::new(""));
+        let stem = layout_path
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_else(|| String::from("atlas"));
+
+        for (i, (page, image)) in self.layout.pages.iter_mut().zip(&self.pages).enumerate() {
+            page.file = format!("{}_{}.png", stem, i);
+            image.save(dir.join(&page.file))?;
+        }
+
+        let json = serde_json::to_string_pretty(&self.layout)?;
+        fs::write(layout_path, json)?;
+        Ok(())
+    }
+
+    // read a layout written by `save` along with its page images
+    pub fn load<P: AsRef<Path>>(
+        assets: &AssetResolver,
+        layout_path: P,
+    ) -> Result<Atlas, AtlasError> {
+        let layout_path = assets.resolve(layout_path)?;
+        let layout: AtlasLayout = serde_json::from_str(&fs::read_to_string(&layout_path)?)?;
+        let dir = layout_path.parent().unwrap_or_else(|| Path::new(""));
+
+        let pages = layout
+            .pages
+            .iter()
+            .map(|page| Ok(image::open(dir.join(&page.file))?.into_rgba8()))
+            .collect::<Result<Vec<_>, AtlasError>>()?;
+
+        Ok(Atlas { layout, pages })
+    }
+}
+
+#[derive(Default)]
+pub struct AtlasBuilder {
+    config: AtlasConfig,
+    images: Vec<(String, RgbaImage)>,
+}
+
+impl AtlasBuilder {
+    pub fn new(config: AtlasConfig) -> Self {
+        AtlasBuilder {
+            config,
+            images: vec![],
+        }
+    }
+
+    pub fn add<S: Into<String>>(&mut self, name: S, image: RgbaImage) -> &mut Self {
+        self.images.push((name.into(), image));
+        self
+    }
+
+    // the image is registered under the path it was requested as
+    pub fn add_file<P: AsRef<Path>>(
+        &mut self,
+        assets: &AssetResolver,
+        path: P,
+    ) -> Result<&mut Self, ImageError> {
+        let name = path.as_ref().to_string_lossy().into_owned();
+        let image = load_image(assets, path)?.into_rgba8();
+        Ok(self.add(name, image))
+    }
+
+    pub fn build(self) -> Result<Atlas, AtlasError> {
+        let AtlasConfig {
+            page_width,
+            page_height,
+            padding,
+            extrude,
+        } = self.config;
+        let border = extrude * 2 + padding;
+
+        // tallest first packs tighter with a skyline
+        let mut order: Vec<usize> = (0..self.images.len()).collect();
+        order.sort_by_key(|&i| {
+            let image = &self.images[i].1;
+            (
+                std::cmp::Reverse(image.height()),
+                std::cmp::Reverse(image.width()),
+            )
+        });
+
+        let mut packers: Vec<SkylinePacker> = vec![];
+        let mut pages: Vec<RgbaImage> = vec![];
+        let mut regions = BTreeMap::new();
+
+        for i in order {
+            let (name, image) = &self.images[i];
+            let (width, height) = (image.width() + border, image.height() + border);
+            if width > page_width || height > page_height {
+                return Err(AtlasError::TooLarge {
+                    name: name.clone(),
+                    width: image.width(),
+                    height: image.height(),
+                });
+            }
+
+            let placed = packers
+                .iter_mut()
+                .enumerate()
+                .find_map(|(page, packer)| packer.insert(width, height).map(|at| (page, at)));
+            let (page, (x, y)) = match placed {
+                Some(placed) => placed,
+                None => {
+                    let mut packer = SkylinePacker::new(page_width, page_height);
+                    let at = packer
+                        .insert(width, height)
+                        .expect("image fits an empty page");
+                    packers.push(packer);
+                    pages.push(RgbaImage::new(page_width, page_height));
+                    (packers.len() - 1, at)
+                }
+            };
+
+            let (x, y) = (x + extrude, y + extrude);
+            blit_extruded(&mut pages[page], image, x, y, extrude);
+
+            let (w, h) = (page_width as f32, page_height as f32);
+            regions.insert(
+                name.clone(),
+                AtlasRegion {
+                    page,
+                    x,
+                    y,
+                    width: image.width(),
+                    height: image.height(),
+                    uv: UvRect {
+                        min: [x as f32 / w, y as f32 / h],
+                        max: [
+                            (x + image.width()) as f32 / w,
+                            (y + image.height()) as f32 / h,
+                        ],
+                    },
+                },
+            );
+        }
+
+        let layout = AtlasLayout {
+            config: self.config,
+            pages: pages
+                .iter()
+                .map(|page| AtlasPage {
+                    width: page.width(),
+                    height: page.height(),
+                    file: String::new(),
+                })
+                .collect(),
+            regions,
+        };
+        Ok(Atlas { layout, pages })
+    }
+}
+
+// copy `image` to (x, y) and repeat its outermost pixels `extrude` times on every side
+fn blit_extruded(page: &mut RgbaImage, image: &RgbaImage, x: u32, y: u32, extrude: u32) {
+    imageops::replace(page, image, x as i64, y as i64);
+    if extrude == 0 || image.width() == 0 || image.height() == 0 {
+        return;
+    }
+
+    let (w, h) = (image.width() as i64, image.height() as i64);
+    let e = extrude as i64;
+    for dy in -e..h + e {
+        for dx in -e..w + e {
+            if (0..w).contains(&dx) && (0..h).contains(&dy) {
+                continue;
+            }
+            let source = image.get_pixel(dx.clamp(0, w - 1) as u32, dy.clamp(0, h - 1) as u32);
+            page.put_pixel((x as i64 + dx) as u32, (y as i64 + dy) as u32, *source);
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum AtlasError {
+    TooLarge {
+        name: String,
+        width: u32,
+        height: u32,
+    },
+    Asset(AssetError),
+    Image(ImageError),
+    Io(io::Error),
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for AtlasError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AtlasError::TooLarge {
+                name,
+                width,
+                height,
+            } => write!(
+                f,
+                "{} ({}x{}) does not fit on an atlas page",
+                name, width, height
+            ),
+            AtlasError::Asset(err) => write!(f, "{}", err),
+            AtlasError::Image(err) => write!(f, "{}", err),
+            AtlasError::Io(err) => write!(f, "{}", err),
+            AtlasError::Json(err) => write!(f, "invalid atlas layout: {}", err),
+        }
+    }
+}
+
+impl Error for AtlasError {}
+
+impl From<AssetError> for AtlasError {
+    fn from(err: AssetError) -> Self {
+        AtlasError::Asset(err)
+    }
+}
+
+impl From<ImageError> for AtlasError {
+    fn from(err: ImageError) -> Self {
+        AtlasError::Image(err)
+    }
+}
+
+impl From<io::Error> for AtlasError {
+    fn from(err: io::Error) -> Self {
+        AtlasError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for AtlasError {
+    fn from(err: serde_json::Error) -> Self {
+        AtlasError::Json(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    const CONFIG: AtlasConfig = AtlasConfig {
+        page_width: 32,
+        page_height: 32,
+        padding: 2,
+        extrude: 1,
+    };
+
+    // every pixel a different color so extruded ones can be told apart
+    fn image(width: u32, height: u32, seed: u8) -> RgbaImage {
+        RgbaImage::from_fn(width, height, |x, y| {
+            Rgba([seed, x as u8 + 1, y as u8 + 1, 255])
+        })
+    }
+
+    #[test]
+    fn regions_and_their_borders_dont_overlap() {
+        let mut builder = AtlasBuilder::new(CONFIG);
+        for i in 0..6 {
+            builder.add(format!("{}", i), image(4 + i, 7 - i, i as u8));
+        }
+        let atlas = builder.build().unwrap();
+        assert_eq!(atlas.pages.len(), 1);
+
+        // region plus extrusion on both sides and padding after it
+        let border = |region: &AtlasRegion| {
+            let e = CONFIG.extrude;
+            (
+                region.x - e,
+                region.y - e,
+                region.width + e * 2 + CONFIG.padding,
+                region.height + e * 2 + CONFIG.padding,
+            )
+        };
+        let regions: Vec<_> = atlas.layout.regions.values().map(border).collect();
+        for (i, a) in regions.iter().enumerate() {
+            for b in &regions[i + 1..] {
+                let apart =
+                    a.0 + a.2 <= b.0 || b.0 + b.2 <= a.0 || a.1 + a.3 <= b.1 || b.1 + b.3 <= a.1;
+                assert!(apart, "{:?} and {:?} overlap", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn edges_are_extruded_and_padding_is_empty() {
+        let source = image(3, 2, 7);
+        let mut builder = AtlasBuilder::new(CONFIG);
+        builder.add("only", source.clone());
+        let atlas = builder.build().unwrap();
+        let region = atlas.region("only").unwrap();
+        assert_eq!(
+            (region.x, region.y, region.width, region.height),
+            (1, 1, 3, 2)
+        );
+        assert_eq!(region.uv.min, [1.0 / 32.0, 1.0 / 32.0]);
+        assert_eq!(region.uv.max, [4.0 / 32.0, 3.0 / 32.0]);
+
+        let page = &atlas.pages[0];
+        for y in 0..2 {
+            for x in 0..3 {
+                assert_eq!(page.get_pixel(x + 1, y + 1), source.get_pixel(x, y));
+            }
+        }
+        // corners and edges repeat the image's outermost pixels
+        assert_eq!(page.get_pixel(0, 0), source.get_pixel(0, 0));
+        assert_eq!(page.get_pixel(4, 3), source.get_pixel(2, 1));
+        assert_eq!(page.get_pixel(2, 0), source.get_pixel(1, 0));
+        assert_eq!(page.get_pixel(0, 2), source.get_pixel(0, 1));
+        // and past them is padding
+        assert_eq!(page.get_pixel(5, 1), &Rgba([0, 0, 0, 0]));
+        assert_eq!(page.get_pixel(1, 4), &Rgba([0, 0, 0, 0]));
+    }
+
+    #[test]
+    fn full_pages_overflow_onto_new_ones() {
+        let mut builder = AtlasBuilder::new(CONFIG);
+        // 20x20 with the border, only one fits a 32x32 page
+        builder.add("a", image(16, 16, 1));
+        builder.add("b", image(16, 16, 2));
+        builder.add("c", image(4, 4, 3));
+        let atlas = builder.build().unwrap();
+
+        assert_eq!(atlas.pages.len(), 2);
+        assert_eq!(atlas.layout.pages.len(), 2);
+        let pages: Vec<_> = ["a", "b"]
+            .iter()
+            .map(|name| atlas.region(name).unwrap().page)
+            .collect();
+        assert_ne!(pages[0], pages[1]);
+        // small ones still go into the gaps of the first page
+        assert_eq!(atlas.region("c").unwrap().page, 0);
+    }
+
+    #[test]
+    fn images_larger_than_a_page_are_refused() {
+        let mut builder = AtlasBuilder::new(CONFIG);
+        builder.add("fits", image(4, 4, 0));
+        // 29 plus the 4 pixel border is over 32
+        builder.add("huge", image(29, 2, 0));
+        match builder.build() {
+            Err(AtlasError::TooLarge {
+                name,
+                width,
+                height,
+            }) => assert_eq!((name.as_str(), width, height), ("huge", 29, 2)),
+            other => panic!(
+                "expected TooLarge, got {:?}",
+                other.map(|atlas| atlas.layout)
+            ),
+        }
+    }
+
+    #[test]
+    fn saved_atlases_load_back() {
+        let dir = std::env::temp_dir().join(format!("common-atlas-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let mut builder = AtlasBuilder::new(CONFIG);
+        builder.add("a", image(16, 16, 1));
+        builder.add("b", image(16, 16, 2));
+        let mut atlas = builder.build().unwrap();
+        atlas.save(dir.join("sprites.json")).unwrap();
+
+        assert_eq!(atlas.layout.pages[1].file, "sprites_1.png");
+        assert!(dir.join("sprites_0.png").is_file());
+        let loaded = Atlas::load(&AssetResolver::new([&dir]), "sprites.json").unwrap();
+        assert_eq!(loaded.layout, atlas.layout);
+        assert_eq!(loaded.pages, atlas.pages);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,14 @@
+pub mod async_texture_loader;
+pub mod atlas;
+pub mod skyline_packer;
+#[allow(clippy::module_inception)]
+pub mod texture;
+pub mod texture_builder;
+pub mod texture_loader;
+
+pub use async_texture_loader::*;
+pub use atlas::*;
+pub use skyline_packer::*;
+pub use texture::*;
+pub use texture_builder::*;
+pub use texture_loader::*;
@@ -0,0 +1,169 @@
+// Skyline bottom-left rectangle packer.
+//
+// The skyline is the top edge of everything placed so far, stored as horizontal
+// segments from left to right. A rectangle is placed where its bottom edge is
+// lowest, ties go to the segment with the least wasted width.
+#[derive(Clone, Debug)]
+pub struct SkylinePacker {
+    width: u32,
+    height: u32,
+    skyline: Vec<Segment>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Segment {
+    x: u32,
+    y: u32,
+    width: u32,
+}
+
+impl SkylinePacker {
+    pub fn new(width: u32, height: u32) -> Self {
+        SkylinePacker {
+            width,
+            height,
+            skyline: vec![Segment { x: 0, y: 0, width }],
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    // reserve a `width` x `height` area, returning its top left corner
+    pub fn insert(&mut self, width: u32, height: u32) -> Option<(u32, u32)> {
+        let mut best: Option<(usize, u32, u32)> = None; // (segment index, y, wasted width)
+        for i in 0..self.skyline.len() {
+            if let Some(y) = self.fits(i, width, height) {
+                let waste = self.skyline[i].width.saturating_sub(width);
+                let better = match best {
+                    None => true,
+                    Some((_, best_y, best_waste)) => {
+                        y < best_y || (y == best_y && waste < best_waste)
+                    }
+                };
+                if better {
+                    best = Some((i, y, waste));
+                }
+            }
+        }
+
+        let (index, y, _) = best?;
+        let x = self.skyline[index].x;
+        self.place(index, x, y, width, height);
+        Some((x, y))
+    }
+
+    // the y a rectangle would rest at when its left edge is on segment `index`
+    fn fits(&self, index: usize, width: u32, height: u32) -> Option<u32> {
+        let x = self.skyline[index].x;
+        if x + width > self.width {
+            return None;
+        }
+        let mut remaining = width as i64;
+        let mut y = 0;
+        for segment in &self.skyline[index..] {
+            if remaining <= 0 {
+                break;
+            }
+            y = y.max(segment.y);
+            if y + height > self.height {
+                return None;
+            }
+            remaining -= segment.width as i64;
+        }
+        Some(y)
+    }
+
+    fn place(&mut self, index: usize, x: u32, y: u32, width: u32, height: u32) {
+        self.skyline.insert(
+            index,
+            Segment {
+                x,
+                y: y + height,
+                width,
+            },
+        );
+
+        // shrink or remove the segments now covered by the new one
+        let right = x + width;
+        let i = index + 1;
+        while i < self.skyline.len() {
+            let segment = self.skyline[i];
+            if segment.x >= right {
+                break;
+            }
+            let segment_right = segment.x + segment.width;
+            if segment_right <= right {
+                self.skyline.remove(i);
+            } else {
+                self.skyline[i].x = right;
+                self.skyline[i].width = segment_right - right;
+                break;
+            }
+        }
+
+        // merge neighbours at the same height
+        let mut i = 0;
+        while i + 1 < self.skyline.len() {
+            if self.skyline[i].y == self.skyline[i + 1].y {
+                self.skyline[i].width += self.skyline[i + 1].width;
+                self.skyline.remove(i + 1);
+            } else {
+                i += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn overlap(a: (u32, u32, u32, u32), b: (u32, u32, u32, u32)) -> bool {
+        a.0 < b.0 + b.2 && b.0 < a.0 + a.2 && a.1 < b.1 + b.3 && b.1 < a.1 + a.3
+    }
+
+    #[test]
+    fn placements_dont_overlap() {
+        let mut packer = SkylinePacker::new(64, 64);
+        let sizes = [
+            (20, 30),
+            (10, 10),
+            (30, 5),
+            (16, 16),
+            (7, 40),
+            (25, 12),
+            (8, 8),
+        ];
+        let mut placed = vec![];
+        for (width, height) in sizes {
+            let (x, y) = packer.insert(width, height).expect("room left");
+            assert!(x + width <= 64 && y + height <= 64);
+            let rect = (x, y, width, height);
+            assert!(placed.iter().all(|&other| !overlap(rect, other)));
+            placed.push(rect);
+        }
+    }
+
+    #[test]
+    fn lowest_spot_first() {
+        let mut packer = SkylinePacker::new(10, 10);
+        assert_eq!(packer.insert(4, 6), Some((0, 0)));
+        assert_eq!(packer.insert(6, 2), Some((4, 0)));
+        // next to the short one rather than on top of the tall one
+        assert_eq!(packer.insert(6, 2), Some((4, 2)));
+    }
+
+    #[test]
+    fn full_packers_refuse() {
+        let mut packer = SkylinePacker::new(8, 8);
+        assert_eq!(packer.insert(9, 1), None);
+        assert_eq!(packer.insert(8, 8), Some((0, 0)));
+        assert_eq!(packer.insert(1, 1), None);
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{
+    capabilities::Features,
+    gl::{self, types::*},
+    state_cache,
+};
+use std::{
+    cell::Cell,
+    rc::{Rc, Weak},
+};
+
+pub struct Texture {
+    pub id: GLuint,
+    // (width, height), shared with an `AsyncTextureLoader` still decoding into
+    // the texture, it sets the real size once the image is uploaded
+    size: Rc<Cell<(u32, u32)>>,
+}
+
+impl Texture {
+    pub fn new(id: GLuint, width: u32, height: u32) -> Texture {
+        Texture {
+            id,
+            size: Rc::new(Cell::new((width, height))),
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.size.get().0
+    }
+
+    pub fn height(&self) -> u32 {
+        self.size.get().1
+    }
+
+    pub(crate) fn set_size(&self, width: u32, height: u32) {
+        self.size.set((width, height));
+    }
+
+    // gone once the texture is dropped, see `AsyncTextureLoader`
+    pub(crate) fn size_ref(&self) -> Weak<Cell<(u32, u32)>> {
+        Rc::downgrade(&self.size)
+    }
+
+    // bind to texture unit `unit`, i.e. GL_TEXTURE0 + unit
+    // with direct state access the active unit stays what it was
+    pub fn bind(&self, gl: &mut gl::Gl, unit: GLuint) {
+        if Features::current().direct_state_access {
+            state_cache::bind_texture_unit(gl, unit, gl::TEXTURE_2D, self.id);
+        } else {
+            state_cache::active_texture(gl, gl::TEXTURE0 + unit);
+            state_cache::bind_texture(gl, gl::TEXTURE_2D, self.id);
+        }
+    }
+}
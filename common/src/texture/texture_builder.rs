@@ -0,0 +1,166 @@
+use crate::{
+    asset::AssetResolver,
+    capabilities::Features,
+    gl::{self, types::*},
+    state_cache,
+    texture::{texture::Texture, texture_loader::load_image},
+};
+use image::{DynamicImage, ImageError};
+use std::path::Path;
+
+// sampling state applied when a texture is created
+// the defaults match the textures chapter: repeat, linear filtering, mipmaps
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TextureParams {
+    pub wrap_s: GLenum,
+    pub wrap_t: GLenum,
+    pub min_filter: GLenum,
+    pub mag_filter: GLenum,
+    pub flip_vertically: bool,
+    pub generate_mipmaps: bool,
+}
+
+impl Default for TextureParams {
+    fn default() -> Self {
+        TextureParams {
+            wrap_s: gl::REPEAT,
+            wrap_t: gl::REPEAT,
+            min_filter: gl::LINEAR,
+            mag_filter: gl::LINEAR,
+            flip_vertically: false,
+            generate_mipmaps: true,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct TextureBuilder {
+    assets: AssetResolver,
+    params: TextureParams,
+}
+
+impl TextureBuilder {
+    // image paths are looked up through `assets` instead of the working directory
+    pub fn new(assets: AssetResolver) -> Self {
+        TextureBuilder {
+            assets,
+            params: TextureParams::default(),
+        }
+    }
+
+    pub fn params(mut self, params: TextureParams) -> Self {
+        self.params = params;
+        self
+    }
+
+    pub fn wrap(mut self, wrap_s: GLenum, wrap_t: GLenum) -> Self {
+        self.params.wrap_s = wrap_s;
+        self.params.wrap_t = wrap_t;
+        self
+    }
+
+    pub fn filter(mut self, min_filter: GLenum, mag_filter: GLenum) -> Self {
+        self.params.min_filter = min_filter;
+        self.params.mag_filter = mag_filter;
+        self
+    }
+
+    // OpenGL expects the first row of pixels at the bottom, most image formats store it at the top
+    pub fn flip_vertically(mut self, flip: bool) -> Self {
+        self.params.flip_vertically = flip;
+        self
+    }
+
+    pub fn build<P: AsRef<Path>>(&self, gl: &mut gl::Gl, path: P) -> Texture {
+        match self.try_build(gl, path) {
+            Ok(texture) => texture,
+            Err(err) => {
+                panic!("{}", err);
+            }
+        }
+    }
+
+    pub fn try_build<P: AsRef<Path>>(
+        &self,
+        gl: &mut gl::Gl,
+        path: P,
+    ) -> Result<Texture, ImageError> {
+        let img = load_image(&self.assets, path)?;
+        Ok(upload_image(gl, img, &self.params))
+    }
+}
+
+// create a texture object from an already decoded image
+pub fn upload_image(gl: &mut gl::Gl, img: DynamicImage, params: &TextureParams) -> Texture {
+    let mut id = 0;
+    let parameters = [
+        (gl::TEXTURE_WRAP_S, params.wrap_s),
+        (gl::TEXTURE_WRAP_T, params.wrap_t),
+        (gl::TEXTURE_MIN_FILTER, params.min_filter),
+        (gl::TEXTURE_MAG_FILTER, params.mag_filter),
+    ];
+    unsafe {
+        if Features::current().direct_state_access {
+            // sampling state without binding, the pixels still go through
+            // the binding since they may change size later
+            gl.CreateTextures(gl::TEXTURE_2D, 1, &mut id);
+            for (name, value) in parameters {
+                gl.TextureParameteri(id, name, value as GLint);
+            }
+        } else {
+            gl.GenTextures(1, &mut id);
+            state_cache::bind_texture(gl, gl::TEXTURE_2D, id);
+            for (name, value) in parameters {
+                gl.TexParameteri(gl::TEXTURE_2D, name, value as GLint);
+            }
+        }
+    }
+    upload_image_into(gl, id, img, params)
+}
+
+// replace the pixels of an existing texture object, its sampling state is left alone
+// the GL_TEXTURE_2D binding of the active texture unit is restored afterwards
+pub fn upload_image_into(
+    gl: &mut gl::Gl,
+    id: GLuint,
+    img: DynamicImage,
+    params: &TextureParams,
+) -> Texture {
+    let img = if params.flip_vertically {
+        img.flipv()
+    } else {
+        img
+    };
+    let (width, height) = (img.width(), img.height());
+    let (format, data) = if img.color().has_alpha() {
+        (gl::RGBA, img.into_rgba8().into_raw())
+    } else {
+        (gl::RGB, img.into_rgb8().into_raw())
+    };
+
+    unsafe {
+        let mut bound = 0;
+        gl.GetIntegerv(gl::TEXTURE_BINDING_2D, &mut bound);
+        gl.BindTexture(gl::TEXTURE_2D, id);
+        // rows of tightly packed RGB data are not 4 byte aligned
+        gl.PixelStorei(gl::UNPACK_ALIGNMENT, 1);
+        gl.TexImage2D(
+            gl::TEXTURE_2D,
+            0,
+            format as GLint,
+            width as GLsizei,
+            height as GLsizei,
+            0,
+            format,
+            gl::UNSIGNED_BYTE,
+            data.as_ptr() as *const GLvoid,
+        );
+        gl.PixelStorei(gl::UNPACK_ALIGNMENT, 4);
+        if params.generate_mipmaps {
+            gl.GenerateMipmap(gl::TEXTURE_2D);
+        }
+        gl.BindTexture(gl::TEXTURE_2D, bound as GLuint);
+    }
+
+    Texture::new(id, width, height)
+}
@@ -0,0 +1,42 @@
+use crate::asset::AssetResolver;
+use image::{DynamicImage, ImageError};
+use std::{
+    error::Error,
+    fmt,
+    path::{Path, PathBuf},
+};
+
+// resolve `path` against the asset roots and decode the image
+pub fn load_image<P: AsRef<Path>>(
+    assets: &AssetResolver,
+    path: P,
+) -> Result<DynamicImage, ImageError> {
+    let path = assets
+        .resolve(path)
+        .map_err(|err| ImageError::IoError(err.into()))?;
+    image::open(path)
+}
+
+// an image that could not be found or decoded, with the path it was requested as
+#[derive(Debug)]
+pub struct TextureError {
+    pub path: PathBuf,
+    pub source: ImageError,
+}
+
+impl fmt::Display for TextureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "failed to load texture {}: {}",
+            self.path.display(),
+            self.source
+        )
+    }
+}
+
+impl Error for TextureError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
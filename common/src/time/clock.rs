@@ -0,0 +1,307 @@
+use crate::time::TimeSource;
+use std::time::Instant;
+
+// longest frame the clock will report, anything slower (a breakpoint, dragging
+// the window) is treated as this long so the simulation doesn't jump
+pub const MAX_FRAME_TIME: f32 = 0.25;
+
+// how much of each new frame time goes into the smoothed fps
+const FPS_SMOOTHING: f32 = 0.1;
+
+// Frame timing for the render loop. Call `advance` once per frame to read the
+// time from the clock's `TimeSource`, or `tick` with a time of your own, then
+// read `time` for animations, `dt` for variable rate updates or `fixed_steps`
+// and `alpha` for a fixed timestep.
+//
+// Pausing and scaling only affect `dt`, `time` and the fixed steps. `fps` and
+// `unscaled_dt` always report the real frame rate.
+#[derive(Clone, Debug)]
+pub struct Clock {
+    last: Option<f64>,
+    dt: f32,
+    unscaled_dt: f32,
+    time: f64,
+    frame: u64,
+    fps: f32,
+    paused: bool,
+    scale: f32,
+    fixed: Option<FixedStep>,
+    source: TimeSource,
+    started: Instant,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct FixedStep {
+    step: f32,
+    // at most this many steps per frame, the rest of a long frame is dropped
+    max_steps: u32,
+    accumulator: f32,
+    steps: u32,
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Clock::new()
+    }
+}
+
+impl Clock {
+    pub fn new() -> Self {
+        Clock {
+            last: None,
+            dt: 0.0,
+            unscaled_dt: 0.0,
+            time: 0.0,
+            frame: 0,
+            fps: 0.0,
+            paused: false,
+            scale: 1.0,
+            fixed: None,
+            source: TimeSource::Real,
+            started: Instant::now(),
+        }
+    }
+
+    // read frame times from `source` in `advance`
+    pub fn with_source(mut self, source: TimeSource) -> Self {
+        self.source = source;
+        self
+    }
+
+    // also run `step` second updates, e.g. 1.0 / 60.0 for physics
+    pub fn with_fixed_timestep(mut self, step: f32) -> Self {
+        assert!(step > 0.0, "fixed timestep must be positive");
+        self.fixed = Some(FixedStep {
+            step,
+            max_steps: (MAX_FRAME_TIME / step).ceil().max(1.0) as u32,
+            accumulator: 0.0,
+            steps: 0,
+        });
+        self
+    }
+
+    // start a new frame at the time source's next time, returns the scaled
+    // delta time
+    pub fn advance(&mut self) -> f32 {
+        let real = self.started.elapsed().as_secs_f64();
+        let now = self.source.at(self.frame, real);
+        self.tick(now)
+    }
+
+    // start a new frame at `now` seconds, returns the scaled delta time
+    pub fn tick(&mut self, now: f64) -> f32 {
+        // scripted jumps are meant, only real hitches get clamped
+        let max_frame_time = if self.source.is_real() {
+            MAX_FRAME_TIME as f64
+        } else {
+            f64::INFINITY
+        };
+        let precise = match self.last {
+            Some(last) => (now - last).clamp(0.0, max_frame_time),
+            None => {
+                // animations start wherever the source starts, e.g. a fixed time
+                self.time = now;
+                0.0
+            }
+        };
+        self.last = Some(now);
+        self.frame += 1;
+        let elapsed = precise as f32;
+
+        self.unscaled_dt = elapsed;
+        if elapsed > 0.0 {
+            let fps = 1.0 / elapsed;
+            self.fps = if self.fps == 0.0 {
+                fps
+            } else {
+                self.fps + (fps - self.fps) * FPS_SMOOTHING
+            };
+        }
+
+        self.dt = if self.paused {
+            0.0
+        } else {
+            elapsed * self.scale
+        };
+        // summed in f64 so `time` follows the source exactly at a scale of 1
+        if !self.paused {
+            self.time += precise * self.scale as f64;
+        }
+
+        if let Some(fixed) = &mut self.fixed {
+            fixed.accumulator += self.dt;
+            let due = (fixed.accumulator / fixed.step) as u32;
+            fixed.steps = due.min(fixed.max_steps);
+            fixed.accumulator -= due as f32 * fixed.step;
+        }
+
+        self.dt
+    }
+
+    // seconds since the last frame, 0 while paused
+    pub fn dt(&self) -> f32 {
+        self.dt
+    }
+
+    // real seconds since the last frame, ignoring pause and scale
+    pub fn unscaled_dt(&self) -> f32 {
+        self.unscaled_dt
+    }
+
+    // seconds of scaled time, starting at the first tick's time
+    // use this rather than `glfw.get_time()` so headless runs are reproducible
+    pub fn time(&self) -> f64 {
+        self.time
+    }
+
+    // number of ticks so far
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    // frames per second, smoothed over the last few frames
+    pub fn fps(&self) -> f32 {
+        self.fps
+    }
+
+    pub fn source(&self) -> &TimeSource {
+        &self.source
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    pub fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+    }
+
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    // 2.0 runs twice as fast, 0.5 is slow motion
+    pub fn set_scale(&mut self, scale: f32) {
+        self.scale = scale.max(0.0);
+    }
+
+    // length of a fixed update, None without a fixed timestep
+    pub fn fixed_timestep(&self) -> Option<f32> {
+        self.fixed.map(|fixed| fixed.step)
+    }
+
+    // fixed updates to run this frame
+    pub fn fixed_steps(&self) -> u32 {
+        self.fixed.map_or(0, |fixed| fixed.steps)
+    }
+
+    // how far the current frame is between the last fixed update and the next,
+    // 0..1, for interpolating what gets rendered
+    pub fn alpha(&self) -> f32 {
+        self.fixed
+            .map_or(1.0, |fixed| fixed.accumulator / fixed.step)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pause_stops_time_but_not_fps() {
+        let mut clock = Clock::new();
+        clock.tick(0.0);
+        clock.tick(0.1);
+        clock.set_paused(true);
+        assert_eq!(clock.tick(0.2), 0.0);
+        assert!((clock.unscaled_dt() - 0.1).abs() < 1e-6);
+        assert!((clock.fps() - 10.0).abs() < 1e-3);
+        assert!((clock.time() - 0.1).abs() < 1e-9);
+
+        clock.toggle_pause();
+        clock.tick(0.3);
+        assert!((clock.dt() - 0.1).abs() < 1e-6);
+        assert!((clock.time() - 0.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn scale_changes_dt_and_time() {
+        let mut clock = Clock::new();
+        clock.set_scale(0.5);
+        clock.tick(1.0);
+        assert_eq!(clock.time(), 1.0);
+        assert!((clock.tick(1.2) - 0.1).abs() < 1e-6);
+        assert!((clock.unscaled_dt() - 0.2).abs() < 1e-6);
+        assert!((clock.time() - 1.1).abs() < 1e-9);
+
+        // negative scales would run time backwards
+        clock.set_scale(-1.0);
+        assert_eq!(clock.scale(), 0.0);
+        assert_eq!(clock.tick(1.4), 0.0);
+    }
+
+    #[test]
+    fn long_real_frames_are_clamped() {
+        let mut clock = Clock::new();
+        clock.tick(0.0);
+        assert_eq!(clock.tick(5.0), MAX_FRAME_TIME);
+        assert_eq!(clock.time(), MAX_FRAME_TIME as f64);
+        // and time never goes backwards
+        assert_eq!(clock.tick(4.0), 0.0);
+    }
+
+    #[test]
+    fn scripted_jumps_are_not_clamped() {
+        let mut clock = Clock::new().with_source(TimeSource::Scripted(vec![0.0, 5.0]));
+        clock.advance();
+        assert_eq!(clock.advance(), 5.0);
+        assert_eq!(clock.time(), 5.0);
+    }
+
+    #[test]
+    fn fixed_steps_accumulate() {
+        let source = TimeSource::Scripted(vec![0.0, 0.3125, 0.375]);
+        let mut clock = Clock::new().with_source(source).with_fixed_timestep(0.125);
+        clock.advance();
+        assert_eq!((clock.fixed_steps(), clock.alpha()), (0, 0.0));
+        // two steps and half of the next one left over
+        clock.advance();
+        assert_eq!((clock.fixed_steps(), clock.alpha()), (2, 0.5));
+        clock.advance();
+        assert_eq!((clock.fixed_steps(), clock.alpha()), (1, 0.0));
+        // the script ran out, time stands still
+        clock.advance();
+        assert_eq!(
+            (clock.frame(), clock.dt(), clock.fixed_steps()),
+            (4, 0.0, 0)
+        );
+    }
+
+    #[test]
+    fn long_frames_drop_extra_steps() {
+        let source = TimeSource::Fixed {
+            start: 0.0,
+            step: 1.125,
+        };
+        let mut clock = Clock::new().with_source(source).with_fixed_timestep(0.125);
+        clock.advance();
+        clock.advance();
+        // MAX_FRAME_TIME worth of steps run, the rest is dropped
+        assert_eq!((clock.fixed_steps(), clock.alpha()), (2, 0.0));
+        assert_eq!(clock.time(), 1.125);
+    }
+
+    #[test]
+    fn paused_clocks_run_no_steps() {
+        let source = TimeSource::Scripted(vec![0.0, 0.5]);
+        let mut clock = Clock::new().with_source(source).with_fixed_timestep(0.25);
+        clock.set_paused(true);
+        clock.advance();
+        clock.advance();
+        assert_eq!((clock.fixed_steps(), clock.alpha()), (0, 0.0));
+    }
+}
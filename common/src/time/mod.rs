@@ -0,0 +1,5 @@
+pub mod clock;
+pub mod time_source;
+
+pub use clock::*;
+pub use time_source::*;
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+// Where a `Clock` gets the time of each frame from. Anything but `Real` makes
+// every run produce the same frames, for recordings and image tests.
+// In TOML:
+//   time_source = "real"
+//   time_source = { fixed = { start = 1.0, step = 0.0 } }
+//   time_source = { scripted = [0.0, 0.5, 2.0] }
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeSource {
+    // wall clock seconds since the clock was created
+    #[default]
+    Real,
+    // `start` on the first frame, then `step` more on each frame
+    // a step of 0 freezes time
+    Fixed {
+        start: f64,
+        step: f64,
+    },
+    // one timestamp per frame, the last one repeats once they run out
+    Scripted(Vec<f64>),
+}
+
+impl TimeSource {
+    // time of frame number `frame` (0 is the first), `real` is the wall clock
+    pub fn at(&self, frame: u64, real: f64) -> f64 {
+        match self {
+            TimeSource::Real => real,
+            TimeSource::Fixed { start, step } => start + step * frame as f64,
+            TimeSource::Scripted(times) => times
+                .get(frame as usize)
+                .or(times.last())
+                .copied()
+                .unwrap_or(0.0),
+        }
+    }
+
+    pub fn is_real(&self) -> bool {
+        matches!(self, TimeSource::Real)
+    }
+}
@@ -0,0 +1,186 @@
+// AssetManager on glad's mock driver: loads are shared, counted and deleted
+// once the last handle is gone.
+
+use common::{
+    asset::{AssetManager, AssetResolver},
+    gl,
+    mesh::Mesh,
+    state_cache,
+    texture::{AsyncTextureLoader, TextureParams},
+};
+use glad::mock;
+use image::RgbImage;
+use std::{fs, path::PathBuf};
+
+// a texture and a shader in a directory of their own, tests run in parallel
+fn asset_dir(test: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("common-assets-{}-{}", test, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    RgbImage::from_pixel(2, 2, image::Rgb([255, 0, 0]))
+        .save(dir.join("red.png"))
+        .unwrap();
+    fs::write(dir.join("shader.vs"), "void main() {}").unwrap();
+    fs::write(dir.join("shader.fs"), "void main() {}").unwrap();
+    dir
+}
+
+#[test]
+fn same_file_and_params_load_once() {
+    let (mut gl, _) = mock::load_with(gl::load);
+    let dir = asset_dir("dedup");
+    let mut manager = AssetManager::new(AssetResolver::new([&dir]));
+
+    let first = manager
+        .texture(&mut gl, "red.png", TextureParams::default())
+        .unwrap();
+    // another spelling of the same file
+    let second = manager
+        .texture(&mut gl, dir.join("red.png"), TextureParams::default())
+        .unwrap();
+    assert!(first.ptr_eq(&second));
+    assert_eq!(first.ref_count(), 2);
+
+    let nearest = TextureParams {
+        min_filter: gl::NEAREST,
+        ..Default::default()
+    };
+    let third = manager.texture(&mut gl, "red.png", nearest).unwrap();
+    assert!(!first.ptr_eq(&third));
+
+    let shader = manager.shader(&mut gl, "shader.vs", "shader.fs").unwrap();
+    let again = manager.shader(&mut gl, "shader.vs", "shader.fs").unwrap();
+    assert!(shader.ptr_eq(&again));
+    let mesh = manager.mesh(&mut gl, "triangle", |gl| {
+        Mesh::new(gl, &[0.0; 9], &[0, 1, 2], &[3])
+    });
+    let again = manager.mesh(&mut gl, "triangle", |_| unreachable!("loaded twice"));
+    assert!(mesh.ptr_eq(&again));
+
+    assert_eq!(manager.len(), 4);
+    mock::with_state(|state| assert_eq!(state.textures.len(), 2));
+}
+
+#[test]
+fn async_loads_are_shared_with_sync_ones() {
+    let (mut gl, _) = mock::load_with(gl::load);
+    let dir = asset_dir("async");
+    let assets = AssetResolver::new([&dir]);
+    let mut manager = AssetManager::new(assets.clone());
+    let mut loader = AsyncTextureLoader::with_threads(assets, 1);
+
+    let first = manager
+        .texture_async(&mut gl, &mut loader, "red.png", TextureParams::default())
+        .unwrap();
+    let second = manager
+        .texture_async(&mut gl, &mut loader, "red.png", TextureParams::default())
+        .unwrap();
+    let third = manager
+        .texture(&mut gl, "red.png", TextureParams::default())
+        .unwrap();
+    assert!(first.ptr_eq(&second) && first.ptr_eq(&third));
+    assert_eq!(loader.pending(), 1);
+
+    assert_eq!(loader.finish(&mut gl).unwrap(), 1);
+    mock::with_state(|state| assert_eq!(state.textures[&first.id].width, 2));
+}
+
+#[test]
+fn last_handle_frees_the_gl_objects() {
+    let (mut gl, _) = mock::load_with(gl::load);
+    let dir = asset_dir("refcount");
+    let mut manager = AssetManager::new(AssetResolver::new([&dir]));
+
+    let texture = manager
+        .texture(&mut gl, "red.png", TextureParams::default())
+        .unwrap();
+    let clone = texture.clone();
+    let id = texture.id;
+    drop(texture);
+    assert_eq!(manager.collect_garbage(&mut gl), 0);
+    mock::with_state(|state| assert!(state.textures.contains_key(&id)));
+
+    drop(clone);
+    assert!(manager.is_empty());
+    assert_eq!(manager.collect_garbage(&mut gl), 1);
+    mock::with_state(|state| assert!(!state.textures.contains_key(&id)));
+
+    // loading it again after that is a fresh texture
+    let reloaded = manager
+        .texture(&mut gl, "red.png", TextureParams::default())
+        .unwrap();
+    assert_ne!(reloaded.id, id);
+    assert_eq!(reloaded.ref_count(), 1);
+}
+
+#[test]
+fn collected_names_are_forgotten_by_the_state_cache() {
+    let (mut gl, _) = mock::load_with(gl::load);
+    let dir = asset_dir("invalidate");
+    let mut manager = AssetManager::new(AssetResolver::new([&dir]));
+
+    let texture = manager
+        .texture(&mut gl, "red.png", TextureParams::default())
+        .unwrap();
+    let id = texture.id;
+    state_cache::active_texture(&mut gl, gl::TEXTURE0);
+    state_cache::bind_texture(&mut gl, gl::TEXTURE_2D, id);
+    drop(texture);
+    manager.collect_garbage(&mut gl);
+
+    // GL unbound the deleted name, binding it again mustn't be skipped
+    state_cache::reset_stats(&mut gl);
+    state_cache::bind_texture(&mut gl, gl::TEXTURE_2D, id);
+    assert_eq!(state_cache::stats(&gl).skipped, 0);
+}
+
+#[test]
+fn missing_files_are_an_error() {
+    let (mut gl, _) = mock::load_with(gl::load);
+    let dir = asset_dir("missing");
+    let mut manager = AssetManager::new(AssetResolver::new([&dir]));
+
+    let err = manager
+        .texture(&mut gl, "missing.png", TextureParams::default())
+        .unwrap_err();
+    assert_eq!(err.tried, [dir.join("missing.png")]);
+    assert!(manager.shader(&mut gl, "shader.vs", "missing.fs").is_err());
+    assert!(manager.is_empty());
+}
+
+#[test]
+fn async_textures_get_the_image_size_once_uploaded() {
+    let (mut gl, _) = mock::load_with(gl::load);
+    let dir = asset_dir("size");
+    RgbImage::new(4, 3).save(dir.join("wide.png")).unwrap();
+    let assets = AssetResolver::new([&dir]);
+    let mut manager = AssetManager::new(assets.clone());
+    let mut loader = AsyncTextureLoader::with_threads(assets, 1);
+
+    let texture = manager
+        .texture_async(&mut gl, &mut loader, "wide.png", TextureParams::default())
+        .unwrap();
+    // the placeholder's
+    assert_eq!((texture.width(), texture.height()), (2, 2));
+    loader.finish(&mut gl).unwrap();
+    assert_eq!((texture.width(), texture.height()), (4, 3));
+}
+
+#[test]
+fn textures_dropped_while_decoding_are_skipped() {
+    let (mut gl, _) = mock::load_with(gl::load);
+    let dir = asset_dir("dropped");
+    let assets = AssetResolver::new([&dir]);
+    let mut manager = AssetManager::new(assets.clone());
+    let mut loader = AsyncTextureLoader::with_threads(assets, 1);
+
+    let texture = manager
+        .texture_async(&mut gl, &mut loader, "red.png", TextureParams::default())
+        .unwrap();
+    drop(texture);
+    assert_eq!(manager.collect_garbage(&mut gl), 1);
+
+    // uploading into the deleted name would be a GL error
+    assert_eq!(loader.finish(&mut gl).unwrap(), 0);
+    assert!(loader.is_idle());
+    mock::with_state(|state| assert!(state.errors.is_empty()));
+}
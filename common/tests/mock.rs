@@ -0,0 +1,396 @@
+// The GL wrappers in common, run against glad's mock driver so they're checked
+// without a GPU or a window.
+
+use common::{
+    asset::AssetResolver,
+    buffer::StorageBuffer,
+    capabilities::{Capabilities, Features},
+    debug_output,
+    framebuffer::RenderTarget,
+    gl,
+    mesh::Mesh,
+    shader::ShaderBuilder,
+    texture::{upload_image, TextureParams},
+};
+use glad::mock::{self, Attachment, Dispatch, Uniform, VertexBuffer};
+use image::{DynamicImage, RgbImage};
+use std::{fs, path::PathBuf};
+
+const VERTEX: &str = "#version 330 core
+layout (location = 0) in vec3 aPos;
+uniform mat4 transform;
+void main() { gl_Position = transform * vec4(aPos, 1.0); }
+";
+
+const FRAGMENT: &str = "#version 330 core
+out vec4 FragColor;
+uniform vec4 color;
+uniform sampler2D textures[2];
+void main() { FragColor = color; }
+";
+
+// removed again when dropped, also when the test panics
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn assets(&self) -> AssetResolver {
+        AssetResolver::new([&self.0])
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+// shader files in a directory of their own, tests run in parallel
+fn shader_dir(test: &str, vertex: &str, fragment: &str) -> TempDir {
+    let dir = std::env::temp_dir().join(format!("common-mock-{}-{}", test, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("shader.vs"), vertex).unwrap();
+    fs::write(dir.join("shader.fs"), fragment).unwrap();
+    TempDir(dir)
+}
+
+// a mock context of another version, common takes its paths on this thread
+fn load_version(major: i32, minor: i32) -> gl::Gl {
+    let (mut gl, _) = mock::load_with(gl::load);
+    mock::set_version(major, minor);
+    Features::detect(&Capabilities::query(&mut gl));
+    gl
+}
+
+fn f32_bytes(values: &[f32]) -> Vec<u8> {
+    values
+        .iter()
+        .flat_map(|value| value.to_ne_bytes())
+        .collect()
+}
+
+#[test]
+fn mesh_uploads_vertices_and_layout() {
+    let (mut gl, report) = mock::load_with(gl::load);
+    // the mock only has what common calls, names in the report have no gl prefix
+    for name in ["BufferData", "GenVertexArrays", "VertexAttribPointer"] {
+        assert!(gl.is_loaded(name), "{} not loaded", name);
+        assert!(!report.missing_core.contains(&name));
+    }
+
+    let vertices = [0.0, 0.5, 0.0, 0.5, 1.0, -0.5, -0.5, 0.0, 0.0, 0.0];
+    let mesh = Mesh::new(&mut gl, &vertices, &[0, 1, 0], &[3, 2]);
+    assert_eq!(mesh.count, 3);
+
+    mock::with_state(|state| {
+        assert_eq!(state.buffers[&mesh.vbo].data, f32_bytes(&vertices));
+        assert_eq!(state.buffers[&mesh.ebo].data.len(), 3 * 4);
+        // unbound again, but the vertex array keeps its element buffer
+        assert_eq!(state.vertex_array, 0);
+        let vao = &state.vertex_arrays[&mesh.vao];
+        assert_eq!(vao.element_buffer, mesh.ebo);
+
+        let position = vao.attributes[&0];
+        let uv = vao.attributes[&1];
+        assert!(position.enabled && uv.enabled);
+        assert_eq!(
+            (position.size, position.stride, position.offset),
+            (3, 20, 0)
+        );
+        assert_eq!((uv.size, uv.stride, uv.offset), (2, 20, 12));
+        assert_eq!(uv.buffer, mesh.vbo);
+    });
+
+    mesh.draw(&mut gl);
+    mock::with_state(|state| {
+        assert_eq!(state.draws.len(), 1);
+        assert!(state.draws[0].indexed);
+        assert_eq!(state.draws[0].count, 3);
+        assert_eq!(state.draws[0].vertex_array, mesh.vao);
+        assert!(state.errors.is_empty());
+    });
+}
+
+#[test]
+fn shader_builder_links_and_sets_uniforms() {
+    let (mut gl, _) = mock::load_with(gl::load);
+    let dir = shader_dir("link", VERTEX, FRAGMENT);
+    let shader = ShaderBuilder::new(dir.assets()).build(&mut gl, "shader.vs", "shader.fs");
+
+    shader.use_program(&mut gl);
+    shader.set_vec4(&mut gl, c"color", [1.0, 0.5, 0.25, 1.0]);
+    shader.set_int(&mut gl, c"textures[1]", 1);
+    shader.set_mat4(&mut gl, c"transform", [[1.0, 0.0, 0.0, 0.0]; 4]);
+    // not declared, GL ignores location -1
+    shader.set_float(&mut gl, c"missing", 1.0);
+
+    mock::with_state(|state| {
+        let program = &state.programs[&shader.id];
+        assert!(program.linked);
+        // both shaders were deleted after linking
+        assert!(program.shaders.iter().all(|id| state.shaders[id].deleted));
+        assert_eq!(
+            state.uniform(shader.id, "color"),
+            Some(&Uniform::Float(vec![1.0, 0.5, 0.25, 1.0]))
+        );
+        assert_eq!(
+            state.uniform(shader.id, "textures[1]"),
+            Some(&Uniform::Int(vec![1]))
+        );
+        match state.uniform(shader.id, "transform") {
+            Some(Uniform::Float(values)) => assert_eq!(values.len(), 16),
+            other => panic!("transform is {:?}", other),
+        }
+        assert!(state.errors.is_empty());
+    });
+}
+
+#[test]
+fn shader_builder_labels_the_program() {
+    let (mut gl, _) = mock::load_with(gl::load);
+    // the mock has KHR_debug
+    assert!(debug_output::detect(&Capabilities::query(&mut gl)));
+
+    let dir = shader_dir("label", VERTEX, FRAGMENT);
+    let shader = ShaderBuilder::new(dir.assets()).build(&mut gl, "shader.vs", "shader.fs");
+    mock::with_state(|state| {
+        assert_eq!(
+            state.labels[&(gl::PROGRAM, shader.id)],
+            "shader.vs + shader.fs"
+        );
+    });
+}
+
+#[test]
+#[should_panic(expected = "FRAGMENT::ERROR::SHADER::COMPILATION_FAILED\n0:2(1): error: broken")]
+fn shader_builder_panics_with_the_compile_log() {
+    let (mut gl, _) = mock::load_with(gl::load);
+    let dir = shader_dir(
+        "compile",
+        VERTEX,
+        "#version 330 core\n#error broken\nvoid main() {}\n",
+    );
+    ShaderBuilder::new(dir.assets()).build(&mut gl, "shader.vs", "shader.fs");
+}
+
+#[test]
+#[should_panic(expected = "asset not found: missing.vs\ntried:\n")]
+fn shader_builder_panics_with_the_search_roots() {
+    let (mut gl, _) = mock::load_with(gl::load);
+    let dir = shader_dir("missing", VERTEX, FRAGMENT);
+    ShaderBuilder::new(dir.assets()).build(&mut gl, "missing.vs", "shader.fs");
+}
+
+#[test]
+fn upload_image_unpacks_rgb_rows() {
+    let (mut gl, _) = mock::load_with(gl::load);
+    // 3 byte pixels, rows of 9 bytes aren't 4 byte aligned
+    let image = RgbImage::from_fn(3, 2, |x, y| image::Rgb([x as u8, y as u8, 7]));
+    let params = TextureParams {
+        wrap_s: gl::CLAMP_TO_EDGE,
+        ..TextureParams::default()
+    };
+    let texture = upload_image(&mut gl, DynamicImage::ImageRgb8(image.clone()), &params);
+
+    mock::with_state(|state| {
+        let uploaded = &state.textures[&texture.id];
+        assert_eq!((uploaded.width, uploaded.height), (3, 2));
+        assert_eq!(uploaded.format, gl::RGB);
+        assert_eq!(uploaded.pixels, image.into_raw());
+        assert_eq!(
+            uploaded.parameters[&gl::TEXTURE_WRAP_S],
+            gl::CLAMP_TO_EDGE as i32
+        );
+        assert!(uploaded.mipmaps);
+        assert!(state.errors.is_empty());
+    });
+}
+
+#[test]
+fn render_target_is_complete_and_restores_the_binding() {
+    let (mut gl, _) = mock::load_with(gl::load);
+    let target = RenderTarget::new(&mut gl, 64, 32);
+
+    mock::with_state(|state| {
+        assert_eq!(state.draw_framebuffer, 0);
+        let attachments = &state.framebuffers[&target.fbo].attachments;
+        assert_eq!(
+            attachments[&gl::COLOR_ATTACHMENT0],
+            Attachment::Texture(target.color)
+        );
+        assert_eq!(
+            attachments[&gl::DEPTH_STENCIL_ATTACHMENT],
+            Attachment::Renderbuffer(target.depth_stencil)
+        );
+        let renderbuffer = state.renderbuffers[&target.depth_stencil];
+        assert_eq!((renderbuffer.width, renderbuffer.height), (64, 32));
+    });
+
+    target.bind(&mut gl);
+    unsafe {
+        gl.ClearColor(1.0, 0.0, 0.0, 1.0);
+        gl.Clear(gl::COLOR_BUFFER_BIT);
+    }
+    let image = target.read_pixels(&mut gl);
+    assert_eq!(image.dimensions(), (64, 32));
+    assert_eq!(image.get_pixel(0, 0).0, [255, 0, 0, 255]);
+    mock::with_state(|state| assert_eq!(state.viewport, [0, 0, 64, 32]));
+}
+
+#[test]
+fn capabilities_match_the_mock() {
+    let (mut gl, _) = mock::load_with(gl::load);
+    let capabilities = Capabilities::query(&mut gl);
+    assert_eq!(capabilities.gl_version, (3, 3));
+    assert!(capabilities.core_profile);
+    assert_eq!(capabilities.renderer, "mock");
+    assert!(capabilities.has_extension("GL_KHR_debug"));
+    assert_eq!(capabilities.limits.max_viewport_dims, (16384, 16384));
+    // 3.3 takes the 3.3 paths, the extensions for the 4.x ones don't count
+    assert_eq!(capabilities.features, Features::default());
+    assert!(Features::for_version((4, 5)).direct_state_access);
+    assert!(!Features::for_version((4, 3)).direct_state_access);
+    mock::with_state(|state| assert!(state.errors.is_empty()));
+}
+
+#[test]
+fn capabilities_follow_the_mock_version() {
+    let mut gl = load_version(4, 5);
+    let capabilities = Capabilities::query(&mut gl);
+    assert_eq!(capabilities.gl_version, (4, 5));
+    assert!(capabilities.version.starts_with("4.5 "));
+    assert_eq!(capabilities.shading_language_version, "4.50");
+    assert_eq!(Features::current(), Features::for_version((4, 5)));
+}
+
+#[test]
+fn mesh_is_created_without_binding_on_4_5() {
+    let mut gl = load_version(4, 5);
+    let vertices = [0.0, 0.5, 0.0, 0.5, 1.0, -0.5, -0.5, 0.0, 0.0, 0.0];
+    let mesh = Mesh::new(&mut gl, &vertices, &[0, 1, 0], &[3, 2]);
+
+    mock::with_state(|state| {
+        assert_eq!(state.buffers[&mesh.vbo].data, f32_bytes(&vertices));
+        assert_eq!(state.buffers[&mesh.ebo].data.len(), 3 * 4);
+        assert_eq!(state.vertex_array, 0);
+        assert!(state.buffer_bindings.is_empty());
+
+        let vao = &state.vertex_arrays[&mesh.vao];
+        assert_eq!(vao.element_buffer, mesh.ebo);
+        assert_eq!(
+            vao.vertex_buffers[&0],
+            VertexBuffer {
+                buffer: mesh.vbo,
+                offset: 0,
+                stride: 20,
+            }
+        );
+        // the same layout as the bound 3.3 path
+        let position = vao.attributes[&0];
+        let uv = vao.attributes[&1];
+        assert!(position.enabled && uv.enabled);
+        assert_eq!(
+            (position.size, position.stride, position.offset),
+            (3, 20, 0)
+        );
+        assert_eq!((uv.size, uv.stride, uv.offset), (2, 20, 12));
+        assert_eq!((uv.binding, uv.buffer), (0, mesh.vbo));
+    });
+
+    mesh.draw(&mut gl);
+    mock::with_state(|state| {
+        assert_eq!(state.draws[0].vertex_array, mesh.vao);
+        assert!(state.errors.is_empty());
+    });
+}
+
+#[test]
+fn textures_are_created_and_bound_by_unit_on_4_5() {
+    let mut gl = load_version(4, 5);
+    let image = RgbImage::from_fn(3, 2, |x, y| image::Rgb([x as u8, y as u8, 7]));
+    let params = TextureParams {
+        min_filter: gl::NEAREST,
+        ..TextureParams::default()
+    };
+    let texture = upload_image(&mut gl, DynamicImage::ImageRgb8(image.clone()), &params);
+
+    mock::with_state(|state| {
+        let uploaded = &state.textures[&texture.id];
+        assert_eq!(uploaded.target, gl::TEXTURE_2D);
+        assert_eq!(uploaded.pixels, image.into_raw());
+        assert_eq!(
+            uploaded.parameters[&gl::TEXTURE_MIN_FILTER],
+            gl::NEAREST as i32
+        );
+        // the upload put the unit's binding back
+        assert_eq!(state.bound_texture(gl::TEXTURE_2D), 0);
+    });
+
+    texture.bind(&mut gl, 3);
+    mock::with_state(|state| {
+        assert_eq!(state.texture_bindings[&(3, gl::TEXTURE_2D)], texture.id);
+        // without touching the active unit
+        assert_eq!(state.active_texture, 0);
+        assert!(state.errors.is_empty());
+    });
+}
+
+#[test]
+fn storage_buffers_bind_by_index_and_read_back() {
+    let values = [1.0f32, 2.0, 3.0];
+    // 4.3 binds to edit, 4.5 doesn't
+    for (minor, bound) in [(3, true), (5, false)] {
+        let mut gl = load_version(4, minor);
+        let buffer = StorageBuffer::new(&mut gl, &values);
+        mock::with_state(|state| {
+            assert_eq!(state.buffers[&buffer.id].data, f32_bytes(&values));
+            assert_eq!(state.buffers[&buffer.id].usage, gl::DYNAMIC_COPY);
+            let binding = state.bound_buffer(gl::SHADER_STORAGE_BUFFER);
+            assert_eq!(binding == buffer.id, bound);
+        });
+
+        buffer.bind(&mut gl, 2);
+        assert_eq!(buffer.read::<f32>(&mut gl), values);
+        mock::with_state(|state| {
+            let key = (gl::SHADER_STORAGE_BUFFER, 2);
+            assert_eq!(state.indexed_buffer_bindings[&key], buffer.id);
+            assert!(state.errors.is_empty());
+        });
+    }
+}
+
+#[test]
+fn compute_shaders_link_alone_and_dispatch() {
+    let dir = shader_dir("compute", VERTEX, FRAGMENT);
+    fs::write(
+        dir.0.join("double.comp"),
+        "#version 430 core
+layout (local_size_x = 1) in;
+layout (std430, binding = 0) buffer Values { float values[]; };
+void main() { values[gl_GlobalInvocationID.x] *= 2.0; }
+",
+    )
+    .unwrap();
+    let mut gl = load_version(4, 3);
+    let shader = ShaderBuilder::new(dir.assets()).build_compute(&mut gl, "double.comp");
+    shader.dispatch(&mut gl, [3, 1, 1]);
+
+    mock::with_state(|state| {
+        assert!(state.programs[&shader.id].linked);
+        assert_eq!(state.current_program, shader.id);
+        assert_eq!(
+            state.dispatches,
+            [Dispatch {
+                groups: [3, 1, 1],
+                program: shader.id,
+            }]
+        );
+        assert!(state.errors.is_empty());
+    });
+}
+
+#[test]
+#[should_panic(expected = "compute shaders need a GL 4.3 context")]
+fn compute_shaders_panic_on_3_3() {
+    let mut gl = load_version(3, 3);
+    ShaderBuilder::new(AssetResolver::default()).build_compute(&mut gl, "double.comp");
+}
@@ -0,0 +1,143 @@
+// The state cache on glad's mock driver: redundant changes are skipped and
+// counted, the ones that matter still reach GL.
+
+use common::{
+    asset::AssetResolver,
+    gl::{self, types::GLuint},
+    mesh::Mesh,
+    shader::ShaderBuilder,
+    state_cache,
+};
+use glad::mock;
+use std::fs;
+
+// a linked program, the mock won't use any other
+fn program(gl: &mut gl::Gl, test: &str) -> GLuint {
+    let dir = std::env::temp_dir().join(format!("common-state-{}-{}", test, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("shader.vs"), "void main() {}").unwrap();
+    fs::write(dir.join("shader.fs"), "void main() {}").unwrap();
+    ShaderBuilder::new(AssetResolver::new([dir]))
+        .build(gl, "shader.vs", "shader.fs")
+        .id
+}
+
+#[test]
+fn redundant_changes_are_skipped() {
+    let (mut gl, _) = mock::load_with(gl::load);
+    let program = program(&mut gl, "skipped");
+    let mesh = Mesh::new(&mut gl, &[0.0; 9], &[0, 1, 2], &[3]);
+    state_cache::reset_stats(&mut gl);
+
+    for _ in 0..3 {
+        state_cache::use_program(&mut gl, program);
+        state_cache::enable(&mut gl, gl::DEPTH_TEST);
+        state_cache::depth_func(&mut gl, gl::LEQUAL);
+        state_cache::blend_func(&mut gl, gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA);
+        state_cache::viewport(&mut gl, 0, 0, 800, 600);
+        mesh.draw(&mut gl);
+    }
+    let stats = state_cache::stats(&gl);
+    assert_eq!((stats.issued, stats.skipped), (6, 12));
+    assert_eq!(
+        stats.to_string(),
+        "6 state changes issued, 12 redundant ones skipped (67%)"
+    );
+
+    mock::with_state(|state| {
+        assert_eq!(state.current_program, program);
+        assert!(state.enabled.contains(&gl::DEPTH_TEST));
+        assert_eq!(state.depth_func, gl::LEQUAL);
+        assert_eq!(state.blend_func, (gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA));
+        assert_eq!(state.viewport, [0, 0, 800, 600]);
+        assert_eq!(state.draws.len(), 3);
+        assert!(state.draws.iter().all(|draw| draw.vertex_array == mesh.vao));
+    });
+}
+
+#[test]
+fn textures_are_tracked_per_unit() {
+    let (mut gl, _) = mock::load_with(gl::load);
+    let mut textures = [0; 2];
+    unsafe { gl.GenTextures(2, textures.as_mut_ptr()) };
+
+    state_cache::active_texture(&mut gl, gl::TEXTURE0);
+    state_cache::bind_texture(&mut gl, gl::TEXTURE_2D, textures[0]);
+    state_cache::active_texture(&mut gl, gl::TEXTURE1);
+    state_cache::bind_texture(&mut gl, gl::TEXTURE_2D, textures[1]);
+    state_cache::reset_stats(&mut gl);
+    // same texture, other unit
+    state_cache::bind_texture(&mut gl, gl::TEXTURE_2D, textures[0]);
+    state_cache::active_texture(&mut gl, gl::TEXTURE0);
+    state_cache::bind_texture(&mut gl, gl::TEXTURE_2D, textures[0]);
+    assert_eq!(state_cache::stats(&gl).skipped, 1);
+
+    mock::with_state(|state| {
+        assert_eq!(state.texture_bindings[&(0, gl::TEXTURE_2D)], textures[0]);
+        assert_eq!(state.texture_bindings[&(1, gl::TEXTURE_2D)], textures[0]);
+    });
+}
+
+#[test]
+fn plain_calls_keep_the_cache_up_to_date() {
+    let (mut gl, _) = mock::load_with(gl::load);
+    let program = program(&mut gl, "plain");
+    state_cache::use_program(&mut gl, program);
+    unsafe { gl.UseProgram(0) };
+
+    state_cache::use_program(&mut gl, program);
+    mock::with_state(|state| assert_eq!(state.current_program, program));
+
+    // and skip what they already did
+    unsafe { gl.Enable(gl::BLEND) };
+    state_cache::reset_stats(&mut gl);
+    state_cache::enable(&mut gl, gl::BLEND);
+    assert_eq!(state_cache::stats(&gl).skipped, 1);
+}
+
+#[test]
+fn deleted_names_are_forgotten() {
+    let (mut gl, _) = mock::load_with(gl::load);
+    let mesh = Mesh::new(&mut gl, &[0.0; 9], &[0, 1, 2], &[3]);
+    state_cache::bind_vertex_array(&mut gl, mesh.vao);
+    state_cache::bind_buffer(&mut gl, gl::ARRAY_BUFFER, mesh.vbo);
+    unsafe {
+        gl.DeleteBuffers(1, &mesh.vbo);
+        gl.DeleteVertexArrays(1, &mesh.vao);
+    }
+
+    // GL unbound them, binding the names again mustn't be skipped
+    state_cache::reset_stats(&mut gl);
+    state_cache::bind_vertex_array(&mut gl, mesh.vao);
+    state_cache::bind_buffer(&mut gl, gl::ARRAY_BUFFER, mesh.vbo);
+    assert_eq!(state_cache::stats(&gl).skipped, 0);
+}
+
+#[test]
+fn every_context_has_its_own_cache() {
+    let (mut gl, _) = mock::load_with(gl::load);
+    state_cache::enable(&mut gl, gl::DEPTH_TEST);
+    let (mut other, _) = mock::load_with(gl::load);
+    assert_eq!(state_cache::stats(&other), Default::default());
+
+    state_cache::enable(&mut other, gl::DEPTH_TEST);
+    assert_eq!(state_cache::stats(&other).issued, 1);
+    mock::with_state(|state| assert!(state.enabled.contains(&gl::DEPTH_TEST)));
+}
+
+#[test]
+fn invalidate_forgets_changes_around_the_cache() {
+    let (mut gl, _) = mock::load_with(gl::load);
+    let program = program(&mut gl, "invalidate");
+    state_cache::use_program(&mut gl, program);
+    // straight to the bindings, the cache doesn't see it
+    unsafe { (*gl).UseProgram(0) };
+
+    // the cache still thinks it's current
+    state_cache::use_program(&mut gl, program);
+    mock::with_state(|state| assert_eq!(state.current_program, 0));
+
+    state_cache::invalidate(&mut gl);
+    state_cache::use_program(&mut gl, program);
+    mock::with_state(|state| assert_eq!(state.current_program, program));
+}
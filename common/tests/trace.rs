@@ -0,0 +1,174 @@
+// glad's call tracing, recorded from common's wrappers on the mock driver and
+// replayed onto a fresh one.
+
+use common::{
+    asset::AssetResolver,
+    mesh::Mesh,
+    shader::ShaderBuilder,
+    texture::{upload_image, TextureParams},
+};
+use glad::{
+    gl33 as gl,
+    mock::{self, Uniform},
+    replay::Replayer,
+    trace::{self, Event, TraceError, TraceReader},
+};
+use image::{DynamicImage, RgbImage};
+use std::{fs, io::Cursor, path::PathBuf, sync::Mutex};
+
+const VERTEX: &str = "#version 330 core
+layout (location = 0) in vec3 aPos;
+void main() { gl_Position = vec4(aPos, 1.0); }
+";
+
+const FRAGMENT: &str = "#version 330 core
+out vec4 FragColor;
+uniform vec4 color;
+void main() { FragColor = color; }
+";
+
+// there's one recorder per process, tests run in parallel
+// a failed test poisons the lock, the others can go on
+static TRACING: Mutex<()> = Mutex::new(());
+
+fn temp_dir(test: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("common-trace-{}-{}", test, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn events(path: &PathBuf) -> Vec<Event> {
+    TraceReader::open(path)
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap()
+}
+
+#[test]
+fn replay_recreates_the_traced_objects() {
+    let _tracing = TRACING.lock().unwrap_or_else(|err| err.into_inner());
+    let dir = temp_dir("replay");
+    fs::write(dir.join("shader.vs"), VERTEX).unwrap();
+    fs::write(dir.join("shader.fs"), FRAGMENT).unwrap();
+    let path = dir.join("scene.gltrace");
+
+    // whichever bindings common is built on, the replay is always gl33
+    let (mut gl, _) = mock::load_with(common::gl::load);
+    trace::start(&path).unwrap();
+    let vertices = [0.0, 0.5, 0.0, 0.5, -0.5, 0.0, -0.5, -0.5, 0.0];
+    let mesh = Mesh::new(&mut gl, &vertices, &[0, 1, 2], &[3]);
+    let shader =
+        ShaderBuilder::new(AssetResolver::new([dir])).build(&mut gl, "shader.vs", "shader.fs");
+    let image = RgbImage::from_fn(3, 2, |x, y| image::Rgb([x as u8, y as u8, 7]));
+    upload_image(
+        &mut gl,
+        DynamicImage::ImageRgb8(image.clone()),
+        &TextureParams::default(),
+    );
+    trace::frame();
+    shader.use_program(&mut gl);
+    shader.set_vec4(&mut gl, c"color", [1.0, 0.5, 0.25, 1.0]);
+    mesh.draw(&mut gl);
+    trace::frame();
+    assert!(trace::stop().unwrap() > 0);
+
+    let events = events(&path);
+    assert_eq!(events.iter().filter(|e| **e == Event::Frame).count(), 2);
+
+    // names taken before the replay starts, so it gets different ones
+    let (gl, _) = mock::load();
+    let mut taken = [0; 4];
+    unsafe {
+        gl.GenBuffers(4, taken.as_mut_ptr());
+        gl.GenVertexArrays(4, taken.as_mut_ptr());
+        gl.GenTextures(4, taken.as_mut_ptr());
+        gl.CreateProgram();
+    }
+    let mut replayer = Replayer::new(0);
+    for event in &events {
+        unsafe { replayer.replay(&gl, event) };
+    }
+    assert!(replayer.skipped.is_empty(), "{:?}", replayer.skipped);
+
+    mock::with_state(|state| {
+        assert!(state.errors.is_empty());
+        let draw = state.draws[0];
+        assert_ne!(draw.vertex_array, mesh.vao);
+        let vao = &state.vertex_arrays[&draw.vertex_array];
+        let vertices: Vec<u8> = vertices
+            .iter()
+            .flat_map(|v: &f32| v.to_ne_bytes())
+            .collect();
+        assert_eq!(state.buffers[&vao.attributes[&0].buffer].data, vertices);
+
+        assert_ne!(draw.program, shader.id);
+        assert!(state.programs[&draw.program].linked);
+        assert_eq!(
+            state.uniform(draw.program, "color"),
+            Some(&Uniform::Float(vec![1.0, 0.5, 0.25, 1.0]))
+        );
+        let texture = state.textures.values().find(|t| t.width == 3).unwrap();
+        assert_eq!(texture.pixels, image.into_raw());
+    });
+}
+
+#[test]
+fn calls_print_with_their_payloads() {
+    let _tracing = TRACING.lock().unwrap_or_else(|err| err.into_inner());
+    let path = temp_dir("print").join("print.gltrace");
+
+    let (gl, _) = mock::load();
+    trace::start(&path).unwrap();
+    let (mut vao, mut buffer) = (0, 0);
+    unsafe {
+        gl.GenVertexArrays(1, &mut vao);
+        gl.BindVertexArray(vao);
+        gl.GenBuffers(1, &mut buffer);
+        gl.BindBuffer(gl::ARRAY_BUFFER, buffer);
+        gl.BufferData(
+            gl::ARRAY_BUFFER,
+            12,
+            [1.0f32, 2.0, 3.0].as_ptr().cast(),
+            gl::STATIC_DRAW,
+        );
+        gl.VertexAttribPointer(0, 3, gl::FLOAT, gl::FALSE, 12, 4 as *const _);
+        gl.ObjectLabel(gl::BUFFER, buffer, -1, c"positions".as_ptr());
+    }
+    trace::stop().unwrap();
+
+    let calls: Vec<String> = events(&path)
+        .iter()
+        .map(|event| match event {
+            Event::Call(call) => call.to_string(),
+            other => panic!("{:?}", other),
+        })
+        .collect();
+    assert_eq!(
+        calls[4],
+        "glBufferData(target: 0x8892, size: 12, data: [12 bytes], usage: 0x88e4)"
+    );
+    assert_eq!(
+        calls[5],
+        "glVertexAttribPointer(index: 0, size: 3, type_: 0x1406, normalized: 0, stride: 12, pointer: offset 4)"
+    );
+    assert_eq!(
+        calls[6],
+        format!(
+            "glObjectLabel(identifier: 0x82e0, name: {}, length: -1, label: \"positions\")",
+            buffer
+        )
+    );
+}
+
+#[test]
+fn reader_rejects_other_files() {
+    let reader = TraceReader::new(Cursor::new(b"\x89PNG\r\n\x1a\n".to_vec()));
+    assert!(matches!(reader, Err(TraceError::NotATrace)));
+
+    let mut cut_off = trace::MAGIC.to_vec();
+    cut_off.extend_from_slice(&trace::FORMAT_VERSION.to_le_bytes());
+    // a call record that ends after its tag
+    cut_off.push(1);
+    let mut reader = TraceReader::new(Cursor::new(cut_off)).unwrap();
+    assert!(matches!(reader.next_event(), Err(TraceError::Corrupt(_))));
+}
@@ -0,0 +1,105 @@
+use common::chapter::{find_chapter, readme, sections, Chapter};
+use getting_started::{CHAPTERS, IN_PROGRESS};
+use std::{
+    env,
+    io::{self, BufRead, Write},
+    path::PathBuf,
+    process::{self, Command},
+};
+
+const USAGE: &str = "\
+usage: launcher [<chapter> [window options...]]
+
+  <chapter>     number or binary name of the chapter to run, anything after
+                it is passed on, see `--help` of any chapter
+                without a chapter, asks which one to run
+  --list        print the chapters and exit
+  --readme      print the package README generated from the chapter list
+  --help        print this and exit";
+
+fn main() {
+    let mut args = env::args().skip(1);
+    match args.next().as_deref() {
+        None => interactive(),
+        Some("--list") => list(),
+        Some("--readme") => print!("{}", readme(env!("CARGO_PKG_NAME"), CHAPTERS, IN_PROGRESS)),
+        Some("--help" | "-h") => println!("{}", USAGE),
+        Some(query) => match find_chapter(CHAPTERS, query) {
+            Some(chapter) => process::exit(run(chapter, args.collect())),
+            None => {
+                eprintln!("no chapter {}, run with --list to see them all", query);
+                process::exit(2);
+            }
+        },
+    }
+}
+
+// the chapters numbered and grouped by section
+fn list() {
+    let mut number = 0;
+    for (section, group) in sections(CHAPTERS) {
+        println!("{}", section);
+        for chapter in group {
+            number += 1;
+            println!("  {:>2}  {:<24} {}", number, chapter.name, chapter.title);
+        }
+    }
+}
+
+// keep asking for chapters until the input is empty
+fn interactive() {
+    let stdin = io::stdin();
+    loop {
+        list();
+        print!("\nchapter to run (number or name, enter to quit): ");
+        let _ = io::stdout().flush();
+
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line).unwrap_or(0) == 0 || line.trim().is_empty() {
+            return;
+        }
+        match find_chapter(CHAPTERS, &line) {
+            Some(chapter) => {
+                run(chapter, vec![]);
+            }
+            None => eprintln!("no chapter {}", line.trim()),
+        }
+        println!();
+    }
+}
+
+// run the chapter's binary and wait for it, returns its exit code
+fn run(chapter: &Chapter, args: Vec<String>) -> i32 {
+    println!(
+        "{}\n{}\n{}",
+        chapter.title, chapter.description, chapter.url
+    );
+
+    // cargo puts every binary of the package next to this one, but
+    // `cargo run --bin launcher` only builds the launcher, let cargo build the
+    // chapter then
+    let binary = sibling_binary(chapter.name).filter(|path| path.exists());
+    let mut command = match binary {
+        Some(path) => Command::new(path),
+        None => {
+            let mut cargo = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".into()));
+            cargo
+                .args(["run", "-p", env!("CARGO_PKG_NAME"), "--bin", chapter.name])
+                .arg("--");
+            cargo
+        }
+    };
+
+    match command.args(args).status() {
+        Ok(status) => status.code().unwrap_or(1),
+        Err(err) => {
+            eprintln!("failed to run {}: {}", chapter.name, err);
+            1
+        }
+    }
+}
+
+fn sibling_binary(name: &str) -> Option<PathBuf> {
+    let launcher = env::current_exe().ok()?;
+    Some(launcher.with_file_name(format!("{}{}", name, env::consts::EXE_SUFFIX)))
+}
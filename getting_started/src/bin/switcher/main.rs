@@ -0,0 +1,30 @@
+use common::{
+    app::{self, SceneSwitcher, WindowConfig},
+    chapter::find_chapter,
+};
+use getting_started::CHAPTERS;
+use std::{env, process};
+
+// every chapter in one window, Page Down/Page Up switch to the next/previous one
+// usage: switcher [<chapter>] [window options...]
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    // an optional chapter number or name to start with, before the window options
+    let start = match args.first() {
+        Some(query) if !query.starts_with('-') => {
+            let start = find_chapter(CHAPTERS, query)
+                .and_then(|chapter| CHAPTERS.iter().position(|c| c.name == chapter.name))
+                .unwrap_or_else(|| {
+                    eprintln!("no chapter {}, see `launcher --list`", query);
+                    process::exit(2);
+                });
+            args.remove(0);
+            start
+        }
+        _ => 0,
+    };
+
+    // look up shaders and textures relative to this package, wherever we are run from
+    let config = WindowConfig::from_arg_list(args).with_asset_root(env!("CARGO_MANIFEST_DIR"));
+    app::run_with(config, |window| SceneSwitcher::new(window, CHAPTERS, start));
+}
@@ -0,0 +1,168 @@
+use crate::scenes::*;
+use common::{app::boxed_scene, chapter::Chapter};
+
+const HELLO_WINDOW: &str = "https://learnopengl.com/Getting-started/Hello-Window";
+const HELLO_TRIANGLE: &str = "https://learnopengl.com/Getting-started/Hello-Triangle";
+const SHADERS: &str = "https://learnopengl.com/Getting-started/Shaders";
+const TEXTURES: &str = "https://learnopengl.com/Getting-started/Textures";
+const TRANSFORMATIONS: &str = "https://learnopengl.com/Getting-started/Transformations";
+
+// every tutorial in src/bin in reading order, the launcher, the switcher and
+// README.md are generated from this, add new tutorials here
+pub const CHAPTERS: &[Chapter] = &[
+    Chapter {
+        name: "hello_window",
+        title: "Hello Window",
+        section: "Hello Window",
+        url: HELLO_WINDOW,
+        description: "An empty window cleared to a color",
+        scene: boxed_scene::<HelloWindow>,
+    },
+    Chapter {
+        name: "hello_triangle",
+        title: "Hello Triangle",
+        section: "Hello Triangle",
+        url: HELLO_TRIANGLE,
+        description: "An orange triangle from a vertex buffer and a shader program",
+        scene: boxed_scene::<HelloTriangle>,
+    },
+    Chapter {
+        name: "hello_triangle_ebo",
+        title: "Hello Triangle Element Buffer Objects",
+        section: "Hello Triangle",
+        url: HELLO_TRIANGLE,
+        description: "A rectangle drawn from two triangles sharing indexed vertices",
+        scene: boxed_scene::<HelloTriangleEbo>,
+    },
+    Chapter {
+        name: "hello_triangle_ex_1",
+        title: "Hello Triangle Exercise 1",
+        section: "Hello Triangle",
+        url: HELLO_TRIANGLE,
+        description: "Two triangles next to each other from one vertex buffer",
+        scene: boxed_scene::<HelloTriangleEx1>,
+    },
+    Chapter {
+        name: "hello_triangle_ex_2",
+        title: "Hello Triangle Exercise 2",
+        section: "Hello Triangle",
+        url: HELLO_TRIANGLE,
+        description: "The same two triangles, each with its own VAO and VBO",
+        scene: boxed_scene::<HelloTriangleEx2>,
+    },
+    Chapter {
+        name: "hello_triangle_ex_3",
+        title: "Hello Triangle Exercise 3",
+        section: "Hello Triangle",
+        url: HELLO_TRIANGLE,
+        description: "Two triangles drawn with two shader programs, one of them yellow",
+        scene: boxed_scene::<HelloTriangleEx3>,
+    },
+    Chapter {
+        name: "shaders_ins_outs",
+        title: "Shaders Ins and Outs",
+        section: "Shaders",
+        url: SHADERS,
+        description: "A color passed from the vertex shader to the fragment shader",
+        scene: boxed_scene::<ShadersInsOuts>,
+    },
+    Chapter {
+        name: "shaders_uniforms",
+        title: "Shaders Uniforms",
+        section: "Shaders",
+        url: SHADERS,
+        description: "A triangle pulsing green through a uniform set every frame",
+        scene: boxed_scene::<ShadersUniforms>,
+    },
+    Chapter {
+        name: "shaders_more_attributes",
+        title: "Shaders More Attributes!",
+        section: "Shaders",
+        url: SHADERS,
+        description: "Per-vertex colors interpolated across the triangle",
+        scene: boxed_scene::<ShadersMoreAttributes>,
+    },
+    Chapter {
+        name: "shaders_class",
+        title: "Shaders Class",
+        section: "Shaders",
+        url: SHADERS,
+        description: "The colored triangle with its shaders loaded from files",
+        scene: boxed_scene::<ShadersClass>,
+    },
+    Chapter {
+        name: "textures_triangle",
+        title: "Textures Triangle with Texture",
+        section: "Textures",
+        url: TEXTURES,
+        description: "A brick wall texture on a triangle",
+        scene: boxed_scene::<TexturesTriangle>,
+    },
+    Chapter {
+        name: "textures_rectangle",
+        title: "Textures Rectangle with Texture",
+        section: "Textures",
+        url: TEXTURES,
+        description: "The container texture on a rectangle",
+        scene: boxed_scene::<TexturesRectangle>,
+    },
+    Chapter {
+        name: "textures_units",
+        title: "Textures Units",
+        section: "Textures",
+        url: TEXTURES,
+        description: "Two textures mixed in the fragment shader",
+        scene: boxed_scene::<TexturesUnits>,
+    },
+    Chapter {
+        name: "textures_ex_1",
+        title: "Textures Exercise 1",
+        section: "Textures",
+        url: TEXTURES,
+        description: "The happy face flipped to look the other way",
+        scene: boxed_scene::<TexturesEx1>,
+    },
+    Chapter {
+        name: "textures_ex_2",
+        title: "Textures Exercise 2",
+        section: "Textures",
+        url: TEXTURES,
+        description: "Texture coordinates up to 2.0 with different wrapping modes",
+        scene: boxed_scene::<TexturesEx2>,
+    },
+    Chapter {
+        name: "textures_ex_3",
+        title: "Textures Exercise 3",
+        section: "Textures",
+        url: TEXTURES,
+        description: "The center pixels of the textures magnified with nearest filtering",
+        scene: boxed_scene::<TexturesEx3>,
+    },
+    Chapter {
+        name: "textures_ex_4",
+        title: "Textures Exercise 4",
+        section: "Textures",
+        url: TEXTURES,
+        description: "Up and Down fade between the two textures",
+        scene: boxed_scene::<TexturesEx4>,
+    },
+    Chapter {
+        name: "transformations",
+        title: "Transformations",
+        section: "Transformations",
+        url: TRANSFORMATIONS,
+        description: "A container rotating in the bottom right corner, includes Exercise 1",
+        scene: boxed_scene::<Transformations>,
+    },
+    Chapter {
+        name: "transformations_ex_2",
+        title: "Transformations Exercise 2",
+        section: "Transformations",
+        url: TRANSFORMATIONS,
+        description: "A second container in the top left corner scaling over time",
+        scene: boxed_scene::<TransformationsEx2>,
+    },
+];
+
+// LearnOpenGL chapters this package doesn't have yet
+pub const IN_PROGRESS: &[&str] = &["Coordinate Systems", "Camera"];
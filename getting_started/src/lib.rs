@@ -0,0 +1,7 @@
+// the scenes keep the original tutorials' CStr::from_bytes_with_nul uniform names
+#![allow(clippy::manual_c_str_literals)]
+
+pub mod chapters;
+pub mod scenes;
+
+pub use chapters::*;
@@ -0,0 +1,229 @@
+/*
+    Hello Triangle - Element Buffer Objects Section
+    https://learnopengl.com/Getting-started/Hello-Triangle
+*/
+
+use common::{
+    app::{App, Scene, Window},
+    gl::{self, types::*},
+    state_cache,
+};
+use std::ffi::{CStr, CString};
+use std::{ptr, str};
+
+const VERTEX_SHADER_SOURCE: &str = r#"
+#version 330 core
+layout (location = 0) in vec3 aPos;
+void main()
+{
+    gl_Position = vec4(aPos.x, aPos.y, aPos.z, 1.0);
+}
+"#;
+
+const FRAGMENT_SHADER_SOURCE: &str = r#"
+#version 330 core
+out vec4 FragColor;
+void main()
+{
+    // Hex #ff8033, RBGA 0-255 rgba(255, 128, 51, 1)
+    FragColor = vec4(1.0f, 0.5f, 0.2f, 1.0f); // RGBA 0-1
+}
+"#;
+
+pub struct HelloTriangleEbo {
+    shader_program: u32,
+    vbo: u32,
+    vao: u32,
+    ebo: u32,
+}
+
+impl App for HelloTriangleEbo {
+    fn init(window: &mut Window) -> Self {
+        let gl = &mut window.gl;
+
+        let (shader_program, vbo, vao, ebo) = unsafe {
+            // build and compile our shader program
+            // ------------------------------------
+            // vertex shader
+            let vertex_shader = gl.CreateShader(gl::VERTEX_SHADER);
+            let vertex_shader_cstring = CString::new(VERTEX_SHADER_SOURCE.as_bytes()).unwrap();
+            let vertex_shader_ptr = &vertex_shader_cstring.as_ptr();
+            gl.ShaderSource(vertex_shader, 1, vertex_shader_ptr, ptr::null());
+            gl.CompileShader(vertex_shader);
+
+            // check for shader compile errors
+            let mut success = gl::FALSE as GLint;
+            let len = 512;
+            let mut info_log = Vec::with_capacity(len as usize - 1); // minus the null terminator
+            gl.GetShaderiv(vertex_shader, gl::COMPILE_STATUS, &mut success);
+            if success == gl::FALSE as GLint {
+                gl.GetShaderInfoLog(
+                    vertex_shader,
+                    len,
+                    ptr::null_mut(),
+                    info_log.as_mut_ptr() as *mut GLchar,
+                );
+                println!(
+                    "ERROR::SHADER::VERTEX::COMPILATION_FAILED\n{}",
+                    CStr::from_ptr(info_log.as_ptr()).to_string_lossy(),
+                );
+            };
+
+            // fragment shader
+            let fragment_shader = gl.CreateShader(gl::FRAGMENT_SHADER);
+            let fragment_shader_cstring = CString::new(FRAGMENT_SHADER_SOURCE.as_bytes()).unwrap();
+            let fragment_shader_ptr = &fragment_shader_cstring.as_ptr();
+            gl.ShaderSource(fragment_shader, 1, fragment_shader_ptr, ptr::null());
+            gl.CompileShader(fragment_shader);
+
+            // check for shader compile errors
+            gl.GetShaderiv(fragment_shader, gl::COMPILE_STATUS, &mut success);
+            if success == gl::FALSE as GLint {
+                gl.GetShaderInfoLog(
+                    fragment_shader,
+                    len,
+                    ptr::null_mut(),
+                    info_log.as_mut_ptr() as *mut GLchar,
+                );
+                println!(
+                    "ERROR::SHADER::FRAGMENT::COMPILATION_FAILED\n{}",
+                    CStr::from_ptr(info_log.as_ptr()).to_string_lossy(),
+                );
+            };
+
+            // link shaders
+            let shader_program = gl.CreateProgram();
+            gl.AttachShader(shader_program, vertex_shader);
+            gl.AttachShader(shader_program, fragment_shader);
+            gl.LinkProgram(shader_program);
+
+            // check for linker errors
+            gl.GetProgramiv(shader_program, gl::LINK_STATUS, &mut success);
+            if success == gl::FALSE as GLint {
+                gl.GetProgramInfoLog(
+                    shader_program,
+                    len,
+                    ptr::null_mut(),
+                    info_log.as_mut_ptr() as *mut GLchar,
+                );
+                println!(
+                    "ERROR::SHADER::PROGRAM::COMPILATION_FAILED\n{}",
+                    CStr::from_ptr(info_log.as_ptr()).to_string_lossy(),
+                );
+            };
+
+            // delete the shaders as they're linked into our program
+            gl.DeleteShader(vertex_shader);
+            gl.DeleteShader(fragment_shader);
+
+            // set up vertex data (and buffer(s)) and configure vertex attributes
+            // ------------------------------------------------------------------
+            let vertices: [f32; 12] = [
+                0.5, 0.5, 0.0, // top right
+                0.5, -0.5, 0.0, // bottom right
+                -0.5, -0.5, 0.0, // bottom left
+                -0.5, 0.5, 0.0, // top left
+            ];
+
+            let indices: [u32; 6] = [
+                0, 1, 3, // first triangle
+                1, 2, 3, // second triangle
+            ];
+
+            let mut vbo = 0;
+            let mut ebo = 0;
+            let mut vao = 0;
+            gl.GenVertexArrays(1, &mut vao);
+            gl.GenBuffers(1, &mut vbo);
+            gl.GenBuffers(1, &mut ebo);
+            // bind the Vertex Array Object first, then bind and set vertex buffer(s), and then configure vertex attributes(s).
+            // bind the Vertex Array Object
+            gl.BindVertexArray(vao);
+
+            // copy our vertices array into a buffer for OpenGL to use
+            gl.BindBuffer(gl::ARRAY_BUFFER, vbo);
+            gl.BufferData(
+                gl::ARRAY_BUFFER,
+                (vertices.len() * std::mem::size_of::<GLfloat>()) as GLsizeiptr,
+                vertices.as_ptr() as *const GLvoid,
+                gl::STATIC_DRAW,
+            );
+
+            gl.BindBuffer(gl::ELEMENT_ARRAY_BUFFER, ebo);
+            gl.BufferData(
+                gl::ELEMENT_ARRAY_BUFFER,
+                (indices.len() * std::mem::size_of::<GLfloat>()) as GLsizeiptr,
+                indices.as_ptr() as *const GLvoid,
+                gl::STATIC_DRAW,
+            );
+            // set the vertex attributes pointers
+            gl.VertexAttribPointer(
+                0,
+                3,
+                gl::FLOAT,
+                gl::FALSE,
+                3 * std::mem::size_of::<GLfloat>() as GLsizei,
+                ptr::null(),
+            );
+            gl.EnableVertexAttribArray(0);
+
+            // note that this is allowed, the call to glVertexAttribPointer registered VBO as the vertex attribute's bound vertex buffer object so afterwards we can safely unbind
+            gl.BindBuffer(gl::ARRAY_BUFFER, 0);
+            gl.DrawElements(
+                gl::TRIANGLES,
+                indices.len().try_into().unwrap(),
+                gl::UNSIGNED_INT,
+                ptr::null(),
+            );
+
+            // You can unbind the VAO afterwards so other VAO calls won't accidentally modify this VAO, but this rarely happens. Modifying other
+            // VAOs requires a call to glBindVertexArray anyways so we generally don't unbind VAOs (nor VBOs) when it's not directly necessary.
+            gl.BindVertexArray(0);
+
+            // uncomment this call to draw in wireframe polygons.
+            // gl.PolygonMode(gl::FRONT_AND_BACK, gl::LINE);
+
+            (shader_program, vbo, vao, ebo)
+        };
+
+        HelloTriangleEbo {
+            shader_program,
+            vbo,
+            vao,
+            ebo,
+        }
+    }
+}
+
+impl Scene for HelloTriangleEbo {
+    fn render(&mut self, window: &mut Window) {
+        let gl = &mut window.gl;
+
+        unsafe {
+            let [r, g, b, a] = window.config.clear_color;
+            gl.ClearColor(r, g, b, a);
+            gl.Clear(gl::COLOR_BUFFER_BIT);
+
+            // draw our first triangle
+            // use our shader program when we want to render an object
+            state_cache::use_program(gl, self.shader_program);
+            // Core OpenGL requires that we use a VAO so it knows what to do with our vertex inputs.
+            // If we fail to bind a VAO, OpenGL will most likely refuse to draw anything.
+            state_cache::bind_vertex_array(gl, self.vao);
+            // draw the object
+            gl.DrawElements(gl::TRIANGLES, 6, gl::UNSIGNED_INT, ptr::null());
+        }
+    }
+
+    // optional: de-allocate all resources once they've outlived their purpose:
+    // ------------------------------------------------------------------------
+    fn shutdown(&mut self, window: &mut Window) {
+        let gl = &mut window.gl;
+        unsafe {
+            gl.DeleteVertexArrays(1, &self.vao);
+            gl.DeleteBuffers(1, &self.vbo);
+            gl.DeleteBuffers(1, &self.ebo);
+            gl.DeleteProgram(self.shader_program);
+        }
+    }
+}
@@ -0,0 +1,28 @@
+/*
+    Hello Window
+    https://learnopengl.com/Getting-started/Hello-Window
+*/
+
+use common::{
+    app::{App, Scene, Window},
+    gl,
+};
+
+// the window, GL context and render loop are owned by common::app::run
+pub struct HelloWindow;
+
+impl App for HelloWindow {
+    fn init(_window: &mut Window) -> Self {
+        HelloWindow
+    }
+}
+
+impl Scene for HelloWindow {
+    fn render(&mut self, window: &mut Window) {
+        unsafe {
+            let [r, g, b, a] = window.config.clear_color;
+            window.gl.ClearColor(r, g, b, a);
+            window.gl.Clear(gl::COLOR_BUFFER_BIT);
+        }
+    }
+}
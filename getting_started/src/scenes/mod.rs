@@ -0,0 +1,39 @@
+pub mod hello_triangle;
+pub mod hello_triangle_ebo;
+pub mod hello_triangle_ex_1;
+pub mod hello_triangle_ex_2;
+pub mod hello_triangle_ex_3;
+pub mod hello_window;
+pub mod shaders_class;
+pub mod shaders_ins_outs;
+pub mod shaders_more_attributes;
+pub mod shaders_uniforms;
+pub mod textures_ex_1;
+pub mod textures_ex_2;
+pub mod textures_ex_3;
+pub mod textures_ex_4;
+pub mod textures_rectangle;
+pub mod textures_triangle;
+pub mod textures_units;
+pub mod transformations;
+pub mod transformations_ex_2;
+
+pub use hello_triangle::*;
+pub use hello_triangle_ebo::*;
+pub use hello_triangle_ex_1::*;
+pub use hello_triangle_ex_2::*;
+pub use hello_triangle_ex_3::*;
+pub use hello_window::*;
+pub use shaders_class::*;
+pub use shaders_ins_outs::*;
+pub use shaders_more_attributes::*;
+pub use shaders_uniforms::*;
+pub use textures_ex_1::*;
+pub use textures_ex_2::*;
+pub use textures_ex_3::*;
+pub use textures_ex_4::*;
+pub use textures_rectangle::*;
+pub use textures_triangle::*;
+pub use textures_units::*;
+pub use transformations::*;
+pub use transformations_ex_2::*;
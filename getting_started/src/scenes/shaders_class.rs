@@ -0,0 +1,131 @@
+/*
+    Shaders - Our own shader class
+    https://learnopengl.com/Getting-started/Shaders
+*/
+
+use common::{
+    app::{App, Scene, Window},
+    gl::{self, types::*},
+    shader::{Shader, ShaderBuilder},
+    state_cache,
+};
+use std::{ffi::CStr, ptr};
+
+pub struct ShadersClass {
+    our_shader: Shader,
+    vbo: u32,
+    vao: u32,
+}
+
+impl App for ShadersClass {
+    fn init(window: &mut Window) -> Self {
+        let (our_shader, vbo, vao) = unsafe {
+            let shader = ShaderBuilder::new(window.assets.clone()).build(
+                &mut window.gl,
+                "src/bin/shaders_class/shader.vs",
+                "src/bin/shaders_class/shader.fs",
+            );
+
+            // set up vertex data (and buffer(s)) and configure vertex attributes
+            // ------------------------------------------------------------------
+            let vertices: [f32; 18] = [
+                // positions     // colors
+                0.5, -0.5, 0.0, 1.0, 0.0, 0.0, // bottom right, red
+                -0.5, -0.5, 0.0, 0.0, 1.0, 0.0, // bottom left, green
+                0.0, 0.5, 0.0, 0.0, 0.0, 1.0, // top, blue
+            ];
+
+            let mut vbo = 0;
+            let mut vao = 0;
+            window.gl.GenVertexArrays(1, &mut vao);
+            window.gl.GenBuffers(1, &mut vbo);
+            // bind the Vertex Array Object first, then bind and set vertex buffer(s), and then configure vertex attributes(s).
+            // bind the Vertex Array Object
+            window.gl.BindVertexArray(vao);
+
+            // copy our vertices array into a buffer for OpenGL to use
+            window.gl.BindBuffer(gl::ARRAY_BUFFER, vbo);
+            window.gl.BufferData(
+                gl::ARRAY_BUFFER,
+                (vertices.len() * std::mem::size_of::<GLfloat>()) as GLsizeiptr,
+                vertices.as_ptr() as *const GLvoid,
+                gl::STATIC_DRAW,
+            );
+            // position attribute
+            window.gl.VertexAttribPointer(
+                0,
+                3,
+                gl::FLOAT,
+                gl::FALSE,
+                6 * std::mem::size_of::<GLfloat>() as GLsizei,
+                ptr::null(),
+            );
+            window.gl.EnableVertexAttribArray(0);
+
+            // color attribute
+            window.gl.VertexAttribPointer(
+                1,
+                3,
+                gl::FLOAT,
+                gl::FALSE,
+                6 * std::mem::size_of::<GLfloat>() as GLsizei,
+                (3 * std::mem::size_of::<GLfloat>()) as *const GLvoid,
+            );
+            window.gl.EnableVertexAttribArray(1);
+
+            // note that this is allowed, the call to glVertexAttribPointer registered VBO as the vertex attribute's bound vertex buffer object so afterwards we can safely unbind
+            // window.gl.BindBuffer(gl::ARRAY_BUFFER, 0);
+
+            // You can unbind the VAO afterwards so other VAO calls won't accidentally modify this VAO, but this rarely happens. Modifying other
+            // VAOs requires a call to glBindVertexArray anyways so we generally don't unbind VAOs (nor VBOs) when it's not directly necessary.
+            // window.gl.BindVertexArray(0);
+
+            // uncomment this call to draw in wireframe polygons.
+            // window.gl.PolygonMode(gl::FRONT_AND_BACK, gl::LINE);
+
+            (shader, vbo, vao)
+        };
+
+        ShadersClass {
+            our_shader,
+            vbo,
+            vao,
+        }
+    }
+}
+
+impl Scene for ShadersClass {
+    fn render(&mut self, window: &mut Window) {
+        unsafe {
+            // clear the color buffer
+            let [r, g, b, a] = window.config.clear_color;
+            window.gl.ClearColor(r, g, b, a);
+            window.gl.Clear(gl::COLOR_BUFFER_BIT);
+
+            // activate the shader
+            self.our_shader.use_program(&mut window.gl);
+
+            // Exercise 2
+            // add offset to x position
+            self.our_shader.set_float(
+                &mut window.gl,
+                CStr::from_bytes_with_nul(b"xOffset\0").unwrap(),
+                0.5,
+            );
+
+            // render the triangle
+            state_cache::bind_vertex_array(&mut window.gl, self.vao);
+            window.gl.DrawArrays(gl::TRIANGLES, 0, 3);
+        }
+    }
+
+    // optional: de-allocate all resources once they've outlived their purpose:
+    // ------------------------------------------------------------------------
+    fn shutdown(&mut self, window: &mut Window) {
+        unsafe {
+            window.gl.DeleteVertexArrays(1, &self.vao);
+            window.gl.DeleteBuffers(1, &self.vbo);
+            window.gl.DeleteProgram(self.our_shader.id);
+        }
+    }
+}
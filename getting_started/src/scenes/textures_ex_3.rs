@@ -0,0 +1,259 @@
+/*
+    Textures - Exercise 3
+    https://learnopengl.com/Getting-started/Textures
+*/
+
+use common::{
+    app::{App, Scene, Window},
+    asset::Handle,
+    gl::{self, types::*},
+    shader::{Shader, ShaderBuilder},
+    state_cache,
+    texture::{Texture, TextureParams},
+};
+use std::{ffi::CStr, mem, ptr};
+
+pub struct TexturesEx3 {
+    our_shader: Shader,
+    vbo: u32,
+    vao: u32,
+    ebo: u32,
+    texture1: Handle<Texture>,
+    texture2: Handle<Texture>,
+}
+
+impl App for TexturesEx3 {
+    fn init(window: &mut Window) -> Self {
+        let (our_shader, vbo, vao, ebo, texture1, texture2) = unsafe {
+            // build and compile our shader program
+            // ------------------------------------
+
+            let shader = ShaderBuilder::new(window.assets.clone()).build(
+                &mut window.gl,
+                "src/bin/textures_ex_3/texture.vs",
+                "src/bin/textures_ex_3/texture.fs",
+            );
+            // set up vertex data (and buffer(s)) and configure vertex attributes
+            // ------------------------------------------------------------------
+            let positions: [f32; 12] = [
+                0.5, 0.5, 0.0, // top right
+                0.5, -0.5, 0.0, // bottom right
+                -0.5, -0.5, 0.0, // bottom left
+                -0.5, 0.5, 0.0, // top left
+            ];
+
+            let colors: [f32; 12] = [
+                1.0, 0.0, 0.0, // red
+                0.0, 1.0, 0.0, // green
+                0.0, 0.0, 1.0, // blue
+                1.0, 1.0, 0.0, // yellow
+            ];
+
+            // texture coords (note that we changed them to 'zoom in' on our texture image)
+            let texture_coords: [f32; 8] = [
+                0.55, 0.55, // top right
+                0.55, 0.45, // bottom right
+                0.45, 0.45, // bottom left
+                0.45, 0.55, // top left
+            ];
+
+            // combine arrays into vertex data
+            let mut vertices = gen_vertices(positions.to_vec(), colors.to_vec(), 3, 3);
+            vertices = gen_vertices(vertices, texture_coords.to_vec(), 6, 2);
+            // convert vec to array
+            let vertices: [f32; 32] = vertices.try_into().unwrap();
+
+            let indices: [u32; 6] = [
+                0, 1, 3, // first triangle
+                1, 2, 3, // second triangle
+            ];
+
+            let mut vbo = 0;
+            let mut ebo = 0;
+            let mut vao = 0;
+            window.gl.GenVertexArrays(1, &mut vao);
+            window.gl.GenBuffers(1, &mut vbo);
+            window.gl.GenBuffers(1, &mut ebo);
+            // bind the Vertex Array Object first, then bind and set vertex buffer(s), and then configure vertex attributes(s).
+            // bind the Vertex Array Object
+            window.gl.BindVertexArray(vao);
+
+            // copy our vertices array into a buffer for Openwindow.gl to use
+            window.gl.BindBuffer(gl::ARRAY_BUFFER, vbo);
+            window.gl.BufferData(
+                gl::ARRAY_BUFFER,
+                (vertices.len() * mem::size_of::<GLfloat>()) as GLsizeiptr,
+                vertices.as_ptr() as *const GLvoid,
+                gl::STATIC_DRAW,
+            );
+
+            window.gl.BindBuffer(gl::ELEMENT_ARRAY_BUFFER, ebo);
+            window.gl.BufferData(
+                gl::ELEMENT_ARRAY_BUFFER,
+                (indices.len() * mem::size_of::<GLfloat>()) as GLsizeiptr,
+                indices.as_ptr() as *const GLvoid,
+                gl::STATIC_DRAW,
+            );
+            let stride = (8 * mem::size_of::<GLfloat>()) as GLsizei;
+            // position attribute
+            window
+                .gl
+                .VertexAttribPointer(0, 3, gl::FLOAT, gl::FALSE, stride, ptr::null());
+            window.gl.EnableVertexAttribArray(0);
+
+            // color attribute
+            window.gl.VertexAttribPointer(
+                1,
+                3,
+                gl::FLOAT,
+                gl::FALSE,
+                stride,
+                (3 * mem::size_of::<GLfloat>()) as *const GLvoid,
+            );
+            window.gl.EnableVertexAttribArray(1);
+
+            // texture attribute
+            window.gl.VertexAttribPointer(
+                2,
+                2,
+                gl::FLOAT,
+                gl::FALSE,
+                stride,
+                (6 * mem::size_of::<GLfloat>()) as *const GLvoid,
+            );
+            window.gl.EnableVertexAttribArray(2);
+
+            // load and create a texture
+            // -------------------------
+            // texture 1
+            // ---------
+            // load image, create texture and generate mipmaps
+            // decoded on a worker thread, a placeholder is shown until `textures.poll` uploads it
+            // the asset manager hands out the same texture to anything loading it the same way
+            let texture1 = window
+                .asset_manager
+                .texture_async(
+                    &mut window.gl,
+                    &mut window.textures,
+                    "resources/container.jpg",
+                    TextureParams {
+                        // set the texture wrapping parameters
+                        // note that we set the container wrapping method to GL_CLAMP_TO_EDGE
+                        wrap_s: gl::CLAMP_TO_EDGE,
+                        wrap_t: gl::CLAMP_TO_EDGE,
+                        // set texture filtering parameters
+                        min_filter: gl::NEAREST,
+                        mag_filter: gl::NEAREST,
+                        ..Default::default()
+                    },
+                )
+                .unwrap_or_else(|err| panic!("{}", err));
+
+            // texture 2
+            // ---------
+            // load image, create texture and generate mipmaps
+            // decoded on a worker thread, a placeholder is shown until `textures.poll` uploads it
+            let texture2 = window
+                .asset_manager
+                .texture_async(
+                    &mut window.gl,
+                    &mut window.textures,
+                    "resources/awesomeface.png",
+                    TextureParams {
+                        // set the texture wrapping parameters
+                        wrap_s: gl::REPEAT,
+                        wrap_t: gl::REPEAT,
+                        // set texture filtering parameters
+                        // set texture filtering to nearest neighbor to clearly see the texels/pixels
+                        min_filter: gl::NEAREST,
+                        mag_filter: gl::NEAREST,
+                        // flip the texture vertically
+                        flip_vertically: true,
+                        ..Default::default()
+                    },
+                )
+                .unwrap_or_else(|err| panic!("{}", err));
+
+            // tell opengl for each sampler to which texture unit it belongs to (only has to be done once)
+            // -------------------------------------------------------------------------------------------
+            // don't forget to activate/use the shader before setting uniforms!
+            shader.use_program(&mut window.gl);
+            // either set it manually like so:
+            window.gl.Uniform1i(
+                window.gl.GetUniformLocation(
+                    shader.id,
+                    CStr::from_bytes_with_nul(b"texture1\0").unwrap().as_ptr(),
+                ),
+                0,
+            );
+            // or set it via the shader class
+            shader.set_int(
+                &mut window.gl,
+                CStr::from_bytes_with_nul(b"texture2\0").unwrap(),
+                1,
+            );
+
+            // uncomment this call to draw in wireframe polygons.
+            // window.gl.PolygonMode(gl::FRONT_AND_BACK, gl::LINE);
+
+            (shader, vbo, vao, ebo, texture1, texture2)
+        };
+
+        TexturesEx3 {
+            our_shader,
+            vbo,
+            vao,
+            ebo,
+            texture1,
+            texture2,
+        }
+    }
+}
+
+impl Scene for TexturesEx3 {
+    fn render(&mut self, window: &mut Window) {
+        unsafe {
+            let [r, g, b, a] = window.config.clear_color;
+            window.gl.ClearColor(r, g, b, a);
+            window.gl.Clear(gl::COLOR_BUFFER_BIT);
+
+            // bind Texture
+            state_cache::active_texture(&mut window.gl, gl::TEXTURE0);
+            state_cache::bind_texture(&mut window.gl, gl::TEXTURE_2D, self.texture1.id);
+            state_cache::active_texture(&mut window.gl, gl::TEXTURE1);
+            state_cache::bind_texture(&mut window.gl, gl::TEXTURE_2D, self.texture2.id);
+
+            // render the container
+            self.our_shader.use_program(&mut window.gl);
+            state_cache::bind_vertex_array(&mut window.gl, self.vao);
+            window
+                .gl
+                .DrawElements(gl::TRIANGLES, 6, gl::UNSIGNED_INT, ptr::null());
+        }
+    }
+
+    // optional: de-allocate all resources once they've outlived their purpose:
+    // ------------------------------------------------------------------------
+    fn shutdown(&mut self, window: &mut Window) {
+        unsafe {
+            window.gl.DeleteVertexArrays(1, &self.vao);
+            window.gl.DeleteBuffers(1, &self.vbo);
+            window.gl.DeleteBuffers(1, &self.ebo);
+            window.gl.DeleteProgram(self.our_shader.id);
+        }
+    }
+}
+
+// combine two vectors into a single array of vertices according to a step size
+// Example:
+//   gen_vertices(vec![1.0, 2.0, 3.0, 4.0, 5.0], vec![9.0, 8.0, 7.0, 6.0, 5.0], 2, 2)
+//   => vec![1.0, 2.0, 9.0, 8.0, 3.0, 4.0, 7.0, 6.0, 5.0, 5.0]
+// -------------------------------------------------------------------------------------------------
+fn gen_vertices(lhs: Vec<f32>, rhs: Vec<f32>, lhs_step: usize, rhs_step: usize) -> Vec<f32> {
+    let mut vertices: Vec<f32> = vec![];
+    for i in 0..lhs.len() / lhs_step {
+        vertices.extend_from_slice(&lhs[i * lhs_step..(i + 1) * lhs_step]);
+        vertices.extend_from_slice(&rhs[i * rhs_step..(i + 1) * rhs_step]);
+    }
+    vertices
+}
@@ -0,0 +1,38 @@
+// The chapter list has to match src/bin, and README.md has to be generated from
+// the current list.
+
+use common::chapter::readme;
+use getting_started::{CHAPTERS, IN_PROGRESS};
+use std::{fs, path::PathBuf};
+
+// binaries that aren't tutorials
+const TOOLS: &[&str] = &["launcher", "switcher"];
+
+fn package_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+#[test]
+fn every_binary_is_a_chapter() {
+    let mut binaries: Vec<String> = fs::read_dir(package_dir().join("src/bin"))
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+        .filter(|name| !TOOLS.contains(&name.as_str()))
+        .collect();
+    binaries.sort();
+
+    let mut chapters: Vec<String> = CHAPTERS.iter().map(|c| c.name.to_string()).collect();
+    chapters.sort();
+
+    assert_eq!(binaries, chapters, "src/chapters.rs is out of date");
+}
+
+#[test]
+fn readme_is_generated() {
+    let expected = readme(env!("CARGO_PKG_NAME"), CHAPTERS, IN_PROGRESS);
+    let actual = fs::read_to_string(package_dir().join("README.md")).unwrap();
+    assert!(
+        actual == expected,
+        "README.md is out of date, regenerate it with\n  cargo run -p getting_started --bin launcher -- --readme > getting_started/README.md"
+    );
+}
@@ -0,0 +1,257 @@
+// Golden image tests: every tutorial is rendered headlessly at a fixed time and
+// compared against tests/golden/<bin>.png.
+//
+// Needs a headless OpenGL 3.3 context, by default OSMesa (Mesa llvmpipe), see
+// the README. Without one the tests are skipped with a message. Environment
+// variables:
+//   GOLDEN_CONTEXT_API  native, egl or osmesa (default), never skipped when set
+//   UPDATE_GOLDEN=1     write the rendered frames as the new references
+//
+// On a mismatch the rendered frame and a diff image are written to
+// target/golden/<bin>.png and target/golden/<bin>_diff.png.
+
+use image::{Rgba, RgbaImage};
+use std::{
+    env, fs,
+    io::{self, Write},
+    path::PathBuf,
+    process::Command,
+    sync::OnceLock,
+};
+
+// time the tutorials' clocks are frozen at, far enough in for animations to have moved
+const TIME: &str = "1.0";
+// a few frames so anything that needs a frame to settle has had it
+const FRAMES: &str = "3";
+
+// pixels further apart than this CIE76 color difference count as different,
+// around 2.3 is the smallest difference people notice
+const MAX_DELTA_E: f32 = 4.0;
+// and at most this share of pixels may differ, rasterization rules allow a
+// little freedom along triangle edges
+const MAX_DIFFERENT: f32 = 0.002;
+
+fn golden_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/golden")
+}
+
+fn output_dir() -> PathBuf {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../target/golden");
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn context_api() -> String {
+    env::var("GOLDEN_CONTEXT_API").unwrap_or_else(|_| String::from("osmesa"))
+}
+
+// whether a headless context can be created here, probed once with the
+// simplest tutorial. An API asked for explicitly isn't probed, it has to work.
+fn context_available() -> bool {
+    static AVAILABLE: OnceLock<bool> = OnceLock::new();
+    *AVAILABLE.get_or_init(|| {
+        if env::var_os("GOLDEN_CONTEXT_API").is_some() {
+            return true;
+        }
+        let probe = Command::new(env!("CARGO_BIN_EXE_hello_window"))
+            .args([
+                "--headless",
+                "--context-api",
+                &context_api(),
+                "--frames",
+                "1",
+            ])
+            .output()
+            .expect("failed to start hello_window");
+        if !probe.status.success() {
+            let stderr = String::from_utf8_lossy(&probe.stderr);
+            // straight to stderr, eprintln! is captured for passing tests
+            let _ = writeln!(
+                io::stderr(),
+                "skipping golden tests, no headless {} context: {}",
+                context_api(),
+                stderr
+                    .lines()
+                    .find(|line| line.contains("GLFW"))
+                    .unwrap_or("")
+            );
+        }
+        probe.status.success()
+    })
+}
+
+fn render(name: &str, exe: &str) -> RgbaImage {
+    let output = output_dir().join(format!("{}.png", name));
+    let context_api = context_api();
+    let status = Command::new(exe)
+        .args(["--headless", "--context-api", &context_api])
+        .args(["--size", "800x600", "--frames", FRAMES, "--time", TIME])
+        .arg("--output")
+        .arg(&output)
+        .status()
+        .unwrap_or_else(|err| panic!("failed to start {}: {}", exe, err));
+    assert!(status.success(), "{} exited with {}", name, status);
+
+    image::open(&output)
+        .unwrap_or_else(|err| panic!("failed to read {}: {}", output.display(), err))
+        .into_rgba8()
+}
+
+fn check(name: &str, exe: &str) {
+    if !context_available() {
+        return;
+    }
+    let actual = render(name, exe);
+    let reference_path = golden_dir().join(format!("{}.png", name));
+
+    if env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::create_dir_all(golden_dir()).unwrap();
+        actual.save(&reference_path).unwrap();
+        return;
+    }
+
+    let reference = image::open(&reference_path)
+        .unwrap_or_else(|err| {
+            panic!(
+                "no reference image {} ({}), run with UPDATE_GOLDEN=1 to create it",
+                reference_path.display(),
+                err
+            )
+        })
+        .into_rgba8();
+    assert_eq!(
+        actual.dimensions(),
+        reference.dimensions(),
+        "{} rendered at the wrong size",
+        name
+    );
+
+    let comparison = compare(&actual, &reference);
+    if comparison.different_share > MAX_DIFFERENT {
+        let diff_path = output_dir().join(format!("{}_diff.png", name));
+        comparison.diff.save(&diff_path).unwrap();
+        panic!(
+            "{} differs from its reference in {:.3}% of pixels (max delta E {:.1}, PSNR {:.1} dB), see {}",
+            name,
+            comparison.different_share * 100.0,
+            comparison.max_delta_e,
+            comparison.psnr,
+            diff_path.display()
+        );
+    }
+}
+
+struct Comparison {
+    different_share: f32,
+    max_delta_e: f32,
+    psnr: f32,
+    // the reference in grey with every differing pixel in red
+    diff: RgbaImage,
+}
+
+fn compare(actual: &RgbaImage, reference: &RgbaImage) -> Comparison {
+    let mut diff = RgbaImage::new(actual.width(), actual.height());
+    let mut different = 0;
+    let mut max_delta_e: f32 = 0.0;
+    let mut squared_error = 0.0;
+
+    for (x, y, a) in actual.enumerate_pixels() {
+        let r = reference.get_pixel(x, y);
+        let delta_e = delta_e(a, r);
+        max_delta_e = max_delta_e.max(delta_e);
+        for c in 0..3 {
+            squared_error += (a[c] as f64 - r[c] as f64).powi(2);
+        }
+
+        let pixel = if delta_e > MAX_DELTA_E {
+            different += 1;
+            Rgba([255, 0, 0, 255])
+        } else {
+            let grey = (luma(r) * 0.5) as u8;
+            Rgba([grey, grey, grey, 255])
+        };
+        diff.put_pixel(x, y, pixel);
+    }
+
+    let pixels = (actual.width() * actual.height()).max(1) as f64;
+    let mse = squared_error / (pixels * 3.0);
+    Comparison {
+        different_share: different as f32 / pixels as f32,
+        max_delta_e,
+        psnr: if mse == 0.0 {
+            f32::INFINITY
+        } else {
+            (10.0 * (255.0 * 255.0 / mse).log10()) as f32
+        },
+        diff,
+    }
+}
+
+fn luma(p: &Rgba<u8>) -> f32 {
+    0.2126 * p[0] as f32 + 0.7152 * p[1] as f32 + 0.0722 * p[2] as f32
+}
+
+// perceptual color difference, euclidean distance in CIELAB
+fn delta_e(a: &Rgba<u8>, b: &Rgba<u8>) -> f32 {
+    let (a, b) = (lab(a), lab(b));
+    ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt()
+}
+
+// sRGB to CIELAB with a D65 white point
+fn lab(p: &Rgba<u8>) -> [f32; 3] {
+    let linear = |c: u8| {
+        let c = c as f32 / 255.0;
+        if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    let (r, g, b) = (linear(p[0]), linear(p[1]), linear(p[2]));
+    let x = (0.4124 * r + 0.3576 * g + 0.1805 * b) / 0.95047;
+    let y = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+    let z = (0.0193 * r + 0.1192 * g + 0.9505 * b) / 1.08883;
+
+    let f = |t: f32| {
+        if t > 0.008856 {
+            t.cbrt()
+        } else {
+            7.787 * t + 16.0 / 116.0
+        }
+    };
+    let (fx, fy, fz) = (f(x), f(y), f(z));
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+macro_rules! golden {
+    ($($bin:ident),* $(,)?) => {
+        $(
+            #[test]
+            fn $bin() {
+                check(stringify!($bin), env!(concat!("CARGO_BIN_EXE_", stringify!($bin))));
+            }
+        )*
+    };
+}
+
+golden!(
+    hello_window,
+    hello_triangle,
+    hello_triangle_ebo,
+    hello_triangle_ex_1,
+    hello_triangle_ex_2,
+    hello_triangle_ex_3,
+    shaders_uniforms,
+    shaders_ins_outs,
+    shaders_more_attributes,
+    shaders_class,
+    textures_triangle,
+    textures_rectangle,
+    textures_units,
+    textures_ex_1,
+    textures_ex_2,
+    textures_ex_3,
+    textures_ex_4,
+    transformations,
+    transformations_ex_2,
+);
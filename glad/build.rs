@@ -0,0 +1,507 @@
+// Generates the `glad::gl` bindings from the vendored gl.xml when the `slim`
+// feature is on. Only the core functions and enums of the chosen version and
+// profile go in, plus the extensions enabled as features (or listed in
+// GLAD_EXTENSIONS), so there's far less to compile and load than gl33/gl46.
+//
+// Cargo.toml has the version-*, compat and extension features, any extension
+// in gl.xml can be added there with its exact name, e.g. `GL_KHR_debug = ["slim"]`.
+//
+// The static parts (FnPtr, types, the func! macro) live in src/gl.rs, this
+// writes three files into OUT_DIR which it includes:
+//   gl_enumerations.rs   pub const NAME: type = value;
+//   gl_functions.rs      the Gl struct and its func! list
+//   gl_loader.rs         load, CORE_FUNCTIONS, FUNCTIONS and the name lookup
+//
+// Whatever the features it writes the version each core function appeared in,
+// see `core_versions`, and with `trace` the replay table, see `replay_table`.
+
+use roxmltree::{Document, Node};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    env,
+    fmt::Write,
+    fs,
+    path::PathBuf,
+};
+
+// in the order they're checked, the newest enabled one wins
+const VERSIONS: &[(u32, u32)] = &[
+    (3, 3),
+    (4, 0),
+    (4, 1),
+    (4, 2),
+    (4, 3),
+    (4, 4),
+    (4, 5),
+    (4, 6),
+];
+
+const RUST_KEYWORDS: &[&str] = &["box", "fn", "impl", "in", "loop", "ref", "type", "use"];
+
+struct Command {
+    // without the gl prefix
+    name: String,
+    ret: String,
+    params: Vec<(String, String)>,
+    alias: Option<String>,
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=gl.xml");
+    println!("cargo:rerun-if-env-changed=GLAD_EXTENSIONS");
+    if feature("trace") {
+        replay_table();
+    }
+
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let xml = fs::read_to_string(manifest_dir.join("gl.xml")).expect("failed to read gl.xml");
+    let registry = Document::parse(&xml).expect("failed to parse gl.xml");
+    let registry = registry.root_element();
+    core_versions(registry);
+    if !feature("slim") {
+        return;
+    }
+
+    let version = VERSIONS
+        .iter()
+        .copied()
+        .rev()
+        .find(|(major, minor)| feature(&format!("version-{}-{}", major, minor)))
+        .unwrap_or((3, 3));
+    let core = !feature("compat");
+
+    let mut enums = BTreeSet::new();
+    let mut core_commands = BTreeSet::new();
+    for feature in children(registry, "feature") {
+        if feature.attribute("api") != Some("gl") || parse_version(feature) > version {
+            continue;
+        }
+        for require in children(feature, "require") {
+            if profile_matches(require, core) {
+                add_names(require, &mut enums, &mut core_commands);
+            }
+        }
+        // compatibility contexts keep everything
+        for remove in children(feature, "remove") {
+            if core && profile_matches(remove, core) {
+                for node in remove.children().filter(Node::is_element) {
+                    let name = node.attribute("name").unwrap();
+                    enums.remove(name);
+                    core_commands.remove(name);
+                }
+            }
+        }
+    }
+
+    let mut commands = core_commands.clone();
+    for name in extensions() {
+        let extension = children(registry, "extensions")
+            .flat_map(|extensions| children(extensions, "extension"))
+            .find(|extension| {
+                let found = extension.attribute("name").unwrap_or("");
+                found.eq_ignore_ascii_case(&name)
+            })
+            .unwrap_or_else(|| panic!("gl.xml has no extension {}", name));
+        let name = extension.attribute("name").unwrap();
+        let supported = extension.attribute("supported").unwrap_or("");
+        let api = if core { "glcore" } else { "gl" };
+        // gl.xml is conservative, e.g. anisotropic filtering isn't marked
+        // glcore but drivers have it in core contexts anyway
+        if !supported.split('|').any(|s| s == api) {
+            println!("cargo:warning={} isn't marked as {} in gl.xml", name, api);
+        }
+        for require in children(extension, "require") {
+            let api_matches = matches!(require.attribute("api"), None | Some("gl"));
+            if api_matches && profile_matches(require, core) {
+                add_names(require, &mut enums, &mut commands);
+            }
+        }
+    }
+
+    let values = enum_values(registry);
+    let all_commands = parse_commands(registry);
+    let selected: BTreeMap<&str, &Command> = commands
+        .iter()
+        .map(|name| {
+            let command = all_commands
+                .get(name.as_str())
+                .unwrap_or_else(|| panic!("gl.xml has no command {}", name));
+            (command.name.as_str(), command)
+        })
+        .collect();
+    let core_commands: Vec<&str> = core_commands.iter().map(|name| &name[2..]).collect();
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let write = |file: &str, contents: String| {
+        fs::write(out_dir.join(file), contents)
+            .unwrap_or_else(|err| panic!("failed to write {}: {}", file, err))
+    };
+    write("gl_enumerations.rs", enumerations(&enums, &values));
+    write("gl_functions.rs", functions(&selected));
+    write("gl_loader.rs", loader(version, &selected, &core_commands));
+}
+
+// The `trace` feature replays calls by name, which takes every gl33 function
+// with its parameter names and types. They come from the func! list in
+// src/gl33.rs rather than gl.xml, which is newer than the checked-in bindings.
+// Writes OUT_DIR/gl33_replay.rs, included by src/replay.rs:
+//   SIGNATURES   every function, sorted by name
+//   dispatch     calls a function by name with trace values
+fn replay_table() {
+    println!("cargo:rerun-if-changed=src/gl33.rs");
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let source =
+        fs::read_to_string(manifest_dir.join("src/gl33.rs")).expect("failed to read gl33.rs");
+
+    let mut commands = BTreeMap::new();
+    let mut rest = source.as_str();
+    while let Some(start) = rest.find("func!(") {
+        rest = &rest[start + "func!(".len()..];
+        let end = rest.find(");").expect("func! without an end in gl33.rs");
+        let parts: Vec<&str> = rest[..end]
+            .split(',')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .collect();
+        rest = &rest[end..];
+
+        let params = parts[2..]
+            .iter()
+            .map(|param| {
+                let (name, ty) = param
+                    .split_once(':')
+                    .expect("func! parameter without a type");
+                (name.trim().to_string(), ty.trim().to_string())
+            })
+            .collect();
+        let command = Command {
+            name: parts[0].to_string(),
+            ret: parts[1].to_string(),
+            params,
+            alias: None,
+        };
+        commands.insert(command.name.clone(), command);
+    }
+
+    let mut out = String::from("pub static SIGNATURES: &[Signature] = &[\n");
+    for command in commands.values() {
+        let params = command
+            .params
+            .iter()
+            .map(|(name, ty)| format!("(\"{}\", \"{}\")", name, ty))
+            .collect::<Vec<_>>();
+        let _ = writeln!(
+            out,
+            "    Signature {{ name: \"{}\", ret: \"{}\", params: &[{}] }},",
+            command.name,
+            command.ret,
+            params.join(", ")
+        );
+    }
+    out += "];\n\n";
+
+    out += "pub(crate) unsafe fn dispatch(gl: &Gl, function: &str, args: &[Value]) -> Option<Value> {\n";
+    out += "    Some(match function {\n";
+    for command in commands.values() {
+        // a callback from a trace can't be called, it's from another process
+        let callback = |ty: &str| ty.starts_with("GLDEBUGPROC") || ty == "GLVULKANPROCNV";
+        if callback(&command.ret) || command.params.iter().any(|(_, ty)| callback(ty)) {
+            continue;
+        }
+        let args = (0..command.params.len())
+            .map(|i| format!("Arg::arg(args[{}])", i))
+            .collect::<Vec<_>>();
+        let call = format!("gl.{}({})", command.name, args.join(", "));
+        let value = match command.ret.as_str() {
+            "()" => format!("{{ {}; Value::Void }}", call),
+            _ => format!("Value::from({})", call),
+        };
+        let _ = writeln!(out, "        \"{}\" => {},", command.name, value);
+    }
+    out += "        _ => return None,\n    })\n}\n";
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("gl33_replay.rs"), out).expect("failed to write gl33_replay.rs");
+}
+
+// Writes OUT_DIR/core_versions.rs, included by src/load_report.rs:
+//   CORE_VERSIONS   (name without the gl prefix, the first version with it),
+//                   sorted by name
+// The same for every set of bindings, so a report can leave out what a context
+// older than the bindings never had.
+fn core_versions(registry: Node) {
+    let mut versions = BTreeMap::new();
+    for feature in children(registry, "feature") {
+        if feature.attribute("api") != Some("gl") {
+            continue;
+        }
+        let version = parse_version(feature);
+        for require in children(feature, "require") {
+            for command in children(require, "command") {
+                let name = &command.attribute("name").unwrap()[2..];
+                let first = versions.entry(name).or_insert(version);
+                *first = version.min(*first);
+            }
+        }
+    }
+
+    let mut out = String::from("static CORE_VERSIONS: &[(&str, (u32, u32))] = &[\n");
+    for (name, (major, minor)) in versions {
+        let _ = writeln!(out, "    (\"{}\", ({}, {})),", name, major, minor);
+    }
+    out += "];\n";
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("core_versions.rs"), out).expect("failed to write core_versions.rs");
+}
+
+fn feature(name: &str) -> bool {
+    let var = format!("CARGO_FEATURE_{}", name.to_uppercase().replace('-', "_"));
+    env::var_os(var).is_some()
+}
+
+// extension features are named like the extension, GLAD_EXTENSIONS adds more
+// Cargo only tells build scripts about enabled features as CARGO_FEATURE_<NAME>,
+// so take every GL_* feature variable as an extension and get the name's case
+// back from gl.xml.
+fn extensions() -> Vec<String> {
+    let mut names = BTreeSet::new();
+    if let Ok(list) = env::var("GLAD_EXTENSIONS") {
+        names.extend(
+            list.split(',')
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .map(String::from),
+        );
+    }
+    for (var, _) in env::vars() {
+        if let Some(name) = var.strip_prefix("CARGO_FEATURE_GL_") {
+            names.insert(format!("GL_{}", name));
+        }
+    }
+    names.into_iter().collect()
+}
+
+fn children<'a, 'input>(
+    node: Node<'a, 'input>,
+    tag: &'static str,
+) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children().filter(move |child| child.has_tag_name(tag))
+}
+
+fn parse_version(feature: Node) -> (u32, u32) {
+    let number = feature.attribute("number").unwrap();
+    let (major, minor) = number.split_once('.').unwrap();
+    (major.parse().unwrap(), minor.parse().unwrap())
+}
+
+fn profile_matches(node: Node, core: bool) -> bool {
+    match node.attribute("profile") {
+        Some("core") => core,
+        Some("compatibility") => !core,
+        _ => true,
+    }
+}
+
+fn add_names(require: Node, enums: &mut BTreeSet<String>, commands: &mut BTreeSet<String>) {
+    for node in require.children().filter(Node::is_element) {
+        let name = node.attribute("name").unwrap().to_string();
+        match node.tag_name().name() {
+            "enum" => enums.insert(name),
+            "command" => commands.insert(name),
+            _ => false,
+        };
+    }
+}
+
+// name -> (value, type attribute), GL's definitions win over GLES ones
+fn enum_values<'a>(registry: Node<'a, '_>) -> BTreeMap<&'a str, (&'a str, Option<&'a str>)> {
+    let mut values = BTreeMap::new();
+    for group in children(registry, "enums") {
+        for node in children(group, "enum") {
+            if !matches!(node.attribute("api"), None | Some("gl")) {
+                continue;
+            }
+            values.insert(
+                node.attribute("name").unwrap(),
+                (node.attribute("value").unwrap(), node.attribute("type")),
+            );
+        }
+    }
+    values
+}
+
+// keyed by the full name, e.g. "glClear"
+fn parse_commands(registry: Node) -> BTreeMap<String, Command> {
+    let mut commands = BTreeMap::new();
+    for command in children(registry, "commands").flat_map(|node| children(node, "command")) {
+        let proto = children(command, "proto").next().unwrap();
+        let (name, ret) = declaration(proto);
+        let ret = match ret.as_str() {
+            "void" => String::from("()"),
+            _ => rust_type(&ret),
+        };
+        let params = children(command, "param")
+            .map(|param| {
+                let (name, ty) = declaration(param);
+                let name = match RUST_KEYWORDS.contains(&name.as_str()) {
+                    true => format!("{}_", name),
+                    false => name,
+                };
+                (name, rust_type(&ty))
+            })
+            .collect();
+        let alias = children(command, "alias")
+            .next()
+            .map(|alias| alias.attribute("name").unwrap()[2..].to_string());
+        commands.insert(
+            name.clone(),
+            Command {
+                name: name[2..].to_string(),
+                ret,
+                params,
+                alias,
+            },
+        );
+    }
+    commands
+}
+
+// the <name> of a <proto> or <param> and the C type around it
+fn declaration(node: Node) -> (String, String) {
+    let mut name = String::new();
+    let mut ty = String::new();
+    for child in node.children() {
+        if child.has_tag_name("name") {
+            name = child.text().unwrap_or("").to_string();
+        } else if child.is_text() || child.has_tag_name("ptype") {
+            ty += child.text().unwrap_or("");
+        }
+    }
+    (name, ty.trim().to_string())
+}
+
+// "const GLchar *const*" -> "*const *const GLchar"
+fn rust_type(c: &str) -> String {
+    let c = c.replace('*', " * ");
+    let mut base = "";
+    let mut base_const = false;
+    // whether each pointer is itself const, that decides the next level's mutability
+    let mut pointers: Vec<bool> = vec![];
+    for token in c.split_whitespace() {
+        match token {
+            "const" => match pointers.last_mut() {
+                Some(pointer) => *pointer = true,
+                None => base_const = true,
+            },
+            "struct" => {}
+            "*" => pointers.push(false),
+            ty => base = ty,
+        }
+    }
+
+    let mut ty = match base {
+        "void" => String::from("c_void"),
+        ty => ty.to_string(),
+    };
+    let mut pointee_const = base_const;
+    for pointer_const in pointers {
+        let mutability = if pointee_const { "const" } else { "mut" };
+        ty = format!("*{} {}", mutability, ty);
+        pointee_const = pointer_const;
+    }
+    ty
+}
+
+fn enumerations(enums: &BTreeSet<String>, values: &BTreeMap<&str, (&str, Option<&str>)>) -> String {
+    let mut out = String::new();
+    for name in enums {
+        let (value, suffix) = values
+            .get(name.as_str())
+            .unwrap_or_else(|| panic!("gl.xml has no value for {}", name));
+        let ty = match (name.as_str(), suffix) {
+            ("GL_TRUE" | "GL_FALSE", _) => "c_uchar",
+            (_, Some("ull")) => "u64",
+            _ if value.starts_with('-') => "c_int",
+            _ => "c_uint",
+        };
+        let _ = writeln!(out, "pub const {}: {} = {};", const_name(name), ty, value);
+    }
+    out
+}
+
+// GL_2D -> _2D, identifiers can't start with a digit
+fn const_name(name: &str) -> String {
+    let name = &name[3..];
+    match name.starts_with(|c: char| c.is_ascii_digit()) {
+        true => format!("_{}", name),
+        false => name.to_string(),
+    }
+}
+
+fn functions(commands: &BTreeMap<&str, &Command>) -> String {
+    let mut out = String::from("pub struct Gl {\n");
+    for name in commands.keys() {
+        let _ = writeln!(out, "    pub(super) {}: FnPtr,", name);
+    }
+    out += "}\n\nimpl Gl {\n";
+    for command in commands.values() {
+        let _ = write!(out, "    func!({}, {},", command.name, command.ret);
+        let params = command
+            .params
+            .iter()
+            .map(|(name, ty)| format!(" {}: {}", name, ty))
+            .collect::<Vec<_>>();
+        let _ = writeln!(out, "{});", params.join(","));
+    }
+    out += "}\n";
+    out
+}
+
+fn loader(version: (u32, u32), commands: &BTreeMap<&str, &Command>, core: &[&str]) -> String {
+    let mut out = String::new();
+    out += "// the functions and which of them the driver had\n";
+    out += "pub fn load<F>(mut loadfn: F) -> (functions::Gl, LoadReport)\n";
+    out += "where\n    F: FnMut(&'static str) -> *const c_void,\n{\n";
+    out += "    #[allow(unused_mut)]\n    let mut ctx = Gl {\n";
+    for name in commands.keys() {
+        let _ = writeln!(out, "        {0}: FnPtr::new(loadfn(\"gl{0}\")),", name);
+    }
+    out += "    };\n\n";
+    // both ways, whichever name the driver had fills in the other
+    for command in commands.values() {
+        if let Some(alias) = command.alias.as_deref() {
+            if commands.contains_key(alias) {
+                let _ = writeln!(out, "    ctx.{}.aliased(&ctx.{});", command.name, alias);
+                let _ = writeln!(out, "    ctx.{}.aliased(&ctx.{});", alias, command.name);
+            }
+        }
+    }
+    out += "\n    let report = ctx.load_report();\n    (ctx, report)\n}\n\n";
+
+    let _ = writeln!(
+        out,
+        "// the core version these bindings were generated for\npub const CORE_VERSION: (u32, u32) = ({}, {});\n",
+        version.0, version.1
+    );
+    out += "// core functions of CORE_VERSION, without the gl prefix\n";
+    out += "pub static CORE_FUNCTIONS: &[&str] = &[\n";
+    for name in core {
+        let _ = writeln!(out, "    \"{}\",", name);
+    }
+    out += "];\n\n";
+    out += "// every function in these bindings, without the gl prefix\n";
+    out += "pub static FUNCTIONS: &[&str] = &[\n";
+    for name in commands.keys() {
+        let _ = writeln!(out, "    \"{}\",", name);
+    }
+    out += "];\n\n";
+
+    out += "impl Gl {\n    fn fn_ptr(&self, name: &str) -> Option<&FnPtr> {\n        Some(match name {\n";
+    for name in commands.keys() {
+        let _ = writeln!(out, "            \"{0}\" => &self.{0},", name);
+    }
+    out += "            _ => return None,\n        })\n    }\n}\n";
+    out
+}
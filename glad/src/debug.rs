@@ -0,0 +1,107 @@
+// Error checking for the `debug` feature: every generated function drains
+// glGetError after the call and reports what it finds with the function, its
+// arguments and the line that called it.
+//
+// What happens to an error is set with `set_error_mode`, or the GL_ERRORS
+// environment variable (panic, log or ignore). Panicking is the default.
+
+use std::{
+    env,
+    panic::Location,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorMode {
+    Panic,
+    // print to stderr and carry on
+    Log,
+    Ignore,
+}
+
+// not set yet, read GL_ERRORS on first use
+const UNSET: u8 = u8::MAX;
+
+static MODE: AtomicU8 = AtomicU8::new(UNSET);
+
+// a broken context can report errors forever, stop draining after this many
+const MAX_ERRORS: usize = 8;
+
+pub fn set_error_mode(mode: ErrorMode) {
+    MODE.store(mode as u8, Ordering::Relaxed);
+}
+
+pub fn error_mode() -> ErrorMode {
+    match MODE.load(Ordering::Relaxed) {
+        UNSET => {
+            let mode = match env::var("GL_ERRORS").as_deref() {
+                Ok("log") => ErrorMode::Log,
+                Ok("ignore") => ErrorMode::Ignore,
+                _ => ErrorMode::Panic,
+            };
+            set_error_mode(mode);
+            mode
+        }
+        mode if mode == ErrorMode::Log as u8 => ErrorMode::Log,
+        mode if mode == ErrorMode::Ignore as u8 => ErrorMode::Ignore,
+        _ => ErrorMode::Panic,
+    }
+}
+
+// name of a glGetError code, e.g. "GL_INVALID_ENUM"
+pub fn error_name(error: u32) -> &'static str {
+    match error {
+        0x0500 => "GL_INVALID_ENUM",
+        0x0501 => "GL_INVALID_VALUE",
+        0x0502 => "GL_INVALID_OPERATION",
+        0x0503 => "GL_STACK_OVERFLOW",
+        0x0504 => "GL_STACK_UNDERFLOW",
+        0x0505 => "GL_OUT_OF_MEMORY",
+        0x0506 => "GL_INVALID_FRAMEBUFFER_OPERATION",
+        0x0507 => "GL_CONTEXT_LOST",
+        _ => "unknown error",
+    }
+}
+
+// called by the generated functions right after `function` returned
+// `get_error` is the unchecked glGetError, `args` only runs when there's an error
+#[inline]
+#[track_caller]
+pub(crate) fn check<E, A>(get_error: E, function: &str, args: A)
+where
+    E: Fn() -> u32,
+    A: FnOnce() -> String,
+{
+    let mode = error_mode();
+    if mode == ErrorMode::Ignore {
+        return;
+    }
+
+    let mut errors = vec![];
+    while errors.len() < MAX_ERRORS {
+        match get_error() {
+            0 => break,
+            error => errors.push(error),
+        }
+    }
+    if errors.is_empty() {
+        return;
+    }
+
+    let errors = errors
+        .iter()
+        .map(|&error| format!("{} (0x{:04x})", error_name(error), error))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let message = format!(
+        "gl{}({}) at {}: {}",
+        function,
+        args(),
+        Location::caller(),
+        errors
+    );
+    match mode {
+        ErrorMode::Panic => panic!("{}", message),
+        _ => eprintln!("{}", message),
+    }
+}
@@ -0,0 +1,251 @@
+// Bindings generated by build.rs from gl.xml, for the version, profile and
+// extensions picked with the `slim` features, see Cargo.toml. Same API as
+// gl33/gl46 with only what was asked for.
+
+pub use self::enumerations::*;
+pub use self::functions::*;
+pub use self::types::*;
+
+use crate::LoadReport;
+use std::os::raw::c_void;
+
+#[derive(Copy, Clone)]
+struct FnPtr {
+    ptr: *const c_void,
+    is_loaded: bool,
+}
+
+#[allow(dead_code)]
+impl FnPtr {
+    fn new(ptr: *const c_void) -> FnPtr {
+        if !ptr.is_null() {
+            FnPtr {
+                ptr,
+                is_loaded: true,
+            }
+        } else {
+            FnPtr {
+                ptr: FnPtr::not_initialized as *const c_void,
+                is_loaded: false,
+            }
+        }
+    }
+
+    fn set_ptr(&mut self, ptr: *const c_void) {
+        *self = Self::new(ptr);
+    }
+
+    fn aliased(&mut self, other: &FnPtr) {
+        if !self.is_loaded && other.is_loaded {
+            *self = *other;
+        }
+    }
+
+    #[inline(never)]
+    fn not_initialized() -> ! {
+        panic!("gl: function not initialized")
+    }
+
+    // what the generated functions call instead of a pointer that didn't load
+    #[cold]
+    #[inline(never)]
+    fn missing(name: &str) -> ! {
+        panic!(
+            "gl{} is not loaded, the driver doesn't have it, see the LoadReport returned by load",
+            name
+        )
+    }
+}
+
+unsafe impl Sync for FnPtr {}
+unsafe impl Send for FnPtr {}
+
+pub mod types {
+    #![allow(dead_code, non_snake_case, non_camel_case_types)]
+
+    use std::os::raw;
+
+    pub type GLvoid = raw::c_void;
+
+    pub type GLbyte = raw::c_char;
+    pub type GLubyte = raw::c_uchar;
+    pub type GLchar = raw::c_char;
+    pub type GLboolean = raw::c_uchar;
+
+    pub type GLshort = raw::c_short;
+    pub type GLushort = raw::c_ushort;
+
+    pub type GLint = raw::c_int;
+    pub type GLuint = raw::c_uint;
+    pub type GLint64 = i64;
+    pub type GLuint64 = u64;
+
+    pub type GLintptr = isize;
+    pub type GLsizeiptr = isize;
+    pub type GLintptrARB = isize;
+    pub type GLsizeiptrARB = isize;
+    pub type GLint64EXT = i64;
+    pub type GLuint64EXT = u64;
+
+    pub type GLsizei = GLint;
+    pub type GLclampx = raw::c_int;
+    pub type GLfixed = GLint;
+    pub type GLhalf = raw::c_ushort;
+    pub type GLhalfNV = raw::c_ushort;
+    pub type GLhalfARB = raw::c_ushort;
+
+    pub type GLenum = raw::c_uint;
+    pub type GLbitfield = raw::c_uint;
+
+    pub type GLfloat = raw::c_float;
+    pub type GLdouble = raw::c_double;
+    pub type GLclampf = raw::c_float;
+    pub type GLclampd = raw::c_double;
+
+    pub type GLcharARB = raw::c_char;
+
+    #[cfg(target_os = "macos")]
+    pub type GLhandleARB = *const raw::c_void;
+    #[cfg(not(target_os = "macos"))]
+    pub type GLhandleARB = raw::c_uint;
+
+    pub enum __GLsync {}
+
+    pub type GLsync = *const __GLsync;
+
+    pub enum _cl_context {}
+
+    pub enum _cl_event {}
+
+    pub type GLvdpauSurfaceNV = GLintptr;
+    pub type GLeglClientBufferEXT = *const raw::c_void;
+    pub type GLeglImageOES = *const raw::c_void;
+
+    pub type GLDEBUGPROC = extern "system" fn(
+        source: GLenum,
+        type_: GLenum,
+        id: GLuint,
+        severity: GLenum,
+        length: GLsizei,
+        message: *const GLchar,
+        userParam: *mut raw::c_void,
+    );
+    pub type GLDEBUGPROCARB = extern "system" fn(
+        source: GLenum,
+        type_: GLenum,
+        id: GLuint,
+        severity: GLenum,
+        length: GLsizei,
+        message: *const GLchar,
+        userParam: *mut raw::c_void,
+    );
+    pub type GLDEBUGPROCKHR = extern "system" fn(
+        source: GLenum,
+        type_: GLenum,
+        id: GLuint,
+        severity: GLenum,
+        length: GLsizei,
+        message: *const GLchar,
+        userParam: *mut GLvoid,
+    );
+    pub type GLDEBUGPROCAMD = extern "system" fn(
+        id: GLuint,
+        category: GLenum,
+        severity: GLenum,
+        length: GLsizei,
+        message: *const GLchar,
+        userParam: *mut GLvoid,
+    );
+    pub type GLVULKANPROCNV = extern "system" fn();
+}
+
+pub mod enumerations {
+    #![allow(dead_code, non_upper_case_globals, unused_imports)]
+
+    use super::types::*;
+    use std::os::raw::*;
+
+    include!(concat!(env!("OUT_DIR"), "/gl_enumerations.rs"));
+}
+
+pub mod functions {
+    #![allow(non_snake_case, unused_variables, dead_code, unused_imports)]
+
+    use super::types::*;
+    use super::*;
+    use std::mem::transmute;
+    use std::os::raw::*;
+
+    macro_rules! func {
+        // never checked or traced, the checks call it
+        (GetError, $ret:ty, ) => {
+            #[inline] pub unsafe fn GetError(&self) -> $ret {
+                if !self.GetError.is_loaded { FnPtr::missing("GetError") }
+                transmute::<_, extern "system" fn() -> $ret>(self.GetError.ptr)()
+            }
+        };
+        ($fun:ident, $ret:ty, $($name:ident: $typ:ty),*) => {
+            #[cfg(not(any(feature = "debug", feature = "trace")))]
+            #[inline] pub unsafe fn $fun(&self, $($name: $typ),*) -> $ret {
+                if !self.$fun.is_loaded { FnPtr::missing(stringify!($fun)) }
+                transmute::<_, extern "system" fn($($typ),*) -> $ret>(self.$fun.ptr)($($name),*)
+            }
+
+            // with the debug feature every call is followed by glGetError, see `crate::debug`
+            // with the trace feature calls are recorded while a trace runs, see `crate::trace`
+            #[cfg(any(feature = "debug", feature = "trace"))]
+            #[inline] #[track_caller] pub unsafe fn $fun(&self, $($name: $typ),*) -> $ret {
+                if !self.$fun.is_loaded { FnPtr::missing(stringify!($fun)) }
+                #[cfg(feature = "trace")]
+                let call = crate::trace::begin(stringify!($fun), || vec![$(crate::trace::Value::from($name)),*]);
+                let result = transmute::<_, extern "system" fn($($typ),*) -> $ret>(self.$fun.ptr)($($name),*);
+                #[cfg(feature = "trace")]
+                crate::trace::end(call, crate::trace::Value::from(result));
+                #[cfg(feature = "debug")]
+                crate::debug::check(
+                    || self.GetError(),
+                    stringify!($fun),
+                    || {
+                        let args: &[String] = &[$(format!("{}: {:?}", stringify!($name), $name)),*];
+                        args.join(", ")
+                    },
+                );
+                result
+            }
+        };
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/gl_functions.rs"));
+}
+
+include!(concat!(env!("OUT_DIR"), "/gl_loader.rs"));
+
+impl Gl {
+    // whether the driver returned `name`, with or without the gl prefix
+    // false for names these bindings don't have
+    pub fn is_loaded(&self, name: &str) -> bool {
+        let name = name.strip_prefix("gl").unwrap_or(name);
+        self.fn_ptr(name).map_or(false, |ptr| ptr.is_loaded)
+    }
+
+    pub fn load_report(&self) -> LoadReport {
+        let missing = |names: &'static [&'static str]| {
+            names
+                .iter()
+                .copied()
+                .filter(|name| !self.is_loaded(name))
+                .collect::<Vec<_>>()
+        };
+        let missing_core = missing(CORE_FUNCTIONS);
+        let missing_extensions = missing(FUNCTIONS)
+            .into_iter()
+            .filter(|name| CORE_FUNCTIONS.binary_search(name).is_err())
+            .collect::<Vec<_>>();
+        LoadReport {
+            version: CORE_VERSION,
+            loaded: FUNCTIONS.len() - missing_core.len() - missing_extensions.len(),
+            missing_core,
+            missing_extensions,
+        }
+    }
+}
@@ -2,6 +2,7 @@ pub use self::enumerations::*;
 pub use self::functions::*;
 pub use self::types::*;
 
+use crate::LoadReport;
 use std::os::raw::c_void;
 
 #[derive(Copy, Clone)]
@@ -40,6 +41,16 @@ impl FnPtr {
     fn not_initialized() -> ! {
         panic!("gl: function not initialized")
     }
+
+    // what the generated functions call instead of a pointer that didn't load
+    #[cold]
+    #[inline(never)]
+    fn missing(name: &str) -> ! {
+        panic!(
+            "gl{} is not loaded, the driver doesn't have it, see the LoadReport returned by load",
+            name
+        )
+    }
 }
 
 unsafe impl Sync for FnPtr {}
@@ -4661,11 +4672,42 @@ pub mod functions {
     use std::os::raw::*;
 
     macro_rules! func {
+        // never checked or traced, the checks call it
+        (GetError, $ret:ty, ) => {
+            #[inline] pub unsafe fn GetError(&self) -> $ret {
+                if !self.GetError.is_loaded { FnPtr::missing("GetError") }
+                transmute::<_, extern "system" fn() -> $ret>(self.GetError.ptr)()
+            }
+        };
         ($fun:ident, $ret:ty, $($name:ident: $typ:ty),*) => {
+            #[cfg(not(any(feature = "debug", feature = "trace")))]
             #[inline] pub unsafe fn $fun(&self, $($name: $typ),*) -> $ret {
+                if !self.$fun.is_loaded { FnPtr::missing(stringify!($fun)) }
                 transmute::<_, extern "system" fn($($typ),*) -> $ret>(self.$fun.ptr)($($name),*)
             }
-        }
+
+            // with the debug feature every call is followed by glGetError, see `crate::debug`
+            // with the trace feature calls are recorded while a trace runs, see `crate::trace`
+            #[cfg(any(feature = "debug", feature = "trace"))]
+            #[inline] #[track_caller] pub unsafe fn $fun(&self, $($name: $typ),*) -> $ret {
+                if !self.$fun.is_loaded { FnPtr::missing(stringify!($fun)) }
+                #[cfg(feature = "trace")]
+                let call = crate::trace::begin(stringify!($fun), || vec![$(crate::trace::Value::from($name)),*]);
+                let result = transmute::<_, extern "system" fn($($typ),*) -> $ret>(self.$fun.ptr)($($name),*);
+                #[cfg(feature = "trace")]
+                crate::trace::end(call, crate::trace::Value::from(result));
+                #[cfg(feature = "debug")]
+                crate::debug::check(
+                    || self.GetError(),
+                    stringify!($fun),
+                    || {
+                        let args: &[String] = &[$(format!("{}: {:?}", stringify!($name), $name)),*];
+                        args.join(", ")
+                    },
+                );
+                result
+            }
+        };
     }
 
     pub struct Gl {
@@ -22109,7 +22151,8 @@ pub mod functions {
     }
 }
 
-pub fn load<F>(mut loadfn: F) -> functions::Gl
+// the functions and which of them the driver had
+pub fn load<F>(mut loadfn: F) -> (functions::Gl, LoadReport)
 where
     F: FnMut(&'static str) -> *const c_void,
 {
@@ -25768,5 +25811,5816 @@ where
     ctx.VertexAttribPointer.aliased(&ctx.VertexAttribPointerARB);
     ctx.VertexAttribPointerARB.aliased(&ctx.VertexAttribPointer);
 
-    ctx
+    let report = ctx.load_report();
+    (ctx, report)
+}
+
+// the core version these bindings were generated for
+pub const CORE_VERSION: (u32, u32) = (4, 6);
+
+// functions of the 4.6 core profile, without the gl prefix
+pub static CORE_FUNCTIONS: &[&str] = &[
+    "ActiveShaderProgram",
+    "ActiveTexture",
+    "AttachShader",
+    "BeginConditionalRender",
+    "BeginQuery",
+    "BeginQueryIndexed",
+    "BeginTransformFeedback",
+    "BindAttribLocation",
+    "BindBuffer",
+    "BindBufferBase",
+    "BindBufferRange",
+    "BindBuffersBase",
+    "BindBuffersRange",
+    "BindFragDataLocation",
+    "BindFragDataLocationIndexed",
+    "BindFramebuffer",
+    "BindImageTexture",
+    "BindImageTextures",
+    "BindProgramPipeline",
+    "BindRenderbuffer",
+    "BindSampler",
+    "BindSamplers",
+    "BindTexture",
+    "BindTextureUnit",
+    "BindTextures",
+    "BindTransformFeedback",
+    "BindVertexArray",
+    "BindVertexBuffer",
+    "BindVertexBuffers",
+    "BlendColor",
+    "BlendEquation",
+    "BlendEquationSeparate",
+    "BlendEquationSeparatei",
+    "BlendEquationi",
+    "BlendFunc",
+    "BlendFuncSeparate",
+    "BlendFuncSeparatei",
+    "BlendFunci",
+    "BlitFramebuffer",
+    "BlitNamedFramebuffer",
+    "BufferData",
+    "BufferStorage",
+    "BufferSubData",
+    "CheckFramebufferStatus",
+    "CheckNamedFramebufferStatus",
+    "ClampColor",
+    "Clear",
+    "ClearBufferData",
+    "ClearBufferSubData",
+    "ClearBufferfi",
+    "ClearBufferfv",
+    "ClearBufferiv",
+    "ClearBufferuiv",
+    "ClearColor",
+    "ClearDepth",
+    "ClearDepthf",
+    "ClearNamedBufferData",
+    "ClearNamedBufferSubData",
+    "ClearNamedFramebufferfi",
+    "ClearNamedFramebufferfv",
+    "ClearNamedFramebufferiv",
+    "ClearNamedFramebufferuiv",
+    "ClearStencil",
+    "ClearTexImage",
+    "ClearTexSubImage",
+    "ClientWaitSync",
+    "ClipControl",
+    "ColorMask",
+    "ColorMaski",
+    "CompileShader",
+    "CompressedTexImage1D",
+    "CompressedTexImage2D",
+    "CompressedTexImage3D",
+    "CompressedTexSubImage1D",
+    "CompressedTexSubImage2D",
+    "CompressedTexSubImage3D",
+    "CompressedTextureSubImage1D",
+    "CompressedTextureSubImage2D",
+    "CompressedTextureSubImage3D",
+    "CopyBufferSubData",
+    "CopyImageSubData",
+    "CopyNamedBufferSubData",
+    "CopyTexImage1D",
+    "CopyTexImage2D",
+    "CopyTexSubImage1D",
+    "CopyTexSubImage2D",
+    "CopyTexSubImage3D",
+    "CopyTextureSubImage1D",
+    "CopyTextureSubImage2D",
+    "CopyTextureSubImage3D",
+    "CreateBuffers",
+    "CreateFramebuffers",
+    "CreateProgram",
+    "CreateProgramPipelines",
+    "CreateQueries",
+    "CreateRenderbuffers",
+    "CreateSamplers",
+    "CreateShader",
+    "CreateShaderProgramv",
+    "CreateTextures",
+    "CreateTransformFeedbacks",
+    "CreateVertexArrays",
+    "CullFace",
+    "DebugMessageCallback",
+    "DebugMessageControl",
+    "DebugMessageInsert",
+    "DeleteBuffers",
+    "DeleteFramebuffers",
+    "DeleteProgram",
+    "DeleteProgramPipelines",
+    "DeleteQueries",
+    "DeleteRenderbuffers",
+    "DeleteSamplers",
+    "DeleteShader",
+    "DeleteSync",
+    "DeleteTextures",
+    "DeleteTransformFeedbacks",
+    "DeleteVertexArrays",
+    "DepthFunc",
+    "DepthMask",
+    "DepthRange",
+    "DepthRangeArrayv",
+    "DepthRangeIndexed",
+    "DepthRangef",
+    "DetachShader",
+    "Disable",
+    "DisableVertexArrayAttrib",
+    "DisableVertexAttribArray",
+    "Disablei",
+    "DispatchCompute",
+    "DispatchComputeIndirect",
+    "DrawArrays",
+    "DrawArraysIndirect",
+    "DrawArraysInstanced",
+    "DrawArraysInstancedBaseInstance",
+    "DrawBuffer",
+    "DrawBuffers",
+    "DrawElements",
+    "DrawElementsBaseVertex",
+    "DrawElementsIndirect",
+    "DrawElementsInstanced",
+    "DrawElementsInstancedBaseInstance",
+    "DrawElementsInstancedBaseVertex",
+    "DrawElementsInstancedBaseVertexBaseInstance",
+    "DrawRangeElements",
+    "DrawRangeElementsBaseVertex",
+    "DrawTransformFeedback",
+    "DrawTransformFeedbackInstanced",
+    "DrawTransformFeedbackStream",
+    "DrawTransformFeedbackStreamInstanced",
+    "Enable",
+    "EnableVertexArrayAttrib",
+    "EnableVertexAttribArray",
+    "Enablei",
+    "EndConditionalRender",
+    "EndQuery",
+    "EndQueryIndexed",
+    "EndTransformFeedback",
+    "FenceSync",
+    "Finish",
+    "Flush",
+    "FlushMappedBufferRange",
+    "FlushMappedNamedBufferRange",
+    "FramebufferParameteri",
+    "FramebufferRenderbuffer",
+    "FramebufferTexture",
+    "FramebufferTexture1D",
+    "FramebufferTexture2D",
+    "FramebufferTexture3D",
+    "FramebufferTextureLayer",
+    "FrontFace",
+    "GenBuffers",
+    "GenFramebuffers",
+    "GenProgramPipelines",
+    "GenQueries",
+    "GenRenderbuffers",
+    "GenSamplers",
+    "GenTextures",
+    "GenTransformFeedbacks",
+    "GenVertexArrays",
+    "GenerateMipmap",
+    "GenerateTextureMipmap",
+    "GetActiveAtomicCounterBufferiv",
+    "GetActiveAttrib",
+    "GetActiveSubroutineName",
+    "GetActiveSubroutineUniformName",
+    "GetActiveSubroutineUniformiv",
+    "GetActiveUniform",
+    "GetActiveUniformBlockName",
+    "GetActiveUniformBlockiv",
+    "GetActiveUniformName",
+    "GetActiveUniformsiv",
+    "GetAttachedShaders",
+    "GetAttribLocation",
+    "GetBooleani_v",
+    "GetBooleanv",
+    "GetBufferParameteri64v",
+    "GetBufferParameteriv",
+    "GetBufferPointerv",
+    "GetBufferSubData",
+    "GetCompressedTexImage",
+    "GetCompressedTextureImage",
+    "GetCompressedTextureSubImage",
+    "GetDebugMessageLog",
+    "GetDoublei_v",
+    "GetDoublev",
+    "GetError",
+    "GetFloati_v",
+    "GetFloatv",
+    "GetFragDataIndex",
+    "GetFragDataLocation",
+    "GetFramebufferAttachmentParameteriv",
+    "GetFramebufferParameteriv",
+    "GetGraphicsResetStatus",
+    "GetInteger64i_v",
+    "GetInteger64v",
+    "GetIntegeri_v",
+    "GetIntegerv",
+    "GetInternalformati64v",
+    "GetInternalformativ",
+    "GetMultisamplefv",
+    "GetNamedBufferParameteri64v",
+    "GetNamedBufferParameteriv",
+    "GetNamedBufferPointerv",
+    "GetNamedBufferSubData",
+    "GetNamedFramebufferAttachmentParameteriv",
+    "GetNamedFramebufferParameteriv",
+    "GetNamedRenderbufferParameteriv",
+    "GetObjectLabel",
+    "GetObjectPtrLabel",
+    "GetPointerv",
+    "GetProgramBinary",
+    "GetProgramInfoLog",
+    "GetProgramInterfaceiv",
+    "GetProgramPipelineInfoLog",
+    "GetProgramPipelineiv",
+    "GetProgramResourceIndex",
+    "GetProgramResourceLocation",
+    "GetProgramResourceLocationIndex",
+    "GetProgramResourceName",
+    "GetProgramResourceiv",
+    "GetProgramStageiv",
+    "GetProgramiv",
+    "GetQueryBufferObjecti64v",
+    "GetQueryBufferObjectiv",
+    "GetQueryBufferObjectui64v",
+    "GetQueryBufferObjectuiv",
+    "GetQueryIndexediv",
+    "GetQueryObjecti64v",
+    "GetQueryObjectiv",
+    "GetQueryObjectui64v",
+    "GetQueryObjectuiv",
+    "GetQueryiv",
+    "GetRenderbufferParameteriv",
+    "GetSamplerParameterIiv",
+    "GetSamplerParameterIuiv",
+    "GetSamplerParameterfv",
+    "GetSamplerParameteriv",
+    "GetShaderInfoLog",
+    "GetShaderPrecisionFormat",
+    "GetShaderSource",
+    "GetShaderiv",
+    "GetString",
+    "GetStringi",
+    "GetSubroutineIndex",
+    "GetSubroutineUniformLocation",
+    "GetSynciv",
+    "GetTexImage",
+    "GetTexLevelParameterfv",
+    "GetTexLevelParameteriv",
+    "GetTexParameterIiv",
+    "GetTexParameterIuiv",
+    "GetTexParameterfv",
+    "GetTexParameteriv",
+    "GetTextureImage",
+    "GetTextureLevelParameterfv",
+    "GetTextureLevelParameteriv",
+    "GetTextureParameterIiv",
+    "GetTextureParameterIuiv",
+    "GetTextureParameterfv",
+    "GetTextureParameteriv",
+    "GetTextureSubImage",
+    "GetTransformFeedbackVarying",
+    "GetTransformFeedbacki64_v",
+    "GetTransformFeedbacki_v",
+    "GetTransformFeedbackiv",
+    "GetUniformBlockIndex",
+    "GetUniformIndices",
+    "GetUniformLocation",
+    "GetUniformSubroutineuiv",
+    "GetUniformdv",
+    "GetUniformfv",
+    "GetUniformiv",
+    "GetUniformuiv",
+    "GetVertexArrayIndexed64iv",
+    "GetVertexArrayIndexediv",
+    "GetVertexArrayiv",
+    "GetVertexAttribIiv",
+    "GetVertexAttribIuiv",
+    "GetVertexAttribLdv",
+    "GetVertexAttribPointerv",
+    "GetVertexAttribdv",
+    "GetVertexAttribfv",
+    "GetVertexAttribiv",
+    "GetnCompressedTexImage",
+    "GetnTexImage",
+    "GetnUniformdv",
+    "GetnUniformfv",
+    "GetnUniformiv",
+    "GetnUniformuiv",
+    "Hint",
+    "InvalidateBufferData",
+    "InvalidateBufferSubData",
+    "InvalidateFramebuffer",
+    "InvalidateNamedFramebufferData",
+    "InvalidateNamedFramebufferSubData",
+    "InvalidateSubFramebuffer",
+    "InvalidateTexImage",
+    "InvalidateTexSubImage",
+    "IsBuffer",
+    "IsEnabled",
+    "IsEnabledi",
+    "IsFramebuffer",
+    "IsProgram",
+    "IsProgramPipeline",
+    "IsQuery",
+    "IsRenderbuffer",
+    "IsSampler",
+    "IsShader",
+    "IsSync",
+    "IsTexture",
+    "IsTransformFeedback",
+    "IsVertexArray",
+    "LineWidth",
+    "LinkProgram",
+    "LogicOp",
+    "MapBuffer",
+    "MapBufferRange",
+    "MapNamedBuffer",
+    "MapNamedBufferRange",
+    "MemoryBarrier",
+    "MemoryBarrierByRegion",
+    "MinSampleShading",
+    "MultiDrawArrays",
+    "MultiDrawArraysIndirect",
+    "MultiDrawArraysIndirectCount",
+    "MultiDrawElements",
+    "MultiDrawElementsBaseVertex",
+    "MultiDrawElementsIndirect",
+    "MultiDrawElementsIndirectCount",
+    "NamedBufferData",
+    "NamedBufferStorage",
+    "NamedBufferSubData",
+    "NamedFramebufferDrawBuffer",
+    "NamedFramebufferDrawBuffers",
+    "NamedFramebufferParameteri",
+    "NamedFramebufferReadBuffer",
+    "NamedFramebufferRenderbuffer",
+    "NamedFramebufferTexture",
+    "NamedFramebufferTextureLayer",
+    "NamedRenderbufferStorage",
+    "NamedRenderbufferStorageMultisample",
+    "ObjectLabel",
+    "ObjectPtrLabel",
+    "PatchParameterfv",
+    "PatchParameteri",
+    "PauseTransformFeedback",
+    "PixelStoref",
+    "PixelStorei",
+    "PointParameterf",
+    "PointParameterfv",
+    "PointParameteri",
+    "PointParameteriv",
+    "PointSize",
+    "PolygonMode",
+    "PolygonOffset",
+    "PolygonOffsetClamp",
+    "PopDebugGroup",
+    "PrimitiveRestartIndex",
+    "ProgramBinary",
+    "ProgramParameteri",
+    "ProgramUniform1d",
+    "ProgramUniform1dv",
+    "ProgramUniform1f",
+    "ProgramUniform1fv",
+    "ProgramUniform1i",
+    "ProgramUniform1iv",
+    "ProgramUniform1ui",
+    "ProgramUniform1uiv",
+    "ProgramUniform2d",
+    "ProgramUniform2dv",
+    "ProgramUniform2f",
+    "ProgramUniform2fv",
+    "ProgramUniform2i",
+    "ProgramUniform2iv",
+    "ProgramUniform2ui",
+    "ProgramUniform2uiv",
+    "ProgramUniform3d",
+    "ProgramUniform3dv",
+    "ProgramUniform3f",
+    "ProgramUniform3fv",
+    "ProgramUniform3i",
+    "ProgramUniform3iv",
+    "ProgramUniform3ui",
+    "ProgramUniform3uiv",
+    "ProgramUniform4d",
+    "ProgramUniform4dv",
+    "ProgramUniform4f",
+    "ProgramUniform4fv",
+    "ProgramUniform4i",
+    "ProgramUniform4iv",
+    "ProgramUniform4ui",
+    "ProgramUniform4uiv",
+    "ProgramUniformMatrix2dv",
+    "ProgramUniformMatrix2fv",
+    "ProgramUniformMatrix2x3dv",
+    "ProgramUniformMatrix2x3fv",
+    "ProgramUniformMatrix2x4dv",
+    "ProgramUniformMatrix2x4fv",
+    "ProgramUniformMatrix3dv",
+    "ProgramUniformMatrix3fv",
+    "ProgramUniformMatrix3x2dv",
+    "ProgramUniformMatrix3x2fv",
+    "ProgramUniformMatrix3x4dv",
+    "ProgramUniformMatrix3x4fv",
+    "ProgramUniformMatrix4dv",
+    "ProgramUniformMatrix4fv",
+    "ProgramUniformMatrix4x2dv",
+    "ProgramUniformMatrix4x2fv",
+    "ProgramUniformMatrix4x3dv",
+    "ProgramUniformMatrix4x3fv",
+    "ProvokingVertex",
+    "PushDebugGroup",
+    "QueryCounter",
+    "ReadBuffer",
+    "ReadPixels",
+    "ReadnPixels",
+    "ReleaseShaderCompiler",
+    "RenderbufferStorage",
+    "RenderbufferStorageMultisample",
+    "ResumeTransformFeedback",
+    "SampleCoverage",
+    "SampleMaski",
+    "SamplerParameterIiv",
+    "SamplerParameterIuiv",
+    "SamplerParameterf",
+    "SamplerParameterfv",
+    "SamplerParameteri",
+    "SamplerParameteriv",
+    "Scissor",
+    "ScissorArrayv",
+    "ScissorIndexed",
+    "ScissorIndexedv",
+    "ShaderBinary",
+    "ShaderSource",
+    "ShaderStorageBlockBinding",
+    "SpecializeShader",
+    "StencilFunc",
+    "StencilFuncSeparate",
+    "StencilMask",
+    "StencilMaskSeparate",
+    "StencilOp",
+    "StencilOpSeparate",
+    "TexBuffer",
+    "TexBufferRange",
+    "TexImage1D",
+    "TexImage2D",
+    "TexImage2DMultisample",
+    "TexImage3D",
+    "TexImage3DMultisample",
+    "TexParameterIiv",
+    "TexParameterIuiv",
+    "TexParameterf",
+    "TexParameterfv",
+    "TexParameteri",
+    "TexParameteriv",
+    "TexStorage1D",
+    "TexStorage2D",
+    "TexStorage2DMultisample",
+    "TexStorage3D",
+    "TexStorage3DMultisample",
+    "TexSubImage1D",
+    "TexSubImage2D",
+    "TexSubImage3D",
+    "TextureBarrier",
+    "TextureBuffer",
+    "TextureBufferRange",
+    "TextureParameterIiv",
+    "TextureParameterIuiv",
+    "TextureParameterf",
+    "TextureParameterfv",
+    "TextureParameteri",
+    "TextureParameteriv",
+    "TextureStorage1D",
+    "TextureStorage2D",
+    "TextureStorage2DMultisample",
+    "TextureStorage3D",
+    "TextureStorage3DMultisample",
+    "TextureSubImage1D",
+    "TextureSubImage2D",
+    "TextureSubImage3D",
+    "TextureView",
+    "TransformFeedbackBufferBase",
+    "TransformFeedbackBufferRange",
+    "TransformFeedbackVaryings",
+    "Uniform1d",
+    "Uniform1dv",
+    "Uniform1f",
+    "Uniform1fv",
+    "Uniform1i",
+    "Uniform1iv",
+    "Uniform1ui",
+    "Uniform1uiv",
+    "Uniform2d",
+    "Uniform2dv",
+    "Uniform2f",
+    "Uniform2fv",
+    "Uniform2i",
+    "Uniform2iv",
+    "Uniform2ui",
+    "Uniform2uiv",
+    "Uniform3d",
+    "Uniform3dv",
+    "Uniform3f",
+    "Uniform3fv",
+    "Uniform3i",
+    "Uniform3iv",
+    "Uniform3ui",
+    "Uniform3uiv",
+    "Uniform4d",
+    "Uniform4dv",
+    "Uniform4f",
+    "Uniform4fv",
+    "Uniform4i",
+    "Uniform4iv",
+    "Uniform4ui",
+    "Uniform4uiv",
+    "UniformBlockBinding",
+    "UniformMatrix2dv",
+    "UniformMatrix2fv",
+    "UniformMatrix2x3dv",
+    "UniformMatrix2x3fv",
+    "UniformMatrix2x4dv",
+    "UniformMatrix2x4fv",
+    "UniformMatrix3dv",
+    "UniformMatrix3fv",
+    "UniformMatrix3x2dv",
+    "UniformMatrix3x2fv",
+    "UniformMatrix3x4dv",
+    "UniformMatrix3x4fv",
+    "UniformMatrix4dv",
+    "UniformMatrix4fv",
+    "UniformMatrix4x2dv",
+    "UniformMatrix4x2fv",
+    "UniformMatrix4x3dv",
+    "UniformMatrix4x3fv",
+    "UniformSubroutinesuiv",
+    "UnmapBuffer",
+    "UnmapNamedBuffer",
+    "UseProgram",
+    "UseProgramStages",
+    "ValidateProgram",
+    "ValidateProgramPipeline",
+    "VertexArrayAttribBinding",
+    "VertexArrayAttribFormat",
+    "VertexArrayAttribIFormat",
+    "VertexArrayAttribLFormat",
+    "VertexArrayBindingDivisor",
+    "VertexArrayElementBuffer",
+    "VertexArrayVertexBuffer",
+    "VertexArrayVertexBuffers",
+    "VertexAttrib1d",
+    "VertexAttrib1dv",
+    "VertexAttrib1f",
+    "VertexAttrib1fv",
+    "VertexAttrib1s",
+    "VertexAttrib1sv",
+    "VertexAttrib2d",
+    "VertexAttrib2dv",
+    "VertexAttrib2f",
+    "VertexAttrib2fv",
+    "VertexAttrib2s",
+    "VertexAttrib2sv",
+    "VertexAttrib3d",
+    "VertexAttrib3dv",
+    "VertexAttrib3f",
+    "VertexAttrib3fv",
+    "VertexAttrib3s",
+    "VertexAttrib3sv",
+    "VertexAttrib4Nbv",
+    "VertexAttrib4Niv",
+    "VertexAttrib4Nsv",
+    "VertexAttrib4Nub",
+    "VertexAttrib4Nubv",
+    "VertexAttrib4Nuiv",
+    "VertexAttrib4Nusv",
+    "VertexAttrib4bv",
+    "VertexAttrib4d",
+    "VertexAttrib4dv",
+    "VertexAttrib4f",
+    "VertexAttrib4fv",
+    "VertexAttrib4iv",
+    "VertexAttrib4s",
+    "VertexAttrib4sv",
+    "VertexAttrib4ubv",
+    "VertexAttrib4uiv",
+    "VertexAttrib4usv",
+    "VertexAttribBinding",
+    "VertexAttribDivisor",
+    "VertexAttribFormat",
+    "VertexAttribI1i",
+    "VertexAttribI1iv",
+    "VertexAttribI1ui",
+    "VertexAttribI1uiv",
+    "VertexAttribI2i",
+    "VertexAttribI2iv",
+    "VertexAttribI2ui",
+    "VertexAttribI2uiv",
+    "VertexAttribI3i",
+    "VertexAttribI3iv",
+    "VertexAttribI3ui",
+    "VertexAttribI3uiv",
+    "VertexAttribI4bv",
+    "VertexAttribI4i",
+    "VertexAttribI4iv",
+    "VertexAttribI4sv",
+    "VertexAttribI4ubv",
+    "VertexAttribI4ui",
+    "VertexAttribI4uiv",
+    "VertexAttribI4usv",
+    "VertexAttribIFormat",
+    "VertexAttribIPointer",
+    "VertexAttribL1d",
+    "VertexAttribL1dv",
+    "VertexAttribL2d",
+    "VertexAttribL2dv",
+    "VertexAttribL3d",
+    "VertexAttribL3dv",
+    "VertexAttribL4d",
+    "VertexAttribL4dv",
+    "VertexAttribLFormat",
+    "VertexAttribLPointer",
+    "VertexAttribP1ui",
+    "VertexAttribP1uiv",
+    "VertexAttribP2ui",
+    "VertexAttribP2uiv",
+    "VertexAttribP3ui",
+    "VertexAttribP3uiv",
+    "VertexAttribP4ui",
+    "VertexAttribP4uiv",
+    "VertexAttribPointer",
+    "VertexBindingDivisor",
+    "Viewport",
+    "ViewportArrayv",
+    "ViewportIndexedf",
+    "ViewportIndexedfv",
+    "WaitSync",
+];
+
+// every function in these bindings, core and extensions, without the gl prefix
+pub static FUNCTIONS: &[&str] = &[
+    "AccumxOES",
+    "AcquireKeyedMutexWin32EXT",
+    "ActiveProgramEXT",
+    "ActiveShaderProgram",
+    "ActiveStencilFaceEXT",
+    "ActiveTexture",
+    "ActiveTextureARB",
+    "ActiveVaryingNV",
+    "AlphaFragmentOp1ATI",
+    "AlphaFragmentOp2ATI",
+    "AlphaFragmentOp3ATI",
+    "AlphaFuncxOES",
+    "AlphaToCoverageDitherControlNV",
+    "ApplyFramebufferAttachmentCMAAINTEL",
+    "ApplyTextureEXT",
+    "AreProgramsResidentNV",
+    "AreTexturesResidentEXT",
+    "ArrayElementEXT",
+    "ArrayObjectATI",
+    "AsyncCopyBufferSubDataNVX",
+    "AsyncCopyImageSubDataNVX",
+    "AsyncMarkerSGIX",
+    "AttachObjectARB",
+    "AttachShader",
+    "BeginConditionalRender",
+    "BeginConditionalRenderNV",
+    "BeginConditionalRenderNVX",
+    "BeginFragmentShaderATI",
+    "BeginOcclusionQueryNV",
+    "BeginPerfMonitorAMD",
+    "BeginPerfQueryINTEL",
+    "BeginQuery",
+    "BeginQueryARB",
+    "BeginQueryIndexed",
+    "BeginTransformFeedback",
+    "BeginTransformFeedbackEXT",
+    "BeginTransformFeedbackNV",
+    "BeginVertexShaderEXT",
+    "BeginVideoCaptureNV",
+    "BindAttribLocation",
+    "BindAttribLocationARB",
+    "BindBuffer",
+    "BindBufferARB",
+    "BindBufferBase",
+    "BindBufferBaseEXT",
+    "BindBufferBaseNV",
+    "BindBufferOffsetEXT",
+    "BindBufferOffsetNV",
+    "BindBufferRange",
+    "BindBufferRangeEXT",
+    "BindBufferRangeNV",
+    "BindBuffersBase",
+    "BindBuffersRange",
+    "BindFragDataLocation",
+    "BindFragDataLocationEXT",
+    "BindFragDataLocationIndexed",
+    "BindFragmentShaderATI",
+    "BindFramebuffer",
+    "BindFramebufferEXT",
+    "BindImageTexture",
+    "BindImageTextureEXT",
+    "BindImageTextures",
+    "BindLightParameterEXT",
+    "BindMaterialParameterEXT",
+    "BindMultiTextureEXT",
+    "BindParameterEXT",
+    "BindProgramARB",
+    "BindProgramNV",
+    "BindProgramPipeline",
+    "BindRenderbuffer",
+    "BindRenderbufferEXT",
+    "BindSampler",
+    "BindSamplers",
+    "BindShadingRateImageNV",
+    "BindTexGenParameterEXT",
+    "BindTexture",
+    "BindTextureEXT",
+    "BindTextureUnit",
+    "BindTextureUnitParameterEXT",
+    "BindTextures",
+    "BindTransformFeedback",
+    "BindTransformFeedbackNV",
+    "BindVertexArray",
+    "BindVertexArrayAPPLE",
+    "BindVertexBuffer",
+    "BindVertexBuffers",
+    "BindVertexShaderEXT",
+    "BindVideoCaptureStreamBufferNV",
+    "BindVideoCaptureStreamTextureNV",
+    "Binormal3bEXT",
+    "Binormal3bvEXT",
+    "Binormal3dEXT",
+    "Binormal3dvEXT",
+    "Binormal3fEXT",
+    "Binormal3fvEXT",
+    "Binormal3iEXT",
+    "Binormal3ivEXT",
+    "Binormal3sEXT",
+    "Binormal3svEXT",
+    "BinormalPointerEXT",
+    "BitmapxOES",
+    "BlendBarrierKHR",
+    "BlendBarrierNV",
+    "BlendColor",
+    "BlendColorEXT",
+    "BlendColorxOES",
+    "BlendEquation",
+    "BlendEquationEXT",
+    "BlendEquationIndexedAMD",
+    "BlendEquationSeparate",
+    "BlendEquationSeparateEXT",
+    "BlendEquationSeparateIndexedAMD",
+    "BlendEquationSeparatei",
+    "BlendEquationSeparateiARB",
+    "BlendEquationi",
+    "BlendEquationiARB",
+    "BlendFunc",
+    "BlendFuncIndexedAMD",
+    "BlendFuncSeparate",
+    "BlendFuncSeparateEXT",
+    "BlendFuncSeparateINGR",
+    "BlendFuncSeparateIndexedAMD",
+    "BlendFuncSeparatei",
+    "BlendFuncSeparateiARB",
+    "BlendFunci",
+    "BlendFunciARB",
+    "BlendParameteriNV",
+    "BlitFramebuffer",
+    "BlitFramebufferEXT",
+    "BlitFramebufferLayerEXT",
+    "BlitFramebufferLayersEXT",
+    "BlitNamedFramebuffer",
+    "BufferAddressRangeNV",
+    "BufferAttachMemoryNV",
+    "BufferData",
+    "BufferDataARB",
+    "BufferPageCommitmentARB",
+    "BufferPageCommitmentMemNV",
+    "BufferParameteriAPPLE",
+    "BufferStorage",
+    "BufferStorageExternalEXT",
+    "BufferStorageMemEXT",
+    "BufferSubData",
+    "BufferSubDataARB",
+    "CallCommandListNV",
+    "CheckFramebufferStatus",
+    "CheckFramebufferStatusEXT",
+    "CheckNamedFramebufferStatus",
+    "CheckNamedFramebufferStatusEXT",
+    "ClampColor",
+    "ClampColorARB",
+    "Clear",
+    "ClearAccumxOES",
+    "ClearBufferData",
+    "ClearBufferSubData",
+    "ClearBufferfi",
+    "ClearBufferfv",
+    "ClearBufferiv",
+    "ClearBufferuiv",
+    "ClearColor",
+    "ClearColorIiEXT",
+    "ClearColorIuiEXT",
+    "ClearColorxOES",
+    "ClearDepth",
+    "ClearDepthdNV",
+    "ClearDepthf",
+    "ClearDepthfOES",
+    "ClearDepthxOES",
+    "ClearNamedBufferData",
+    "ClearNamedBufferDataEXT",
+    "ClearNamedBufferSubData",
+    "ClearNamedBufferSubDataEXT",
+    "ClearNamedFramebufferfi",
+    "ClearNamedFramebufferfv",
+    "ClearNamedFramebufferiv",
+    "ClearNamedFramebufferuiv",
+    "ClearStencil",
+    "ClearTexImage",
+    "ClearTexSubImage",
+    "ClientActiveTextureARB",
+    "ClientActiveVertexStreamATI",
+    "ClientAttribDefaultEXT",
+    "ClientWaitSemaphoreui64NVX",
+    "ClientWaitSync",
+    "ClipControl",
+    "ClipPlanefOES",
+    "ClipPlanexOES",
+    "Color3fVertex3fSUN",
+    "Color3fVertex3fvSUN",
+    "Color3hNV",
+    "Color3hvNV",
+    "Color3xOES",
+    "Color3xvOES",
+    "Color4fNormal3fVertex3fSUN",
+    "Color4fNormal3fVertex3fvSUN",
+    "Color4hNV",
+    "Color4hvNV",
+    "Color4ubVertex2fSUN",
+    "Color4ubVertex2fvSUN",
+    "Color4ubVertex3fSUN",
+    "Color4ubVertex3fvSUN",
+    "Color4xOES",
+    "Color4xvOES",
+    "ColorFormatNV",
+    "ColorFragmentOp1ATI",
+    "ColorFragmentOp2ATI",
+    "ColorFragmentOp3ATI",
+    "ColorMask",
+    "ColorMaskIndexedEXT",
+    "ColorMaski",
+    "ColorPointerEXT",
+    "ColorPointerListIBM",
+    "ColorPointervINTEL",
+    "ColorSubTableEXT",
+    "ColorTableEXT",
+    "ColorTableParameterfvSGI",
+    "ColorTableParameterivSGI",
+    "ColorTableSGI",
+    "CombinerInputNV",
+    "CombinerOutputNV",
+    "CombinerParameterfNV",
+    "CombinerParameterfvNV",
+    "CombinerParameteriNV",
+    "CombinerParameterivNV",
+    "CombinerStageParameterfvNV",
+    "CommandListSegmentsNV",
+    "CompileCommandListNV",
+    "CompileShader",
+    "CompileShaderARB",
+    "CompileShaderIncludeARB",
+    "CompressedMultiTexImage1DEXT",
+    "CompressedMultiTexImage2DEXT",
+    "CompressedMultiTexImage3DEXT",
+    "CompressedMultiTexSubImage1DEXT",
+    "CompressedMultiTexSubImage2DEXT",
+    "CompressedMultiTexSubImage3DEXT",
+    "CompressedTexImage1D",
+    "CompressedTexImage1DARB",
+    "CompressedTexImage2D",
+    "CompressedTexImage2DARB",
+    "CompressedTexImage3D",
+    "CompressedTexImage3DARB",
+    "CompressedTexSubImage1D",
+    "CompressedTexSubImage1DARB",
+    "CompressedTexSubImage2D",
+    "CompressedTexSubImage2DARB",
+    "CompressedTexSubImage3D",
+    "CompressedTexSubImage3DARB",
+    "CompressedTextureImage1DEXT",
+    "CompressedTextureImage2DEXT",
+    "CompressedTextureImage3DEXT",
+    "CompressedTextureSubImage1D",
+    "CompressedTextureSubImage1DEXT",
+    "CompressedTextureSubImage2D",
+    "CompressedTextureSubImage2DEXT",
+    "CompressedTextureSubImage3D",
+    "CompressedTextureSubImage3DEXT",
+    "ConservativeRasterParameterfNV",
+    "ConservativeRasterParameteriNV",
+    "ConvolutionFilter1DEXT",
+    "ConvolutionFilter2DEXT",
+    "ConvolutionParameterfEXT",
+    "ConvolutionParameterfvEXT",
+    "ConvolutionParameteriEXT",
+    "ConvolutionParameterivEXT",
+    "ConvolutionParameterxOES",
+    "ConvolutionParameterxvOES",
+    "CopyBufferSubData",
+    "CopyColorSubTableEXT",
+    "CopyColorTableSGI",
+    "CopyConvolutionFilter1DEXT",
+    "CopyConvolutionFilter2DEXT",
+    "CopyImageSubData",
+    "CopyImageSubDataNV",
+    "CopyMultiTexImage1DEXT",
+    "CopyMultiTexImage2DEXT",
+    "CopyMultiTexSubImage1DEXT",
+    "CopyMultiTexSubImage2DEXT",
+    "CopyMultiTexSubImage3DEXT",
+    "CopyNamedBufferSubData",
+    "CopyPathNV",
+    "CopyTexImage1D",
+    "CopyTexImage1DEXT",
+    "CopyTexImage2D",
+    "CopyTexImage2DEXT",
+    "CopyTexSubImage1D",
+    "CopyTexSubImage1DEXT",
+    "CopyTexSubImage2D",
+    "CopyTexSubImage2DEXT",
+    "CopyTexSubImage3D",
+    "CopyTexSubImage3DEXT",
+    "CopyTextureImage1DEXT",
+    "CopyTextureImage2DEXT",
+    "CopyTextureSubImage1D",
+    "CopyTextureSubImage1DEXT",
+    "CopyTextureSubImage2D",
+    "CopyTextureSubImage2DEXT",
+    "CopyTextureSubImage3D",
+    "CopyTextureSubImage3DEXT",
+    "CoverFillPathInstancedNV",
+    "CoverFillPathNV",
+    "CoverStrokePathInstancedNV",
+    "CoverStrokePathNV",
+    "CoverageModulationNV",
+    "CoverageModulationTableNV",
+    "CreateBuffers",
+    "CreateCommandListsNV",
+    "CreateFramebuffers",
+    "CreateMemoryObjectsEXT",
+    "CreatePerfQueryINTEL",
+    "CreateProgram",
+    "CreateProgramObjectARB",
+    "CreateProgramPipelines",
+    "CreateProgressFenceNVX",
+    "CreateQueries",
+    "CreateRenderbuffers",
+    "CreateSamplers",
+    "CreateSemaphoresNV",
+    "CreateShader",
+    "CreateShaderObjectARB",
+    "CreateShaderProgramEXT",
+    "CreateShaderProgramv",
+    "CreateStatesNV",
+    "CreateSyncFromCLeventARB",
+    "CreateTextures",
+    "CreateTransformFeedbacks",
+    "CreateVertexArrays",
+    "CullFace",
+    "CullParameterdvEXT",
+    "CullParameterfvEXT",
+    "CurrentPaletteMatrixARB",
+    "DebugMessageCallback",
+    "DebugMessageCallbackAMD",
+    "DebugMessageCallbackARB",
+    "DebugMessageControl",
+    "DebugMessageControlARB",
+    "DebugMessageEnableAMD",
+    "DebugMessageInsert",
+    "DebugMessageInsertAMD",
+    "DebugMessageInsertARB",
+    "DeformSGIX",
+    "DeformationMap3dSGIX",
+    "DeformationMap3fSGIX",
+    "DeleteAsyncMarkersSGIX",
+    "DeleteBuffers",
+    "DeleteBuffersARB",
+    "DeleteCommandListsNV",
+    "DeleteFencesAPPLE",
+    "DeleteFencesNV",
+    "DeleteFragmentShaderATI",
+    "DeleteFramebuffers",
+    "DeleteFramebuffersEXT",
+    "DeleteMemoryObjectsEXT",
+    "DeleteNamedStringARB",
+    "DeleteNamesAMD",
+    "DeleteObjectARB",
+    "DeleteOcclusionQueriesNV",
+    "DeletePathsNV",
+    "DeletePerfMonitorsAMD",
+    "DeletePerfQueryINTEL",
+    "DeleteProgram",
+    "DeleteProgramPipelines",
+    "DeleteProgramsARB",
+    "DeleteProgramsNV",
+    "DeleteQueries",
+    "DeleteQueriesARB",
+    "DeleteQueryResourceTagNV",
+    "DeleteRenderbuffers",
+    "DeleteRenderbuffersEXT",
+    "DeleteSamplers",
+    "DeleteSemaphoresEXT",
+    "DeleteShader",
+    "DeleteStatesNV",
+    "DeleteSync",
+    "DeleteTextures",
+    "DeleteTexturesEXT",
+    "DeleteTransformFeedbacks",
+    "DeleteTransformFeedbacksNV",
+    "DeleteVertexArrays",
+    "DeleteVertexArraysAPPLE",
+    "DeleteVertexShaderEXT",
+    "DepthBoundsEXT",
+    "DepthBoundsdNV",
+    "DepthFunc",
+    "DepthMask",
+    "DepthRange",
+    "DepthRangeArraydvNV",
+    "DepthRangeArrayv",
+    "DepthRangeIndexed",
+    "DepthRangeIndexeddNV",
+    "DepthRangedNV",
+    "DepthRangef",
+    "DepthRangefOES",
+    "DepthRangexOES",
+    "DetachObjectARB",
+    "DetachShader",
+    "DetailTexFuncSGIS",
+    "Disable",
+    "DisableClientStateIndexedEXT",
+    "DisableClientStateiEXT",
+    "DisableIndexedEXT",
+    "DisableVariantClientStateEXT",
+    "DisableVertexArrayAttrib",
+    "DisableVertexArrayAttribEXT",
+    "DisableVertexArrayEXT",
+    "DisableVertexAttribAPPLE",
+    "DisableVertexAttribArray",
+    "DisableVertexAttribArrayARB",
+    "Disablei",
+    "DispatchCompute",
+    "DispatchComputeGroupSizeARB",
+    "DispatchComputeIndirect",
+    "DrawArrays",
+    "DrawArraysEXT",
+    "DrawArraysIndirect",
+    "DrawArraysInstanced",
+    "DrawArraysInstancedARB",
+    "DrawArraysInstancedBaseInstance",
+    "DrawArraysInstancedEXT",
+    "DrawBuffer",
+    "DrawBuffers",
+    "DrawBuffersARB",
+    "DrawBuffersATI",
+    "DrawCommandsAddressNV",
+    "DrawCommandsNV",
+    "DrawCommandsStatesAddressNV",
+    "DrawCommandsStatesNV",
+    "DrawElementArrayAPPLE",
+    "DrawElementArrayATI",
+    "DrawElements",
+    "DrawElementsBaseVertex",
+    "DrawElementsIndirect",
+    "DrawElementsInstanced",
+    "DrawElementsInstancedARB",
+    "DrawElementsInstancedBaseInstance",
+    "DrawElementsInstancedBaseVertex",
+    "DrawElementsInstancedBaseVertexBaseInstance",
+    "DrawElementsInstancedEXT",
+    "DrawMeshArraysSUN",
+    "DrawMeshTasksIndirectNV",
+    "DrawMeshTasksNV",
+    "DrawRangeElementArrayAPPLE",
+    "DrawRangeElementArrayATI",
+    "DrawRangeElements",
+    "DrawRangeElementsBaseVertex",
+    "DrawRangeElementsEXT",
+    "DrawTextureNV",
+    "DrawTransformFeedback",
+    "DrawTransformFeedbackInstanced",
+    "DrawTransformFeedbackNV",
+    "DrawTransformFeedbackStream",
+    "DrawTransformFeedbackStreamInstanced",
+    "DrawVkImageNV",
+    "EGLImageTargetTexStorageEXT",
+    "EGLImageTargetTextureStorageEXT",
+    "EdgeFlagFormatNV",
+    "EdgeFlagPointerEXT",
+    "EdgeFlagPointerListIBM",
+    "ElementPointerAPPLE",
+    "ElementPointerATI",
+    "Enable",
+    "EnableClientStateIndexedEXT",
+    "EnableClientStateiEXT",
+    "EnableIndexedEXT",
+    "EnableVariantClientStateEXT",
+    "EnableVertexArrayAttrib",
+    "EnableVertexArrayAttribEXT",
+    "EnableVertexArrayEXT",
+    "EnableVertexAttribAPPLE",
+    "EnableVertexAttribArray",
+    "EnableVertexAttribArrayARB",
+    "Enablei",
+    "EndConditionalRender",
+    "EndConditionalRenderNV",
+    "EndConditionalRenderNVX",
+    "EndFragmentShaderATI",
+    "EndOcclusionQueryNV",
+    "EndPerfMonitorAMD",
+    "EndPerfQueryINTEL",
+    "EndQuery",
+    "EndQueryARB",
+    "EndQueryIndexed",
+    "EndTransformFeedback",
+    "EndTransformFeedbackEXT",
+    "EndTransformFeedbackNV",
+    "EndVertexShaderEXT",
+    "EndVideoCaptureNV",
+    "EvalCoord1xOES",
+    "EvalCoord1xvOES",
+    "EvalCoord2xOES",
+    "EvalCoord2xvOES",
+    "EvalMapsNV",
+    "EvaluateDepthValuesARB",
+    "ExecuteProgramNV",
+    "ExtractComponentEXT",
+    "FeedbackBufferxOES",
+    "FenceSync",
+    "FinalCombinerInputNV",
+    "Finish",
+    "FinishAsyncSGIX",
+    "FinishFenceAPPLE",
+    "FinishFenceNV",
+    "FinishObjectAPPLE",
+    "FinishTextureSUNX",
+    "Flush",
+    "FlushMappedBufferRange",
+    "FlushMappedBufferRangeAPPLE",
+    "FlushMappedNamedBufferRange",
+    "FlushMappedNamedBufferRangeEXT",
+    "FlushPixelDataRangeNV",
+    "FlushRasterSGIX",
+    "FlushStaticDataIBM",
+    "FlushVertexArrayRangeAPPLE",
+    "FlushVertexArrayRangeNV",
+    "FogCoordFormatNV",
+    "FogCoordPointerEXT",
+    "FogCoordPointerListIBM",
+    "FogCoorddEXT",
+    "FogCoorddvEXT",
+    "FogCoordfEXT",
+    "FogCoordfvEXT",
+    "FogCoordhNV",
+    "FogCoordhvNV",
+    "FogFuncSGIS",
+    "FogxOES",
+    "FogxvOES",
+    "FragmentColorMaterialSGIX",
+    "FragmentCoverageColorNV",
+    "FragmentLightModelfSGIX",
+    "FragmentLightModelfvSGIX",
+    "FragmentLightModeliSGIX",
+    "FragmentLightModelivSGIX",
+    "FragmentLightfSGIX",
+    "FragmentLightfvSGIX",
+    "FragmentLightiSGIX",
+    "FragmentLightivSGIX",
+    "FragmentMaterialfSGIX",
+    "FragmentMaterialfvSGIX",
+    "FragmentMaterialiSGIX",
+    "FragmentMaterialivSGIX",
+    "FrameTerminatorGREMEDY",
+    "FrameZoomSGIX",
+    "FramebufferDrawBufferEXT",
+    "FramebufferDrawBuffersEXT",
+    "FramebufferFetchBarrierEXT",
+    "FramebufferParameteri",
+    "FramebufferParameteriMESA",
+    "FramebufferReadBufferEXT",
+    "FramebufferRenderbuffer",
+    "FramebufferRenderbufferEXT",
+    "FramebufferSampleLocationsfvARB",
+    "FramebufferSampleLocationsfvNV",
+    "FramebufferSamplePositionsfvAMD",
+    "FramebufferTexture",
+    "FramebufferTexture1D",
+    "FramebufferTexture1DEXT",
+    "FramebufferTexture2D",
+    "FramebufferTexture2DEXT",
+    "FramebufferTexture3D",
+    "FramebufferTexture3DEXT",
+    "FramebufferTextureARB",
+    "FramebufferTextureEXT",
+    "FramebufferTextureFaceARB",
+    "FramebufferTextureFaceEXT",
+    "FramebufferTextureLayer",
+    "FramebufferTextureLayerARB",
+    "FramebufferTextureLayerEXT",
+    "FramebufferTextureMultiviewOVR",
+    "FreeObjectBufferATI",
+    "FrontFace",
+    "FrustumfOES",
+    "FrustumxOES",
+    "GenAsyncMarkersSGIX",
+    "GenBuffers",
+    "GenBuffersARB",
+    "GenFencesAPPLE",
+    "GenFencesNV",
+    "GenFragmentShadersATI",
+    "GenFramebuffers",
+    "GenFramebuffersEXT",
+    "GenNamesAMD",
+    "GenOcclusionQueriesNV",
+    "GenPathsNV",
+    "GenPerfMonitorsAMD",
+    "GenProgramPipelines",
+    "GenProgramsARB",
+    "GenProgramsNV",
+    "GenQueries",
+    "GenQueriesARB",
+    "GenQueryResourceTagNV",
+    "GenRenderbuffers",
+    "GenRenderbuffersEXT",
+    "GenSamplers",
+    "GenSemaphoresEXT",
+    "GenSymbolsEXT",
+    "GenTextures",
+    "GenTexturesEXT",
+    "GenTransformFeedbacks",
+    "GenTransformFeedbacksNV",
+    "GenVertexArrays",
+    "GenVertexArraysAPPLE",
+    "GenVertexShadersEXT",
+    "GenerateMipmap",
+    "GenerateMipmapEXT",
+    "GenerateMultiTexMipmapEXT",
+    "GenerateTextureMipmap",
+    "GenerateTextureMipmapEXT",
+    "GetActiveAtomicCounterBufferiv",
+    "GetActiveAttrib",
+    "GetActiveAttribARB",
+    "GetActiveSubroutineName",
+    "GetActiveSubroutineUniformName",
+    "GetActiveSubroutineUniformiv",
+    "GetActiveUniform",
+    "GetActiveUniformARB",
+    "GetActiveUniformBlockName",
+    "GetActiveUniformBlockiv",
+    "GetActiveUniformName",
+    "GetActiveUniformsiv",
+    "GetActiveVaryingNV",
+    "GetArrayObjectfvATI",
+    "GetArrayObjectivATI",
+    "GetAttachedObjectsARB",
+    "GetAttachedShaders",
+    "GetAttribLocation",
+    "GetAttribLocationARB",
+    "GetBooleanIndexedvEXT",
+    "GetBooleani_v",
+    "GetBooleanv",
+    "GetBufferParameteri64v",
+    "GetBufferParameteriv",
+    "GetBufferParameterivARB",
+    "GetBufferParameterui64vNV",
+    "GetBufferPointerv",
+    "GetBufferPointervARB",
+    "GetBufferSubData",
+    "GetBufferSubDataARB",
+    "GetClipPlanefOES",
+    "GetClipPlanexOES",
+    "GetColorTableEXT",
+    "GetColorTableParameterfvEXT",
+    "GetColorTableParameterfvSGI",
+    "GetColorTableParameterivEXT",
+    "GetColorTableParameterivSGI",
+    "GetColorTableSGI",
+    "GetCombinerInputParameterfvNV",
+    "GetCombinerInputParameterivNV",
+    "GetCombinerOutputParameterfvNV",
+    "GetCombinerOutputParameterivNV",
+    "GetCombinerStageParameterfvNV",
+    "GetCommandHeaderNV",
+    "GetCompressedMultiTexImageEXT",
+    "GetCompressedTexImage",
+    "GetCompressedTexImageARB",
+    "GetCompressedTextureImage",
+    "GetCompressedTextureImageEXT",
+    "GetCompressedTextureSubImage",
+    "GetConvolutionFilterEXT",
+    "GetConvolutionParameterfvEXT",
+    "GetConvolutionParameterivEXT",
+    "GetConvolutionParameterxvOES",
+    "GetCoverageModulationTableNV",
+    "GetDebugMessageLog",
+    "GetDebugMessageLogAMD",
+    "GetDebugMessageLogARB",
+    "GetDetailTexFuncSGIS",
+    "GetDoubleIndexedvEXT",
+    "GetDoublei_v",
+    "GetDoublei_vEXT",
+    "GetDoublev",
+    "GetError",
+    "GetFenceivNV",
+    "GetFinalCombinerInputParameterfvNV",
+    "GetFinalCombinerInputParameterivNV",
+    "GetFirstPerfQueryIdINTEL",
+    "GetFixedvOES",
+    "GetFloatIndexedvEXT",
+    "GetFloati_v",
+    "GetFloati_vEXT",
+    "GetFloatv",
+    "GetFogFuncSGIS",
+    "GetFragDataIndex",
+    "GetFragDataLocation",
+    "GetFragDataLocationEXT",
+    "GetFragmentLightfvSGIX",
+    "GetFragmentLightivSGIX",
+    "GetFragmentMaterialfvSGIX",
+    "GetFragmentMaterialivSGIX",
+    "GetFramebufferAttachmentParameteriv",
+    "GetFramebufferAttachmentParameterivEXT",
+    "GetFramebufferParameterfvAMD",
+    "GetFramebufferParameteriv",
+    "GetFramebufferParameterivEXT",
+    "GetFramebufferParameterivMESA",
+    "GetGraphicsResetStatus",
+    "GetGraphicsResetStatusARB",
+    "GetHandleARB",
+    "GetHistogramEXT",
+    "GetHistogramParameterfvEXT",
+    "GetHistogramParameterivEXT",
+    "GetHistogramParameterxvOES",
+    "GetImageHandleARB",
+    "GetImageHandleNV",
+    "GetImageTransformParameterfvHP",
+    "GetImageTransformParameterivHP",
+    "GetInfoLogARB",
+    "GetInstrumentsSGIX",
+    "GetInteger64i_v",
+    "GetInteger64v",
+    "GetIntegerIndexedvEXT",
+    "GetIntegeri_v",
+    "GetIntegerui64i_vNV",
+    "GetIntegerui64vNV",
+    "GetIntegerv",
+    "GetInternalformatSampleivNV",
+    "GetInternalformati64v",
+    "GetInternalformativ",
+    "GetInvariantBooleanvEXT",
+    "GetInvariantFloatvEXT",
+    "GetInvariantIntegervEXT",
+    "GetLightxOES",
+    "GetListParameterfvSGIX",
+    "GetListParameterivSGIX",
+    "GetLocalConstantBooleanvEXT",
+    "GetLocalConstantFloatvEXT",
+    "GetLocalConstantIntegervEXT",
+    "GetMapAttribParameterfvNV",
+    "GetMapAttribParameterivNV",
+    "GetMapControlPointsNV",
+    "GetMapParameterfvNV",
+    "GetMapParameterivNV",
+    "GetMapxvOES",
+    "GetMaterialxOES",
+    "GetMemoryObjectDetachedResourcesuivNV",
+    "GetMemoryObjectParameterivEXT",
+    "GetMinmaxEXT",
+    "GetMinmaxParameterfvEXT",
+    "GetMinmaxParameterivEXT",
+    "GetMultiTexEnvfvEXT",
+    "GetMultiTexEnvivEXT",
+    "GetMultiTexGendvEXT",
+    "GetMultiTexGenfvEXT",
+    "GetMultiTexGenivEXT",
+    "GetMultiTexImageEXT",
+    "GetMultiTexLevelParameterfvEXT",
+    "GetMultiTexLevelParameterivEXT",
+    "GetMultiTexParameterIivEXT",
+    "GetMultiTexParameterIuivEXT",
+    "GetMultiTexParameterfvEXT",
+    "GetMultiTexParameterivEXT",
+    "GetMultisamplefv",
+    "GetMultisamplefvNV",
+    "GetNamedBufferParameteri64v",
+    "GetNamedBufferParameteriv",
+    "GetNamedBufferParameterivEXT",
+    "GetNamedBufferParameterui64vNV",
+    "GetNamedBufferPointerv",
+    "GetNamedBufferPointervEXT",
+    "GetNamedBufferSubData",
+    "GetNamedBufferSubDataEXT",
+    "GetNamedFramebufferAttachmentParameteriv",
+    "GetNamedFramebufferAttachmentParameterivEXT",
+    "GetNamedFramebufferParameterfvAMD",
+    "GetNamedFramebufferParameteriv",
+    "GetNamedFramebufferParameterivEXT",
+    "GetNamedProgramLocalParameterIivEXT",
+    "GetNamedProgramLocalParameterIuivEXT",
+    "GetNamedProgramLocalParameterdvEXT",
+    "GetNamedProgramLocalParameterfvEXT",
+    "GetNamedProgramStringEXT",
+    "GetNamedProgramivEXT",
+    "GetNamedRenderbufferParameteriv",
+    "GetNamedRenderbufferParameterivEXT",
+    "GetNamedStringARB",
+    "GetNamedStringivARB",
+    "GetNextPerfQueryIdINTEL",
+    "GetObjectBufferfvATI",
+    "GetObjectBufferivATI",
+    "GetObjectLabel",
+    "GetObjectLabelEXT",
+    "GetObjectParameterfvARB",
+    "GetObjectParameterivAPPLE",
+    "GetObjectParameterivARB",
+    "GetObjectPtrLabel",
+    "GetOcclusionQueryivNV",
+    "GetOcclusionQueryuivNV",
+    "GetPathCommandsNV",
+    "GetPathCoordsNV",
+    "GetPathDashArrayNV",
+    "GetPathLengthNV",
+    "GetPathMetricRangeNV",
+    "GetPathMetricsNV",
+    "GetPathParameterfvNV",
+    "GetPathParameterivNV",
+    "GetPathSpacingNV",
+    "GetPerfCounterInfoINTEL",
+    "GetPerfMonitorCounterDataAMD",
+    "GetPerfMonitorCounterInfoAMD",
+    "GetPerfMonitorCounterStringAMD",
+    "GetPerfMonitorCountersAMD",
+    "GetPerfMonitorGroupStringAMD",
+    "GetPerfMonitorGroupsAMD",
+    "GetPerfQueryDataINTEL",
+    "GetPerfQueryIdByNameINTEL",
+    "GetPerfQueryInfoINTEL",
+    "GetPixelMapxv",
+    "GetPixelTexGenParameterfvSGIS",
+    "GetPixelTexGenParameterivSGIS",
+    "GetPixelTransformParameterfvEXT",
+    "GetPixelTransformParameterivEXT",
+    "GetPointerIndexedvEXT",
+    "GetPointeri_vEXT",
+    "GetPointerv",
+    "GetPointervEXT",
+    "GetProgramBinary",
+    "GetProgramEnvParameterIivNV",
+    "GetProgramEnvParameterIuivNV",
+    "GetProgramEnvParameterdvARB",
+    "GetProgramEnvParameterfvARB",
+    "GetProgramInfoLog",
+    "GetProgramInterfaceiv",
+    "GetProgramLocalParameterIivNV",
+    "GetProgramLocalParameterIuivNV",
+    "GetProgramLocalParameterdvARB",
+    "GetProgramLocalParameterfvARB",
+    "GetProgramNamedParameterdvNV",
+    "GetProgramNamedParameterfvNV",
+    "GetProgramParameterdvNV",
+    "GetProgramParameterfvNV",
+    "GetProgramPipelineInfoLog",
+    "GetProgramPipelineiv",
+    "GetProgramResourceIndex",
+    "GetProgramResourceLocation",
+    "GetProgramResourceLocationIndex",
+    "GetProgramResourceName",
+    "GetProgramResourcefvNV",
+    "GetProgramResourceiv",
+    "GetProgramStageiv",
+    "GetProgramStringARB",
+    "GetProgramStringNV",
+    "GetProgramSubroutineParameteruivNV",
+    "GetProgramiv",
+    "GetProgramivARB",
+    "GetProgramivNV",
+    "GetQueryBufferObjecti64v",
+    "GetQueryBufferObjectiv",
+    "GetQueryBufferObjectui64v",
+    "GetQueryBufferObjectuiv",
+    "GetQueryIndexediv",
+    "GetQueryObjecti64v",
+    "GetQueryObjecti64vEXT",
+    "GetQueryObjectiv",
+    "GetQueryObjectivARB",
+    "GetQueryObjectui64v",
+    "GetQueryObjectui64vEXT",
+    "GetQueryObjectuiv",
+    "GetQueryObjectuivARB",
+    "GetQueryiv",
+    "GetQueryivARB",
+    "GetRenderbufferParameteriv",
+    "GetRenderbufferParameterivEXT",
+    "GetSamplerParameterIiv",
+    "GetSamplerParameterIuiv",
+    "GetSamplerParameterfv",
+    "GetSamplerParameteriv",
+    "GetSemaphoreParameterivNV",
+    "GetSemaphoreParameterui64vEXT",
+    "GetSeparableFilterEXT",
+    "GetShaderInfoLog",
+    "GetShaderPrecisionFormat",
+    "GetShaderSource",
+    "GetShaderSourceARB",
+    "GetShaderiv",
+    "GetShadingRateImagePaletteNV",
+    "GetShadingRateSampleLocationivNV",
+    "GetSharpenTexFuncSGIS",
+    "GetStageIndexNV",
+    "GetString",
+    "GetStringi",
+    "GetSubroutineIndex",
+    "GetSubroutineUniformLocation",
+    "GetSynciv",
+    "GetTexBumpParameterfvATI",
+    "GetTexBumpParameterivATI",
+    "GetTexEnvxvOES",
+    "GetTexFilterFuncSGIS",
+    "GetTexGenxvOES",
+    "GetTexImage",
+    "GetTexLevelParameterfv",
+    "GetTexLevelParameteriv",
+    "GetTexLevelParameterxvOES",
+    "GetTexParameterIiv",
+    "GetTexParameterIivEXT",
+    "GetTexParameterIuiv",
+    "GetTexParameterIuivEXT",
+    "GetTexParameterPointervAPPLE",
+    "GetTexParameterfv",
+    "GetTexParameteriv",
+    "GetTexParameterxvOES",
+    "GetTextureHandleARB",
+    "GetTextureHandleNV",
+    "GetTextureImage",
+    "GetTextureImageEXT",
+    "GetTextureLevelParameterfv",
+    "GetTextureLevelParameterfvEXT",
+    "GetTextureLevelParameteriv",
+    "GetTextureLevelParameterivEXT",
+    "GetTextureParameterIiv",
+    "GetTextureParameterIivEXT",
+    "GetTextureParameterIuiv",
+    "GetTextureParameterIuivEXT",
+    "GetTextureParameterfv",
+    "GetTextureParameterfvEXT",
+    "GetTextureParameteriv",
+    "GetTextureParameterivEXT",
+    "GetTextureSamplerHandleARB",
+    "GetTextureSamplerHandleNV",
+    "GetTextureSubImage",
+    "GetTrackMatrixivNV",
+    "GetTransformFeedbackVarying",
+    "GetTransformFeedbackVaryingEXT",
+    "GetTransformFeedbackVaryingNV",
+    "GetTransformFeedbacki64_v",
+    "GetTransformFeedbacki_v",
+    "GetTransformFeedbackiv",
+    "GetUniformBlockIndex",
+    "GetUniformBufferSizeEXT",
+    "GetUniformIndices",
+    "GetUniformLocation",
+    "GetUniformLocationARB",
+    "GetUniformOffsetEXT",
+    "GetUniformSubroutineuiv",
+    "GetUniformdv",
+    "GetUniformfv",
+    "GetUniformfvARB",
+    "GetUniformi64vARB",
+    "GetUniformi64vNV",
+    "GetUniformiv",
+    "GetUniformivARB",
+    "GetUniformui64vARB",
+    "GetUniformui64vNV",
+    "GetUniformuiv",
+    "GetUniformuivEXT",
+    "GetUnsignedBytei_vEXT",
+    "GetUnsignedBytevEXT",
+    "GetVariantArrayObjectfvATI",
+    "GetVariantArrayObjectivATI",
+    "GetVariantBooleanvEXT",
+    "GetVariantFloatvEXT",
+    "GetVariantIntegervEXT",
+    "GetVariantPointervEXT",
+    "GetVaryingLocationNV",
+    "GetVertexArrayIndexed64iv",
+    "GetVertexArrayIndexediv",
+    "GetVertexArrayIntegeri_vEXT",
+    "GetVertexArrayIntegervEXT",
+    "GetVertexArrayPointeri_vEXT",
+    "GetVertexArrayPointervEXT",
+    "GetVertexArrayiv",
+    "GetVertexAttribArrayObjectfvATI",
+    "GetVertexAttribArrayObjectivATI",
+    "GetVertexAttribIiv",
+    "GetVertexAttribIivEXT",
+    "GetVertexAttribIuiv",
+    "GetVertexAttribIuivEXT",
+    "GetVertexAttribLdv",
+    "GetVertexAttribLdvEXT",
+    "GetVertexAttribLi64vNV",
+    "GetVertexAttribLui64vARB",
+    "GetVertexAttribLui64vNV",
+    "GetVertexAttribPointerv",
+    "GetVertexAttribPointervARB",
+    "GetVertexAttribPointervNV",
+    "GetVertexAttribdv",
+    "GetVertexAttribdvARB",
+    "GetVertexAttribdvNV",
+    "GetVertexAttribfv",
+    "GetVertexAttribfvARB",
+    "GetVertexAttribfvNV",
+    "GetVertexAttribiv",
+    "GetVertexAttribivARB",
+    "GetVertexAttribivNV",
+    "GetVideoCaptureStreamdvNV",
+    "GetVideoCaptureStreamfvNV",
+    "GetVideoCaptureStreamivNV",
+    "GetVideoCaptureivNV",
+    "GetVideoi64vNV",
+    "GetVideoivNV",
+    "GetVideoui64vNV",
+    "GetVideouivNV",
+    "GetVkProcAddrNV",
+    "GetnCompressedTexImage",
+    "GetnCompressedTexImageARB",
+    "GetnTexImage",
+    "GetnTexImageARB",
+    "GetnUniformdv",
+    "GetnUniformdvARB",
+    "GetnUniformfv",
+    "GetnUniformfvARB",
+    "GetnUniformi64vARB",
+    "GetnUniformiv",
+    "GetnUniformivARB",
+    "GetnUniformui64vARB",
+    "GetnUniformuiv",
+    "GetnUniformuivARB",
+    "GlobalAlphaFactorbSUN",
+    "GlobalAlphaFactordSUN",
+    "GlobalAlphaFactorfSUN",
+    "GlobalAlphaFactoriSUN",
+    "GlobalAlphaFactorsSUN",
+    "GlobalAlphaFactorubSUN",
+    "GlobalAlphaFactoruiSUN",
+    "GlobalAlphaFactorusSUN",
+    "Hint",
+    "HintPGI",
+    "HistogramEXT",
+    "IglooInterfaceSGIX",
+    "ImageTransformParameterfHP",
+    "ImageTransformParameterfvHP",
+    "ImageTransformParameteriHP",
+    "ImageTransformParameterivHP",
+    "ImportMemoryFdEXT",
+    "ImportMemoryWin32HandleEXT",
+    "ImportMemoryWin32NameEXT",
+    "ImportSemaphoreFdEXT",
+    "ImportSemaphoreWin32HandleEXT",
+    "ImportSemaphoreWin32NameEXT",
+    "ImportSyncEXT",
+    "IndexFormatNV",
+    "IndexFuncEXT",
+    "IndexMaterialEXT",
+    "IndexPointerEXT",
+    "IndexPointerListIBM",
+    "IndexxOES",
+    "IndexxvOES",
+    "InsertComponentEXT",
+    "InsertEventMarkerEXT",
+    "InstrumentsBufferSGIX",
+    "InterpolatePathsNV",
+    "InvalidateBufferData",
+    "InvalidateBufferSubData",
+    "InvalidateFramebuffer",
+    "InvalidateNamedFramebufferData",
+    "InvalidateNamedFramebufferSubData",
+    "InvalidateSubFramebuffer",
+    "InvalidateTexImage",
+    "InvalidateTexSubImage",
+    "IsAsyncMarkerSGIX",
+    "IsBuffer",
+    "IsBufferARB",
+    "IsBufferResidentNV",
+    "IsCommandListNV",
+    "IsEnabled",
+    "IsEnabledIndexedEXT",
+    "IsEnabledi",
+    "IsFenceAPPLE",
+    "IsFenceNV",
+    "IsFramebuffer",
+    "IsFramebufferEXT",
+    "IsImageHandleResidentARB",
+    "IsImageHandleResidentNV",
+    "IsMemoryObjectEXT",
+    "IsNameAMD",
+    "IsNamedBufferResidentNV",
+    "IsNamedStringARB",
+    "IsObjectBufferATI",
+    "IsOcclusionQueryNV",
+    "IsPathNV",
+    "IsPointInFillPathNV",
+    "IsPointInStrokePathNV",
+    "IsProgram",
+    "IsProgramARB",
+    "IsProgramNV",
+    "IsProgramPipeline",
+    "IsQuery",
+    "IsQueryARB",
+    "IsRenderbuffer",
+    "IsRenderbufferEXT",
+    "IsSampler",
+    "IsSemaphoreEXT",
+    "IsShader",
+    "IsStateNV",
+    "IsSync",
+    "IsTexture",
+    "IsTextureEXT",
+    "IsTextureHandleResidentARB",
+    "IsTextureHandleResidentNV",
+    "IsTransformFeedback",
+    "IsTransformFeedbackNV",
+    "IsVariantEnabledEXT",
+    "IsVertexArray",
+    "IsVertexArrayAPPLE",
+    "IsVertexAttribEnabledAPPLE",
+    "LGPUCopyImageSubDataNVX",
+    "LGPUInterlockNVX",
+    "LGPUNamedBufferSubDataNVX",
+    "LabelObjectEXT",
+    "LightEnviSGIX",
+    "LightModelxOES",
+    "LightModelxvOES",
+    "LightxOES",
+    "LightxvOES",
+    "LineWidth",
+    "LineWidthxOES",
+    "LinkProgram",
+    "LinkProgramARB",
+    "ListDrawCommandsStatesClientNV",
+    "ListParameterfSGIX",
+    "ListParameterfvSGIX",
+    "ListParameteriSGIX",
+    "ListParameterivSGIX",
+    "LoadIdentityDeformationMapSGIX",
+    "LoadMatrixxOES",
+    "LoadProgramNV",
+    "LoadTransposeMatrixdARB",
+    "LoadTransposeMatrixfARB",
+    "LoadTransposeMatrixxOES",
+    "LockArraysEXT",
+    "LogicOp",
+    "MakeBufferNonResidentNV",
+    "MakeBufferResidentNV",
+    "MakeImageHandleNonResidentARB",
+    "MakeImageHandleNonResidentNV",
+    "MakeImageHandleResidentARB",
+    "MakeImageHandleResidentNV",
+    "MakeNamedBufferNonResidentNV",
+    "MakeNamedBufferResidentNV",
+    "MakeTextureHandleNonResidentARB",
+    "MakeTextureHandleNonResidentNV",
+    "MakeTextureHandleResidentARB",
+    "MakeTextureHandleResidentNV",
+    "Map1xOES",
+    "Map2xOES",
+    "MapBuffer",
+    "MapBufferARB",
+    "MapBufferRange",
+    "MapControlPointsNV",
+    "MapGrid1xOES",
+    "MapGrid2xOES",
+    "MapNamedBuffer",
+    "MapNamedBufferEXT",
+    "MapNamedBufferRange",
+    "MapNamedBufferRangeEXT",
+    "MapObjectBufferATI",
+    "MapParameterfvNV",
+    "MapParameterivNV",
+    "MapTexture2DINTEL",
+    "MapVertexAttrib1dAPPLE",
+    "MapVertexAttrib1fAPPLE",
+    "MapVertexAttrib2dAPPLE",
+    "MapVertexAttrib2fAPPLE",
+    "MaterialxOES",
+    "MaterialxvOES",
+    "MatrixFrustumEXT",
+    "MatrixIndexPointerARB",
+    "MatrixIndexubvARB",
+    "MatrixIndexuivARB",
+    "MatrixIndexusvARB",
+    "MatrixLoad3x2fNV",
+    "MatrixLoad3x3fNV",
+    "MatrixLoadIdentityEXT",
+    "MatrixLoadTranspose3x3fNV",
+    "MatrixLoadTransposedEXT",
+    "MatrixLoadTransposefEXT",
+    "MatrixLoaddEXT",
+    "MatrixLoadfEXT",
+    "MatrixMult3x2fNV",
+    "MatrixMult3x3fNV",
+    "MatrixMultTranspose3x3fNV",
+    "MatrixMultTransposedEXT",
+    "MatrixMultTransposefEXT",
+    "MatrixMultdEXT",
+    "MatrixMultfEXT",
+    "MatrixOrthoEXT",
+    "MatrixPopEXT",
+    "MatrixPushEXT",
+    "MatrixRotatedEXT",
+    "MatrixRotatefEXT",
+    "MatrixScaledEXT",
+    "MatrixScalefEXT",
+    "MatrixTranslatedEXT",
+    "MatrixTranslatefEXT",
+    "MaxShaderCompilerThreadsARB",
+    "MaxShaderCompilerThreadsKHR",
+    "MemoryBarrier",
+    "MemoryBarrierByRegion",
+    "MemoryBarrierEXT",
+    "MemoryObjectParameterivEXT",
+    "MinSampleShading",
+    "MinSampleShadingARB",
+    "MinmaxEXT",
+    "MultMatrixxOES",
+    "MultTransposeMatrixdARB",
+    "MultTransposeMatrixfARB",
+    "MultTransposeMatrixxOES",
+    "MultiDrawArrays",
+    "MultiDrawArraysEXT",
+    "MultiDrawArraysIndirect",
+    "MultiDrawArraysIndirectAMD",
+    "MultiDrawArraysIndirectBindlessCountNV",
+    "MultiDrawArraysIndirectBindlessNV",
+    "MultiDrawArraysIndirectCount",
+    "MultiDrawArraysIndirectCountARB",
+    "MultiDrawElementArrayAPPLE",
+    "MultiDrawElements",
+    "MultiDrawElementsBaseVertex",
+    "MultiDrawElementsEXT",
+    "MultiDrawElementsIndirect",
+    "MultiDrawElementsIndirectAMD",
+    "MultiDrawElementsIndirectBindlessCountNV",
+    "MultiDrawElementsIndirectBindlessNV",
+    "MultiDrawElementsIndirectCount",
+    "MultiDrawElementsIndirectCountARB",
+    "MultiDrawMeshTasksIndirectCountNV",
+    "MultiDrawMeshTasksIndirectNV",
+    "MultiDrawRangeElementArrayAPPLE",
+    "MultiModeDrawArraysIBM",
+    "MultiModeDrawElementsIBM",
+    "MultiTexBufferEXT",
+    "MultiTexCoord1bOES",
+    "MultiTexCoord1bvOES",
+    "MultiTexCoord1dARB",
+    "MultiTexCoord1dvARB",
+    "MultiTexCoord1fARB",
+    "MultiTexCoord1fvARB",
+    "MultiTexCoord1hNV",
+    "MultiTexCoord1hvNV",
+    "MultiTexCoord1iARB",
+    "MultiTexCoord1ivARB",
+    "MultiTexCoord1sARB",
+    "MultiTexCoord1svARB",
+    "MultiTexCoord1xOES",
+    "MultiTexCoord1xvOES",
+    "MultiTexCoord2bOES",
+    "MultiTexCoord2bvOES",
+    "MultiTexCoord2dARB",
+    "MultiTexCoord2dvARB",
+    "MultiTexCoord2fARB",
+    "MultiTexCoord2fvARB",
+    "MultiTexCoord2hNV",
+    "MultiTexCoord2hvNV",
+    "MultiTexCoord2iARB",
+    "MultiTexCoord2ivARB",
+    "MultiTexCoord2sARB",
+    "MultiTexCoord2svARB",
+    "MultiTexCoord2xOES",
+    "MultiTexCoord2xvOES",
+    "MultiTexCoord3bOES",
+    "MultiTexCoord3bvOES",
+    "MultiTexCoord3dARB",
+    "MultiTexCoord3dvARB",
+    "MultiTexCoord3fARB",
+    "MultiTexCoord3fvARB",
+    "MultiTexCoord3hNV",
+    "MultiTexCoord3hvNV",
+    "MultiTexCoord3iARB",
+    "MultiTexCoord3ivARB",
+    "MultiTexCoord3sARB",
+    "MultiTexCoord3svARB",
+    "MultiTexCoord3xOES",
+    "MultiTexCoord3xvOES",
+    "MultiTexCoord4bOES",
+    "MultiTexCoord4bvOES",
+    "MultiTexCoord4dARB",
+    "MultiTexCoord4dvARB",
+    "MultiTexCoord4fARB",
+    "MultiTexCoord4fvARB",
+    "MultiTexCoord4hNV",
+    "MultiTexCoord4hvNV",
+    "MultiTexCoord4iARB",
+    "MultiTexCoord4ivARB",
+    "MultiTexCoord4sARB",
+    "MultiTexCoord4svARB",
+    "MultiTexCoord4xOES",
+    "MultiTexCoord4xvOES",
+    "MultiTexCoordPointerEXT",
+    "MultiTexEnvfEXT",
+    "MultiTexEnvfvEXT",
+    "MultiTexEnviEXT",
+    "MultiTexEnvivEXT",
+    "MultiTexGendEXT",
+    "MultiTexGendvEXT",
+    "MultiTexGenfEXT",
+    "MultiTexGenfvEXT",
+    "MultiTexGeniEXT",
+    "MultiTexGenivEXT",
+    "MultiTexImage1DEXT",
+    "MultiTexImage2DEXT",
+    "MultiTexImage3DEXT",
+    "MultiTexParameterIivEXT",
+    "MultiTexParameterIuivEXT",
+    "MultiTexParameterfEXT",
+    "MultiTexParameterfvEXT",
+    "MultiTexParameteriEXT",
+    "MultiTexParameterivEXT",
+    "MultiTexRenderbufferEXT",
+    "MultiTexSubImage1DEXT",
+    "MultiTexSubImage2DEXT",
+    "MultiTexSubImage3DEXT",
+    "MulticastBarrierNV",
+    "MulticastBlitFramebufferNV",
+    "MulticastBufferSubDataNV",
+    "MulticastCopyBufferSubDataNV",
+    "MulticastCopyImageSubDataNV",
+    "MulticastFramebufferSampleLocationsfvNV",
+    "MulticastGetQueryObjecti64vNV",
+    "MulticastGetQueryObjectivNV",
+    "MulticastGetQueryObjectui64vNV",
+    "MulticastGetQueryObjectuivNV",
+    "MulticastScissorArrayvNVX",
+    "MulticastViewportArrayvNVX",
+    "MulticastViewportPositionWScaleNVX",
+    "MulticastWaitSyncNV",
+    "NamedBufferAttachMemoryNV",
+    "NamedBufferData",
+    "NamedBufferDataEXT",
+    "NamedBufferPageCommitmentARB",
+    "NamedBufferPageCommitmentEXT",
+    "NamedBufferPageCommitmentMemNV",
+    "NamedBufferStorage",
+    "NamedBufferStorageEXT",
+    "NamedBufferStorageExternalEXT",
+    "NamedBufferStorageMemEXT",
+    "NamedBufferSubData",
+    "NamedBufferSubDataEXT",
+    "NamedCopyBufferSubDataEXT",
+    "NamedFramebufferDrawBuffer",
+    "NamedFramebufferDrawBuffers",
+    "NamedFramebufferParameteri",
+    "NamedFramebufferParameteriEXT",
+    "NamedFramebufferReadBuffer",
+    "NamedFramebufferRenderbuffer",
+    "NamedFramebufferRenderbufferEXT",
+    "NamedFramebufferSampleLocationsfvARB",
+    "NamedFramebufferSampleLocationsfvNV",
+    "NamedFramebufferSamplePositionsfvAMD",
+    "NamedFramebufferTexture",
+    "NamedFramebufferTexture1DEXT",
+    "NamedFramebufferTexture2DEXT",
+    "NamedFramebufferTexture3DEXT",
+    "NamedFramebufferTextureEXT",
+    "NamedFramebufferTextureFaceEXT",
+    "NamedFramebufferTextureLayer",
+    "NamedFramebufferTextureLayerEXT",
+    "NamedProgramLocalParameter4dEXT",
+    "NamedProgramLocalParameter4dvEXT",
+    "NamedProgramLocalParameter4fEXT",
+    "NamedProgramLocalParameter4fvEXT",
+    "NamedProgramLocalParameterI4iEXT",
+    "NamedProgramLocalParameterI4ivEXT",
+    "NamedProgramLocalParameterI4uiEXT",
+    "NamedProgramLocalParameterI4uivEXT",
+    "NamedProgramLocalParameters4fvEXT",
+    "NamedProgramLocalParametersI4ivEXT",
+    "NamedProgramLocalParametersI4uivEXT",
+    "NamedProgramStringEXT",
+    "NamedRenderbufferStorage",
+    "NamedRenderbufferStorageEXT",
+    "NamedRenderbufferStorageMultisample",
+    "NamedRenderbufferStorageMultisampleAdvancedAMD",
+    "NamedRenderbufferStorageMultisampleCoverageEXT",
+    "NamedRenderbufferStorageMultisampleEXT",
+    "NamedStringARB",
+    "NewObjectBufferATI",
+    "Normal3fVertex3fSUN",
+    "Normal3fVertex3fvSUN",
+    "Normal3hNV",
+    "Normal3hvNV",
+    "Normal3xOES",
+    "Normal3xvOES",
+    "NormalFormatNV",
+    "NormalPointerEXT",
+    "NormalPointerListIBM",
+    "NormalPointervINTEL",
+    "NormalStream3bATI",
+    "NormalStream3bvATI",
+    "NormalStream3dATI",
+    "NormalStream3dvATI",
+    "NormalStream3fATI",
+    "NormalStream3fvATI",
+    "NormalStream3iATI",
+    "NormalStream3ivATI",
+    "NormalStream3sATI",
+    "NormalStream3svATI",
+    "ObjectLabel",
+    "ObjectPtrLabel",
+    "ObjectPurgeableAPPLE",
+    "ObjectUnpurgeableAPPLE",
+    "OrthofOES",
+    "OrthoxOES",
+    "PNTrianglesfATI",
+    "PNTrianglesiATI",
+    "PassTexCoordATI",
+    "PassThroughxOES",
+    "PatchParameterfv",
+    "PatchParameteri",
+    "PathCommandsNV",
+    "PathCoordsNV",
+    "PathCoverDepthFuncNV",
+    "PathDashArrayNV",
+    "PathGlyphIndexArrayNV",
+    "PathGlyphIndexRangeNV",
+    "PathGlyphRangeNV",
+    "PathGlyphsNV",
+    "PathMemoryGlyphIndexArrayNV",
+    "PathParameterfNV",
+    "PathParameterfvNV",
+    "PathParameteriNV",
+    "PathParameterivNV",
+    "PathStencilDepthOffsetNV",
+    "PathStencilFuncNV",
+    "PathStringNV",
+    "PathSubCommandsNV",
+    "PathSubCoordsNV",
+    "PauseTransformFeedback",
+    "PauseTransformFeedbackNV",
+    "PixelDataRangeNV",
+    "PixelMapx",
+    "PixelStoref",
+    "PixelStorei",
+    "PixelStorex",
+    "PixelTexGenParameterfSGIS",
+    "PixelTexGenParameterfvSGIS",
+    "PixelTexGenParameteriSGIS",
+    "PixelTexGenParameterivSGIS",
+    "PixelTexGenSGIX",
+    "PixelTransferxOES",
+    "PixelTransformParameterfEXT",
+    "PixelTransformParameterfvEXT",
+    "PixelTransformParameteriEXT",
+    "PixelTransformParameterivEXT",
+    "PixelZoomxOES",
+    "PointAlongPathNV",
+    "PointParameterf",
+    "PointParameterfARB",
+    "PointParameterfEXT",
+    "PointParameterfSGIS",
+    "PointParameterfv",
+    "PointParameterfvARB",
+    "PointParameterfvEXT",
+    "PointParameterfvSGIS",
+    "PointParameteri",
+    "PointParameteriNV",
+    "PointParameteriv",
+    "PointParameterivNV",
+    "PointParameterxvOES",
+    "PointSize",
+    "PointSizexOES",
+    "PollAsyncSGIX",
+    "PollInstrumentsSGIX",
+    "PolygonMode",
+    "PolygonOffset",
+    "PolygonOffsetClamp",
+    "PolygonOffsetClampEXT",
+    "PolygonOffsetEXT",
+    "PolygonOffsetxOES",
+    "PopDebugGroup",
+    "PopGroupMarkerEXT",
+    "PresentFrameDualFillNV",
+    "PresentFrameKeyedNV",
+    "PrimitiveBoundingBoxARB",
+    "PrimitiveRestartIndex",
+    "PrimitiveRestartIndexNV",
+    "PrimitiveRestartNV",
+    "PrioritizeTexturesEXT",
+    "PrioritizeTexturesxOES",
+    "ProgramBinary",
+    "ProgramBufferParametersIivNV",
+    "ProgramBufferParametersIuivNV",
+    "ProgramBufferParametersfvNV",
+    "ProgramEnvParameter4dARB",
+    "ProgramEnvParameter4dvARB",
+    "ProgramEnvParameter4fARB",
+    "ProgramEnvParameter4fvARB",
+    "ProgramEnvParameterI4iNV",
+    "ProgramEnvParameterI4ivNV",
+    "ProgramEnvParameterI4uiNV",
+    "ProgramEnvParameterI4uivNV",
+    "ProgramEnvParameters4fvEXT",
+    "ProgramEnvParametersI4ivNV",
+    "ProgramEnvParametersI4uivNV",
+    "ProgramLocalParameter4dARB",
+    "ProgramLocalParameter4dvARB",
+    "ProgramLocalParameter4fARB",
+    "ProgramLocalParameter4fvARB",
+    "ProgramLocalParameterI4iNV",
+    "ProgramLocalParameterI4ivNV",
+    "ProgramLocalParameterI4uiNV",
+    "ProgramLocalParameterI4uivNV",
+    "ProgramLocalParameters4fvEXT",
+    "ProgramLocalParametersI4ivNV",
+    "ProgramLocalParametersI4uivNV",
+    "ProgramNamedParameter4dNV",
+    "ProgramNamedParameter4dvNV",
+    "ProgramNamedParameter4fNV",
+    "ProgramNamedParameter4fvNV",
+    "ProgramParameter4dNV",
+    "ProgramParameter4dvNV",
+    "ProgramParameter4fNV",
+    "ProgramParameter4fvNV",
+    "ProgramParameteri",
+    "ProgramParameteriARB",
+    "ProgramParameteriEXT",
+    "ProgramParameters4dvNV",
+    "ProgramParameters4fvNV",
+    "ProgramPathFragmentInputGenNV",
+    "ProgramStringARB",
+    "ProgramSubroutineParametersuivNV",
+    "ProgramUniform1d",
+    "ProgramUniform1dEXT",
+    "ProgramUniform1dv",
+    "ProgramUniform1dvEXT",
+    "ProgramUniform1f",
+    "ProgramUniform1fEXT",
+    "ProgramUniform1fv",
+    "ProgramUniform1fvEXT",
+    "ProgramUniform1i",
+    "ProgramUniform1i64ARB",
+    "ProgramUniform1i64NV",
+    "ProgramUniform1i64vARB",
+    "ProgramUniform1i64vNV",
+    "ProgramUniform1iEXT",
+    "ProgramUniform1iv",
+    "ProgramUniform1ivEXT",
+    "ProgramUniform1ui",
+    "ProgramUniform1ui64ARB",
+    "ProgramUniform1ui64NV",
+    "ProgramUniform1ui64vARB",
+    "ProgramUniform1ui64vNV",
+    "ProgramUniform1uiEXT",
+    "ProgramUniform1uiv",
+    "ProgramUniform1uivEXT",
+    "ProgramUniform2d",
+    "ProgramUniform2dEXT",
+    "ProgramUniform2dv",
+    "ProgramUniform2dvEXT",
+    "ProgramUniform2f",
+    "ProgramUniform2fEXT",
+    "ProgramUniform2fv",
+    "ProgramUniform2fvEXT",
+    "ProgramUniform2i",
+    "ProgramUniform2i64ARB",
+    "ProgramUniform2i64NV",
+    "ProgramUniform2i64vARB",
+    "ProgramUniform2i64vNV",
+    "ProgramUniform2iEXT",
+    "ProgramUniform2iv",
+    "ProgramUniform2ivEXT",
+    "ProgramUniform2ui",
+    "ProgramUniform2ui64ARB",
+    "ProgramUniform2ui64NV",
+    "ProgramUniform2ui64vARB",
+    "ProgramUniform2ui64vNV",
+    "ProgramUniform2uiEXT",
+    "ProgramUniform2uiv",
+    "ProgramUniform2uivEXT",
+    "ProgramUniform3d",
+    "ProgramUniform3dEXT",
+    "ProgramUniform3dv",
+    "ProgramUniform3dvEXT",
+    "ProgramUniform3f",
+    "ProgramUniform3fEXT",
+    "ProgramUniform3fv",
+    "ProgramUniform3fvEXT",
+    "ProgramUniform3i",
+    "ProgramUniform3i64ARB",
+    "ProgramUniform3i64NV",
+    "ProgramUniform3i64vARB",
+    "ProgramUniform3i64vNV",
+    "ProgramUniform3iEXT",
+    "ProgramUniform3iv",
+    "ProgramUniform3ivEXT",
+    "ProgramUniform3ui",
+    "ProgramUniform3ui64ARB",
+    "ProgramUniform3ui64NV",
+    "ProgramUniform3ui64vARB",
+    "ProgramUniform3ui64vNV",
+    "ProgramUniform3uiEXT",
+    "ProgramUniform3uiv",
+    "ProgramUniform3uivEXT",
+    "ProgramUniform4d",
+    "ProgramUniform4dEXT",
+    "ProgramUniform4dv",
+    "ProgramUniform4dvEXT",
+    "ProgramUniform4f",
+    "ProgramUniform4fEXT",
+    "ProgramUniform4fv",
+    "ProgramUniform4fvEXT",
+    "ProgramUniform4i",
+    "ProgramUniform4i64ARB",
+    "ProgramUniform4i64NV",
+    "ProgramUniform4i64vARB",
+    "ProgramUniform4i64vNV",
+    "ProgramUniform4iEXT",
+    "ProgramUniform4iv",
+    "ProgramUniform4ivEXT",
+    "ProgramUniform4ui",
+    "ProgramUniform4ui64ARB",
+    "ProgramUniform4ui64NV",
+    "ProgramUniform4ui64vARB",
+    "ProgramUniform4ui64vNV",
+    "ProgramUniform4uiEXT",
+    "ProgramUniform4uiv",
+    "ProgramUniform4uivEXT",
+    "ProgramUniformHandleui64ARB",
+    "ProgramUniformHandleui64NV",
+    "ProgramUniformHandleui64vARB",
+    "ProgramUniformHandleui64vNV",
+    "ProgramUniformMatrix2dv",
+    "ProgramUniformMatrix2dvEXT",
+    "ProgramUniformMatrix2fv",
+    "ProgramUniformMatrix2fvEXT",
+    "ProgramUniformMatrix2x3dv",
+    "ProgramUniformMatrix2x3dvEXT",
+    "ProgramUniformMatrix2x3fv",
+    "ProgramUniformMatrix2x3fvEXT",
+    "ProgramUniformMatrix2x4dv",
+    "ProgramUniformMatrix2x4dvEXT",
+    "ProgramUniformMatrix2x4fv",
+    "ProgramUniformMatrix2x4fvEXT",
+    "ProgramUniformMatrix3dv",
+    "ProgramUniformMatrix3dvEXT",
+    "ProgramUniformMatrix3fv",
+    "ProgramUniformMatrix3fvEXT",
+    "ProgramUniformMatrix3x2dv",
+    "ProgramUniformMatrix3x2dvEXT",
+    "ProgramUniformMatrix3x2fv",
+    "ProgramUniformMatrix3x2fvEXT",
+    "ProgramUniformMatrix3x4dv",
+    "ProgramUniformMatrix3x4dvEXT",
+    "ProgramUniformMatrix3x4fv",
+    "ProgramUniformMatrix3x4fvEXT",
+    "ProgramUniformMatrix4dv",
+    "ProgramUniformMatrix4dvEXT",
+    "ProgramUniformMatrix4fv",
+    "ProgramUniformMatrix4fvEXT",
+    "ProgramUniformMatrix4x2dv",
+    "ProgramUniformMatrix4x2dvEXT",
+    "ProgramUniformMatrix4x2fv",
+    "ProgramUniformMatrix4x2fvEXT",
+    "ProgramUniformMatrix4x3dv",
+    "ProgramUniformMatrix4x3dvEXT",
+    "ProgramUniformMatrix4x3fv",
+    "ProgramUniformMatrix4x3fvEXT",
+    "ProgramUniformui64NV",
+    "ProgramUniformui64vNV",
+    "ProgramVertexLimitNV",
+    "ProvokingVertex",
+    "ProvokingVertexEXT",
+    "PushClientAttribDefaultEXT",
+    "PushDebugGroup",
+    "PushGroupMarkerEXT",
+    "QueryCounter",
+    "QueryMatrixxOES",
+    "QueryObjectParameteruiAMD",
+    "QueryResourceNV",
+    "QueryResourceTagNV",
+    "RasterPos2xOES",
+    "RasterPos2xvOES",
+    "RasterPos3xOES",
+    "RasterPos3xvOES",
+    "RasterPos4xOES",
+    "RasterPos4xvOES",
+    "RasterSamplesEXT",
+    "ReadBuffer",
+    "ReadInstrumentsSGIX",
+    "ReadPixels",
+    "ReadnPixels",
+    "ReadnPixelsARB",
+    "RectxOES",
+    "RectxvOES",
+    "ReferencePlaneSGIX",
+    "ReleaseKeyedMutexWin32EXT",
+    "ReleaseShaderCompiler",
+    "RenderGpuMaskNV",
+    "RenderbufferStorage",
+    "RenderbufferStorageEXT",
+    "RenderbufferStorageMultisample",
+    "RenderbufferStorageMultisampleAdvancedAMD",
+    "RenderbufferStorageMultisampleCoverageNV",
+    "RenderbufferStorageMultisampleEXT",
+    "ReplacementCodePointerSUN",
+    "ReplacementCodeubSUN",
+    "ReplacementCodeubvSUN",
+    "ReplacementCodeuiColor3fVertex3fSUN",
+    "ReplacementCodeuiColor3fVertex3fvSUN",
+    "ReplacementCodeuiColor4fNormal3fVertex3fSUN",
+    "ReplacementCodeuiColor4fNormal3fVertex3fvSUN",
+    "ReplacementCodeuiColor4ubVertex3fSUN",
+    "ReplacementCodeuiColor4ubVertex3fvSUN",
+    "ReplacementCodeuiNormal3fVertex3fSUN",
+    "ReplacementCodeuiNormal3fVertex3fvSUN",
+    "ReplacementCodeuiSUN",
+    "ReplacementCodeuiTexCoord2fColor4fNormal3fVertex3fSUN",
+    "ReplacementCodeuiTexCoord2fColor4fNormal3fVertex3fvSUN",
+    "ReplacementCodeuiTexCoord2fNormal3fVertex3fSUN",
+    "ReplacementCodeuiTexCoord2fNormal3fVertex3fvSUN",
+    "ReplacementCodeuiTexCoord2fVertex3fSUN",
+    "ReplacementCodeuiTexCoord2fVertex3fvSUN",
+    "ReplacementCodeuiVertex3fSUN",
+    "ReplacementCodeuiVertex3fvSUN",
+    "ReplacementCodeuivSUN",
+    "ReplacementCodeusSUN",
+    "ReplacementCodeusvSUN",
+    "RequestResidentProgramsNV",
+    "ResetHistogramEXT",
+    "ResetMemoryObjectParameterNV",
+    "ResetMinmaxEXT",
+    "ResizeBuffersMESA",
+    "ResolveDepthValuesNV",
+    "ResumeTransformFeedback",
+    "ResumeTransformFeedbackNV",
+    "RotatexOES",
+    "SampleCoverage",
+    "SampleCoverageARB",
+    "SampleMapATI",
+    "SampleMaskEXT",
+    "SampleMaskIndexedNV",
+    "SampleMaskSGIS",
+    "SampleMaski",
+    "SamplePatternEXT",
+    "SamplePatternSGIS",
+    "SamplerParameterIiv",
+    "SamplerParameterIuiv",
+    "SamplerParameterf",
+    "SamplerParameterfv",
+    "SamplerParameteri",
+    "SamplerParameteriv",
+    "ScalexOES",
+    "Scissor",
+    "ScissorArrayv",
+    "ScissorExclusiveArrayvNV",
+    "ScissorExclusiveNV",
+    "ScissorIndexed",
+    "ScissorIndexedv",
+    "SecondaryColor3bEXT",
+    "SecondaryColor3bvEXT",
+    "SecondaryColor3dEXT",
+    "SecondaryColor3dvEXT",
+    "SecondaryColor3fEXT",
+    "SecondaryColor3fvEXT",
+    "SecondaryColor3hNV",
+    "SecondaryColor3hvNV",
+    "SecondaryColor3iEXT",
+    "SecondaryColor3ivEXT",
+    "SecondaryColor3sEXT",
+    "SecondaryColor3svEXT",
+    "SecondaryColor3ubEXT",
+    "SecondaryColor3ubvEXT",
+    "SecondaryColor3uiEXT",
+    "SecondaryColor3uivEXT",
+    "SecondaryColor3usEXT",
+    "SecondaryColor3usvEXT",
+    "SecondaryColorFormatNV",
+    "SecondaryColorPointerEXT",
+    "SecondaryColorPointerListIBM",
+    "SelectPerfMonitorCountersAMD",
+    "SemaphoreParameterivNV",
+    "SemaphoreParameterui64vEXT",
+    "SeparableFilter2DEXT",
+    "SetFenceAPPLE",
+    "SetFenceNV",
+    "SetFragmentShaderConstantATI",
+    "SetInvariantEXT",
+    "SetLocalConstantEXT",
+    "SetMultisamplefvAMD",
+    "ShaderBinary",
+    "ShaderOp1EXT",
+    "ShaderOp2EXT",
+    "ShaderOp3EXT",
+    "ShaderSource",
+    "ShaderSourceARB",
+    "ShaderStorageBlockBinding",
+    "ShadingRateImageBarrierNV",
+    "ShadingRateImagePaletteNV",
+    "ShadingRateSampleOrderCustomNV",
+    "ShadingRateSampleOrderNV",
+    "SharpenTexFuncSGIS",
+    "SignalSemaphoreEXT",
+    "SignalSemaphoreui64NVX",
+    "SignalVkFenceNV",
+    "SignalVkSemaphoreNV",
+    "SpecializeShader",
+    "SpecializeShaderARB",
+    "SpriteParameterfSGIX",
+    "SpriteParameterfvSGIX",
+    "SpriteParameteriSGIX",
+    "SpriteParameterivSGIX",
+    "StartInstrumentsSGIX",
+    "StateCaptureNV",
+    "StencilClearTagEXT",
+    "StencilFillPathInstancedNV",
+    "StencilFillPathNV",
+    "StencilFunc",
+    "StencilFuncSeparate",
+    "StencilFuncSeparateATI",
+    "StencilMask",
+    "StencilMaskSeparate",
+    "StencilOp",
+    "StencilOpSeparate",
+    "StencilOpSeparateATI",
+    "StencilOpValueAMD",
+    "StencilStrokePathInstancedNV",
+    "StencilStrokePathNV",
+    "StencilThenCoverFillPathInstancedNV",
+    "StencilThenCoverFillPathNV",
+    "StencilThenCoverStrokePathInstancedNV",
+    "StencilThenCoverStrokePathNV",
+    "StopInstrumentsSGIX",
+    "StringMarkerGREMEDY",
+    "SubpixelPrecisionBiasNV",
+    "SwizzleEXT",
+    "SyncTextureINTEL",
+    "TagSampleBufferSGIX",
+    "Tangent3bEXT",
+    "Tangent3bvEXT",
+    "Tangent3dEXT",
+    "Tangent3dvEXT",
+    "Tangent3fEXT",
+    "Tangent3fvEXT",
+    "Tangent3iEXT",
+    "Tangent3ivEXT",
+    "Tangent3sEXT",
+    "Tangent3svEXT",
+    "TangentPointerEXT",
+    "TbufferMask3DFX",
+    "TessellationFactorAMD",
+    "TessellationModeAMD",
+    "TestFenceAPPLE",
+    "TestFenceNV",
+    "TestObjectAPPLE",
+    "TexAttachMemoryNV",
+    "TexBuffer",
+    "TexBufferARB",
+    "TexBufferEXT",
+    "TexBufferRange",
+    "TexBumpParameterfvATI",
+    "TexBumpParameterivATI",
+    "TexCoord1bOES",
+    "TexCoord1bvOES",
+    "TexCoord1hNV",
+    "TexCoord1hvNV",
+    "TexCoord1xOES",
+    "TexCoord1xvOES",
+    "TexCoord2bOES",
+    "TexCoord2bvOES",
+    "TexCoord2fColor3fVertex3fSUN",
+    "TexCoord2fColor3fVertex3fvSUN",
+    "TexCoord2fColor4fNormal3fVertex3fSUN",
+    "TexCoord2fColor4fNormal3fVertex3fvSUN",
+    "TexCoord2fColor4ubVertex3fSUN",
+    "TexCoord2fColor4ubVertex3fvSUN",
+    "TexCoord2fNormal3fVertex3fSUN",
+    "TexCoord2fNormal3fVertex3fvSUN",
+    "TexCoord2fVertex3fSUN",
+    "TexCoord2fVertex3fvSUN",
+    "TexCoord2hNV",
+    "TexCoord2hvNV",
+    "TexCoord2xOES",
+    "TexCoord2xvOES",
+    "TexCoord3bOES",
+    "TexCoord3bvOES",
+    "TexCoord3hNV",
+    "TexCoord3hvNV",
+    "TexCoord3xOES",
+    "TexCoord3xvOES",
+    "TexCoord4bOES",
+    "TexCoord4bvOES",
+    "TexCoord4fColor4fNormal3fVertex4fSUN",
+    "TexCoord4fColor4fNormal3fVertex4fvSUN",
+    "TexCoord4fVertex4fSUN",
+    "TexCoord4fVertex4fvSUN",
+    "TexCoord4hNV",
+    "TexCoord4hvNV",
+    "TexCoord4xOES",
+    "TexCoord4xvOES",
+    "TexCoordFormatNV",
+    "TexCoordPointerEXT",
+    "TexCoordPointerListIBM",
+    "TexCoordPointervINTEL",
+    "TexEnvxOES",
+    "TexEnvxvOES",
+    "TexFilterFuncSGIS",
+    "TexGenxOES",
+    "TexGenxvOES",
+    "TexImage1D",
+    "TexImage2D",
+    "TexImage2DMultisample",
+    "TexImage2DMultisampleCoverageNV",
+    "TexImage3D",
+    "TexImage3DEXT",
+    "TexImage3DMultisample",
+    "TexImage3DMultisampleCoverageNV",
+    "TexImage4DSGIS",
+    "TexPageCommitmentARB",
+    "TexPageCommitmentMemNV",
+    "TexParameterIiv",
+    "TexParameterIivEXT",
+    "TexParameterIuiv",
+    "TexParameterIuivEXT",
+    "TexParameterf",
+    "TexParameterfv",
+    "TexParameteri",
+    "TexParameteriv",
+    "TexParameterxOES",
+    "TexParameterxvOES",
+    "TexRenderbufferNV",
+    "TexStorage1D",
+    "TexStorage1DEXT",
+    "TexStorage2D",
+    "TexStorage2DEXT",
+    "TexStorage2DMultisample",
+    "TexStorage3D",
+    "TexStorage3DEXT",
+    "TexStorage3DMultisample",
+    "TexStorageMem1DEXT",
+    "TexStorageMem2DEXT",
+    "TexStorageMem2DMultisampleEXT",
+    "TexStorageMem3DEXT",
+    "TexStorageMem3DMultisampleEXT",
+    "TexStorageSparseAMD",
+    "TexSubImage1D",
+    "TexSubImage1DEXT",
+    "TexSubImage2D",
+    "TexSubImage2DEXT",
+    "TexSubImage3D",
+    "TexSubImage3DEXT",
+    "TexSubImage4DSGIS",
+    "TextureAttachMemoryNV",
+    "TextureBarrier",
+    "TextureBarrierNV",
+    "TextureBuffer",
+    "TextureBufferEXT",
+    "TextureBufferRange",
+    "TextureBufferRangeEXT",
+    "TextureColorMaskSGIS",
+    "TextureImage1DEXT",
+    "TextureImage2DEXT",
+    "TextureImage2DMultisampleCoverageNV",
+    "TextureImage2DMultisampleNV",
+    "TextureImage3DEXT",
+    "TextureImage3DMultisampleCoverageNV",
+    "TextureImage3DMultisampleNV",
+    "TextureLightEXT",
+    "TextureMaterialEXT",
+    "TextureNormalEXT",
+    "TexturePageCommitmentEXT",
+    "TexturePageCommitmentMemNV",
+    "TextureParameterIiv",
+    "TextureParameterIivEXT",
+    "TextureParameterIuiv",
+    "TextureParameterIuivEXT",
+    "TextureParameterf",
+    "TextureParameterfEXT",
+    "TextureParameterfv",
+    "TextureParameterfvEXT",
+    "TextureParameteri",
+    "TextureParameteriEXT",
+    "TextureParameteriv",
+    "TextureParameterivEXT",
+    "TextureRangeAPPLE",
+    "TextureRenderbufferEXT",
+    "TextureStorage1D",
+    "TextureStorage1DEXT",
+    "TextureStorage2D",
+    "TextureStorage2DEXT",
+    "TextureStorage2DMultisample",
+    "TextureStorage2DMultisampleEXT",
+    "TextureStorage3D",
+    "TextureStorage3DEXT",
+    "TextureStorage3DMultisample",
+    "TextureStorage3DMultisampleEXT",
+    "TextureStorageMem1DEXT",
+    "TextureStorageMem2DEXT",
+    "TextureStorageMem2DMultisampleEXT",
+    "TextureStorageMem3DEXT",
+    "TextureStorageMem3DMultisampleEXT",
+    "TextureStorageSparseAMD",
+    "TextureSubImage1D",
+    "TextureSubImage1DEXT",
+    "TextureSubImage2D",
+    "TextureSubImage2DEXT",
+    "TextureSubImage3D",
+    "TextureSubImage3DEXT",
+    "TextureView",
+    "TrackMatrixNV",
+    "TransformFeedbackAttribsNV",
+    "TransformFeedbackBufferBase",
+    "TransformFeedbackBufferRange",
+    "TransformFeedbackStreamAttribsNV",
+    "TransformFeedbackVaryings",
+    "TransformFeedbackVaryingsEXT",
+    "TransformFeedbackVaryingsNV",
+    "TransformPathNV",
+    "TranslatexOES",
+    "Uniform1d",
+    "Uniform1dv",
+    "Uniform1f",
+    "Uniform1fARB",
+    "Uniform1fv",
+    "Uniform1fvARB",
+    "Uniform1i",
+    "Uniform1i64ARB",
+    "Uniform1i64NV",
+    "Uniform1i64vARB",
+    "Uniform1i64vNV",
+    "Uniform1iARB",
+    "Uniform1iv",
+    "Uniform1ivARB",
+    "Uniform1ui",
+    "Uniform1ui64ARB",
+    "Uniform1ui64NV",
+    "Uniform1ui64vARB",
+    "Uniform1ui64vNV",
+    "Uniform1uiEXT",
+    "Uniform1uiv",
+    "Uniform1uivEXT",
+    "Uniform2d",
+    "Uniform2dv",
+    "Uniform2f",
+    "Uniform2fARB",
+    "Uniform2fv",
+    "Uniform2fvARB",
+    "Uniform2i",
+    "Uniform2i64ARB",
+    "Uniform2i64NV",
+    "Uniform2i64vARB",
+    "Uniform2i64vNV",
+    "Uniform2iARB",
+    "Uniform2iv",
+    "Uniform2ivARB",
+    "Uniform2ui",
+    "Uniform2ui64ARB",
+    "Uniform2ui64NV",
+    "Uniform2ui64vARB",
+    "Uniform2ui64vNV",
+    "Uniform2uiEXT",
+    "Uniform2uiv",
+    "Uniform2uivEXT",
+    "Uniform3d",
+    "Uniform3dv",
+    "Uniform3f",
+    "Uniform3fARB",
+    "Uniform3fv",
+    "Uniform3fvARB",
+    "Uniform3i",
+    "Uniform3i64ARB",
+    "Uniform3i64NV",
+    "Uniform3i64vARB",
+    "Uniform3i64vNV",
+    "Uniform3iARB",
+    "Uniform3iv",
+    "Uniform3ivARB",
+    "Uniform3ui",
+    "Uniform3ui64ARB",
+    "Uniform3ui64NV",
+    "Uniform3ui64vARB",
+    "Uniform3ui64vNV",
+    "Uniform3uiEXT",
+    "Uniform3uiv",
+    "Uniform3uivEXT",
+    "Uniform4d",
+    "Uniform4dv",
+    "Uniform4f",
+    "Uniform4fARB",
+    "Uniform4fv",
+    "Uniform4fvARB",
+    "Uniform4i",
+    "Uniform4i64ARB",
+    "Uniform4i64NV",
+    "Uniform4i64vARB",
+    "Uniform4i64vNV",
+    "Uniform4iARB",
+    "Uniform4iv",
+    "Uniform4ivARB",
+    "Uniform4ui",
+    "Uniform4ui64ARB",
+    "Uniform4ui64NV",
+    "Uniform4ui64vARB",
+    "Uniform4ui64vNV",
+    "Uniform4uiEXT",
+    "Uniform4uiv",
+    "Uniform4uivEXT",
+    "UniformBlockBinding",
+    "UniformBufferEXT",
+    "UniformHandleui64ARB",
+    "UniformHandleui64NV",
+    "UniformHandleui64vARB",
+    "UniformHandleui64vNV",
+    "UniformMatrix2dv",
+    "UniformMatrix2fv",
+    "UniformMatrix2fvARB",
+    "UniformMatrix2x3dv",
+    "UniformMatrix2x3fv",
+    "UniformMatrix2x4dv",
+    "UniformMatrix2x4fv",
+    "UniformMatrix3dv",
+    "UniformMatrix3fv",
+    "UniformMatrix3fvARB",
+    "UniformMatrix3x2dv",
+    "UniformMatrix3x2fv",
+    "UniformMatrix3x4dv",
+    "UniformMatrix3x4fv",
+    "UniformMatrix4dv",
+    "UniformMatrix4fv",
+    "UniformMatrix4fvARB",
+    "UniformMatrix4x2dv",
+    "UniformMatrix4x2fv",
+    "UniformMatrix4x3dv",
+    "UniformMatrix4x3fv",
+    "UniformSubroutinesuiv",
+    "Uniformui64NV",
+    "Uniformui64vNV",
+    "UnlockArraysEXT",
+    "UnmapBuffer",
+    "UnmapBufferARB",
+    "UnmapNamedBuffer",
+    "UnmapNamedBufferEXT",
+    "UnmapObjectBufferATI",
+    "UnmapTexture2DINTEL",
+    "UpdateObjectBufferATI",
+    "UploadGpuMaskNVX",
+    "UseProgram",
+    "UseProgramObjectARB",
+    "UseProgramStages",
+    "UseShaderProgramEXT",
+    "VDPAUFiniNV",
+    "VDPAUGetSurfaceivNV",
+    "VDPAUInitNV",
+    "VDPAUIsSurfaceNV",
+    "VDPAUMapSurfacesNV",
+    "VDPAURegisterOutputSurfaceNV",
+    "VDPAURegisterVideoSurfaceNV",
+    "VDPAURegisterVideoSurfaceWithPictureStructureNV",
+    "VDPAUSurfaceAccessNV",
+    "VDPAUUnmapSurfacesNV",
+    "VDPAUUnregisterSurfaceNV",
+    "ValidateProgram",
+    "ValidateProgramARB",
+    "ValidateProgramPipeline",
+    "VariantArrayObjectATI",
+    "VariantPointerEXT",
+    "VariantbvEXT",
+    "VariantdvEXT",
+    "VariantfvEXT",
+    "VariantivEXT",
+    "VariantsvEXT",
+    "VariantubvEXT",
+    "VariantuivEXT",
+    "VariantusvEXT",
+    "Vertex2bOES",
+    "Vertex2bvOES",
+    "Vertex2hNV",
+    "Vertex2hvNV",
+    "Vertex2xOES",
+    "Vertex2xvOES",
+    "Vertex3bOES",
+    "Vertex3bvOES",
+    "Vertex3hNV",
+    "Vertex3hvNV",
+    "Vertex3xOES",
+    "Vertex3xvOES",
+    "Vertex4bOES",
+    "Vertex4bvOES",
+    "Vertex4hNV",
+    "Vertex4hvNV",
+    "Vertex4xOES",
+    "Vertex4xvOES",
+    "VertexArrayAttribBinding",
+    "VertexArrayAttribFormat",
+    "VertexArrayAttribIFormat",
+    "VertexArrayAttribLFormat",
+    "VertexArrayBindVertexBufferEXT",
+    "VertexArrayBindingDivisor",
+    "VertexArrayColorOffsetEXT",
+    "VertexArrayEdgeFlagOffsetEXT",
+    "VertexArrayElementBuffer",
+    "VertexArrayFogCoordOffsetEXT",
+    "VertexArrayIndexOffsetEXT",
+    "VertexArrayMultiTexCoordOffsetEXT",
+    "VertexArrayNormalOffsetEXT",
+    "VertexArrayParameteriAPPLE",
+    "VertexArrayRangeAPPLE",
+    "VertexArrayRangeNV",
+    "VertexArraySecondaryColorOffsetEXT",
+    "VertexArrayTexCoordOffsetEXT",
+    "VertexArrayVertexAttribBindingEXT",
+    "VertexArrayVertexAttribDivisorEXT",
+    "VertexArrayVertexAttribFormatEXT",
+    "VertexArrayVertexAttribIFormatEXT",
+    "VertexArrayVertexAttribIOffsetEXT",
+    "VertexArrayVertexAttribLFormatEXT",
+    "VertexArrayVertexAttribLOffsetEXT",
+    "VertexArrayVertexAttribOffsetEXT",
+    "VertexArrayVertexBindingDivisorEXT",
+    "VertexArrayVertexBuffer",
+    "VertexArrayVertexBuffers",
+    "VertexArrayVertexOffsetEXT",
+    "VertexAttrib1d",
+    "VertexAttrib1dARB",
+    "VertexAttrib1dNV",
+    "VertexAttrib1dv",
+    "VertexAttrib1dvARB",
+    "VertexAttrib1dvNV",
+    "VertexAttrib1f",
+    "VertexAttrib1fARB",
+    "VertexAttrib1fNV",
+    "VertexAttrib1fv",
+    "VertexAttrib1fvARB",
+    "VertexAttrib1fvNV",
+    "VertexAttrib1hNV",
+    "VertexAttrib1hvNV",
+    "VertexAttrib1s",
+    "VertexAttrib1sARB",
+    "VertexAttrib1sNV",
+    "VertexAttrib1sv",
+    "VertexAttrib1svARB",
+    "VertexAttrib1svNV",
+    "VertexAttrib2d",
+    "VertexAttrib2dARB",
+    "VertexAttrib2dNV",
+    "VertexAttrib2dv",
+    "VertexAttrib2dvARB",
+    "VertexAttrib2dvNV",
+    "VertexAttrib2f",
+    "VertexAttrib2fARB",
+    "VertexAttrib2fNV",
+    "VertexAttrib2fv",
+    "VertexAttrib2fvARB",
+    "VertexAttrib2fvNV",
+    "VertexAttrib2hNV",
+    "VertexAttrib2hvNV",
+    "VertexAttrib2s",
+    "VertexAttrib2sARB",
+    "VertexAttrib2sNV",
+    "VertexAttrib2sv",
+    "VertexAttrib2svARB",
+    "VertexAttrib2svNV",
+    "VertexAttrib3d",
+    "VertexAttrib3dARB",
+    "VertexAttrib3dNV",
+    "VertexAttrib3dv",
+    "VertexAttrib3dvARB",
+    "VertexAttrib3dvNV",
+    "VertexAttrib3f",
+    "VertexAttrib3fARB",
+    "VertexAttrib3fNV",
+    "VertexAttrib3fv",
+    "VertexAttrib3fvARB",
+    "VertexAttrib3fvNV",
+    "VertexAttrib3hNV",
+    "VertexAttrib3hvNV",
+    "VertexAttrib3s",
+    "VertexAttrib3sARB",
+    "VertexAttrib3sNV",
+    "VertexAttrib3sv",
+    "VertexAttrib3svARB",
+    "VertexAttrib3svNV",
+    "VertexAttrib4Nbv",
+    "VertexAttrib4NbvARB",
+    "VertexAttrib4Niv",
+    "VertexAttrib4NivARB",
+    "VertexAttrib4Nsv",
+    "VertexAttrib4NsvARB",
+    "VertexAttrib4Nub",
+    "VertexAttrib4NubARB",
+    "VertexAttrib4Nubv",
+    "VertexAttrib4NubvARB",
+    "VertexAttrib4Nuiv",
+    "VertexAttrib4NuivARB",
+    "VertexAttrib4Nusv",
+    "VertexAttrib4NusvARB",
+    "VertexAttrib4bv",
+    "VertexAttrib4bvARB",
+    "VertexAttrib4d",
+    "VertexAttrib4dARB",
+    "VertexAttrib4dNV",
+    "VertexAttrib4dv",
+    "VertexAttrib4dvARB",
+    "VertexAttrib4dvNV",
+    "VertexAttrib4f",
+    "VertexAttrib4fARB",
+    "VertexAttrib4fNV",
+    "VertexAttrib4fv",
+    "VertexAttrib4fvARB",
+    "VertexAttrib4fvNV",
+    "VertexAttrib4hNV",
+    "VertexAttrib4hvNV",
+    "VertexAttrib4iv",
+    "VertexAttrib4ivARB",
+    "VertexAttrib4s",
+    "VertexAttrib4sARB",
+    "VertexAttrib4sNV",
+    "VertexAttrib4sv",
+    "VertexAttrib4svARB",
+    "VertexAttrib4svNV",
+    "VertexAttrib4ubNV",
+    "VertexAttrib4ubv",
+    "VertexAttrib4ubvARB",
+    "VertexAttrib4ubvNV",
+    "VertexAttrib4uiv",
+    "VertexAttrib4uivARB",
+    "VertexAttrib4usv",
+    "VertexAttrib4usvARB",
+    "VertexAttribArrayObjectATI",
+    "VertexAttribBinding",
+    "VertexAttribDivisor",
+    "VertexAttribDivisorARB",
+    "VertexAttribFormat",
+    "VertexAttribFormatNV",
+    "VertexAttribI1i",
+    "VertexAttribI1iEXT",
+    "VertexAttribI1iv",
+    "VertexAttribI1ivEXT",
+    "VertexAttribI1ui",
+    "VertexAttribI1uiEXT",
+    "VertexAttribI1uiv",
+    "VertexAttribI1uivEXT",
+    "VertexAttribI2i",
+    "VertexAttribI2iEXT",
+    "VertexAttribI2iv",
+    "VertexAttribI2ivEXT",
+    "VertexAttribI2ui",
+    "VertexAttribI2uiEXT",
+    "VertexAttribI2uiv",
+    "VertexAttribI2uivEXT",
+    "VertexAttribI3i",
+    "VertexAttribI3iEXT",
+    "VertexAttribI3iv",
+    "VertexAttribI3ivEXT",
+    "VertexAttribI3ui",
+    "VertexAttribI3uiEXT",
+    "VertexAttribI3uiv",
+    "VertexAttribI3uivEXT",
+    "VertexAttribI4bv",
+    "VertexAttribI4bvEXT",
+    "VertexAttribI4i",
+    "VertexAttribI4iEXT",
+    "VertexAttribI4iv",
+    "VertexAttribI4ivEXT",
+    "VertexAttribI4sv",
+    "VertexAttribI4svEXT",
+    "VertexAttribI4ubv",
+    "VertexAttribI4ubvEXT",
+    "VertexAttribI4ui",
+    "VertexAttribI4uiEXT",
+    "VertexAttribI4uiv",
+    "VertexAttribI4uivEXT",
+    "VertexAttribI4usv",
+    "VertexAttribI4usvEXT",
+    "VertexAttribIFormat",
+    "VertexAttribIFormatNV",
+    "VertexAttribIPointer",
+    "VertexAttribIPointerEXT",
+    "VertexAttribL1d",
+    "VertexAttribL1dEXT",
+    "VertexAttribL1dv",
+    "VertexAttribL1dvEXT",
+    "VertexAttribL1i64NV",
+    "VertexAttribL1i64vNV",
+    "VertexAttribL1ui64ARB",
+    "VertexAttribL1ui64NV",
+    "VertexAttribL1ui64vARB",
+    "VertexAttribL1ui64vNV",
+    "VertexAttribL2d",
+    "VertexAttribL2dEXT",
+    "VertexAttribL2dv",
+    "VertexAttribL2dvEXT",
+    "VertexAttribL2i64NV",
+    "VertexAttribL2i64vNV",
+    "VertexAttribL2ui64NV",
+    "VertexAttribL2ui64vNV",
+    "VertexAttribL3d",
+    "VertexAttribL3dEXT",
+    "VertexAttribL3dv",
+    "VertexAttribL3dvEXT",
+    "VertexAttribL3i64NV",
+    "VertexAttribL3i64vNV",
+    "VertexAttribL3ui64NV",
+    "VertexAttribL3ui64vNV",
+    "VertexAttribL4d",
+    "VertexAttribL4dEXT",
+    "VertexAttribL4dv",
+    "VertexAttribL4dvEXT",
+    "VertexAttribL4i64NV",
+    "VertexAttribL4i64vNV",
+    "VertexAttribL4ui64NV",
+    "VertexAttribL4ui64vNV",
+    "VertexAttribLFormat",
+    "VertexAttribLFormatNV",
+    "VertexAttribLPointer",
+    "VertexAttribLPointerEXT",
+    "VertexAttribP1ui",
+    "VertexAttribP1uiv",
+    "VertexAttribP2ui",
+    "VertexAttribP2uiv",
+    "VertexAttribP3ui",
+    "VertexAttribP3uiv",
+    "VertexAttribP4ui",
+    "VertexAttribP4uiv",
+    "VertexAttribParameteriAMD",
+    "VertexAttribPointer",
+    "VertexAttribPointerARB",
+    "VertexAttribPointerNV",
+    "VertexAttribs1dvNV",
+    "VertexAttribs1fvNV",
+    "VertexAttribs1hvNV",
+    "VertexAttribs1svNV",
+    "VertexAttribs2dvNV",
+    "VertexAttribs2fvNV",
+    "VertexAttribs2hvNV",
+    "VertexAttribs2svNV",
+    "VertexAttribs3dvNV",
+    "VertexAttribs3fvNV",
+    "VertexAttribs3hvNV",
+    "VertexAttribs3svNV",
+    "VertexAttribs4dvNV",
+    "VertexAttribs4fvNV",
+    "VertexAttribs4hvNV",
+    "VertexAttribs4svNV",
+    "VertexAttribs4ubvNV",
+    "VertexBindingDivisor",
+    "VertexBlendARB",
+    "VertexBlendEnvfATI",
+    "VertexBlendEnviATI",
+    "VertexFormatNV",
+    "VertexPointerEXT",
+    "VertexPointerListIBM",
+    "VertexPointervINTEL",
+    "VertexStream1dATI",
+    "VertexStream1dvATI",
+    "VertexStream1fATI",
+    "VertexStream1fvATI",
+    "VertexStream1iATI",
+    "VertexStream1ivATI",
+    "VertexStream1sATI",
+    "VertexStream1svATI",
+    "VertexStream2dATI",
+    "VertexStream2dvATI",
+    "VertexStream2fATI",
+    "VertexStream2fvATI",
+    "VertexStream2iATI",
+    "VertexStream2ivATI",
+    "VertexStream2sATI",
+    "VertexStream2svATI",
+    "VertexStream3dATI",
+    "VertexStream3dvATI",
+    "VertexStream3fATI",
+    "VertexStream3fvATI",
+    "VertexStream3iATI",
+    "VertexStream3ivATI",
+    "VertexStream3sATI",
+    "VertexStream3svATI",
+    "VertexStream4dATI",
+    "VertexStream4dvATI",
+    "VertexStream4fATI",
+    "VertexStream4fvATI",
+    "VertexStream4iATI",
+    "VertexStream4ivATI",
+    "VertexStream4sATI",
+    "VertexStream4svATI",
+    "VertexWeightPointerEXT",
+    "VertexWeightfEXT",
+    "VertexWeightfvEXT",
+    "VertexWeighthNV",
+    "VertexWeighthvNV",
+    "VideoCaptureNV",
+    "VideoCaptureStreamParameterdvNV",
+    "VideoCaptureStreamParameterfvNV",
+    "VideoCaptureStreamParameterivNV",
+    "Viewport",
+    "ViewportArrayv",
+    "ViewportIndexedf",
+    "ViewportIndexedfv",
+    "ViewportPositionWScaleNV",
+    "ViewportSwizzleNV",
+    "WaitSemaphoreEXT",
+    "WaitSemaphoreui64NVX",
+    "WaitSync",
+    "WaitVkSemaphoreNV",
+    "WeightPathsNV",
+    "WeightPointerARB",
+    "WeightbvARB",
+    "WeightdvARB",
+    "WeightfvARB",
+    "WeightivARB",
+    "WeightsvARB",
+    "WeightubvARB",
+    "WeightuivARB",
+    "WeightusvARB",
+    "WindowPos2dARB",
+    "WindowPos2dMESA",
+    "WindowPos2dvARB",
+    "WindowPos2dvMESA",
+    "WindowPos2fARB",
+    "WindowPos2fMESA",
+    "WindowPos2fvARB",
+    "WindowPos2fvMESA",
+    "WindowPos2iARB",
+    "WindowPos2iMESA",
+    "WindowPos2ivARB",
+    "WindowPos2ivMESA",
+    "WindowPos2sARB",
+    "WindowPos2sMESA",
+    "WindowPos2svARB",
+    "WindowPos2svMESA",
+    "WindowPos3dARB",
+    "WindowPos3dMESA",
+    "WindowPos3dvARB",
+    "WindowPos3dvMESA",
+    "WindowPos3fARB",
+    "WindowPos3fMESA",
+    "WindowPos3fvARB",
+    "WindowPos3fvMESA",
+    "WindowPos3iARB",
+    "WindowPos3iMESA",
+    "WindowPos3ivARB",
+    "WindowPos3ivMESA",
+    "WindowPos3sARB",
+    "WindowPos3sMESA",
+    "WindowPos3svARB",
+    "WindowPos3svMESA",
+    "WindowPos4dMESA",
+    "WindowPos4dvMESA",
+    "WindowPos4fMESA",
+    "WindowPos4fvMESA",
+    "WindowPos4iMESA",
+    "WindowPos4ivMESA",
+    "WindowPos4sMESA",
+    "WindowPos4svMESA",
+    "WindowRectanglesEXT",
+    "WriteMaskEXT",
+];
+
+impl Gl {
+    // whether the driver returned `name`, with or without the gl prefix
+    // false for names these bindings don't have
+    pub fn is_loaded(&self, name: &str) -> bool {
+        let name = name.strip_prefix("gl").unwrap_or(name);
+        self.fn_ptr(name).map_or(false, |ptr| ptr.is_loaded)
+    }
+
+    pub fn load_report(&self) -> LoadReport {
+        let missing = |names: &'static [&'static str]| {
+            names
+                .iter()
+                .copied()
+                .filter(|name| !self.is_loaded(name))
+                .collect::<Vec<_>>()
+        };
+        let missing_core = missing(CORE_FUNCTIONS);
+        let missing_extensions = missing(FUNCTIONS)
+            .into_iter()
+            .filter(|name| CORE_FUNCTIONS.binary_search(name).is_err())
+            .collect::<Vec<_>>();
+        LoadReport {
+            version: CORE_VERSION,
+            loaded: FUNCTIONS.len() - missing_core.len() - missing_extensions.len(),
+            missing_core,
+            missing_extensions,
+        }
+    }
+
+    fn fn_ptr(&self, name: &str) -> Option<&FnPtr> {
+        Some(match name {
+            "AccumxOES" => &self.AccumxOES,
+            "AcquireKeyedMutexWin32EXT" => &self.AcquireKeyedMutexWin32EXT,
+            "ActiveProgramEXT" => &self.ActiveProgramEXT,
+            "ActiveShaderProgram" => &self.ActiveShaderProgram,
+            "ActiveStencilFaceEXT" => &self.ActiveStencilFaceEXT,
+            "ActiveTexture" => &self.ActiveTexture,
+            "ActiveTextureARB" => &self.ActiveTextureARB,
+            "ActiveVaryingNV" => &self.ActiveVaryingNV,
+            "AlphaFragmentOp1ATI" => &self.AlphaFragmentOp1ATI,
+            "AlphaFragmentOp2ATI" => &self.AlphaFragmentOp2ATI,
+            "AlphaFragmentOp3ATI" => &self.AlphaFragmentOp3ATI,
+            "AlphaFuncxOES" => &self.AlphaFuncxOES,
+            "AlphaToCoverageDitherControlNV" => &self.AlphaToCoverageDitherControlNV,
+            "ApplyFramebufferAttachmentCMAAINTEL" => &self.ApplyFramebufferAttachmentCMAAINTEL,
+            "ApplyTextureEXT" => &self.ApplyTextureEXT,
+            "AreProgramsResidentNV" => &self.AreProgramsResidentNV,
+            "AreTexturesResidentEXT" => &self.AreTexturesResidentEXT,
+            "ArrayElementEXT" => &self.ArrayElementEXT,
+            "ArrayObjectATI" => &self.ArrayObjectATI,
+            "AsyncCopyBufferSubDataNVX" => &self.AsyncCopyBufferSubDataNVX,
+            "AsyncCopyImageSubDataNVX" => &self.AsyncCopyImageSubDataNVX,
+            "AsyncMarkerSGIX" => &self.AsyncMarkerSGIX,
+            "AttachObjectARB" => &self.AttachObjectARB,
+            "AttachShader" => &self.AttachShader,
+            "BeginConditionalRender" => &self.BeginConditionalRender,
+            "BeginConditionalRenderNV" => &self.BeginConditionalRenderNV,
+            "BeginConditionalRenderNVX" => &self.BeginConditionalRenderNVX,
+            "BeginFragmentShaderATI" => &self.BeginFragmentShaderATI,
+            "BeginOcclusionQueryNV" => &self.BeginOcclusionQueryNV,
+            "BeginPerfMonitorAMD" => &self.BeginPerfMonitorAMD,
+            "BeginPerfQueryINTEL" => &self.BeginPerfQueryINTEL,
+            "BeginQuery" => &self.BeginQuery,
+            "BeginQueryARB" => &self.BeginQueryARB,
+            "BeginQueryIndexed" => &self.BeginQueryIndexed,
+            "BeginTransformFeedback" => &self.BeginTransformFeedback,
+            "BeginTransformFeedbackEXT" => &self.BeginTransformFeedbackEXT,
+            "BeginTransformFeedbackNV" => &self.BeginTransformFeedbackNV,
+            "BeginVertexShaderEXT" => &self.BeginVertexShaderEXT,
+            "BeginVideoCaptureNV" => &self.BeginVideoCaptureNV,
+            "BindAttribLocation" => &self.BindAttribLocation,
+            "BindAttribLocationARB" => &self.BindAttribLocationARB,
+            "BindBuffer" => &self.BindBuffer,
+            "BindBufferARB" => &self.BindBufferARB,
+            "BindBufferBase" => &self.BindBufferBase,
+            "BindBufferBaseEXT" => &self.BindBufferBaseEXT,
+            "BindBufferBaseNV" => &self.BindBufferBaseNV,
+            "BindBufferOffsetEXT" => &self.BindBufferOffsetEXT,
+            "BindBufferOffsetNV" => &self.BindBufferOffsetNV,
+            "BindBufferRange" => &self.BindBufferRange,
+            "BindBufferRangeEXT" => &self.BindBufferRangeEXT,
+            "BindBufferRangeNV" => &self.BindBufferRangeNV,
+            "BindBuffersBase" => &self.BindBuffersBase,
+            "BindBuffersRange" => &self.BindBuffersRange,
+            "BindFragDataLocation" => &self.BindFragDataLocation,
+            "BindFragDataLocationEXT" => &self.BindFragDataLocationEXT,
+            "BindFragDataLocationIndexed" => &self.BindFragDataLocationIndexed,
+            "BindFragmentShaderATI" => &self.BindFragmentShaderATI,
+            "BindFramebuffer" => &self.BindFramebuffer,
+            "BindFramebufferEXT" => &self.BindFramebufferEXT,
+            "BindImageTexture" => &self.BindImageTexture,
+            "BindImageTextureEXT" => &self.BindImageTextureEXT,
+            "BindImageTextures" => &self.BindImageTextures,
+            "BindLightParameterEXT" => &self.BindLightParameterEXT,
+            "BindMaterialParameterEXT" => &self.BindMaterialParameterEXT,
+            "BindMultiTextureEXT" => &self.BindMultiTextureEXT,
+            "BindParameterEXT" => &self.BindParameterEXT,
+            "BindProgramARB" => &self.BindProgramARB,
+            "BindProgramNV" => &self.BindProgramNV,
+            "BindProgramPipeline" => &self.BindProgramPipeline,
+            "BindRenderbuffer" => &self.BindRenderbuffer,
+            "BindRenderbufferEXT" => &self.BindRenderbufferEXT,
+            "BindSampler" => &self.BindSampler,
+            "BindSamplers" => &self.BindSamplers,
+            "BindShadingRateImageNV" => &self.BindShadingRateImageNV,
+            "BindTexGenParameterEXT" => &self.BindTexGenParameterEXT,
+            "BindTexture" => &self.BindTexture,
+            "BindTextureEXT" => &self.BindTextureEXT,
+            "BindTextureUnit" => &self.BindTextureUnit,
+            "BindTextureUnitParameterEXT" => &self.BindTextureUnitParameterEXT,
+            "BindTextures" => &self.BindTextures,
+            "BindTransformFeedback" => &self.BindTransformFeedback,
+            "BindTransformFeedbackNV" => &self.BindTransformFeedbackNV,
+            "BindVertexArray" => &self.BindVertexArray,
+            "BindVertexArrayAPPLE" => &self.BindVertexArrayAPPLE,
+            "BindVertexBuffer" => &self.BindVertexBuffer,
+            "BindVertexBuffers" => &self.BindVertexBuffers,
+            "BindVertexShaderEXT" => &self.BindVertexShaderEXT,
+            "BindVideoCaptureStreamBufferNV" => &self.BindVideoCaptureStreamBufferNV,
+            "BindVideoCaptureStreamTextureNV" => &self.BindVideoCaptureStreamTextureNV,
+            "Binormal3bEXT" => &self.Binormal3bEXT,
+            "Binormal3bvEXT" => &self.Binormal3bvEXT,
+            "Binormal3dEXT" => &self.Binormal3dEXT,
+            "Binormal3dvEXT" => &self.Binormal3dvEXT,
+            "Binormal3fEXT" => &self.Binormal3fEXT,
+            "Binormal3fvEXT" => &self.Binormal3fvEXT,
+            "Binormal3iEXT" => &self.Binormal3iEXT,
+            "Binormal3ivEXT" => &self.Binormal3ivEXT,
+            "Binormal3sEXT" => &self.Binormal3sEXT,
+            "Binormal3svEXT" => &self.Binormal3svEXT,
+            "BinormalPointerEXT" => &self.BinormalPointerEXT,
+            "BitmapxOES" => &self.BitmapxOES,
+            "BlendBarrierKHR" => &self.BlendBarrierKHR,
+            "BlendBarrierNV" => &self.BlendBarrierNV,
+            "BlendColor" => &self.BlendColor,
+            "BlendColorEXT" => &self.BlendColorEXT,
+            "BlendColorxOES" => &self.BlendColorxOES,
+            "BlendEquation" => &self.BlendEquation,
+            "BlendEquationEXT" => &self.BlendEquationEXT,
+            "BlendEquationIndexedAMD" => &self.BlendEquationIndexedAMD,
+            "BlendEquationSeparate" => &self.BlendEquationSeparate,
+            "BlendEquationSeparateEXT" => &self.BlendEquationSeparateEXT,
+            "BlendEquationSeparateIndexedAMD" => &self.BlendEquationSeparateIndexedAMD,
+            "BlendEquationSeparatei" => &self.BlendEquationSeparatei,
+            "BlendEquationSeparateiARB" => &self.BlendEquationSeparateiARB,
+            "BlendEquationi" => &self.BlendEquationi,
+            "BlendEquationiARB" => &self.BlendEquationiARB,
+            "BlendFunc" => &self.BlendFunc,
+            "BlendFuncIndexedAMD" => &self.BlendFuncIndexedAMD,
+            "BlendFuncSeparate" => &self.BlendFuncSeparate,
+            "BlendFuncSeparateEXT" => &self.BlendFuncSeparateEXT,
+            "BlendFuncSeparateINGR" => &self.BlendFuncSeparateINGR,
+            "BlendFuncSeparateIndexedAMD" => &self.BlendFuncSeparateIndexedAMD,
+            "BlendFuncSeparatei" => &self.BlendFuncSeparatei,
+            "BlendFuncSeparateiARB" => &self.BlendFuncSeparateiARB,
+            "BlendFunci" => &self.BlendFunci,
+            "BlendFunciARB" => &self.BlendFunciARB,
+            "BlendParameteriNV" => &self.BlendParameteriNV,
+            "BlitFramebuffer" => &self.BlitFramebuffer,
+            "BlitFramebufferEXT" => &self.BlitFramebufferEXT,
+            "BlitFramebufferLayerEXT" => &self.BlitFramebufferLayerEXT,
+            "BlitFramebufferLayersEXT" => &self.BlitFramebufferLayersEXT,
+            "BlitNamedFramebuffer" => &self.BlitNamedFramebuffer,
+            "BufferAddressRangeNV" => &self.BufferAddressRangeNV,
+            "BufferAttachMemoryNV" => &self.BufferAttachMemoryNV,
+            "BufferData" => &self.BufferData,
+            "BufferDataARB" => &self.BufferDataARB,
+            "BufferPageCommitmentARB" => &self.BufferPageCommitmentARB,
+            "BufferPageCommitmentMemNV" => &self.BufferPageCommitmentMemNV,
+            "BufferParameteriAPPLE" => &self.BufferParameteriAPPLE,
+            "BufferStorage" => &self.BufferStorage,
+            "BufferStorageExternalEXT" => &self.BufferStorageExternalEXT,
+            "BufferStorageMemEXT" => &self.BufferStorageMemEXT,
+            "BufferSubData" => &self.BufferSubData,
+            "BufferSubDataARB" => &self.BufferSubDataARB,
+            "CallCommandListNV" => &self.CallCommandListNV,
+            "CheckFramebufferStatus" => &self.CheckFramebufferStatus,
+            "CheckFramebufferStatusEXT" => &self.CheckFramebufferStatusEXT,
+            "CheckNamedFramebufferStatus" => &self.CheckNamedFramebufferStatus,
+            "CheckNamedFramebufferStatusEXT" => &self.CheckNamedFramebufferStatusEXT,
+            "ClampColor" => &self.ClampColor,
+            "ClampColorARB" => &self.ClampColorARB,
+            "Clear" => &self.Clear,
+            "ClearAccumxOES" => &self.ClearAccumxOES,
+            "ClearBufferData" => &self.ClearBufferData,
+            "ClearBufferSubData" => &self.ClearBufferSubData,
+            "ClearBufferfi" => &self.ClearBufferfi,
+            "ClearBufferfv" => &self.ClearBufferfv,
+            "ClearBufferiv" => &self.ClearBufferiv,
+            "ClearBufferuiv" => &self.ClearBufferuiv,
+            "ClearColor" => &self.ClearColor,
+            "ClearColorIiEXT" => &self.ClearColorIiEXT,
+            "ClearColorIuiEXT" => &self.ClearColorIuiEXT,
+            "ClearColorxOES" => &self.ClearColorxOES,
+            "ClearDepth" => &self.ClearDepth,
+            "ClearDepthdNV" => &self.ClearDepthdNV,
+            "ClearDepthf" => &self.ClearDepthf,
+            "ClearDepthfOES" => &self.ClearDepthfOES,
+            "ClearDepthxOES" => &self.ClearDepthxOES,
+            "ClearNamedBufferData" => &self.ClearNamedBufferData,
+            "ClearNamedBufferDataEXT" => &self.ClearNamedBufferDataEXT,
+            "ClearNamedBufferSubData" => &self.ClearNamedBufferSubData,
+            "ClearNamedBufferSubDataEXT" => &self.ClearNamedBufferSubDataEXT,
+            "ClearNamedFramebufferfi" => &self.ClearNamedFramebufferfi,
+            "ClearNamedFramebufferfv" => &self.ClearNamedFramebufferfv,
+            "ClearNamedFramebufferiv" => &self.ClearNamedFramebufferiv,
+            "ClearNamedFramebufferuiv" => &self.ClearNamedFramebufferuiv,
+            "ClearStencil" => &self.ClearStencil,
+            "ClearTexImage" => &self.ClearTexImage,
+            "ClearTexSubImage" => &self.ClearTexSubImage,
+            "ClientActiveTextureARB" => &self.ClientActiveTextureARB,
+            "ClientActiveVertexStreamATI" => &self.ClientActiveVertexStreamATI,
+            "ClientAttribDefaultEXT" => &self.ClientAttribDefaultEXT,
+            "ClientWaitSemaphoreui64NVX" => &self.ClientWaitSemaphoreui64NVX,
+            "ClientWaitSync" => &self.ClientWaitSync,
+            "ClipControl" => &self.ClipControl,
+            "ClipPlanefOES" => &self.ClipPlanefOES,
+            "ClipPlanexOES" => &self.ClipPlanexOES,
+            "Color3fVertex3fSUN" => &self.Color3fVertex3fSUN,
+            "Color3fVertex3fvSUN" => &self.Color3fVertex3fvSUN,
+            "Color3hNV" => &self.Color3hNV,
+            "Color3hvNV" => &self.Color3hvNV,
+            "Color3xOES" => &self.Color3xOES,
+            "Color3xvOES" => &self.Color3xvOES,
+            "Color4fNormal3fVertex3fSUN" => &self.Color4fNormal3fVertex3fSUN,
+            "Color4fNormal3fVertex3fvSUN" => &self.Color4fNormal3fVertex3fvSUN,
+            "Color4hNV" => &self.Color4hNV,
+            "Color4hvNV" => &self.Color4hvNV,
+            "Color4ubVertex2fSUN" => &self.Color4ubVertex2fSUN,
+            "Color4ubVertex2fvSUN" => &self.Color4ubVertex2fvSUN,
+            "Color4ubVertex3fSUN" => &self.Color4ubVertex3fSUN,
+            "Color4ubVertex3fvSUN" => &self.Color4ubVertex3fvSUN,
+            "Color4xOES" => &self.Color4xOES,
+            "Color4xvOES" => &self.Color4xvOES,
+            "ColorFormatNV" => &self.ColorFormatNV,
+            "ColorFragmentOp1ATI" => &self.ColorFragmentOp1ATI,
+            "ColorFragmentOp2ATI" => &self.ColorFragmentOp2ATI,
+            "ColorFragmentOp3ATI" => &self.ColorFragmentOp3ATI,
+            "ColorMask" => &self.ColorMask,
+            "ColorMaskIndexedEXT" => &self.ColorMaskIndexedEXT,
+            "ColorMaski" => &self.ColorMaski,
+            "ColorPointerEXT" => &self.ColorPointerEXT,
+            "ColorPointerListIBM" => &self.ColorPointerListIBM,
+            "ColorPointervINTEL" => &self.ColorPointervINTEL,
+            "ColorSubTableEXT" => &self.ColorSubTableEXT,
+            "ColorTableEXT" => &self.ColorTableEXT,
+            "ColorTableParameterfvSGI" => &self.ColorTableParameterfvSGI,
+            "ColorTableParameterivSGI" => &self.ColorTableParameterivSGI,
+            "ColorTableSGI" => &self.ColorTableSGI,
+            "CombinerInputNV" => &self.CombinerInputNV,
+            "CombinerOutputNV" => &self.CombinerOutputNV,
+            "CombinerParameterfNV" => &self.CombinerParameterfNV,
+            "CombinerParameterfvNV" => &self.CombinerParameterfvNV,
+            "CombinerParameteriNV" => &self.CombinerParameteriNV,
+            "CombinerParameterivNV" => &self.CombinerParameterivNV,
+            "CombinerStageParameterfvNV" => &self.CombinerStageParameterfvNV,
+            "CommandListSegmentsNV" => &self.CommandListSegmentsNV,
+            "CompileCommandListNV" => &self.CompileCommandListNV,
+            "CompileShader" => &self.CompileShader,
+            "CompileShaderARB" => &self.CompileShaderARB,
+            "CompileShaderIncludeARB" => &self.CompileShaderIncludeARB,
+            "CompressedMultiTexImage1DEXT" => &self.CompressedMultiTexImage1DEXT,
+            "CompressedMultiTexImage2DEXT" => &self.CompressedMultiTexImage2DEXT,
+            "CompressedMultiTexImage3DEXT" => &self.CompressedMultiTexImage3DEXT,
+            "CompressedMultiTexSubImage1DEXT" => &self.CompressedMultiTexSubImage1DEXT,
+            "CompressedMultiTexSubImage2DEXT" => &self.CompressedMultiTexSubImage2DEXT,
+            "CompressedMultiTexSubImage3DEXT" => &self.CompressedMultiTexSubImage3DEXT,
+            "CompressedTexImage1D" => &self.CompressedTexImage1D,
+            "CompressedTexImage1DARB" => &self.CompressedTexImage1DARB,
+            "CompressedTexImage2D" => &self.CompressedTexImage2D,
+            "CompressedTexImage2DARB" => &self.CompressedTexImage2DARB,
+            "CompressedTexImage3D" => &self.CompressedTexImage3D,
+            "CompressedTexImage3DARB" => &self.CompressedTexImage3DARB,
+            "CompressedTexSubImage1D" => &self.CompressedTexSubImage1D,
+            "CompressedTexSubImage1DARB" => &self.CompressedTexSubImage1DARB,
+            "CompressedTexSubImage2D" => &self.CompressedTexSubImage2D,
+            "CompressedTexSubImage2DARB" => &self.CompressedTexSubImage2DARB,
+            "CompressedTexSubImage3D" => &self.CompressedTexSubImage3D,
+            "CompressedTexSubImage3DARB" => &self.CompressedTexSubImage3DARB,
+            "CompressedTextureImage1DEXT" => &self.CompressedTextureImage1DEXT,
+            "CompressedTextureImage2DEXT" => &self.CompressedTextureImage2DEXT,
+            "CompressedTextureImage3DEXT" => &self.CompressedTextureImage3DEXT,
+            "CompressedTextureSubImage1D" => &self.CompressedTextureSubImage1D,
+            "CompressedTextureSubImage1DEXT" => &self.CompressedTextureSubImage1DEXT,
+            "CompressedTextureSubImage2D" => &self.CompressedTextureSubImage2D,
+            "CompressedTextureSubImage2DEXT" => &self.CompressedTextureSubImage2DEXT,
+            "CompressedTextureSubImage3D" => &self.CompressedTextureSubImage3D,
+            "CompressedTextureSubImage3DEXT" => &self.CompressedTextureSubImage3DEXT,
+            "ConservativeRasterParameterfNV" => &self.ConservativeRasterParameterfNV,
+            "ConservativeRasterParameteriNV" => &self.ConservativeRasterParameteriNV,
+            "ConvolutionFilter1DEXT" => &self.ConvolutionFilter1DEXT,
+            "ConvolutionFilter2DEXT" => &self.ConvolutionFilter2DEXT,
+            "ConvolutionParameterfEXT" => &self.ConvolutionParameterfEXT,
+            "ConvolutionParameterfvEXT" => &self.ConvolutionParameterfvEXT,
+            "ConvolutionParameteriEXT" => &self.ConvolutionParameteriEXT,
+            "ConvolutionParameterivEXT" => &self.ConvolutionParameterivEXT,
+            "ConvolutionParameterxOES" => &self.ConvolutionParameterxOES,
+            "ConvolutionParameterxvOES" => &self.ConvolutionParameterxvOES,
+            "CopyBufferSubData" => &self.CopyBufferSubData,
+            "CopyColorSubTableEXT" => &self.CopyColorSubTableEXT,
+            "CopyColorTableSGI" => &self.CopyColorTableSGI,
+            "CopyConvolutionFilter1DEXT" => &self.CopyConvolutionFilter1DEXT,
+            "CopyConvolutionFilter2DEXT" => &self.CopyConvolutionFilter2DEXT,
+            "CopyImageSubData" => &self.CopyImageSubData,
+            "CopyImageSubDataNV" => &self.CopyImageSubDataNV,
+            "CopyMultiTexImage1DEXT" => &self.CopyMultiTexImage1DEXT,
+            "CopyMultiTexImage2DEXT" => &self.CopyMultiTexImage2DEXT,
+            "CopyMultiTexSubImage1DEXT" => &self.CopyMultiTexSubImage1DEXT,
+            "CopyMultiTexSubImage2DEXT" => &self.CopyMultiTexSubImage2DEXT,
+            "CopyMultiTexSubImage3DEXT" => &self.CopyMultiTexSubImage3DEXT,
+            "CopyNamedBufferSubData" => &self.CopyNamedBufferSubData,
+            "CopyPathNV" => &self.CopyPathNV,
+            "CopyTexImage1D" => &self.CopyTexImage1D,
+            "CopyTexImage1DEXT" => &self.CopyTexImage1DEXT,
+            "CopyTexImage2D" => &self.CopyTexImage2D,
+            "CopyTexImage2DEXT" => &self.CopyTexImage2DEXT,
+            "CopyTexSubImage1D" => &self.CopyTexSubImage1D,
+            "CopyTexSubImage1DEXT" => &self.CopyTexSubImage1DEXT,
+            "CopyTexSubImage2D" => &self.CopyTexSubImage2D,
+            "CopyTexSubImage2DEXT" => &self.CopyTexSubImage2DEXT,
+            "CopyTexSubImage3D" => &self.CopyTexSubImage3D,
+            "CopyTexSubImage3DEXT" => &self.CopyTexSubImage3DEXT,
+            "CopyTextureImage1DEXT" => &self.CopyTextureImage1DEXT,
+            "CopyTextureImage2DEXT" => &self.CopyTextureImage2DEXT,
+            "CopyTextureSubImage1D" => &self.CopyTextureSubImage1D,
+            "CopyTextureSubImage1DEXT" => &self.CopyTextureSubImage1DEXT,
+            "CopyTextureSubImage2D" => &self.CopyTextureSubImage2D,
+            "CopyTextureSubImage2DEXT" => &self.CopyTextureSubImage2DEXT,
+            "CopyTextureSubImage3D" => &self.CopyTextureSubImage3D,
+            "CopyTextureSubImage3DEXT" => &self.CopyTextureSubImage3DEXT,
+            "CoverFillPathInstancedNV" => &self.CoverFillPathInstancedNV,
+            "CoverFillPathNV" => &self.CoverFillPathNV,
+            "CoverStrokePathInstancedNV" => &self.CoverStrokePathInstancedNV,
+            "CoverStrokePathNV" => &self.CoverStrokePathNV,
+            "CoverageModulationNV" => &self.CoverageModulationNV,
+            "CoverageModulationTableNV" => &self.CoverageModulationTableNV,
+            "CreateBuffers" => &self.CreateBuffers,
+            "CreateCommandListsNV" => &self.CreateCommandListsNV,
+            "CreateFramebuffers" => &self.CreateFramebuffers,
+            "CreateMemoryObjectsEXT" => &self.CreateMemoryObjectsEXT,
+            "CreatePerfQueryINTEL" => &self.CreatePerfQueryINTEL,
+            "CreateProgram" => &self.CreateProgram,
+            "CreateProgramObjectARB" => &self.CreateProgramObjectARB,
+            "CreateProgramPipelines" => &self.CreateProgramPipelines,
+            "CreateProgressFenceNVX" => &self.CreateProgressFenceNVX,
+            "CreateQueries" => &self.CreateQueries,
+            "CreateRenderbuffers" => &self.CreateRenderbuffers,
+            "CreateSamplers" => &self.CreateSamplers,
+            "CreateSemaphoresNV" => &self.CreateSemaphoresNV,
+            "CreateShader" => &self.CreateShader,
+            "CreateShaderObjectARB" => &self.CreateShaderObjectARB,
+            "CreateShaderProgramEXT" => &self.CreateShaderProgramEXT,
+            "CreateShaderProgramv" => &self.CreateShaderProgramv,
+            "CreateStatesNV" => &self.CreateStatesNV,
+            "CreateSyncFromCLeventARB" => &self.CreateSyncFromCLeventARB,
+            "CreateTextures" => &self.CreateTextures,
+            "CreateTransformFeedbacks" => &self.CreateTransformFeedbacks,
+            "CreateVertexArrays" => &self.CreateVertexArrays,
+            "CullFace" => &self.CullFace,
+            "CullParameterdvEXT" => &self.CullParameterdvEXT,
+            "CullParameterfvEXT" => &self.CullParameterfvEXT,
+            "CurrentPaletteMatrixARB" => &self.CurrentPaletteMatrixARB,
+            "DebugMessageCallback" => &self.DebugMessageCallback,
+            "DebugMessageCallbackAMD" => &self.DebugMessageCallbackAMD,
+            "DebugMessageCallbackARB" => &self.DebugMessageCallbackARB,
+            "DebugMessageControl" => &self.DebugMessageControl,
+            "DebugMessageControlARB" => &self.DebugMessageControlARB,
+            "DebugMessageEnableAMD" => &self.DebugMessageEnableAMD,
+            "DebugMessageInsert" => &self.DebugMessageInsert,
+            "DebugMessageInsertAMD" => &self.DebugMessageInsertAMD,
+            "DebugMessageInsertARB" => &self.DebugMessageInsertARB,
+            "DeformSGIX" => &self.DeformSGIX,
+            "DeformationMap3dSGIX" => &self.DeformationMap3dSGIX,
+            "DeformationMap3fSGIX" => &self.DeformationMap3fSGIX,
+            "DeleteAsyncMarkersSGIX" => &self.DeleteAsyncMarkersSGIX,
+            "DeleteBuffers" => &self.DeleteBuffers,
+            "DeleteBuffersARB" => &self.DeleteBuffersARB,
+            "DeleteCommandListsNV" => &self.DeleteCommandListsNV,
+            "DeleteFencesAPPLE" => &self.DeleteFencesAPPLE,
+            "DeleteFencesNV" => &self.DeleteFencesNV,
+            "DeleteFragmentShaderATI" => &self.DeleteFragmentShaderATI,
+            "DeleteFramebuffers" => &self.DeleteFramebuffers,
+            "DeleteFramebuffersEXT" => &self.DeleteFramebuffersEXT,
+            "DeleteMemoryObjectsEXT" => &self.DeleteMemoryObjectsEXT,
+            "DeleteNamedStringARB" => &self.DeleteNamedStringARB,
+            "DeleteNamesAMD" => &self.DeleteNamesAMD,
+            "DeleteObjectARB" => &self.DeleteObjectARB,
+            "DeleteOcclusionQueriesNV" => &self.DeleteOcclusionQueriesNV,
+            "DeletePathsNV" => &self.DeletePathsNV,
+            "DeletePerfMonitorsAMD" => &self.DeletePerfMonitorsAMD,
+            "DeletePerfQueryINTEL" => &self.DeletePerfQueryINTEL,
+            "DeleteProgram" => &self.DeleteProgram,
+            "DeleteProgramPipelines" => &self.DeleteProgramPipelines,
+            "DeleteProgramsARB" => &self.DeleteProgramsARB,
+            "DeleteProgramsNV" => &self.DeleteProgramsNV,
+            "DeleteQueries" => &self.DeleteQueries,
+            "DeleteQueriesARB" => &self.DeleteQueriesARB,
+            "DeleteQueryResourceTagNV" => &self.DeleteQueryResourceTagNV,
+            "DeleteRenderbuffers" => &self.DeleteRenderbuffers,
+            "DeleteRenderbuffersEXT" => &self.DeleteRenderbuffersEXT,
+            "DeleteSamplers" => &self.DeleteSamplers,
+            "DeleteSemaphoresEXT" => &self.DeleteSemaphoresEXT,
+            "DeleteShader" => &self.DeleteShader,
+            "DeleteStatesNV" => &self.DeleteStatesNV,
+            "DeleteSync" => &self.DeleteSync,
+            "DeleteTextures" => &self.DeleteTextures,
+            "DeleteTexturesEXT" => &self.DeleteTexturesEXT,
+            "DeleteTransformFeedbacks" => &self.DeleteTransformFeedbacks,
+            "DeleteTransformFeedbacksNV" => &self.DeleteTransformFeedbacksNV,
+            "DeleteVertexArrays" => &self.DeleteVertexArrays,
+            "DeleteVertexArraysAPPLE" => &self.DeleteVertexArraysAPPLE,
+            "DeleteVertexShaderEXT" => &self.DeleteVertexShaderEXT,
+            "DepthBoundsEXT" => &self.DepthBoundsEXT,
+            "DepthBoundsdNV" => &self.DepthBoundsdNV,
+            "DepthFunc" => &self.DepthFunc,
+            "DepthMask" => &self.DepthMask,
+            "DepthRange" => &self.DepthRange,
+            "DepthRangeArraydvNV" => &self.DepthRangeArraydvNV,
+            "DepthRangeArrayv" => &self.DepthRangeArrayv,
+            "DepthRangeIndexed" => &self.DepthRangeIndexed,
+            "DepthRangeIndexeddNV" => &self.DepthRangeIndexeddNV,
+            "DepthRangedNV" => &self.DepthRangedNV,
+            "DepthRangef" => &self.DepthRangef,
+            "DepthRangefOES" => &self.DepthRangefOES,
+            "DepthRangexOES" => &self.DepthRangexOES,
+            "DetachObjectARB" => &self.DetachObjectARB,
+            "DetachShader" => &self.DetachShader,
+            "DetailTexFuncSGIS" => &self.DetailTexFuncSGIS,
+            "Disable" => &self.Disable,
+            "DisableClientStateIndexedEXT" => &self.DisableClientStateIndexedEXT,
+            "DisableClientStateiEXT" => &self.DisableClientStateiEXT,
+            "DisableIndexedEXT" => &self.DisableIndexedEXT,
+            "DisableVariantClientStateEXT" => &self.DisableVariantClientStateEXT,
+            "DisableVertexArrayAttrib" => &self.DisableVertexArrayAttrib,
+            "DisableVertexArrayAttribEXT" => &self.DisableVertexArrayAttribEXT,
+            "DisableVertexArrayEXT" => &self.DisableVertexArrayEXT,
+            "DisableVertexAttribAPPLE" => &self.DisableVertexAttribAPPLE,
+            "DisableVertexAttribArray" => &self.DisableVertexAttribArray,
+            "DisableVertexAttribArrayARB" => &self.DisableVertexAttribArrayARB,
+            "Disablei" => &self.Disablei,
+            "DispatchCompute" => &self.DispatchCompute,
+            "DispatchComputeGroupSizeARB" => &self.DispatchComputeGroupSizeARB,
+            "DispatchComputeIndirect" => &self.DispatchComputeIndirect,
+            "DrawArrays" => &self.DrawArrays,
+            "DrawArraysEXT" => &self.DrawArraysEXT,
+            "DrawArraysIndirect" => &self.DrawArraysIndirect,
+            "DrawArraysInstanced" => &self.DrawArraysInstanced,
+            "DrawArraysInstancedARB" => &self.DrawArraysInstancedARB,
+            "DrawArraysInstancedBaseInstance" => &self.DrawArraysInstancedBaseInstance,
+            "DrawArraysInstancedEXT" => &self.DrawArraysInstancedEXT,
+            "DrawBuffer" => &self.DrawBuffer,
+            "DrawBuffers" => &self.DrawBuffers,
+            "DrawBuffersARB" => &self.DrawBuffersARB,
+            "DrawBuffersATI" => &self.DrawBuffersATI,
+            "DrawCommandsAddressNV" => &self.DrawCommandsAddressNV,
+            "DrawCommandsNV" => &self.DrawCommandsNV,
+            "DrawCommandsStatesAddressNV" => &self.DrawCommandsStatesAddressNV,
+            "DrawCommandsStatesNV" => &self.DrawCommandsStatesNV,
+            "DrawElementArrayAPPLE" => &self.DrawElementArrayAPPLE,
+            "DrawElementArrayATI" => &self.DrawElementArrayATI,
+            "DrawElements" => &self.DrawElements,
+            "DrawElementsBaseVertex" => &self.DrawElementsBaseVertex,
+            "DrawElementsIndirect" => &self.DrawElementsIndirect,
+            "DrawElementsInstanced" => &self.DrawElementsInstanced,
+            "DrawElementsInstancedARB" => &self.DrawElementsInstancedARB,
+            "DrawElementsInstancedBaseInstance" => &self.DrawElementsInstancedBaseInstance,
+            "DrawElementsInstancedBaseVertex" => &self.DrawElementsInstancedBaseVertex,
+            "DrawElementsInstancedBaseVertexBaseInstance" => {
+                &self.DrawElementsInstancedBaseVertexBaseInstance
+            }
+            "DrawElementsInstancedEXT" => &self.DrawElementsInstancedEXT,
+            "DrawMeshArraysSUN" => &self.DrawMeshArraysSUN,
+            "DrawMeshTasksIndirectNV" => &self.DrawMeshTasksIndirectNV,
+            "DrawMeshTasksNV" => &self.DrawMeshTasksNV,
+            "DrawRangeElementArrayAPPLE" => &self.DrawRangeElementArrayAPPLE,
+            "DrawRangeElementArrayATI" => &self.DrawRangeElementArrayATI,
+            "DrawRangeElements" => &self.DrawRangeElements,
+            "DrawRangeElementsBaseVertex" => &self.DrawRangeElementsBaseVertex,
+            "DrawRangeElementsEXT" => &self.DrawRangeElementsEXT,
+            "DrawTextureNV" => &self.DrawTextureNV,
+            "DrawTransformFeedback" => &self.DrawTransformFeedback,
+            "DrawTransformFeedbackInstanced" => &self.DrawTransformFeedbackInstanced,
+            "DrawTransformFeedbackNV" => &self.DrawTransformFeedbackNV,
+            "DrawTransformFeedbackStream" => &self.DrawTransformFeedbackStream,
+            "DrawTransformFeedbackStreamInstanced" => &self.DrawTransformFeedbackStreamInstanced,
+            "DrawVkImageNV" => &self.DrawVkImageNV,
+            "EGLImageTargetTexStorageEXT" => &self.EGLImageTargetTexStorageEXT,
+            "EGLImageTargetTextureStorageEXT" => &self.EGLImageTargetTextureStorageEXT,
+            "EdgeFlagFormatNV" => &self.EdgeFlagFormatNV,
+            "EdgeFlagPointerEXT" => &self.EdgeFlagPointerEXT,
+            "EdgeFlagPointerListIBM" => &self.EdgeFlagPointerListIBM,
+            "ElementPointerAPPLE" => &self.ElementPointerAPPLE,
+            "ElementPointerATI" => &self.ElementPointerATI,
+            "Enable" => &self.Enable,
+            "EnableClientStateIndexedEXT" => &self.EnableClientStateIndexedEXT,
+            "EnableClientStateiEXT" => &self.EnableClientStateiEXT,
+            "EnableIndexedEXT" => &self.EnableIndexedEXT,
+            "EnableVariantClientStateEXT" => &self.EnableVariantClientStateEXT,
+            "EnableVertexArrayAttrib" => &self.EnableVertexArrayAttrib,
+            "EnableVertexArrayAttribEXT" => &self.EnableVertexArrayAttribEXT,
+            "EnableVertexArrayEXT" => &self.EnableVertexArrayEXT,
+            "EnableVertexAttribAPPLE" => &self.EnableVertexAttribAPPLE,
+            "EnableVertexAttribArray" => &self.EnableVertexAttribArray,
+            "EnableVertexAttribArrayARB" => &self.EnableVertexAttribArrayARB,
+            "Enablei" => &self.Enablei,
+            "EndConditionalRender" => &self.EndConditionalRender,
+            "EndConditionalRenderNV" => &self.EndConditionalRenderNV,
+            "EndConditionalRenderNVX" => &self.EndConditionalRenderNVX,
+            "EndFragmentShaderATI" => &self.EndFragmentShaderATI,
+            "EndOcclusionQueryNV" => &self.EndOcclusionQueryNV,
+            "EndPerfMonitorAMD" => &self.EndPerfMonitorAMD,
+            "EndPerfQueryINTEL" => &self.EndPerfQueryINTEL,
+            "EndQuery" => &self.EndQuery,
+            "EndQueryARB" => &self.EndQueryARB,
+            "EndQueryIndexed" => &self.EndQueryIndexed,
+            "EndTransformFeedback" => &self.EndTransformFeedback,
+            "EndTransformFeedbackEXT" => &self.EndTransformFeedbackEXT,
+            "EndTransformFeedbackNV" => &self.EndTransformFeedbackNV,
+            "EndVertexShaderEXT" => &self.EndVertexShaderEXT,
+            "EndVideoCaptureNV" => &self.EndVideoCaptureNV,
+            "EvalCoord1xOES" => &self.EvalCoord1xOES,
+            "EvalCoord1xvOES" => &self.EvalCoord1xvOES,
+            "EvalCoord2xOES" => &self.EvalCoord2xOES,
+            "EvalCoord2xvOES" => &self.EvalCoord2xvOES,
+            "EvalMapsNV" => &self.EvalMapsNV,
+            "EvaluateDepthValuesARB" => &self.EvaluateDepthValuesARB,
+            "ExecuteProgramNV" => &self.ExecuteProgramNV,
+            "ExtractComponentEXT" => &self.ExtractComponentEXT,
+            "FeedbackBufferxOES" => &self.FeedbackBufferxOES,
+            "FenceSync" => &self.FenceSync,
+            "FinalCombinerInputNV" => &self.FinalCombinerInputNV,
+            "Finish" => &self.Finish,
+            "FinishAsyncSGIX" => &self.FinishAsyncSGIX,
+            "FinishFenceAPPLE" => &self.FinishFenceAPPLE,
+            "FinishFenceNV" => &self.FinishFenceNV,
+            "FinishObjectAPPLE" => &self.FinishObjectAPPLE,
+            "FinishTextureSUNX" => &self.FinishTextureSUNX,
+            "Flush" => &self.Flush,
+            "FlushMappedBufferRange" => &self.FlushMappedBufferRange,
+            "FlushMappedBufferRangeAPPLE" => &self.FlushMappedBufferRangeAPPLE,
+            "FlushMappedNamedBufferRange" => &self.FlushMappedNamedBufferRange,
+            "FlushMappedNamedBufferRangeEXT" => &self.FlushMappedNamedBufferRangeEXT,
+            "FlushPixelDataRangeNV" => &self.FlushPixelDataRangeNV,
+            "FlushRasterSGIX" => &self.FlushRasterSGIX,
+            "FlushStaticDataIBM" => &self.FlushStaticDataIBM,
+            "FlushVertexArrayRangeAPPLE" => &self.FlushVertexArrayRangeAPPLE,
+            "FlushVertexArrayRangeNV" => &self.FlushVertexArrayRangeNV,
+            "FogCoordFormatNV" => &self.FogCoordFormatNV,
+            "FogCoordPointerEXT" => &self.FogCoordPointerEXT,
+            "FogCoordPointerListIBM" => &self.FogCoordPointerListIBM,
+            "FogCoorddEXT" => &self.FogCoorddEXT,
+            "FogCoorddvEXT" => &self.FogCoorddvEXT,
+            "FogCoordfEXT" => &self.FogCoordfEXT,
+            "FogCoordfvEXT" => &self.FogCoordfvEXT,
+            "FogCoordhNV" => &self.FogCoordhNV,
+            "FogCoordhvNV" => &self.FogCoordhvNV,
+            "FogFuncSGIS" => &self.FogFuncSGIS,
+            "FogxOES" => &self.FogxOES,
+            "FogxvOES" => &self.FogxvOES,
+            "FragmentColorMaterialSGIX" => &self.FragmentColorMaterialSGIX,
+            "FragmentCoverageColorNV" => &self.FragmentCoverageColorNV,
+            "FragmentLightModelfSGIX" => &self.FragmentLightModelfSGIX,
+            "FragmentLightModelfvSGIX" => &self.FragmentLightModelfvSGIX,
+            "FragmentLightModeliSGIX" => &self.FragmentLightModeliSGIX,
+            "FragmentLightModelivSGIX" => &self.FragmentLightModelivSGIX,
+            "FragmentLightfSGIX" => &self.FragmentLightfSGIX,
+            "FragmentLightfvSGIX" => &self.FragmentLightfvSGIX,
+            "FragmentLightiSGIX" => &self.FragmentLightiSGIX,
+            "FragmentLightivSGIX" => &self.FragmentLightivSGIX,
+            "FragmentMaterialfSGIX" => &self.FragmentMaterialfSGIX,
+            "FragmentMaterialfvSGIX" => &self.FragmentMaterialfvSGIX,
+            "FragmentMaterialiSGIX" => &self.FragmentMaterialiSGIX,
+            "FragmentMaterialivSGIX" => &self.FragmentMaterialivSGIX,
+            "FrameTerminatorGREMEDY" => &self.FrameTerminatorGREMEDY,
+            "FrameZoomSGIX" => &self.FrameZoomSGIX,
+            "FramebufferDrawBufferEXT" => &self.FramebufferDrawBufferEXT,
+            "FramebufferDrawBuffersEXT" => &self.FramebufferDrawBuffersEXT,
+            "FramebufferFetchBarrierEXT" => &self.FramebufferFetchBarrierEXT,
+            "FramebufferParameteri" => &self.FramebufferParameteri,
+            "FramebufferParameteriMESA" => &self.FramebufferParameteriMESA,
+            "FramebufferReadBufferEXT" => &self.FramebufferReadBufferEXT,
+            "FramebufferRenderbuffer" => &self.FramebufferRenderbuffer,
+            "FramebufferRenderbufferEXT" => &self.FramebufferRenderbufferEXT,
+            "FramebufferSampleLocationsfvARB" => &self.FramebufferSampleLocationsfvARB,
+            "FramebufferSampleLocationsfvNV" => &self.FramebufferSampleLocationsfvNV,
+            "FramebufferSamplePositionsfvAMD" => &self.FramebufferSamplePositionsfvAMD,
+            "FramebufferTexture" => &self.FramebufferTexture,
+            "FramebufferTexture1D" => &self.FramebufferTexture1D,
+            "FramebufferTexture1DEXT" => &self.FramebufferTexture1DEXT,
+            "FramebufferTexture2D" => &self.FramebufferTexture2D,
+            "FramebufferTexture2DEXT" => &self.FramebufferTexture2DEXT,
+            "FramebufferTexture3D" => &self.FramebufferTexture3D,
+            "FramebufferTexture3DEXT" => &self.FramebufferTexture3DEXT,
+            "FramebufferTextureARB" => &self.FramebufferTextureARB,
+            "FramebufferTextureEXT" => &self.FramebufferTextureEXT,
+            "FramebufferTextureFaceARB" => &self.FramebufferTextureFaceARB,
+            "FramebufferTextureFaceEXT" => &self.FramebufferTextureFaceEXT,
+            "FramebufferTextureLayer" => &self.FramebufferTextureLayer,
+            "FramebufferTextureLayerARB" => &self.FramebufferTextureLayerARB,
+            "FramebufferTextureLayerEXT" => &self.FramebufferTextureLayerEXT,
+            "FramebufferTextureMultiviewOVR" => &self.FramebufferTextureMultiviewOVR,
+            "FreeObjectBufferATI" => &self.FreeObjectBufferATI,
+            "FrontFace" => &self.FrontFace,
+            "FrustumfOES" => &self.FrustumfOES,
+            "FrustumxOES" => &self.FrustumxOES,
+            "GenAsyncMarkersSGIX" => &self.GenAsyncMarkersSGIX,
+            "GenBuffers" => &self.GenBuffers,
+            "GenBuffersARB" => &self.GenBuffersARB,
+            "GenFencesAPPLE" => &self.GenFencesAPPLE,
+            "GenFencesNV" => &self.GenFencesNV,
+            "GenFragmentShadersATI" => &self.GenFragmentShadersATI,
+            "GenFramebuffers" => &self.GenFramebuffers,
+            "GenFramebuffersEXT" => &self.GenFramebuffersEXT,
+            "GenNamesAMD" => &self.GenNamesAMD,
+            "GenOcclusionQueriesNV" => &self.GenOcclusionQueriesNV,
+            "GenPathsNV" => &self.GenPathsNV,
+            "GenPerfMonitorsAMD" => &self.GenPerfMonitorsAMD,
+            "GenProgramPipelines" => &self.GenProgramPipelines,
+            "GenProgramsARB" => &self.GenProgramsARB,
+            "GenProgramsNV" => &self.GenProgramsNV,
+            "GenQueries" => &self.GenQueries,
+            "GenQueriesARB" => &self.GenQueriesARB,
+            "GenQueryResourceTagNV" => &self.GenQueryResourceTagNV,
+            "GenRenderbuffers" => &self.GenRenderbuffers,
+            "GenRenderbuffersEXT" => &self.GenRenderbuffersEXT,
+            "GenSamplers" => &self.GenSamplers,
+            "GenSemaphoresEXT" => &self.GenSemaphoresEXT,
+            "GenSymbolsEXT" => &self.GenSymbolsEXT,
+            "GenTextures" => &self.GenTextures,
+            "GenTexturesEXT" => &self.GenTexturesEXT,
+            "GenTransformFeedbacks" => &self.GenTransformFeedbacks,
+            "GenTransformFeedbacksNV" => &self.GenTransformFeedbacksNV,
+            "GenVertexArrays" => &self.GenVertexArrays,
+            "GenVertexArraysAPPLE" => &self.GenVertexArraysAPPLE,
+            "GenVertexShadersEXT" => &self.GenVertexShadersEXT,
+            "GenerateMipmap" => &self.GenerateMipmap,
+            "GenerateMipmapEXT" => &self.GenerateMipmapEXT,
+            "GenerateMultiTexMipmapEXT" => &self.GenerateMultiTexMipmapEXT,
+            "GenerateTextureMipmap" => &self.GenerateTextureMipmap,
+            "GenerateTextureMipmapEXT" => &self.GenerateTextureMipmapEXT,
+            "GetActiveAtomicCounterBufferiv" => &self.GetActiveAtomicCounterBufferiv,
+            "GetActiveAttrib" => &self.GetActiveAttrib,
+            "GetActiveAttribARB" => &self.GetActiveAttribARB,
+            "GetActiveSubroutineName" => &self.GetActiveSubroutineName,
+            "GetActiveSubroutineUniformName" => &self.GetActiveSubroutineUniformName,
+            "GetActiveSubroutineUniformiv" => &self.GetActiveSubroutineUniformiv,
+            "GetActiveUniform" => &self.GetActiveUniform,
+            "GetActiveUniformARB" => &self.GetActiveUniformARB,
+            "GetActiveUniformBlockName" => &self.GetActiveUniformBlockName,
+            "GetActiveUniformBlockiv" => &self.GetActiveUniformBlockiv,
+            "GetActiveUniformName" => &self.GetActiveUniformName,
+            "GetActiveUniformsiv" => &self.GetActiveUniformsiv,
+            "GetActiveVaryingNV" => &self.GetActiveVaryingNV,
+            "GetArrayObjectfvATI" => &self.GetArrayObjectfvATI,
+            "GetArrayObjectivATI" => &self.GetArrayObjectivATI,
+            "GetAttachedObjectsARB" => &self.GetAttachedObjectsARB,
+            "GetAttachedShaders" => &self.GetAttachedShaders,
+            "GetAttribLocation" => &self.GetAttribLocation,
+            "GetAttribLocationARB" => &self.GetAttribLocationARB,
+            "GetBooleanIndexedvEXT" => &self.GetBooleanIndexedvEXT,
+            "GetBooleani_v" => &self.GetBooleani_v,
+            "GetBooleanv" => &self.GetBooleanv,
+            "GetBufferParameteri64v" => &self.GetBufferParameteri64v,
+            "GetBufferParameteriv" => &self.GetBufferParameteriv,
+            "GetBufferParameterivARB" => &self.GetBufferParameterivARB,
+            "GetBufferParameterui64vNV" => &self.GetBufferParameterui64vNV,
+            "GetBufferPointerv" => &self.GetBufferPointerv,
+            "GetBufferPointervARB" => &self.GetBufferPointervARB,
+            "GetBufferSubData" => &self.GetBufferSubData,
+            "GetBufferSubDataARB" => &self.GetBufferSubDataARB,
+            "GetClipPlanefOES" => &self.GetClipPlanefOES,
+            "GetClipPlanexOES" => &self.GetClipPlanexOES,
+            "GetColorTableEXT" => &self.GetColorTableEXT,
+            "GetColorTableParameterfvEXT" => &self.GetColorTableParameterfvEXT,
+            "GetColorTableParameterfvSGI" => &self.GetColorTableParameterfvSGI,
+            "GetColorTableParameterivEXT" => &self.GetColorTableParameterivEXT,
+            "GetColorTableParameterivSGI" => &self.GetColorTableParameterivSGI,
+            "GetColorTableSGI" => &self.GetColorTableSGI,
+            "GetCombinerInputParameterfvNV" => &self.GetCombinerInputParameterfvNV,
+            "GetCombinerInputParameterivNV" => &self.GetCombinerInputParameterivNV,
+            "GetCombinerOutputParameterfvNV" => &self.GetCombinerOutputParameterfvNV,
+            "GetCombinerOutputParameterivNV" => &self.GetCombinerOutputParameterivNV,
+            "GetCombinerStageParameterfvNV" => &self.GetCombinerStageParameterfvNV,
+            "GetCommandHeaderNV" => &self.GetCommandHeaderNV,
+            "GetCompressedMultiTexImageEXT" => &self.GetCompressedMultiTexImageEXT,
+            "GetCompressedTexImage" => &self.GetCompressedTexImage,
+            "GetCompressedTexImageARB" => &self.GetCompressedTexImageARB,
+            "GetCompressedTextureImage" => &self.GetCompressedTextureImage,
+            "GetCompressedTextureImageEXT" => &self.GetCompressedTextureImageEXT,
+            "GetCompressedTextureSubImage" => &self.GetCompressedTextureSubImage,
+            "GetConvolutionFilterEXT" => &self.GetConvolutionFilterEXT,
+            "GetConvolutionParameterfvEXT" => &self.GetConvolutionParameterfvEXT,
+            "GetConvolutionParameterivEXT" => &self.GetConvolutionParameterivEXT,
+            "GetConvolutionParameterxvOES" => &self.GetConvolutionParameterxvOES,
+            "GetCoverageModulationTableNV" => &self.GetCoverageModulationTableNV,
+            "GetDebugMessageLog" => &self.GetDebugMessageLog,
+            "GetDebugMessageLogAMD" => &self.GetDebugMessageLogAMD,
+            "GetDebugMessageLogARB" => &self.GetDebugMessageLogARB,
+            "GetDetailTexFuncSGIS" => &self.GetDetailTexFuncSGIS,
+            "GetDoubleIndexedvEXT" => &self.GetDoubleIndexedvEXT,
+            "GetDoublei_v" => &self.GetDoublei_v,
+            "GetDoublei_vEXT" => &self.GetDoublei_vEXT,
+            "GetDoublev" => &self.GetDoublev,
+            "GetError" => &self.GetError,
+            "GetFenceivNV" => &self.GetFenceivNV,
+            "GetFinalCombinerInputParameterfvNV" => &self.GetFinalCombinerInputParameterfvNV,
+            "GetFinalCombinerInputParameterivNV" => &self.GetFinalCombinerInputParameterivNV,
+            "GetFirstPerfQueryIdINTEL" => &self.GetFirstPerfQueryIdINTEL,
+            "GetFixedvOES" => &self.GetFixedvOES,
+            "GetFloatIndexedvEXT" => &self.GetFloatIndexedvEXT,
+            "GetFloati_v" => &self.GetFloati_v,
+            "GetFloati_vEXT" => &self.GetFloati_vEXT,
+            "GetFloatv" => &self.GetFloatv,
+            "GetFogFuncSGIS" => &self.GetFogFuncSGIS,
+            "GetFragDataIndex" => &self.GetFragDataIndex,
+            "GetFragDataLocation" => &self.GetFragDataLocation,
+            "GetFragDataLocationEXT" => &self.GetFragDataLocationEXT,
+            "GetFragmentLightfvSGIX" => &self.GetFragmentLightfvSGIX,
+            "GetFragmentLightivSGIX" => &self.GetFragmentLightivSGIX,
+            "GetFragmentMaterialfvSGIX" => &self.GetFragmentMaterialfvSGIX,
+            "GetFragmentMaterialivSGIX" => &self.GetFragmentMaterialivSGIX,
+            "GetFramebufferAttachmentParameteriv" => &self.GetFramebufferAttachmentParameteriv,
+            "GetFramebufferAttachmentParameterivEXT" => {
+                &self.GetFramebufferAttachmentParameterivEXT
+            }
+            "GetFramebufferParameterfvAMD" => &self.GetFramebufferParameterfvAMD,
+            "GetFramebufferParameteriv" => &self.GetFramebufferParameteriv,
+            "GetFramebufferParameterivEXT" => &self.GetFramebufferParameterivEXT,
+            "GetFramebufferParameterivMESA" => &self.GetFramebufferParameterivMESA,
+            "GetGraphicsResetStatus" => &self.GetGraphicsResetStatus,
+            "GetGraphicsResetStatusARB" => &self.GetGraphicsResetStatusARB,
+            "GetHandleARB" => &self.GetHandleARB,
+            "GetHistogramEXT" => &self.GetHistogramEXT,
+            "GetHistogramParameterfvEXT" => &self.GetHistogramParameterfvEXT,
+            "GetHistogramParameterivEXT" => &self.GetHistogramParameterivEXT,
+            "GetHistogramParameterxvOES" => &self.GetHistogramParameterxvOES,
+            "GetImageHandleARB" => &self.GetImageHandleARB,
+            "GetImageHandleNV" => &self.GetImageHandleNV,
+            "GetImageTransformParameterfvHP" => &self.GetImageTransformParameterfvHP,
+            "GetImageTransformParameterivHP" => &self.GetImageTransformParameterivHP,
+            "GetInfoLogARB" => &self.GetInfoLogARB,
+            "GetInstrumentsSGIX" => &self.GetInstrumentsSGIX,
+            "GetInteger64i_v" => &self.GetInteger64i_v,
+            "GetInteger64v" => &self.GetInteger64v,
+            "GetIntegerIndexedvEXT" => &self.GetIntegerIndexedvEXT,
+            "GetIntegeri_v" => &self.GetIntegeri_v,
+            "GetIntegerui64i_vNV" => &self.GetIntegerui64i_vNV,
+            "GetIntegerui64vNV" => &self.GetIntegerui64vNV,
+            "GetIntegerv" => &self.GetIntegerv,
+            "GetInternalformatSampleivNV" => &self.GetInternalformatSampleivNV,
+            "GetInternalformati64v" => &self.GetInternalformati64v,
+            "GetInternalformativ" => &self.GetInternalformativ,
+            "GetInvariantBooleanvEXT" => &self.GetInvariantBooleanvEXT,
+            "GetInvariantFloatvEXT" => &self.GetInvariantFloatvEXT,
+            "GetInvariantIntegervEXT" => &self.GetInvariantIntegervEXT,
+            "GetLightxOES" => &self.GetLightxOES,
+            "GetListParameterfvSGIX" => &self.GetListParameterfvSGIX,
+            "GetListParameterivSGIX" => &self.GetListParameterivSGIX,
+            "GetLocalConstantBooleanvEXT" => &self.GetLocalConstantBooleanvEXT,
+            "GetLocalConstantFloatvEXT" => &self.GetLocalConstantFloatvEXT,
+            "GetLocalConstantIntegervEXT" => &self.GetLocalConstantIntegervEXT,
+            "GetMapAttribParameterfvNV" => &self.GetMapAttribParameterfvNV,
+            "GetMapAttribParameterivNV" => &self.GetMapAttribParameterivNV,
+            "GetMapControlPointsNV" => &self.GetMapControlPointsNV,
+            "GetMapParameterfvNV" => &self.GetMapParameterfvNV,
+            "GetMapParameterivNV" => &self.GetMapParameterivNV,
+            "GetMapxvOES" => &self.GetMapxvOES,
+            "GetMaterialxOES" => &self.GetMaterialxOES,
+            "GetMemoryObjectDetachedResourcesuivNV" => &self.GetMemoryObjectDetachedResourcesuivNV,
+            "GetMemoryObjectParameterivEXT" => &self.GetMemoryObjectParameterivEXT,
+            "GetMinmaxEXT" => &self.GetMinmaxEXT,
+            "GetMinmaxParameterfvEXT" => &self.GetMinmaxParameterfvEXT,
+            "GetMinmaxParameterivEXT" => &self.GetMinmaxParameterivEXT,
+            "GetMultiTexEnvfvEXT" => &self.GetMultiTexEnvfvEXT,
+            "GetMultiTexEnvivEXT" => &self.GetMultiTexEnvivEXT,
+            "GetMultiTexGendvEXT" => &self.GetMultiTexGendvEXT,
+            "GetMultiTexGenfvEXT" => &self.GetMultiTexGenfvEXT,
+            "GetMultiTexGenivEXT" => &self.GetMultiTexGenivEXT,
+            "GetMultiTexImageEXT" => &self.GetMultiTexImageEXT,
+            "GetMultiTexLevelParameterfvEXT" => &self.GetMultiTexLevelParameterfvEXT,
+            "GetMultiTexLevelParameterivEXT" => &self.GetMultiTexLevelParameterivEXT,
+            "GetMultiTexParameterIivEXT" => &self.GetMultiTexParameterIivEXT,
+            "GetMultiTexParameterIuivEXT" => &self.GetMultiTexParameterIuivEXT,
+            "GetMultiTexParameterfvEXT" => &self.GetMultiTexParameterfvEXT,
+            "GetMultiTexParameterivEXT" => &self.GetMultiTexParameterivEXT,
+            "GetMultisamplefv" => &self.GetMultisamplefv,
+            "GetMultisamplefvNV" => &self.GetMultisamplefvNV,
+            "GetNamedBufferParameteri64v" => &self.GetNamedBufferParameteri64v,
+            "GetNamedBufferParameteriv" => &self.GetNamedBufferParameteriv,
+            "GetNamedBufferParameterivEXT" => &self.GetNamedBufferParameterivEXT,
+            "GetNamedBufferParameterui64vNV" => &self.GetNamedBufferParameterui64vNV,
+            "GetNamedBufferPointerv" => &self.GetNamedBufferPointerv,
+            "GetNamedBufferPointervEXT" => &self.GetNamedBufferPointervEXT,
+            "GetNamedBufferSubData" => &self.GetNamedBufferSubData,
+            "GetNamedBufferSubDataEXT" => &self.GetNamedBufferSubDataEXT,
+            "GetNamedFramebufferAttachmentParameteriv" => {
+                &self.GetNamedFramebufferAttachmentParameteriv
+            }
+            "GetNamedFramebufferAttachmentParameterivEXT" => {
+                &self.GetNamedFramebufferAttachmentParameterivEXT
+            }
+            "GetNamedFramebufferParameterfvAMD" => &self.GetNamedFramebufferParameterfvAMD,
+            "GetNamedFramebufferParameteriv" => &self.GetNamedFramebufferParameteriv,
+            "GetNamedFramebufferParameterivEXT" => &self.GetNamedFramebufferParameterivEXT,
+            "GetNamedProgramLocalParameterIivEXT" => &self.GetNamedProgramLocalParameterIivEXT,
+            "GetNamedProgramLocalParameterIuivEXT" => &self.GetNamedProgramLocalParameterIuivEXT,
+            "GetNamedProgramLocalParameterdvEXT" => &self.GetNamedProgramLocalParameterdvEXT,
+            "GetNamedProgramLocalParameterfvEXT" => &self.GetNamedProgramLocalParameterfvEXT,
+            "GetNamedProgramStringEXT" => &self.GetNamedProgramStringEXT,
+            "GetNamedProgramivEXT" => &self.GetNamedProgramivEXT,
+            "GetNamedRenderbufferParameteriv" => &self.GetNamedRenderbufferParameteriv,
+            "GetNamedRenderbufferParameterivEXT" => &self.GetNamedRenderbufferParameterivEXT,
+            "GetNamedStringARB" => &self.GetNamedStringARB,
+            "GetNamedStringivARB" => &self.GetNamedStringivARB,
+            "GetNextPerfQueryIdINTEL" => &self.GetNextPerfQueryIdINTEL,
+            "GetObjectBufferfvATI" => &self.GetObjectBufferfvATI,
+            "GetObjectBufferivATI" => &self.GetObjectBufferivATI,
+            "GetObjectLabel" => &self.GetObjectLabel,
+            "GetObjectLabelEXT" => &self.GetObjectLabelEXT,
+            "GetObjectParameterfvARB" => &self.GetObjectParameterfvARB,
+            "GetObjectParameterivAPPLE" => &self.GetObjectParameterivAPPLE,
+            "GetObjectParameterivARB" => &self.GetObjectParameterivARB,
+            "GetObjectPtrLabel" => &self.GetObjectPtrLabel,
+            "GetOcclusionQueryivNV" => &self.GetOcclusionQueryivNV,
+            "GetOcclusionQueryuivNV" => &self.GetOcclusionQueryuivNV,
+            "GetPathCommandsNV" => &self.GetPathCommandsNV,
+            "GetPathCoordsNV" => &self.GetPathCoordsNV,
+            "GetPathDashArrayNV" => &self.GetPathDashArrayNV,
+            "GetPathLengthNV" => &self.GetPathLengthNV,
+            "GetPathMetricRangeNV" => &self.GetPathMetricRangeNV,
+            "GetPathMetricsNV" => &self.GetPathMetricsNV,
+            "GetPathParameterfvNV" => &self.GetPathParameterfvNV,
+            "GetPathParameterivNV" => &self.GetPathParameterivNV,
+            "GetPathSpacingNV" => &self.GetPathSpacingNV,
+            "GetPerfCounterInfoINTEL" => &self.GetPerfCounterInfoINTEL,
+            "GetPerfMonitorCounterDataAMD" => &self.GetPerfMonitorCounterDataAMD,
+            "GetPerfMonitorCounterInfoAMD" => &self.GetPerfMonitorCounterInfoAMD,
+            "GetPerfMonitorCounterStringAMD" => &self.GetPerfMonitorCounterStringAMD,
+            "GetPerfMonitorCountersAMD" => &self.GetPerfMonitorCountersAMD,
+            "GetPerfMonitorGroupStringAMD" => &self.GetPerfMonitorGroupStringAMD,
+            "GetPerfMonitorGroupsAMD" => &self.GetPerfMonitorGroupsAMD,
+            "GetPerfQueryDataINTEL" => &self.GetPerfQueryDataINTEL,
+            "GetPerfQueryIdByNameINTEL" => &self.GetPerfQueryIdByNameINTEL,
+            "GetPerfQueryInfoINTEL" => &self.GetPerfQueryInfoINTEL,
+            "GetPixelMapxv" => &self.GetPixelMapxv,
+            "GetPixelTexGenParameterfvSGIS" => &self.GetPixelTexGenParameterfvSGIS,
+            "GetPixelTexGenParameterivSGIS" => &self.GetPixelTexGenParameterivSGIS,
+            "GetPixelTransformParameterfvEXT" => &self.GetPixelTransformParameterfvEXT,
+            "GetPixelTransformParameterivEXT" => &self.GetPixelTransformParameterivEXT,
+            "GetPointerIndexedvEXT" => &self.GetPointerIndexedvEXT,
+            "GetPointeri_vEXT" => &self.GetPointeri_vEXT,
+            "GetPointerv" => &self.GetPointerv,
+            "GetPointervEXT" => &self.GetPointervEXT,
+            "GetProgramBinary" => &self.GetProgramBinary,
+            "GetProgramEnvParameterIivNV" => &self.GetProgramEnvParameterIivNV,
+            "GetProgramEnvParameterIuivNV" => &self.GetProgramEnvParameterIuivNV,
+            "GetProgramEnvParameterdvARB" => &self.GetProgramEnvParameterdvARB,
+            "GetProgramEnvParameterfvARB" => &self.GetProgramEnvParameterfvARB,
+            "GetProgramInfoLog" => &self.GetProgramInfoLog,
+            "GetProgramInterfaceiv" => &self.GetProgramInterfaceiv,
+            "GetProgramLocalParameterIivNV" => &self.GetProgramLocalParameterIivNV,
+            "GetProgramLocalParameterIuivNV" => &self.GetProgramLocalParameterIuivNV,
+            "GetProgramLocalParameterdvARB" => &self.GetProgramLocalParameterdvARB,
+            "GetProgramLocalParameterfvARB" => &self.GetProgramLocalParameterfvARB,
+            "GetProgramNamedParameterdvNV" => &self.GetProgramNamedParameterdvNV,
+            "GetProgramNamedParameterfvNV" => &self.GetProgramNamedParameterfvNV,
+            "GetProgramParameterdvNV" => &self.GetProgramParameterdvNV,
+            "GetProgramParameterfvNV" => &self.GetProgramParameterfvNV,
+            "GetProgramPipelineInfoLog" => &self.GetProgramPipelineInfoLog,
+            "GetProgramPipelineiv" => &self.GetProgramPipelineiv,
+            "GetProgramResourceIndex" => &self.GetProgramResourceIndex,
+            "GetProgramResourceLocation" => &self.GetProgramResourceLocation,
+            "GetProgramResourceLocationIndex" => &self.GetProgramResourceLocationIndex,
+            "GetProgramResourceName" => &self.GetProgramResourceName,
+            "GetProgramResourcefvNV" => &self.GetProgramResourcefvNV,
+            "GetProgramResourceiv" => &self.GetProgramResourceiv,
+            "GetProgramStageiv" => &self.GetProgramStageiv,
+            "GetProgramStringARB" => &self.GetProgramStringARB,
+            "GetProgramStringNV" => &self.GetProgramStringNV,
+            "GetProgramSubroutineParameteruivNV" => &self.GetProgramSubroutineParameteruivNV,
+            "GetProgramiv" => &self.GetProgramiv,
+            "GetProgramivARB" => &self.GetProgramivARB,
+            "GetProgramivNV" => &self.GetProgramivNV,
+            "GetQueryBufferObjecti64v" => &self.GetQueryBufferObjecti64v,
+            "GetQueryBufferObjectiv" => &self.GetQueryBufferObjectiv,
+            "GetQueryBufferObjectui64v" => &self.GetQueryBufferObjectui64v,
+            "GetQueryBufferObjectuiv" => &self.GetQueryBufferObjectuiv,
+            "GetQueryIndexediv" => &self.GetQueryIndexediv,
+            "GetQueryObjecti64v" => &self.GetQueryObjecti64v,
+            "GetQueryObjecti64vEXT" => &self.GetQueryObjecti64vEXT,
+            "GetQueryObjectiv" => &self.GetQueryObjectiv,
+            "GetQueryObjectivARB" => &self.GetQueryObjectivARB,
+            "GetQueryObjectui64v" => &self.GetQueryObjectui64v,
+            "GetQueryObjectui64vEXT" => &self.GetQueryObjectui64vEXT,
+            "GetQueryObjectuiv" => &self.GetQueryObjectuiv,
+            "GetQueryObjectuivARB" => &self.GetQueryObjectuivARB,
+            "GetQueryiv" => &self.GetQueryiv,
+            "GetQueryivARB" => &self.GetQueryivARB,
+            "GetRenderbufferParameteriv" => &self.GetRenderbufferParameteriv,
+            "GetRenderbufferParameterivEXT" => &self.GetRenderbufferParameterivEXT,
+            "GetSamplerParameterIiv" => &self.GetSamplerParameterIiv,
+            "GetSamplerParameterIuiv" => &self.GetSamplerParameterIuiv,
+            "GetSamplerParameterfv" => &self.GetSamplerParameterfv,
+            "GetSamplerParameteriv" => &self.GetSamplerParameteriv,
+            "GetSemaphoreParameterivNV" => &self.GetSemaphoreParameterivNV,
+            "GetSemaphoreParameterui64vEXT" => &self.GetSemaphoreParameterui64vEXT,
+            "GetSeparableFilterEXT" => &self.GetSeparableFilterEXT,
+            "GetShaderInfoLog" => &self.GetShaderInfoLog,
+            "GetShaderPrecisionFormat" => &self.GetShaderPrecisionFormat,
+            "GetShaderSource" => &self.GetShaderSource,
+            "GetShaderSourceARB" => &self.GetShaderSourceARB,
+            "GetShaderiv" => &self.GetShaderiv,
+            "GetShadingRateImagePaletteNV" => &self.GetShadingRateImagePaletteNV,
+            "GetShadingRateSampleLocationivNV" => &self.GetShadingRateSampleLocationivNV,
+            "GetSharpenTexFuncSGIS" => &self.GetSharpenTexFuncSGIS,
+            "GetStageIndexNV" => &self.GetStageIndexNV,
+            "GetString" => &self.GetString,
+            "GetStringi" => &self.GetStringi,
+            "GetSubroutineIndex" => &self.GetSubroutineIndex,
+            "GetSubroutineUniformLocation" => &self.GetSubroutineUniformLocation,
+            "GetSynciv" => &self.GetSynciv,
+            "GetTexBumpParameterfvATI" => &self.GetTexBumpParameterfvATI,
+            "GetTexBumpParameterivATI" => &self.GetTexBumpParameterivATI,
+            "GetTexEnvxvOES" => &self.GetTexEnvxvOES,
+            "GetTexFilterFuncSGIS" => &self.GetTexFilterFuncSGIS,
+            "GetTexGenxvOES" => &self.GetTexGenxvOES,
+            "GetTexImage" => &self.GetTexImage,
+            "GetTexLevelParameterfv" => &self.GetTexLevelParameterfv,
+            "GetTexLevelParameteriv" => &self.GetTexLevelParameteriv,
+            "GetTexLevelParameterxvOES" => &self.GetTexLevelParameterxvOES,
+            "GetTexParameterIiv" => &self.GetTexParameterIiv,
+            "GetTexParameterIivEXT" => &self.GetTexParameterIivEXT,
+            "GetTexParameterIuiv" => &self.GetTexParameterIuiv,
+            "GetTexParameterIuivEXT" => &self.GetTexParameterIuivEXT,
+            "GetTexParameterPointervAPPLE" => &self.GetTexParameterPointervAPPLE,
+            "GetTexParameterfv" => &self.GetTexParameterfv,
+            "GetTexParameteriv" => &self.GetTexParameteriv,
+            "GetTexParameterxvOES" => &self.GetTexParameterxvOES,
+            "GetTextureHandleARB" => &self.GetTextureHandleARB,
+            "GetTextureHandleNV" => &self.GetTextureHandleNV,
+            "GetTextureImage" => &self.GetTextureImage,
+            "GetTextureImageEXT" => &self.GetTextureImageEXT,
+            "GetTextureLevelParameterfv" => &self.GetTextureLevelParameterfv,
+            "GetTextureLevelParameterfvEXT" => &self.GetTextureLevelParameterfvEXT,
+            "GetTextureLevelParameteriv" => &self.GetTextureLevelParameteriv,
+            "GetTextureLevelParameterivEXT" => &self.GetTextureLevelParameterivEXT,
+            "GetTextureParameterIiv" => &self.GetTextureParameterIiv,
+            "GetTextureParameterIivEXT" => &self.GetTextureParameterIivEXT,
+            "GetTextureParameterIuiv" => &self.GetTextureParameterIuiv,
+            "GetTextureParameterIuivEXT" => &self.GetTextureParameterIuivEXT,
+            "GetTextureParameterfv" => &self.GetTextureParameterfv,
+            "GetTextureParameterfvEXT" => &self.GetTextureParameterfvEXT,
+            "GetTextureParameteriv" => &self.GetTextureParameteriv,
+            "GetTextureParameterivEXT" => &self.GetTextureParameterivEXT,
+            "GetTextureSamplerHandleARB" => &self.GetTextureSamplerHandleARB,
+            "GetTextureSamplerHandleNV" => &self.GetTextureSamplerHandleNV,
+            "GetTextureSubImage" => &self.GetTextureSubImage,
+            "GetTrackMatrixivNV" => &self.GetTrackMatrixivNV,
+            "GetTransformFeedbackVarying" => &self.GetTransformFeedbackVarying,
+            "GetTransformFeedbackVaryingEXT" => &self.GetTransformFeedbackVaryingEXT,
+            "GetTransformFeedbackVaryingNV" => &self.GetTransformFeedbackVaryingNV,
+            "GetTransformFeedbacki64_v" => &self.GetTransformFeedbacki64_v,
+            "GetTransformFeedbacki_v" => &self.GetTransformFeedbacki_v,
+            "GetTransformFeedbackiv" => &self.GetTransformFeedbackiv,
+            "GetUniformBlockIndex" => &self.GetUniformBlockIndex,
+            "GetUniformBufferSizeEXT" => &self.GetUniformBufferSizeEXT,
+            "GetUniformIndices" => &self.GetUniformIndices,
+            "GetUniformLocation" => &self.GetUniformLocation,
+            "GetUniformLocationARB" => &self.GetUniformLocationARB,
+            "GetUniformOffsetEXT" => &self.GetUniformOffsetEXT,
+            "GetUniformSubroutineuiv" => &self.GetUniformSubroutineuiv,
+            "GetUniformdv" => &self.GetUniformdv,
+            "GetUniformfv" => &self.GetUniformfv,
+            "GetUniformfvARB" => &self.GetUniformfvARB,
+            "GetUniformi64vARB" => &self.GetUniformi64vARB,
+            "GetUniformi64vNV" => &self.GetUniformi64vNV,
+            "GetUniformiv" => &self.GetUniformiv,
+            "GetUniformivARB" => &self.GetUniformivARB,
+            "GetUniformui64vARB" => &self.GetUniformui64vARB,
+            "GetUniformui64vNV" => &self.GetUniformui64vNV,
+            "GetUniformuiv" => &self.GetUniformuiv,
+            "GetUniformuivEXT" => &self.GetUniformuivEXT,
+            "GetUnsignedBytei_vEXT" => &self.GetUnsignedBytei_vEXT,
+            "GetUnsignedBytevEXT" => &self.GetUnsignedBytevEXT,
+            "GetVariantArrayObjectfvATI" => &self.GetVariantArrayObjectfvATI,
+            "GetVariantArrayObjectivATI" => &self.GetVariantArrayObjectivATI,
+            "GetVariantBooleanvEXT" => &self.GetVariantBooleanvEXT,
+            "GetVariantFloatvEXT" => &self.GetVariantFloatvEXT,
+            "GetVariantIntegervEXT" => &self.GetVariantIntegervEXT,
+            "GetVariantPointervEXT" => &self.GetVariantPointervEXT,
+            "GetVaryingLocationNV" => &self.GetVaryingLocationNV,
+            "GetVertexArrayIndexed64iv" => &self.GetVertexArrayIndexed64iv,
+            "GetVertexArrayIndexediv" => &self.GetVertexArrayIndexediv,
+            "GetVertexArrayIntegeri_vEXT" => &self.GetVertexArrayIntegeri_vEXT,
+            "GetVertexArrayIntegervEXT" => &self.GetVertexArrayIntegervEXT,
+            "GetVertexArrayPointeri_vEXT" => &self.GetVertexArrayPointeri_vEXT,
+            "GetVertexArrayPointervEXT" => &self.GetVertexArrayPointervEXT,
+            "GetVertexArrayiv" => &self.GetVertexArrayiv,
+            "GetVertexAttribArrayObjectfvATI" => &self.GetVertexAttribArrayObjectfvATI,
+            "GetVertexAttribArrayObjectivATI" => &self.GetVertexAttribArrayObjectivATI,
+            "GetVertexAttribIiv" => &self.GetVertexAttribIiv,
+            "GetVertexAttribIivEXT" => &self.GetVertexAttribIivEXT,
+            "GetVertexAttribIuiv" => &self.GetVertexAttribIuiv,
+            "GetVertexAttribIuivEXT" => &self.GetVertexAttribIuivEXT,
+            "GetVertexAttribLdv" => &self.GetVertexAttribLdv,
+            "GetVertexAttribLdvEXT" => &self.GetVertexAttribLdvEXT,
+            "GetVertexAttribLi64vNV" => &self.GetVertexAttribLi64vNV,
+            "GetVertexAttribLui64vARB" => &self.GetVertexAttribLui64vARB,
+            "GetVertexAttribLui64vNV" => &self.GetVertexAttribLui64vNV,
+            "GetVertexAttribPointerv" => &self.GetVertexAttribPointerv,
+            "GetVertexAttribPointervARB" => &self.GetVertexAttribPointervARB,
+            "GetVertexAttribPointervNV" => &self.GetVertexAttribPointervNV,
+            "GetVertexAttribdv" => &self.GetVertexAttribdv,
+            "GetVertexAttribdvARB" => &self.GetVertexAttribdvARB,
+            "GetVertexAttribdvNV" => &self.GetVertexAttribdvNV,
+            "GetVertexAttribfv" => &self.GetVertexAttribfv,
+            "GetVertexAttribfvARB" => &self.GetVertexAttribfvARB,
+            "GetVertexAttribfvNV" => &self.GetVertexAttribfvNV,
+            "GetVertexAttribiv" => &self.GetVertexAttribiv,
+            "GetVertexAttribivARB" => &self.GetVertexAttribivARB,
+            "GetVertexAttribivNV" => &self.GetVertexAttribivNV,
+            "GetVideoCaptureStreamdvNV" => &self.GetVideoCaptureStreamdvNV,
+            "GetVideoCaptureStreamfvNV" => &self.GetVideoCaptureStreamfvNV,
+            "GetVideoCaptureStreamivNV" => &self.GetVideoCaptureStreamivNV,
+            "GetVideoCaptureivNV" => &self.GetVideoCaptureivNV,
+            "GetVideoi64vNV" => &self.GetVideoi64vNV,
+            "GetVideoivNV" => &self.GetVideoivNV,
+            "GetVideoui64vNV" => &self.GetVideoui64vNV,
+            "GetVideouivNV" => &self.GetVideouivNV,
+            "GetVkProcAddrNV" => &self.GetVkProcAddrNV,
+            "GetnCompressedTexImage" => &self.GetnCompressedTexImage,
+            "GetnCompressedTexImageARB" => &self.GetnCompressedTexImageARB,
+            "GetnTexImage" => &self.GetnTexImage,
+            "GetnTexImageARB" => &self.GetnTexImageARB,
+            "GetnUniformdv" => &self.GetnUniformdv,
+            "GetnUniformdvARB" => &self.GetnUniformdvARB,
+            "GetnUniformfv" => &self.GetnUniformfv,
+            "GetnUniformfvARB" => &self.GetnUniformfvARB,
+            "GetnUniformi64vARB" => &self.GetnUniformi64vARB,
+            "GetnUniformiv" => &self.GetnUniformiv,
+            "GetnUniformivARB" => &self.GetnUniformivARB,
+            "GetnUniformui64vARB" => &self.GetnUniformui64vARB,
+            "GetnUniformuiv" => &self.GetnUniformuiv,
+            "GetnUniformuivARB" => &self.GetnUniformuivARB,
+            "GlobalAlphaFactorbSUN" => &self.GlobalAlphaFactorbSUN,
+            "GlobalAlphaFactordSUN" => &self.GlobalAlphaFactordSUN,
+            "GlobalAlphaFactorfSUN" => &self.GlobalAlphaFactorfSUN,
+            "GlobalAlphaFactoriSUN" => &self.GlobalAlphaFactoriSUN,
+            "GlobalAlphaFactorsSUN" => &self.GlobalAlphaFactorsSUN,
+            "GlobalAlphaFactorubSUN" => &self.GlobalAlphaFactorubSUN,
+            "GlobalAlphaFactoruiSUN" => &self.GlobalAlphaFactoruiSUN,
+            "GlobalAlphaFactorusSUN" => &self.GlobalAlphaFactorusSUN,
+            "Hint" => &self.Hint,
+            "HintPGI" => &self.HintPGI,
+            "HistogramEXT" => &self.HistogramEXT,
+            "IglooInterfaceSGIX" => &self.IglooInterfaceSGIX,
+            "ImageTransformParameterfHP" => &self.ImageTransformParameterfHP,
+            "ImageTransformParameterfvHP" => &self.ImageTransformParameterfvHP,
+            "ImageTransformParameteriHP" => &self.ImageTransformParameteriHP,
+            "ImageTransformParameterivHP" => &self.ImageTransformParameterivHP,
+            "ImportMemoryFdEXT" => &self.ImportMemoryFdEXT,
+            "ImportMemoryWin32HandleEXT" => &self.ImportMemoryWin32HandleEXT,
+            "ImportMemoryWin32NameEXT" => &self.ImportMemoryWin32NameEXT,
+            "ImportSemaphoreFdEXT" => &self.ImportSemaphoreFdEXT,
+            "ImportSemaphoreWin32HandleEXT" => &self.ImportSemaphoreWin32HandleEXT,
+            "ImportSemaphoreWin32NameEXT" => &self.ImportSemaphoreWin32NameEXT,
+            "ImportSyncEXT" => &self.ImportSyncEXT,
+            "IndexFormatNV" => &self.IndexFormatNV,
+            "IndexFuncEXT" => &self.IndexFuncEXT,
+            "IndexMaterialEXT" => &self.IndexMaterialEXT,
+            "IndexPointerEXT" => &self.IndexPointerEXT,
+            "IndexPointerListIBM" => &self.IndexPointerListIBM,
+            "IndexxOES" => &self.IndexxOES,
+            "IndexxvOES" => &self.IndexxvOES,
+            "InsertComponentEXT" => &self.InsertComponentEXT,
+            "InsertEventMarkerEXT" => &self.InsertEventMarkerEXT,
+            "InstrumentsBufferSGIX" => &self.InstrumentsBufferSGIX,
+            "InterpolatePathsNV" => &self.InterpolatePathsNV,
+            "InvalidateBufferData" => &self.InvalidateBufferData,
+            "InvalidateBufferSubData" => &self.InvalidateBufferSubData,
+            "InvalidateFramebuffer" => &self.InvalidateFramebuffer,
+            "InvalidateNamedFramebufferData" => &self.InvalidateNamedFramebufferData,
+            "InvalidateNamedFramebufferSubData" => &self.InvalidateNamedFramebufferSubData,
+            "InvalidateSubFramebuffer" => &self.InvalidateSubFramebuffer,
+            "InvalidateTexImage" => &self.InvalidateTexImage,
+            "InvalidateTexSubImage" => &self.InvalidateTexSubImage,
+            "IsAsyncMarkerSGIX" => &self.IsAsyncMarkerSGIX,
+            "IsBuffer" => &self.IsBuffer,
+            "IsBufferARB" => &self.IsBufferARB,
+            "IsBufferResidentNV" => &self.IsBufferResidentNV,
+            "IsCommandListNV" => &self.IsCommandListNV,
+            "IsEnabled" => &self.IsEnabled,
+            "IsEnabledIndexedEXT" => &self.IsEnabledIndexedEXT,
+            "IsEnabledi" => &self.IsEnabledi,
+            "IsFenceAPPLE" => &self.IsFenceAPPLE,
+            "IsFenceNV" => &self.IsFenceNV,
+            "IsFramebuffer" => &self.IsFramebuffer,
+            "IsFramebufferEXT" => &self.IsFramebufferEXT,
+            "IsImageHandleResidentARB" => &self.IsImageHandleResidentARB,
+            "IsImageHandleResidentNV" => &self.IsImageHandleResidentNV,
+            "IsMemoryObjectEXT" => &self.IsMemoryObjectEXT,
+            "IsNameAMD" => &self.IsNameAMD,
+            "IsNamedBufferResidentNV" => &self.IsNamedBufferResidentNV,
+            "IsNamedStringARB" => &self.IsNamedStringARB,
+            "IsObjectBufferATI" => &self.IsObjectBufferATI,
+            "IsOcclusionQueryNV" => &self.IsOcclusionQueryNV,
+            "IsPathNV" => &self.IsPathNV,
+            "IsPointInFillPathNV" => &self.IsPointInFillPathNV,
+            "IsPointInStrokePathNV" => &self.IsPointInStrokePathNV,
+            "IsProgram" => &self.IsProgram,
+            "IsProgramARB" => &self.IsProgramARB,
+            "IsProgramNV" => &self.IsProgramNV,
+            "IsProgramPipeline" => &self.IsProgramPipeline,
+            "IsQuery" => &self.IsQuery,
+            "IsQueryARB" => &self.IsQueryARB,
+            "IsRenderbuffer" => &self.IsRenderbuffer,
+            "IsRenderbufferEXT" => &self.IsRenderbufferEXT,
+            "IsSampler" => &self.IsSampler,
+            "IsSemaphoreEXT" => &self.IsSemaphoreEXT,
+            "IsShader" => &self.IsShader,
+            "IsStateNV" => &self.IsStateNV,
+            "IsSync" => &self.IsSync,
+            "IsTexture" => &self.IsTexture,
+            "IsTextureEXT" => &self.IsTextureEXT,
+            "IsTextureHandleResidentARB" => &self.IsTextureHandleResidentARB,
+            "IsTextureHandleResidentNV" => &self.IsTextureHandleResidentNV,
+            "IsTransformFeedback" => &self.IsTransformFeedback,
+            "IsTransformFeedbackNV" => &self.IsTransformFeedbackNV,
+            "IsVariantEnabledEXT" => &self.IsVariantEnabledEXT,
+            "IsVertexArray" => &self.IsVertexArray,
+            "IsVertexArrayAPPLE" => &self.IsVertexArrayAPPLE,
+            "IsVertexAttribEnabledAPPLE" => &self.IsVertexAttribEnabledAPPLE,
+            "LGPUCopyImageSubDataNVX" => &self.LGPUCopyImageSubDataNVX,
+            "LGPUInterlockNVX" => &self.LGPUInterlockNVX,
+            "LGPUNamedBufferSubDataNVX" => &self.LGPUNamedBufferSubDataNVX,
+            "LabelObjectEXT" => &self.LabelObjectEXT,
+            "LightEnviSGIX" => &self.LightEnviSGIX,
+            "LightModelxOES" => &self.LightModelxOES,
+            "LightModelxvOES" => &self.LightModelxvOES,
+            "LightxOES" => &self.LightxOES,
+            "LightxvOES" => &self.LightxvOES,
+            "LineWidth" => &self.LineWidth,
+            "LineWidthxOES" => &self.LineWidthxOES,
+            "LinkProgram" => &self.LinkProgram,
+            "LinkProgramARB" => &self.LinkProgramARB,
+            "ListDrawCommandsStatesClientNV" => &self.ListDrawCommandsStatesClientNV,
+            "ListParameterfSGIX" => &self.ListParameterfSGIX,
+            "ListParameterfvSGIX" => &self.ListParameterfvSGIX,
+            "ListParameteriSGIX" => &self.ListParameteriSGIX,
+            "ListParameterivSGIX" => &self.ListParameterivSGIX,
+            "LoadIdentityDeformationMapSGIX" => &self.LoadIdentityDeformationMapSGIX,
+            "LoadMatrixxOES" => &self.LoadMatrixxOES,
+            "LoadProgramNV" => &self.LoadProgramNV,
+            "LoadTransposeMatrixdARB" => &self.LoadTransposeMatrixdARB,
+            "LoadTransposeMatrixfARB" => &self.LoadTransposeMatrixfARB,
+            "LoadTransposeMatrixxOES" => &self.LoadTransposeMatrixxOES,
+            "LockArraysEXT" => &self.LockArraysEXT,
+            "LogicOp" => &self.LogicOp,
+            "MakeBufferNonResidentNV" => &self.MakeBufferNonResidentNV,
+            "MakeBufferResidentNV" => &self.MakeBufferResidentNV,
+            "MakeImageHandleNonResidentARB" => &self.MakeImageHandleNonResidentARB,
+            "MakeImageHandleNonResidentNV" => &self.MakeImageHandleNonResidentNV,
+            "MakeImageHandleResidentARB" => &self.MakeImageHandleResidentARB,
+            "MakeImageHandleResidentNV" => &self.MakeImageHandleResidentNV,
+            "MakeNamedBufferNonResidentNV" => &self.MakeNamedBufferNonResidentNV,
+            "MakeNamedBufferResidentNV" => &self.MakeNamedBufferResidentNV,
+            "MakeTextureHandleNonResidentARB" => &self.MakeTextureHandleNonResidentARB,
+            "MakeTextureHandleNonResidentNV" => &self.MakeTextureHandleNonResidentNV,
+            "MakeTextureHandleResidentARB" => &self.MakeTextureHandleResidentARB,
+            "MakeTextureHandleResidentNV" => &self.MakeTextureHandleResidentNV,
+            "Map1xOES" => &self.Map1xOES,
+            "Map2xOES" => &self.Map2xOES,
+            "MapBuffer" => &self.MapBuffer,
+            "MapBufferARB" => &self.MapBufferARB,
+            "MapBufferRange" => &self.MapBufferRange,
+            "MapControlPointsNV" => &self.MapControlPointsNV,
+            "MapGrid1xOES" => &self.MapGrid1xOES,
+            "MapGrid2xOES" => &self.MapGrid2xOES,
+            "MapNamedBuffer" => &self.MapNamedBuffer,
+            "MapNamedBufferEXT" => &self.MapNamedBufferEXT,
+            "MapNamedBufferRange" => &self.MapNamedBufferRange,
+            "MapNamedBufferRangeEXT" => &self.MapNamedBufferRangeEXT,
+            "MapObjectBufferATI" => &self.MapObjectBufferATI,
+            "MapParameterfvNV" => &self.MapParameterfvNV,
+            "MapParameterivNV" => &self.MapParameterivNV,
+            "MapTexture2DINTEL" => &self.MapTexture2DINTEL,
+            "MapVertexAttrib1dAPPLE" => &self.MapVertexAttrib1dAPPLE,
+            "MapVertexAttrib1fAPPLE" => &self.MapVertexAttrib1fAPPLE,
+            "MapVertexAttrib2dAPPLE" => &self.MapVertexAttrib2dAPPLE,
+            "MapVertexAttrib2fAPPLE" => &self.MapVertexAttrib2fAPPLE,
+            "MaterialxOES" => &self.MaterialxOES,
+            "MaterialxvOES" => &self.MaterialxvOES,
+            "MatrixFrustumEXT" => &self.MatrixFrustumEXT,
+            "MatrixIndexPointerARB" => &self.MatrixIndexPointerARB,
+            "MatrixIndexubvARB" => &self.MatrixIndexubvARB,
+            "MatrixIndexuivARB" => &self.MatrixIndexuivARB,
+            "MatrixIndexusvARB" => &self.MatrixIndexusvARB,
+            "MatrixLoad3x2fNV" => &self.MatrixLoad3x2fNV,
+            "MatrixLoad3x3fNV" => &self.MatrixLoad3x3fNV,
+            "MatrixLoadIdentityEXT" => &self.MatrixLoadIdentityEXT,
+            "MatrixLoadTranspose3x3fNV" => &self.MatrixLoadTranspose3x3fNV,
+            "MatrixLoadTransposedEXT" => &self.MatrixLoadTransposedEXT,
+            "MatrixLoadTransposefEXT" => &self.MatrixLoadTransposefEXT,
+            "MatrixLoaddEXT" => &self.MatrixLoaddEXT,
+            "MatrixLoadfEXT" => &self.MatrixLoadfEXT,
+            "MatrixMult3x2fNV" => &self.MatrixMult3x2fNV,
+            "MatrixMult3x3fNV" => &self.MatrixMult3x3fNV,
+            "MatrixMultTranspose3x3fNV" => &self.MatrixMultTranspose3x3fNV,
+            "MatrixMultTransposedEXT" => &self.MatrixMultTransposedEXT,
+            "MatrixMultTransposefEXT" => &self.MatrixMultTransposefEXT,
+            "MatrixMultdEXT" => &self.MatrixMultdEXT,
+            "MatrixMultfEXT" => &self.MatrixMultfEXT,
+            "MatrixOrthoEXT" => &self.MatrixOrthoEXT,
+            "MatrixPopEXT" => &self.MatrixPopEXT,
+            "MatrixPushEXT" => &self.MatrixPushEXT,
+            "MatrixRotatedEXT" => &self.MatrixRotatedEXT,
+            "MatrixRotatefEXT" => &self.MatrixRotatefEXT,
+            "MatrixScaledEXT" => &self.MatrixScaledEXT,
+            "MatrixScalefEXT" => &self.MatrixScalefEXT,
+            "MatrixTranslatedEXT" => &self.MatrixTranslatedEXT,
+            "MatrixTranslatefEXT" => &self.MatrixTranslatefEXT,
+            "MaxShaderCompilerThreadsARB" => &self.MaxShaderCompilerThreadsARB,
+            "MaxShaderCompilerThreadsKHR" => &self.MaxShaderCompilerThreadsKHR,
+            "MemoryBarrier" => &self.MemoryBarrier,
+            "MemoryBarrierByRegion" => &self.MemoryBarrierByRegion,
+            "MemoryBarrierEXT" => &self.MemoryBarrierEXT,
+            "MemoryObjectParameterivEXT" => &self.MemoryObjectParameterivEXT,
+            "MinSampleShading" => &self.MinSampleShading,
+            "MinSampleShadingARB" => &self.MinSampleShadingARB,
+            "MinmaxEXT" => &self.MinmaxEXT,
+            "MultMatrixxOES" => &self.MultMatrixxOES,
+            "MultTransposeMatrixdARB" => &self.MultTransposeMatrixdARB,
+            "MultTransposeMatrixfARB" => &self.MultTransposeMatrixfARB,
+            "MultTransposeMatrixxOES" => &self.MultTransposeMatrixxOES,
+            "MultiDrawArrays" => &self.MultiDrawArrays,
+            "MultiDrawArraysEXT" => &self.MultiDrawArraysEXT,
+            "MultiDrawArraysIndirect" => &self.MultiDrawArraysIndirect,
+            "MultiDrawArraysIndirectAMD" => &self.MultiDrawArraysIndirectAMD,
+            "MultiDrawArraysIndirectBindlessCountNV" => {
+                &self.MultiDrawArraysIndirectBindlessCountNV
+            }
+            "MultiDrawArraysIndirectBindlessNV" => &self.MultiDrawArraysIndirectBindlessNV,
+            "MultiDrawArraysIndirectCount" => &self.MultiDrawArraysIndirectCount,
+            "MultiDrawArraysIndirectCountARB" => &self.MultiDrawArraysIndirectCountARB,
+            "MultiDrawElementArrayAPPLE" => &self.MultiDrawElementArrayAPPLE,
+            "MultiDrawElements" => &self.MultiDrawElements,
+            "MultiDrawElementsBaseVertex" => &self.MultiDrawElementsBaseVertex,
+            "MultiDrawElementsEXT" => &self.MultiDrawElementsEXT,
+            "MultiDrawElementsIndirect" => &self.MultiDrawElementsIndirect,
+            "MultiDrawElementsIndirectAMD" => &self.MultiDrawElementsIndirectAMD,
+            "MultiDrawElementsIndirectBindlessCountNV" => {
+                &self.MultiDrawElementsIndirectBindlessCountNV
+            }
+            "MultiDrawElementsIndirectBindlessNV" => &self.MultiDrawElementsIndirectBindlessNV,
+            "MultiDrawElementsIndirectCount" => &self.MultiDrawElementsIndirectCount,
+            "MultiDrawElementsIndirectCountARB" => &self.MultiDrawElementsIndirectCountARB,
+            "MultiDrawMeshTasksIndirectCountNV" => &self.MultiDrawMeshTasksIndirectCountNV,
+            "MultiDrawMeshTasksIndirectNV" => &self.MultiDrawMeshTasksIndirectNV,
+            "MultiDrawRangeElementArrayAPPLE" => &self.MultiDrawRangeElementArrayAPPLE,
+            "MultiModeDrawArraysIBM" => &self.MultiModeDrawArraysIBM,
+            "MultiModeDrawElementsIBM" => &self.MultiModeDrawElementsIBM,
+            "MultiTexBufferEXT" => &self.MultiTexBufferEXT,
+            "MultiTexCoord1bOES" => &self.MultiTexCoord1bOES,
+            "MultiTexCoord1bvOES" => &self.MultiTexCoord1bvOES,
+            "MultiTexCoord1dARB" => &self.MultiTexCoord1dARB,
+            "MultiTexCoord1dvARB" => &self.MultiTexCoord1dvARB,
+            "MultiTexCoord1fARB" => &self.MultiTexCoord1fARB,
+            "MultiTexCoord1fvARB" => &self.MultiTexCoord1fvARB,
+            "MultiTexCoord1hNV" => &self.MultiTexCoord1hNV,
+            "MultiTexCoord1hvNV" => &self.MultiTexCoord1hvNV,
+            "MultiTexCoord1iARB" => &self.MultiTexCoord1iARB,
+            "MultiTexCoord1ivARB" => &self.MultiTexCoord1ivARB,
+            "MultiTexCoord1sARB" => &self.MultiTexCoord1sARB,
+            "MultiTexCoord1svARB" => &self.MultiTexCoord1svARB,
+            "MultiTexCoord1xOES" => &self.MultiTexCoord1xOES,
+            "MultiTexCoord1xvOES" => &self.MultiTexCoord1xvOES,
+            "MultiTexCoord2bOES" => &self.MultiTexCoord2bOES,
+            "MultiTexCoord2bvOES" => &self.MultiTexCoord2bvOES,
+            "MultiTexCoord2dARB" => &self.MultiTexCoord2dARB,
+            "MultiTexCoord2dvARB" => &self.MultiTexCoord2dvARB,
+            "MultiTexCoord2fARB" => &self.MultiTexCoord2fARB,
+            "MultiTexCoord2fvARB" => &self.MultiTexCoord2fvARB,
+            "MultiTexCoord2hNV" => &self.MultiTexCoord2hNV,
+            "MultiTexCoord2hvNV" => &self.MultiTexCoord2hvNV,
+            "MultiTexCoord2iARB" => &self.MultiTexCoord2iARB,
+            "MultiTexCoord2ivARB" => &self.MultiTexCoord2ivARB,
+            "MultiTexCoord2sARB" => &self.MultiTexCoord2sARB,
+            "MultiTexCoord2svARB" => &self.MultiTexCoord2svARB,
+            "MultiTexCoord2xOES" => &self.MultiTexCoord2xOES,
+            "MultiTexCoord2xvOES" => &self.MultiTexCoord2xvOES,
+            "MultiTexCoord3bOES" => &self.MultiTexCoord3bOES,
+            "MultiTexCoord3bvOES" => &self.MultiTexCoord3bvOES,
+            "MultiTexCoord3dARB" => &self.MultiTexCoord3dARB,
+            "MultiTexCoord3dvARB" => &self.MultiTexCoord3dvARB,
+            "MultiTexCoord3fARB" => &self.MultiTexCoord3fARB,
+            "MultiTexCoord3fvARB" => &self.MultiTexCoord3fvARB,
+            "MultiTexCoord3hNV" => &self.MultiTexCoord3hNV,
+            "MultiTexCoord3hvNV" => &self.MultiTexCoord3hvNV,
+            "MultiTexCoord3iARB" => &self.MultiTexCoord3iARB,
+            "MultiTexCoord3ivARB" => &self.MultiTexCoord3ivARB,
+            "MultiTexCoord3sARB" => &self.MultiTexCoord3sARB,
+            "MultiTexCoord3svARB" => &self.MultiTexCoord3svARB,
+            "MultiTexCoord3xOES" => &self.MultiTexCoord3xOES,
+            "MultiTexCoord3xvOES" => &self.MultiTexCoord3xvOES,
+            "MultiTexCoord4bOES" => &self.MultiTexCoord4bOES,
+            "MultiTexCoord4bvOES" => &self.MultiTexCoord4bvOES,
+            "MultiTexCoord4dARB" => &self.MultiTexCoord4dARB,
+            "MultiTexCoord4dvARB" => &self.MultiTexCoord4dvARB,
+            "MultiTexCoord4fARB" => &self.MultiTexCoord4fARB,
+            "MultiTexCoord4fvARB" => &self.MultiTexCoord4fvARB,
+            "MultiTexCoord4hNV" => &self.MultiTexCoord4hNV,
+            "MultiTexCoord4hvNV" => &self.MultiTexCoord4hvNV,
+            "MultiTexCoord4iARB" => &self.MultiTexCoord4iARB,
+            "MultiTexCoord4ivARB" => &self.MultiTexCoord4ivARB,
+            "MultiTexCoord4sARB" => &self.MultiTexCoord4sARB,
+            "MultiTexCoord4svARB" => &self.MultiTexCoord4svARB,
+            "MultiTexCoord4xOES" => &self.MultiTexCoord4xOES,
+            "MultiTexCoord4xvOES" => &self.MultiTexCoord4xvOES,
+            "MultiTexCoordPointerEXT" => &self.MultiTexCoordPointerEXT,
+            "MultiTexEnvfEXT" => &self.MultiTexEnvfEXT,
+            "MultiTexEnvfvEXT" => &self.MultiTexEnvfvEXT,
+            "MultiTexEnviEXT" => &self.MultiTexEnviEXT,
+            "MultiTexEnvivEXT" => &self.MultiTexEnvivEXT,
+            "MultiTexGendEXT" => &self.MultiTexGendEXT,
+            "MultiTexGendvEXT" => &self.MultiTexGendvEXT,
+            "MultiTexGenfEXT" => &self.MultiTexGenfEXT,
+            "MultiTexGenfvEXT" => &self.MultiTexGenfvEXT,
+            "MultiTexGeniEXT" => &self.MultiTexGeniEXT,
+            "MultiTexGenivEXT" => &self.MultiTexGenivEXT,
+            "MultiTexImage1DEXT" => &self.MultiTexImage1DEXT,
+            "MultiTexImage2DEXT" => &self.MultiTexImage2DEXT,
+            "MultiTexImage3DEXT" => &self.MultiTexImage3DEXT,
+            "MultiTexParameterIivEXT" => &self.MultiTexParameterIivEXT,
+            "MultiTexParameterIuivEXT" => &self.MultiTexParameterIuivEXT,
+            "MultiTexParameterfEXT" => &self.MultiTexParameterfEXT,
+            "MultiTexParameterfvEXT" => &self.MultiTexParameterfvEXT,
+            "MultiTexParameteriEXT" => &self.MultiTexParameteriEXT,
+            "MultiTexParameterivEXT" => &self.MultiTexParameterivEXT,
+            "MultiTexRenderbufferEXT" => &self.MultiTexRenderbufferEXT,
+            "MultiTexSubImage1DEXT" => &self.MultiTexSubImage1DEXT,
+            "MultiTexSubImage2DEXT" => &self.MultiTexSubImage2DEXT,
+            "MultiTexSubImage3DEXT" => &self.MultiTexSubImage3DEXT,
+            "MulticastBarrierNV" => &self.MulticastBarrierNV,
+            "MulticastBlitFramebufferNV" => &self.MulticastBlitFramebufferNV,
+            "MulticastBufferSubDataNV" => &self.MulticastBufferSubDataNV,
+            "MulticastCopyBufferSubDataNV" => &self.MulticastCopyBufferSubDataNV,
+            "MulticastCopyImageSubDataNV" => &self.MulticastCopyImageSubDataNV,
+            "MulticastFramebufferSampleLocationsfvNV" => {
+                &self.MulticastFramebufferSampleLocationsfvNV
+            }
+            "MulticastGetQueryObjecti64vNV" => &self.MulticastGetQueryObjecti64vNV,
+            "MulticastGetQueryObjectivNV" => &self.MulticastGetQueryObjectivNV,
+            "MulticastGetQueryObjectui64vNV" => &self.MulticastGetQueryObjectui64vNV,
+            "MulticastGetQueryObjectuivNV" => &self.MulticastGetQueryObjectuivNV,
+            "MulticastScissorArrayvNVX" => &self.MulticastScissorArrayvNVX,
+            "MulticastViewportArrayvNVX" => &self.MulticastViewportArrayvNVX,
+            "MulticastViewportPositionWScaleNVX" => &self.MulticastViewportPositionWScaleNVX,
+            "MulticastWaitSyncNV" => &self.MulticastWaitSyncNV,
+            "NamedBufferAttachMemoryNV" => &self.NamedBufferAttachMemoryNV,
+            "NamedBufferData" => &self.NamedBufferData,
+            "NamedBufferDataEXT" => &self.NamedBufferDataEXT,
+            "NamedBufferPageCommitmentARB" => &self.NamedBufferPageCommitmentARB,
+            "NamedBufferPageCommitmentEXT" => &self.NamedBufferPageCommitmentEXT,
+            "NamedBufferPageCommitmentMemNV" => &self.NamedBufferPageCommitmentMemNV,
+            "NamedBufferStorage" => &self.NamedBufferStorage,
+            "NamedBufferStorageEXT" => &self.NamedBufferStorageEXT,
+            "NamedBufferStorageExternalEXT" => &self.NamedBufferStorageExternalEXT,
+            "NamedBufferStorageMemEXT" => &self.NamedBufferStorageMemEXT,
+            "NamedBufferSubData" => &self.NamedBufferSubData,
+            "NamedBufferSubDataEXT" => &self.NamedBufferSubDataEXT,
+            "NamedCopyBufferSubDataEXT" => &self.NamedCopyBufferSubDataEXT,
+            "NamedFramebufferDrawBuffer" => &self.NamedFramebufferDrawBuffer,
+            "NamedFramebufferDrawBuffers" => &self.NamedFramebufferDrawBuffers,
+            "NamedFramebufferParameteri" => &self.NamedFramebufferParameteri,
+            "NamedFramebufferParameteriEXT" => &self.NamedFramebufferParameteriEXT,
+            "NamedFramebufferReadBuffer" => &self.NamedFramebufferReadBuffer,
+            "NamedFramebufferRenderbuffer" => &self.NamedFramebufferRenderbuffer,
+            "NamedFramebufferRenderbufferEXT" => &self.NamedFramebufferRenderbufferEXT,
+            "NamedFramebufferSampleLocationsfvARB" => &self.NamedFramebufferSampleLocationsfvARB,
+            "NamedFramebufferSampleLocationsfvNV" => &self.NamedFramebufferSampleLocationsfvNV,
+            "NamedFramebufferSamplePositionsfvAMD" => &self.NamedFramebufferSamplePositionsfvAMD,
+            "NamedFramebufferTexture" => &self.NamedFramebufferTexture,
+            "NamedFramebufferTexture1DEXT" => &self.NamedFramebufferTexture1DEXT,
+            "NamedFramebufferTexture2DEXT" => &self.NamedFramebufferTexture2DEXT,
+            "NamedFramebufferTexture3DEXT" => &self.NamedFramebufferTexture3DEXT,
+            "NamedFramebufferTextureEXT" => &self.NamedFramebufferTextureEXT,
+            "NamedFramebufferTextureFaceEXT" => &self.NamedFramebufferTextureFaceEXT,
+            "NamedFramebufferTextureLayer" => &self.NamedFramebufferTextureLayer,
+            "NamedFramebufferTextureLayerEXT" => &self.NamedFramebufferTextureLayerEXT,
+            "NamedProgramLocalParameter4dEXT" => &self.NamedProgramLocalParameter4dEXT,
+            "NamedProgramLocalParameter4dvEXT" => &self.NamedProgramLocalParameter4dvEXT,
+            "NamedProgramLocalParameter4fEXT" => &self.NamedProgramLocalParameter4fEXT,
+            "NamedProgramLocalParameter4fvEXT" => &self.NamedProgramLocalParameter4fvEXT,
+            "NamedProgramLocalParameterI4iEXT" => &self.NamedProgramLocalParameterI4iEXT,
+            "NamedProgramLocalParameterI4ivEXT" => &self.NamedProgramLocalParameterI4ivEXT,
+            "NamedProgramLocalParameterI4uiEXT" => &self.NamedProgramLocalParameterI4uiEXT,
+            "NamedProgramLocalParameterI4uivEXT" => &self.NamedProgramLocalParameterI4uivEXT,
+            "NamedProgramLocalParameters4fvEXT" => &self.NamedProgramLocalParameters4fvEXT,
+            "NamedProgramLocalParametersI4ivEXT" => &self.NamedProgramLocalParametersI4ivEXT,
+            "NamedProgramLocalParametersI4uivEXT" => &self.NamedProgramLocalParametersI4uivEXT,
+            "NamedProgramStringEXT" => &self.NamedProgramStringEXT,
+            "NamedRenderbufferStorage" => &self.NamedRenderbufferStorage,
+            "NamedRenderbufferStorageEXT" => &self.NamedRenderbufferStorageEXT,
+            "NamedRenderbufferStorageMultisample" => &self.NamedRenderbufferStorageMultisample,
+            "NamedRenderbufferStorageMultisampleAdvancedAMD" => {
+                &self.NamedRenderbufferStorageMultisampleAdvancedAMD
+            }
+            "NamedRenderbufferStorageMultisampleCoverageEXT" => {
+                &self.NamedRenderbufferStorageMultisampleCoverageEXT
+            }
+            "NamedRenderbufferStorageMultisampleEXT" => {
+                &self.NamedRenderbufferStorageMultisampleEXT
+            }
+            "NamedStringARB" => &self.NamedStringARB,
+            "NewObjectBufferATI" => &self.NewObjectBufferATI,
+            "Normal3fVertex3fSUN" => &self.Normal3fVertex3fSUN,
+            "Normal3fVertex3fvSUN" => &self.Normal3fVertex3fvSUN,
+            "Normal3hNV" => &self.Normal3hNV,
+            "Normal3hvNV" => &self.Normal3hvNV,
+            "Normal3xOES" => &self.Normal3xOES,
+            "Normal3xvOES" => &self.Normal3xvOES,
+            "NormalFormatNV" => &self.NormalFormatNV,
+            "NormalPointerEXT" => &self.NormalPointerEXT,
+            "NormalPointerListIBM" => &self.NormalPointerListIBM,
+            "NormalPointervINTEL" => &self.NormalPointervINTEL,
+            "NormalStream3bATI" => &self.NormalStream3bATI,
+            "NormalStream3bvATI" => &self.NormalStream3bvATI,
+            "NormalStream3dATI" => &self.NormalStream3dATI,
+            "NormalStream3dvATI" => &self.NormalStream3dvATI,
+            "NormalStream3fATI" => &self.NormalStream3fATI,
+            "NormalStream3fvATI" => &self.NormalStream3fvATI,
+            "NormalStream3iATI" => &self.NormalStream3iATI,
+            "NormalStream3ivATI" => &self.NormalStream3ivATI,
+            "NormalStream3sATI" => &self.NormalStream3sATI,
+            "NormalStream3svATI" => &self.NormalStream3svATI,
+            "ObjectLabel" => &self.ObjectLabel,
+            "ObjectPtrLabel" => &self.ObjectPtrLabel,
+            "ObjectPurgeableAPPLE" => &self.ObjectPurgeableAPPLE,
+            "ObjectUnpurgeableAPPLE" => &self.ObjectUnpurgeableAPPLE,
+            "OrthofOES" => &self.OrthofOES,
+            "OrthoxOES" => &self.OrthoxOES,
+            "PNTrianglesfATI" => &self.PNTrianglesfATI,
+            "PNTrianglesiATI" => &self.PNTrianglesiATI,
+            "PassTexCoordATI" => &self.PassTexCoordATI,
+            "PassThroughxOES" => &self.PassThroughxOES,
+            "PatchParameterfv" => &self.PatchParameterfv,
+            "PatchParameteri" => &self.PatchParameteri,
+            "PathCommandsNV" => &self.PathCommandsNV,
+            "PathCoordsNV" => &self.PathCoordsNV,
+            "PathCoverDepthFuncNV" => &self.PathCoverDepthFuncNV,
+            "PathDashArrayNV" => &self.PathDashArrayNV,
+            "PathGlyphIndexArrayNV" => &self.PathGlyphIndexArrayNV,
+            "PathGlyphIndexRangeNV" => &self.PathGlyphIndexRangeNV,
+            "PathGlyphRangeNV" => &self.PathGlyphRangeNV,
+            "PathGlyphsNV" => &self.PathGlyphsNV,
+            "PathMemoryGlyphIndexArrayNV" => &self.PathMemoryGlyphIndexArrayNV,
+            "PathParameterfNV" => &self.PathParameterfNV,
+            "PathParameterfvNV" => &self.PathParameterfvNV,
+            "PathParameteriNV" => &self.PathParameteriNV,
+            "PathParameterivNV" => &self.PathParameterivNV,
+            "PathStencilDepthOffsetNV" => &self.PathStencilDepthOffsetNV,
+            "PathStencilFuncNV" => &self.PathStencilFuncNV,
+            "PathStringNV" => &self.PathStringNV,
+            "PathSubCommandsNV" => &self.PathSubCommandsNV,
+            "PathSubCoordsNV" => &self.PathSubCoordsNV,
+            "PauseTransformFeedback" => &self.PauseTransformFeedback,
+            "PauseTransformFeedbackNV" => &self.PauseTransformFeedbackNV,
+            "PixelDataRangeNV" => &self.PixelDataRangeNV,
+            "PixelMapx" => &self.PixelMapx,
+            "PixelStoref" => &self.PixelStoref,
+            "PixelStorei" => &self.PixelStorei,
+            "PixelStorex" => &self.PixelStorex,
+            "PixelTexGenParameterfSGIS" => &self.PixelTexGenParameterfSGIS,
+            "PixelTexGenParameterfvSGIS" => &self.PixelTexGenParameterfvSGIS,
+            "PixelTexGenParameteriSGIS" => &self.PixelTexGenParameteriSGIS,
+            "PixelTexGenParameterivSGIS" => &self.PixelTexGenParameterivSGIS,
+            "PixelTexGenSGIX" => &self.PixelTexGenSGIX,
+            "PixelTransferxOES" => &self.PixelTransferxOES,
+            "PixelTransformParameterfEXT" => &self.PixelTransformParameterfEXT,
+            "PixelTransformParameterfvEXT" => &self.PixelTransformParameterfvEXT,
+            "PixelTransformParameteriEXT" => &self.PixelTransformParameteriEXT,
+            "PixelTransformParameterivEXT" => &self.PixelTransformParameterivEXT,
+            "PixelZoomxOES" => &self.PixelZoomxOES,
+            "PointAlongPathNV" => &self.PointAlongPathNV,
+            "PointParameterf" => &self.PointParameterf,
+            "PointParameterfARB" => &self.PointParameterfARB,
+            "PointParameterfEXT" => &self.PointParameterfEXT,
+            "PointParameterfSGIS" => &self.PointParameterfSGIS,
+            "PointParameterfv" => &self.PointParameterfv,
+            "PointParameterfvARB" => &self.PointParameterfvARB,
+            "PointParameterfvEXT" => &self.PointParameterfvEXT,
+            "PointParameterfvSGIS" => &self.PointParameterfvSGIS,
+            "PointParameteri" => &self.PointParameteri,
+            "PointParameteriNV" => &self.PointParameteriNV,
+            "PointParameteriv" => &self.PointParameteriv,
+            "PointParameterivNV" => &self.PointParameterivNV,
+            "PointParameterxvOES" => &self.PointParameterxvOES,
+            "PointSize" => &self.PointSize,
+            "PointSizexOES" => &self.PointSizexOES,
+            "PollAsyncSGIX" => &self.PollAsyncSGIX,
+            "PollInstrumentsSGIX" => &self.PollInstrumentsSGIX,
+            "PolygonMode" => &self.PolygonMode,
+            "PolygonOffset" => &self.PolygonOffset,
+            "PolygonOffsetClamp" => &self.PolygonOffsetClamp,
+            "PolygonOffsetClampEXT" => &self.PolygonOffsetClampEXT,
+            "PolygonOffsetEXT" => &self.PolygonOffsetEXT,
+            "PolygonOffsetxOES" => &self.PolygonOffsetxOES,
+            "PopDebugGroup" => &self.PopDebugGroup,
+            "PopGroupMarkerEXT" => &self.PopGroupMarkerEXT,
+            "PresentFrameDualFillNV" => &self.PresentFrameDualFillNV,
+            "PresentFrameKeyedNV" => &self.PresentFrameKeyedNV,
+            "PrimitiveBoundingBoxARB" => &self.PrimitiveBoundingBoxARB,
+            "PrimitiveRestartIndex" => &self.PrimitiveRestartIndex,
+            "PrimitiveRestartIndexNV" => &self.PrimitiveRestartIndexNV,
+            "PrimitiveRestartNV" => &self.PrimitiveRestartNV,
+            "PrioritizeTexturesEXT" => &self.PrioritizeTexturesEXT,
+            "PrioritizeTexturesxOES" => &self.PrioritizeTexturesxOES,
+            "ProgramBinary" => &self.ProgramBinary,
+            "ProgramBufferParametersIivNV" => &self.ProgramBufferParametersIivNV,
+            "ProgramBufferParametersIuivNV" => &self.ProgramBufferParametersIuivNV,
+            "ProgramBufferParametersfvNV" => &self.ProgramBufferParametersfvNV,
+            "ProgramEnvParameter4dARB" => &self.ProgramEnvParameter4dARB,
+            "ProgramEnvParameter4dvARB" => &self.ProgramEnvParameter4dvARB,
+            "ProgramEnvParameter4fARB" => &self.ProgramEnvParameter4fARB,
+            "ProgramEnvParameter4fvARB" => &self.ProgramEnvParameter4fvARB,
+            "ProgramEnvParameterI4iNV" => &self.ProgramEnvParameterI4iNV,
+            "ProgramEnvParameterI4ivNV" => &self.ProgramEnvParameterI4ivNV,
+            "ProgramEnvParameterI4uiNV" => &self.ProgramEnvParameterI4uiNV,
+            "ProgramEnvParameterI4uivNV" => &self.ProgramEnvParameterI4uivNV,
+            "ProgramEnvParameters4fvEXT" => &self.ProgramEnvParameters4fvEXT,
+            "ProgramEnvParametersI4ivNV" => &self.ProgramEnvParametersI4ivNV,
+            "ProgramEnvParametersI4uivNV" => &self.ProgramEnvParametersI4uivNV,
+            "ProgramLocalParameter4dARB" => &self.ProgramLocalParameter4dARB,
+            "ProgramLocalParameter4dvARB" => &self.ProgramLocalParameter4dvARB,
+            "ProgramLocalParameter4fARB" => &self.ProgramLocalParameter4fARB,
+            "ProgramLocalParameter4fvARB" => &self.ProgramLocalParameter4fvARB,
+            "ProgramLocalParameterI4iNV" => &self.ProgramLocalParameterI4iNV,
+            "ProgramLocalParameterI4ivNV" => &self.ProgramLocalParameterI4ivNV,
+            "ProgramLocalParameterI4uiNV" => &self.ProgramLocalParameterI4uiNV,
+            "ProgramLocalParameterI4uivNV" => &self.ProgramLocalParameterI4uivNV,
+            "ProgramLocalParameters4fvEXT" => &self.ProgramLocalParameters4fvEXT,
+            "ProgramLocalParametersI4ivNV" => &self.ProgramLocalParametersI4ivNV,
+            "ProgramLocalParametersI4uivNV" => &self.ProgramLocalParametersI4uivNV,
+            "ProgramNamedParameter4dNV" => &self.ProgramNamedParameter4dNV,
+            "ProgramNamedParameter4dvNV" => &self.ProgramNamedParameter4dvNV,
+            "ProgramNamedParameter4fNV" => &self.ProgramNamedParameter4fNV,
+            "ProgramNamedParameter4fvNV" => &self.ProgramNamedParameter4fvNV,
+            "ProgramParameter4dNV" => &self.ProgramParameter4dNV,
+            "ProgramParameter4dvNV" => &self.ProgramParameter4dvNV,
+            "ProgramParameter4fNV" => &self.ProgramParameter4fNV,
+            "ProgramParameter4fvNV" => &self.ProgramParameter4fvNV,
+            "ProgramParameteri" => &self.ProgramParameteri,
+            "ProgramParameteriARB" => &self.ProgramParameteriARB,
+            "ProgramParameteriEXT" => &self.ProgramParameteriEXT,
+            "ProgramParameters4dvNV" => &self.ProgramParameters4dvNV,
+            "ProgramParameters4fvNV" => &self.ProgramParameters4fvNV,
+            "ProgramPathFragmentInputGenNV" => &self.ProgramPathFragmentInputGenNV,
+            "ProgramStringARB" => &self.ProgramStringARB,
+            "ProgramSubroutineParametersuivNV" => &self.ProgramSubroutineParametersuivNV,
+            "ProgramUniform1d" => &self.ProgramUniform1d,
+            "ProgramUniform1dEXT" => &self.ProgramUniform1dEXT,
+            "ProgramUniform1dv" => &self.ProgramUniform1dv,
+            "ProgramUniform1dvEXT" => &self.ProgramUniform1dvEXT,
+            "ProgramUniform1f" => &self.ProgramUniform1f,
+            "ProgramUniform1fEXT" => &self.ProgramUniform1fEXT,
+            "ProgramUniform1fv" => &self.ProgramUniform1fv,
+            "ProgramUniform1fvEXT" => &self.ProgramUniform1fvEXT,
+            "ProgramUniform1i" => &self.ProgramUniform1i,
+            "ProgramUniform1i64ARB" => &self.ProgramUniform1i64ARB,
+            "ProgramUniform1i64NV" => &self.ProgramUniform1i64NV,
+            "ProgramUniform1i64vARB" => &self.ProgramUniform1i64vARB,
+            "ProgramUniform1i64vNV" => &self.ProgramUniform1i64vNV,
+            "ProgramUniform1iEXT" => &self.ProgramUniform1iEXT,
+            "ProgramUniform1iv" => &self.ProgramUniform1iv,
+            "ProgramUniform1ivEXT" => &self.ProgramUniform1ivEXT,
+            "ProgramUniform1ui" => &self.ProgramUniform1ui,
+            "ProgramUniform1ui64ARB" => &self.ProgramUniform1ui64ARB,
+            "ProgramUniform1ui64NV" => &self.ProgramUniform1ui64NV,
+            "ProgramUniform1ui64vARB" => &self.ProgramUniform1ui64vARB,
+            "ProgramUniform1ui64vNV" => &self.ProgramUniform1ui64vNV,
+            "ProgramUniform1uiEXT" => &self.ProgramUniform1uiEXT,
+            "ProgramUniform1uiv" => &self.ProgramUniform1uiv,
+            "ProgramUniform1uivEXT" => &self.ProgramUniform1uivEXT,
+            "ProgramUniform2d" => &self.ProgramUniform2d,
+            "ProgramUniform2dEXT" => &self.ProgramUniform2dEXT,
+            "ProgramUniform2dv" => &self.ProgramUniform2dv,
+            "ProgramUniform2dvEXT" => &self.ProgramUniform2dvEXT,
+            "ProgramUniform2f" => &self.ProgramUniform2f,
+            "ProgramUniform2fEXT" => &self.ProgramUniform2fEXT,
+            "ProgramUniform2fv" => &self.ProgramUniform2fv,
+            "ProgramUniform2fvEXT" => &self.ProgramUniform2fvEXT,
+            "ProgramUniform2i" => &self.ProgramUniform2i,
+            "ProgramUniform2i64ARB" => &self.ProgramUniform2i64ARB,
+            "ProgramUniform2i64NV" => &self.ProgramUniform2i64NV,
+            "ProgramUniform2i64vARB" => &self.ProgramUniform2i64vARB,
+            "ProgramUniform2i64vNV" => &self.ProgramUniform2i64vNV,
+            "ProgramUniform2iEXT" => &self.ProgramUniform2iEXT,
+            "ProgramUniform2iv" => &self.ProgramUniform2iv,
+            "ProgramUniform2ivEXT" => &self.ProgramUniform2ivEXT,
+            "ProgramUniform2ui" => &self.ProgramUniform2ui,
+            "ProgramUniform2ui64ARB" => &self.ProgramUniform2ui64ARB,
+            "ProgramUniform2ui64NV" => &self.ProgramUniform2ui64NV,
+            "ProgramUniform2ui64vARB" => &self.ProgramUniform2ui64vARB,
+            "ProgramUniform2ui64vNV" => &self.ProgramUniform2ui64vNV,
+            "ProgramUniform2uiEXT" => &self.ProgramUniform2uiEXT,
+            "ProgramUniform2uiv" => &self.ProgramUniform2uiv,
+            "ProgramUniform2uivEXT" => &self.ProgramUniform2uivEXT,
+            "ProgramUniform3d" => &self.ProgramUniform3d,
+            "ProgramUniform3dEXT" => &self.ProgramUniform3dEXT,
+            "ProgramUniform3dv" => &self.ProgramUniform3dv,
+            "ProgramUniform3dvEXT" => &self.ProgramUniform3dvEXT,
+            "ProgramUniform3f" => &self.ProgramUniform3f,
+            "ProgramUniform3fEXT" => &self.ProgramUniform3fEXT,
+            "ProgramUniform3fv" => &self.ProgramUniform3fv,
+            "ProgramUniform3fvEXT" => &self.ProgramUniform3fvEXT,
+            "ProgramUniform3i" => &self.ProgramUniform3i,
+            "ProgramUniform3i64ARB" => &self.ProgramUniform3i64ARB,
+            "ProgramUniform3i64NV" => &self.ProgramUniform3i64NV,
+            "ProgramUniform3i64vARB" => &self.ProgramUniform3i64vARB,
+            "ProgramUniform3i64vNV" => &self.ProgramUniform3i64vNV,
+            "ProgramUniform3iEXT" => &self.ProgramUniform3iEXT,
+            "ProgramUniform3iv" => &self.ProgramUniform3iv,
+            "ProgramUniform3ivEXT" => &self.ProgramUniform3ivEXT,
+            "ProgramUniform3ui" => &self.ProgramUniform3ui,
+            "ProgramUniform3ui64ARB" => &self.ProgramUniform3ui64ARB,
+            "ProgramUniform3ui64NV" => &self.ProgramUniform3ui64NV,
+            "ProgramUniform3ui64vARB" => &self.ProgramUniform3ui64vARB,
+            "ProgramUniform3ui64vNV" => &self.ProgramUniform3ui64vNV,
+            "ProgramUniform3uiEXT" => &self.ProgramUniform3uiEXT,
+            "ProgramUniform3uiv" => &self.ProgramUniform3uiv,
+            "ProgramUniform3uivEXT" => &self.ProgramUniform3uivEXT,
+            "ProgramUniform4d" => &self.ProgramUniform4d,
+            "ProgramUniform4dEXT" => &self.ProgramUniform4dEXT,
+            "ProgramUniform4dv" => &self.ProgramUniform4dv,
+            "ProgramUniform4dvEXT" => &self.ProgramUniform4dvEXT,
+            "ProgramUniform4f" => &self.ProgramUniform4f,
+            "ProgramUniform4fEXT" => &self.ProgramUniform4fEXT,
+            "ProgramUniform4fv" => &self.ProgramUniform4fv,
+            "ProgramUniform4fvEXT" => &self.ProgramUniform4fvEXT,
+            "ProgramUniform4i" => &self.ProgramUniform4i,
+            "ProgramUniform4i64ARB" => &self.ProgramUniform4i64ARB,
+            "ProgramUniform4i64NV" => &self.ProgramUniform4i64NV,
+            "ProgramUniform4i64vARB" => &self.ProgramUniform4i64vARB,
+            "ProgramUniform4i64vNV" => &self.ProgramUniform4i64vNV,
+            "ProgramUniform4iEXT" => &self.ProgramUniform4iEXT,
+            "ProgramUniform4iv" => &self.ProgramUniform4iv,
+            "ProgramUniform4ivEXT" => &self.ProgramUniform4ivEXT,
+            "ProgramUniform4ui" => &self.ProgramUniform4ui,
+            "ProgramUniform4ui64ARB" => &self.ProgramUniform4ui64ARB,
+            "ProgramUniform4ui64NV" => &self.ProgramUniform4ui64NV,
+            "ProgramUniform4ui64vARB" => &self.ProgramUniform4ui64vARB,
+            "ProgramUniform4ui64vNV" => &self.ProgramUniform4ui64vNV,
+            "ProgramUniform4uiEXT" => &self.ProgramUniform4uiEXT,
+            "ProgramUniform4uiv" => &self.ProgramUniform4uiv,
+            "ProgramUniform4uivEXT" => &self.ProgramUniform4uivEXT,
+            "ProgramUniformHandleui64ARB" => &self.ProgramUniformHandleui64ARB,
+            "ProgramUniformHandleui64NV" => &self.ProgramUniformHandleui64NV,
+            "ProgramUniformHandleui64vARB" => &self.ProgramUniformHandleui64vARB,
+            "ProgramUniformHandleui64vNV" => &self.ProgramUniformHandleui64vNV,
+            "ProgramUniformMatrix2dv" => &self.ProgramUniformMatrix2dv,
+            "ProgramUniformMatrix2dvEXT" => &self.ProgramUniformMatrix2dvEXT,
+            "ProgramUniformMatrix2fv" => &self.ProgramUniformMatrix2fv,
+            "ProgramUniformMatrix2fvEXT" => &self.ProgramUniformMatrix2fvEXT,
+            "ProgramUniformMatrix2x3dv" => &self.ProgramUniformMatrix2x3dv,
+            "ProgramUniformMatrix2x3dvEXT" => &self.ProgramUniformMatrix2x3dvEXT,
+            "ProgramUniformMatrix2x3fv" => &self.ProgramUniformMatrix2x3fv,
+            "ProgramUniformMatrix2x3fvEXT" => &self.ProgramUniformMatrix2x3fvEXT,
+            "ProgramUniformMatrix2x4dv" => &self.ProgramUniformMatrix2x4dv,
+            "ProgramUniformMatrix2x4dvEXT" => &self.ProgramUniformMatrix2x4dvEXT,
+            "ProgramUniformMatrix2x4fv" => &self.ProgramUniformMatrix2x4fv,
+            "ProgramUniformMatrix2x4fvEXT" => &self.ProgramUniformMatrix2x4fvEXT,
+            "ProgramUniformMatrix3dv" => &self.ProgramUniformMatrix3dv,
+            "ProgramUniformMatrix3dvEXT" => &self.ProgramUniformMatrix3dvEXT,
+            "ProgramUniformMatrix3fv" => &self.ProgramUniformMatrix3fv,
+            "ProgramUniformMatrix3fvEXT" => &self.ProgramUniformMatrix3fvEXT,
+            "ProgramUniformMatrix3x2dv" => &self.ProgramUniformMatrix3x2dv,
+            "ProgramUniformMatrix3x2dvEXT" => &self.ProgramUniformMatrix3x2dvEXT,
+            "ProgramUniformMatrix3x2fv" => &self.ProgramUniformMatrix3x2fv,
+            "ProgramUniformMatrix3x2fvEXT" => &self.ProgramUniformMatrix3x2fvEXT,
+            "ProgramUniformMatrix3x4dv" => &self.ProgramUniformMatrix3x4dv,
+            "ProgramUniformMatrix3x4dvEXT" => &self.ProgramUniformMatrix3x4dvEXT,
+            "ProgramUniformMatrix3x4fv" => &self.ProgramUniformMatrix3x4fv,
+            "ProgramUniformMatrix3x4fvEXT" => &self.ProgramUniformMatrix3x4fvEXT,
+            "ProgramUniformMatrix4dv" => &self.ProgramUniformMatrix4dv,
+            "ProgramUniformMatrix4dvEXT" => &self.ProgramUniformMatrix4dvEXT,
+            "ProgramUniformMatrix4fv" => &self.ProgramUniformMatrix4fv,
+            "ProgramUniformMatrix4fvEXT" => &self.ProgramUniformMatrix4fvEXT,
+            "ProgramUniformMatrix4x2dv" => &self.ProgramUniformMatrix4x2dv,
+            "ProgramUniformMatrix4x2dvEXT" => &self.ProgramUniformMatrix4x2dvEXT,
+            "ProgramUniformMatrix4x2fv" => &self.ProgramUniformMatrix4x2fv,
+            "ProgramUniformMatrix4x2fvEXT" => &self.ProgramUniformMatrix4x2fvEXT,
+            "ProgramUniformMatrix4x3dv" => &self.ProgramUniformMatrix4x3dv,
+            "ProgramUniformMatrix4x3dvEXT" => &self.ProgramUniformMatrix4x3dvEXT,
+            "ProgramUniformMatrix4x3fv" => &self.ProgramUniformMatrix4x3fv,
+            "ProgramUniformMatrix4x3fvEXT" => &self.ProgramUniformMatrix4x3fvEXT,
+            "ProgramUniformui64NV" => &self.ProgramUniformui64NV,
+            "ProgramUniformui64vNV" => &self.ProgramUniformui64vNV,
+            "ProgramVertexLimitNV" => &self.ProgramVertexLimitNV,
+            "ProvokingVertex" => &self.ProvokingVertex,
+            "ProvokingVertexEXT" => &self.ProvokingVertexEXT,
+            "PushClientAttribDefaultEXT" => &self.PushClientAttribDefaultEXT,
+            "PushDebugGroup" => &self.PushDebugGroup,
+            "PushGroupMarkerEXT" => &self.PushGroupMarkerEXT,
+            "QueryCounter" => &self.QueryCounter,
+            "QueryMatrixxOES" => &self.QueryMatrixxOES,
+            "QueryObjectParameteruiAMD" => &self.QueryObjectParameteruiAMD,
+            "QueryResourceNV" => &self.QueryResourceNV,
+            "QueryResourceTagNV" => &self.QueryResourceTagNV,
+            "RasterPos2xOES" => &self.RasterPos2xOES,
+            "RasterPos2xvOES" => &self.RasterPos2xvOES,
+            "RasterPos3xOES" => &self.RasterPos3xOES,
+            "RasterPos3xvOES" => &self.RasterPos3xvOES,
+            "RasterPos4xOES" => &self.RasterPos4xOES,
+            "RasterPos4xvOES" => &self.RasterPos4xvOES,
+            "RasterSamplesEXT" => &self.RasterSamplesEXT,
+            "ReadBuffer" => &self.ReadBuffer,
+            "ReadInstrumentsSGIX" => &self.ReadInstrumentsSGIX,
+            "ReadPixels" => &self.ReadPixels,
+            "ReadnPixels" => &self.ReadnPixels,
+            "ReadnPixelsARB" => &self.ReadnPixelsARB,
+            "RectxOES" => &self.RectxOES,
+            "RectxvOES" => &self.RectxvOES,
+            "ReferencePlaneSGIX" => &self.ReferencePlaneSGIX,
+            "ReleaseKeyedMutexWin32EXT" => &self.ReleaseKeyedMutexWin32EXT,
+            "ReleaseShaderCompiler" => &self.ReleaseShaderCompiler,
+            "RenderGpuMaskNV" => &self.RenderGpuMaskNV,
+            "RenderbufferStorage" => &self.RenderbufferStorage,
+            "RenderbufferStorageEXT" => &self.RenderbufferStorageEXT,
+            "RenderbufferStorageMultisample" => &self.RenderbufferStorageMultisample,
+            "RenderbufferStorageMultisampleAdvancedAMD" => {
+                &self.RenderbufferStorageMultisampleAdvancedAMD
+            }
+            "RenderbufferStorageMultisampleCoverageNV" => {
+                &self.RenderbufferStorageMultisampleCoverageNV
+            }
+            "RenderbufferStorageMultisampleEXT" => &self.RenderbufferStorageMultisampleEXT,
+            "ReplacementCodePointerSUN" => &self.ReplacementCodePointerSUN,
+            "ReplacementCodeubSUN" => &self.ReplacementCodeubSUN,
+            "ReplacementCodeubvSUN" => &self.ReplacementCodeubvSUN,
+            "ReplacementCodeuiColor3fVertex3fSUN" => &self.ReplacementCodeuiColor3fVertex3fSUN,
+            "ReplacementCodeuiColor3fVertex3fvSUN" => &self.ReplacementCodeuiColor3fVertex3fvSUN,
+            "ReplacementCodeuiColor4fNormal3fVertex3fSUN" => {
+                &self.ReplacementCodeuiColor4fNormal3fVertex3fSUN
+            }
+            "ReplacementCodeuiColor4fNormal3fVertex3fvSUN" => {
+                &self.ReplacementCodeuiColor4fNormal3fVertex3fvSUN
+            }
+            "ReplacementCodeuiColor4ubVertex3fSUN" => &self.ReplacementCodeuiColor4ubVertex3fSUN,
+            "ReplacementCodeuiColor4ubVertex3fvSUN" => &self.ReplacementCodeuiColor4ubVertex3fvSUN,
+            "ReplacementCodeuiNormal3fVertex3fSUN" => &self.ReplacementCodeuiNormal3fVertex3fSUN,
+            "ReplacementCodeuiNormal3fVertex3fvSUN" => &self.ReplacementCodeuiNormal3fVertex3fvSUN,
+            "ReplacementCodeuiSUN" => &self.ReplacementCodeuiSUN,
+            "ReplacementCodeuiTexCoord2fColor4fNormal3fVertex3fSUN" => {
+                &self.ReplacementCodeuiTexCoord2fColor4fNormal3fVertex3fSUN
+            }
+            "ReplacementCodeuiTexCoord2fColor4fNormal3fVertex3fvSUN" => {
+                &self.ReplacementCodeuiTexCoord2fColor4fNormal3fVertex3fvSUN
+            }
+            "ReplacementCodeuiTexCoord2fNormal3fVertex3fSUN" => {
+                &self.ReplacementCodeuiTexCoord2fNormal3fVertex3fSUN
+            }
+            "ReplacementCodeuiTexCoord2fNormal3fVertex3fvSUN" => {
+                &self.ReplacementCodeuiTexCoord2fNormal3fVertex3fvSUN
+            }
+            "ReplacementCodeuiTexCoord2fVertex3fSUN" => {
+                &self.ReplacementCodeuiTexCoord2fVertex3fSUN
+            }
+            "ReplacementCodeuiTexCoord2fVertex3fvSUN" => {
+                &self.ReplacementCodeuiTexCoord2fVertex3fvSUN
+            }
+            "ReplacementCodeuiVertex3fSUN" => &self.ReplacementCodeuiVertex3fSUN,
+            "ReplacementCodeuiVertex3fvSUN" => &self.ReplacementCodeuiVertex3fvSUN,
+            "ReplacementCodeuivSUN" => &self.ReplacementCodeuivSUN,
+            "ReplacementCodeusSUN" => &self.ReplacementCodeusSUN,
+            "ReplacementCodeusvSUN" => &self.ReplacementCodeusvSUN,
+            "RequestResidentProgramsNV" => &self.RequestResidentProgramsNV,
+            "ResetHistogramEXT" => &self.ResetHistogramEXT,
+            "ResetMemoryObjectParameterNV" => &self.ResetMemoryObjectParameterNV,
+            "ResetMinmaxEXT" => &self.ResetMinmaxEXT,
+            "ResizeBuffersMESA" => &self.ResizeBuffersMESA,
+            "ResolveDepthValuesNV" => &self.ResolveDepthValuesNV,
+            "ResumeTransformFeedback" => &self.ResumeTransformFeedback,
+            "ResumeTransformFeedbackNV" => &self.ResumeTransformFeedbackNV,
+            "RotatexOES" => &self.RotatexOES,
+            "SampleCoverage" => &self.SampleCoverage,
+            "SampleCoverageARB" => &self.SampleCoverageARB,
+            "SampleMapATI" => &self.SampleMapATI,
+            "SampleMaskEXT" => &self.SampleMaskEXT,
+            "SampleMaskIndexedNV" => &self.SampleMaskIndexedNV,
+            "SampleMaskSGIS" => &self.SampleMaskSGIS,
+            "SampleMaski" => &self.SampleMaski,
+            "SamplePatternEXT" => &self.SamplePatternEXT,
+            "SamplePatternSGIS" => &self.SamplePatternSGIS,
+            "SamplerParameterIiv" => &self.SamplerParameterIiv,
+            "SamplerParameterIuiv" => &self.SamplerParameterIuiv,
+            "SamplerParameterf" => &self.SamplerParameterf,
+            "SamplerParameterfv" => &self.SamplerParameterfv,
+            "SamplerParameteri" => &self.SamplerParameteri,
+            "SamplerParameteriv" => &self.SamplerParameteriv,
+            "ScalexOES" => &self.ScalexOES,
+            "Scissor" => &self.Scissor,
+            "ScissorArrayv" => &self.ScissorArrayv,
+            "ScissorExclusiveArrayvNV" => &self.ScissorExclusiveArrayvNV,
+            "ScissorExclusiveNV" => &self.ScissorExclusiveNV,
+            "ScissorIndexed" => &self.ScissorIndexed,
+            "ScissorIndexedv" => &self.ScissorIndexedv,
+            "SecondaryColor3bEXT" => &self.SecondaryColor3bEXT,
+            "SecondaryColor3bvEXT" => &self.SecondaryColor3bvEXT,
+            "SecondaryColor3dEXT" => &self.SecondaryColor3dEXT,
+            "SecondaryColor3dvEXT" => &self.SecondaryColor3dvEXT,
+            "SecondaryColor3fEXT" => &self.SecondaryColor3fEXT,
+            "SecondaryColor3fvEXT" => &self.SecondaryColor3fvEXT,
+            "SecondaryColor3hNV" => &self.SecondaryColor3hNV,
+            "SecondaryColor3hvNV" => &self.SecondaryColor3hvNV,
+            "SecondaryColor3iEXT" => &self.SecondaryColor3iEXT,
+            "SecondaryColor3ivEXT" => &self.SecondaryColor3ivEXT,
+            "SecondaryColor3sEXT" => &self.SecondaryColor3sEXT,
+            "SecondaryColor3svEXT" => &self.SecondaryColor3svEXT,
+            "SecondaryColor3ubEXT" => &self.SecondaryColor3ubEXT,
+            "SecondaryColor3ubvEXT" => &self.SecondaryColor3ubvEXT,
+            "SecondaryColor3uiEXT" => &self.SecondaryColor3uiEXT,
+            "SecondaryColor3uivEXT" => &self.SecondaryColor3uivEXT,
+            "SecondaryColor3usEXT" => &self.SecondaryColor3usEXT,
+            "SecondaryColor3usvEXT" => &self.SecondaryColor3usvEXT,
+            "SecondaryColorFormatNV" => &self.SecondaryColorFormatNV,
+            "SecondaryColorPointerEXT" => &self.SecondaryColorPointerEXT,
+            "SecondaryColorPointerListIBM" => &self.SecondaryColorPointerListIBM,
+            "SelectPerfMonitorCountersAMD" => &self.SelectPerfMonitorCountersAMD,
+            "SemaphoreParameterivNV" => &self.SemaphoreParameterivNV,
+            "SemaphoreParameterui64vEXT" => &self.SemaphoreParameterui64vEXT,
+            "SeparableFilter2DEXT" => &self.SeparableFilter2DEXT,
+            "SetFenceAPPLE" => &self.SetFenceAPPLE,
+            "SetFenceNV" => &self.SetFenceNV,
+            "SetFragmentShaderConstantATI" => &self.SetFragmentShaderConstantATI,
+            "SetInvariantEXT" => &self.SetInvariantEXT,
+            "SetLocalConstantEXT" => &self.SetLocalConstantEXT,
+            "SetMultisamplefvAMD" => &self.SetMultisamplefvAMD,
+            "ShaderBinary" => &self.ShaderBinary,
+            "ShaderOp1EXT" => &self.ShaderOp1EXT,
+            "ShaderOp2EXT" => &self.ShaderOp2EXT,
+            "ShaderOp3EXT" => &self.ShaderOp3EXT,
+            "ShaderSource" => &self.ShaderSource,
+            "ShaderSourceARB" => &self.ShaderSourceARB,
+            "ShaderStorageBlockBinding" => &self.ShaderStorageBlockBinding,
+            "ShadingRateImageBarrierNV" => &self.ShadingRateImageBarrierNV,
+            "ShadingRateImagePaletteNV" => &self.ShadingRateImagePaletteNV,
+            "ShadingRateSampleOrderCustomNV" => &self.ShadingRateSampleOrderCustomNV,
+            "ShadingRateSampleOrderNV" => &self.ShadingRateSampleOrderNV,
+            "SharpenTexFuncSGIS" => &self.SharpenTexFuncSGIS,
+            "SignalSemaphoreEXT" => &self.SignalSemaphoreEXT,
+            "SignalSemaphoreui64NVX" => &self.SignalSemaphoreui64NVX,
+            "SignalVkFenceNV" => &self.SignalVkFenceNV,
+            "SignalVkSemaphoreNV" => &self.SignalVkSemaphoreNV,
+            "SpecializeShader" => &self.SpecializeShader,
+            "SpecializeShaderARB" => &self.SpecializeShaderARB,
+            "SpriteParameterfSGIX" => &self.SpriteParameterfSGIX,
+            "SpriteParameterfvSGIX" => &self.SpriteParameterfvSGIX,
+            "SpriteParameteriSGIX" => &self.SpriteParameteriSGIX,
+            "SpriteParameterivSGIX" => &self.SpriteParameterivSGIX,
+            "StartInstrumentsSGIX" => &self.StartInstrumentsSGIX,
+            "StateCaptureNV" => &self.StateCaptureNV,
+            "StencilClearTagEXT" => &self.StencilClearTagEXT,
+            "StencilFillPathInstancedNV" => &self.StencilFillPathInstancedNV,
+            "StencilFillPathNV" => &self.StencilFillPathNV,
+            "StencilFunc" => &self.StencilFunc,
+            "StencilFuncSeparate" => &self.StencilFuncSeparate,
+            "StencilFuncSeparateATI" => &self.StencilFuncSeparateATI,
+            "StencilMask" => &self.StencilMask,
+            "StencilMaskSeparate" => &self.StencilMaskSeparate,
+            "StencilOp" => &self.StencilOp,
+            "StencilOpSeparate" => &self.StencilOpSeparate,
+            "StencilOpSeparateATI" => &self.StencilOpSeparateATI,
+            "StencilOpValueAMD" => &self.StencilOpValueAMD,
+            "StencilStrokePathInstancedNV" => &self.StencilStrokePathInstancedNV,
+            "StencilStrokePathNV" => &self.StencilStrokePathNV,
+            "StencilThenCoverFillPathInstancedNV" => &self.StencilThenCoverFillPathInstancedNV,
+            "StencilThenCoverFillPathNV" => &self.StencilThenCoverFillPathNV,
+            "StencilThenCoverStrokePathInstancedNV" => &self.StencilThenCoverStrokePathInstancedNV,
+            "StencilThenCoverStrokePathNV" => &self.StencilThenCoverStrokePathNV,
+            "StopInstrumentsSGIX" => &self.StopInstrumentsSGIX,
+            "StringMarkerGREMEDY" => &self.StringMarkerGREMEDY,
+            "SubpixelPrecisionBiasNV" => &self.SubpixelPrecisionBiasNV,
+            "SwizzleEXT" => &self.SwizzleEXT,
+            "SyncTextureINTEL" => &self.SyncTextureINTEL,
+            "TagSampleBufferSGIX" => &self.TagSampleBufferSGIX,
+            "Tangent3bEXT" => &self.Tangent3bEXT,
+            "Tangent3bvEXT" => &self.Tangent3bvEXT,
+            "Tangent3dEXT" => &self.Tangent3dEXT,
+            "Tangent3dvEXT" => &self.Tangent3dvEXT,
+            "Tangent3fEXT" => &self.Tangent3fEXT,
+            "Tangent3fvEXT" => &self.Tangent3fvEXT,
+            "Tangent3iEXT" => &self.Tangent3iEXT,
+            "Tangent3ivEXT" => &self.Tangent3ivEXT,
+            "Tangent3sEXT" => &self.Tangent3sEXT,
+            "Tangent3svEXT" => &self.Tangent3svEXT,
+            "TangentPointerEXT" => &self.TangentPointerEXT,
+            "TbufferMask3DFX" => &self.TbufferMask3DFX,
+            "TessellationFactorAMD" => &self.TessellationFactorAMD,
+            "TessellationModeAMD" => &self.TessellationModeAMD,
+            "TestFenceAPPLE" => &self.TestFenceAPPLE,
+            "TestFenceNV" => &self.TestFenceNV,
+            "TestObjectAPPLE" => &self.TestObjectAPPLE,
+            "TexAttachMemoryNV" => &self.TexAttachMemoryNV,
+            "TexBuffer" => &self.TexBuffer,
+            "TexBufferARB" => &self.TexBufferARB,
+            "TexBufferEXT" => &self.TexBufferEXT,
+            "TexBufferRange" => &self.TexBufferRange,
+            "TexBumpParameterfvATI" => &self.TexBumpParameterfvATI,
+            "TexBumpParameterivATI" => &self.TexBumpParameterivATI,
+            "TexCoord1bOES" => &self.TexCoord1bOES,
+            "TexCoord1bvOES" => &self.TexCoord1bvOES,
+            "TexCoord1hNV" => &self.TexCoord1hNV,
+            "TexCoord1hvNV" => &self.TexCoord1hvNV,
+            "TexCoord1xOES" => &self.TexCoord1xOES,
+            "TexCoord1xvOES" => &self.TexCoord1xvOES,
+            "TexCoord2bOES" => &self.TexCoord2bOES,
+            "TexCoord2bvOES" => &self.TexCoord2bvOES,
+            "TexCoord2fColor3fVertex3fSUN" => &self.TexCoord2fColor3fVertex3fSUN,
+            "TexCoord2fColor3fVertex3fvSUN" => &self.TexCoord2fColor3fVertex3fvSUN,
+            "TexCoord2fColor4fNormal3fVertex3fSUN" => &self.TexCoord2fColor4fNormal3fVertex3fSUN,
+            "TexCoord2fColor4fNormal3fVertex3fvSUN" => &self.TexCoord2fColor4fNormal3fVertex3fvSUN,
+            "TexCoord2fColor4ubVertex3fSUN" => &self.TexCoord2fColor4ubVertex3fSUN,
+            "TexCoord2fColor4ubVertex3fvSUN" => &self.TexCoord2fColor4ubVertex3fvSUN,
+            "TexCoord2fNormal3fVertex3fSUN" => &self.TexCoord2fNormal3fVertex3fSUN,
+            "TexCoord2fNormal3fVertex3fvSUN" => &self.TexCoord2fNormal3fVertex3fvSUN,
+            "TexCoord2fVertex3fSUN" => &self.TexCoord2fVertex3fSUN,
+            "TexCoord2fVertex3fvSUN" => &self.TexCoord2fVertex3fvSUN,
+            "TexCoord2hNV" => &self.TexCoord2hNV,
+            "TexCoord2hvNV" => &self.TexCoord2hvNV,
+            "TexCoord2xOES" => &self.TexCoord2xOES,
+            "TexCoord2xvOES" => &self.TexCoord2xvOES,
+            "TexCoord3bOES" => &self.TexCoord3bOES,
+            "TexCoord3bvOES" => &self.TexCoord3bvOES,
+            "TexCoord3hNV" => &self.TexCoord3hNV,
+            "TexCoord3hvNV" => &self.TexCoord3hvNV,
+            "TexCoord3xOES" => &self.TexCoord3xOES,
+            "TexCoord3xvOES" => &self.TexCoord3xvOES,
+            "TexCoord4bOES" => &self.TexCoord4bOES,
+            "TexCoord4bvOES" => &self.TexCoord4bvOES,
+            "TexCoord4fColor4fNormal3fVertex4fSUN" => &self.TexCoord4fColor4fNormal3fVertex4fSUN,
+            "TexCoord4fColor4fNormal3fVertex4fvSUN" => &self.TexCoord4fColor4fNormal3fVertex4fvSUN,
+            "TexCoord4fVertex4fSUN" => &self.TexCoord4fVertex4fSUN,
+            "TexCoord4fVertex4fvSUN" => &self.TexCoord4fVertex4fvSUN,
+            "TexCoord4hNV" => &self.TexCoord4hNV,
+            "TexCoord4hvNV" => &self.TexCoord4hvNV,
+            "TexCoord4xOES" => &self.TexCoord4xOES,
+            "TexCoord4xvOES" => &self.TexCoord4xvOES,
+            "TexCoordFormatNV" => &self.TexCoordFormatNV,
+            "TexCoordPointerEXT" => &self.TexCoordPointerEXT,
+            "TexCoordPointerListIBM" => &self.TexCoordPointerListIBM,
+            "TexCoordPointervINTEL" => &self.TexCoordPointervINTEL,
+            "TexEnvxOES" => &self.TexEnvxOES,
+            "TexEnvxvOES" => &self.TexEnvxvOES,
+            "TexFilterFuncSGIS" => &self.TexFilterFuncSGIS,
+            "TexGenxOES" => &self.TexGenxOES,
+            "TexGenxvOES" => &self.TexGenxvOES,
+            "TexImage1D" => &self.TexImage1D,
+            "TexImage2D" => &self.TexImage2D,
+            "TexImage2DMultisample" => &self.TexImage2DMultisample,
+            "TexImage2DMultisampleCoverageNV" => &self.TexImage2DMultisampleCoverageNV,
+            "TexImage3D" => &self.TexImage3D,
+            "TexImage3DEXT" => &self.TexImage3DEXT,
+            "TexImage3DMultisample" => &self.TexImage3DMultisample,
+            "TexImage3DMultisampleCoverageNV" => &self.TexImage3DMultisampleCoverageNV,
+            "TexImage4DSGIS" => &self.TexImage4DSGIS,
+            "TexPageCommitmentARB" => &self.TexPageCommitmentARB,
+            "TexPageCommitmentMemNV" => &self.TexPageCommitmentMemNV,
+            "TexParameterIiv" => &self.TexParameterIiv,
+            "TexParameterIivEXT" => &self.TexParameterIivEXT,
+            "TexParameterIuiv" => &self.TexParameterIuiv,
+            "TexParameterIuivEXT" => &self.TexParameterIuivEXT,
+            "TexParameterf" => &self.TexParameterf,
+            "TexParameterfv" => &self.TexParameterfv,
+            "TexParameteri" => &self.TexParameteri,
+            "TexParameteriv" => &self.TexParameteriv,
+            "TexParameterxOES" => &self.TexParameterxOES,
+            "TexParameterxvOES" => &self.TexParameterxvOES,
+            "TexRenderbufferNV" => &self.TexRenderbufferNV,
+            "TexStorage1D" => &self.TexStorage1D,
+            "TexStorage1DEXT" => &self.TexStorage1DEXT,
+            "TexStorage2D" => &self.TexStorage2D,
+            "TexStorage2DEXT" => &self.TexStorage2DEXT,
+            "TexStorage2DMultisample" => &self.TexStorage2DMultisample,
+            "TexStorage3D" => &self.TexStorage3D,
+            "TexStorage3DEXT" => &self.TexStorage3DEXT,
+            "TexStorage3DMultisample" => &self.TexStorage3DMultisample,
+            "TexStorageMem1DEXT" => &self.TexStorageMem1DEXT,
+            "TexStorageMem2DEXT" => &self.TexStorageMem2DEXT,
+            "TexStorageMem2DMultisampleEXT" => &self.TexStorageMem2DMultisampleEXT,
+            "TexStorageMem3DEXT" => &self.TexStorageMem3DEXT,
+            "TexStorageMem3DMultisampleEXT" => &self.TexStorageMem3DMultisampleEXT,
+            "TexStorageSparseAMD" => &self.TexStorageSparseAMD,
+            "TexSubImage1D" => &self.TexSubImage1D,
+            "TexSubImage1DEXT" => &self.TexSubImage1DEXT,
+            "TexSubImage2D" => &self.TexSubImage2D,
+            "TexSubImage2DEXT" => &self.TexSubImage2DEXT,
+            "TexSubImage3D" => &self.TexSubImage3D,
+            "TexSubImage3DEXT" => &self.TexSubImage3DEXT,
+            "TexSubImage4DSGIS" => &self.TexSubImage4DSGIS,
+            "TextureAttachMemoryNV" => &self.TextureAttachMemoryNV,
+            "TextureBarrier" => &self.TextureBarrier,
+            "TextureBarrierNV" => &self.TextureBarrierNV,
+            "TextureBuffer" => &self.TextureBuffer,
+            "TextureBufferEXT" => &self.TextureBufferEXT,
+            "TextureBufferRange" => &self.TextureBufferRange,
+            "TextureBufferRangeEXT" => &self.TextureBufferRangeEXT,
+            "TextureColorMaskSGIS" => &self.TextureColorMaskSGIS,
+            "TextureImage1DEXT" => &self.TextureImage1DEXT,
+            "TextureImage2DEXT" => &self.TextureImage2DEXT,
+            "TextureImage2DMultisampleCoverageNV" => &self.TextureImage2DMultisampleCoverageNV,
+            "TextureImage2DMultisampleNV" => &self.TextureImage2DMultisampleNV,
+            "TextureImage3DEXT" => &self.TextureImage3DEXT,
+            "TextureImage3DMultisampleCoverageNV" => &self.TextureImage3DMultisampleCoverageNV,
+            "TextureImage3DMultisampleNV" => &self.TextureImage3DMultisampleNV,
+            "TextureLightEXT" => &self.TextureLightEXT,
+            "TextureMaterialEXT" => &self.TextureMaterialEXT,
+            "TextureNormalEXT" => &self.TextureNormalEXT,
+            "TexturePageCommitmentEXT" => &self.TexturePageCommitmentEXT,
+            "TexturePageCommitmentMemNV" => &self.TexturePageCommitmentMemNV,
+            "TextureParameterIiv" => &self.TextureParameterIiv,
+            "TextureParameterIivEXT" => &self.TextureParameterIivEXT,
+            "TextureParameterIuiv" => &self.TextureParameterIuiv,
+            "TextureParameterIuivEXT" => &self.TextureParameterIuivEXT,
+            "TextureParameterf" => &self.TextureParameterf,
+            "TextureParameterfEXT" => &self.TextureParameterfEXT,
+            "TextureParameterfv" => &self.TextureParameterfv,
+            "TextureParameterfvEXT" => &self.TextureParameterfvEXT,
+            "TextureParameteri" => &self.TextureParameteri,
+            "TextureParameteriEXT" => &self.TextureParameteriEXT,
+            "TextureParameteriv" => &self.TextureParameteriv,
+            "TextureParameterivEXT" => &self.TextureParameterivEXT,
+            "TextureRangeAPPLE" => &self.TextureRangeAPPLE,
+            "TextureRenderbufferEXT" => &self.TextureRenderbufferEXT,
+            "TextureStorage1D" => &self.TextureStorage1D,
+            "TextureStorage1DEXT" => &self.TextureStorage1DEXT,
+            "TextureStorage2D" => &self.TextureStorage2D,
+            "TextureStorage2DEXT" => &self.TextureStorage2DEXT,
+            "TextureStorage2DMultisample" => &self.TextureStorage2DMultisample,
+            "TextureStorage2DMultisampleEXT" => &self.TextureStorage2DMultisampleEXT,
+            "TextureStorage3D" => &self.TextureStorage3D,
+            "TextureStorage3DEXT" => &self.TextureStorage3DEXT,
+            "TextureStorage3DMultisample" => &self.TextureStorage3DMultisample,
+            "TextureStorage3DMultisampleEXT" => &self.TextureStorage3DMultisampleEXT,
+            "TextureStorageMem1DEXT" => &self.TextureStorageMem1DEXT,
+            "TextureStorageMem2DEXT" => &self.TextureStorageMem2DEXT,
+            "TextureStorageMem2DMultisampleEXT" => &self.TextureStorageMem2DMultisampleEXT,
+            "TextureStorageMem3DEXT" => &self.TextureStorageMem3DEXT,
+            "TextureStorageMem3DMultisampleEXT" => &self.TextureStorageMem3DMultisampleEXT,
+            "TextureStorageSparseAMD" => &self.TextureStorageSparseAMD,
+            "TextureSubImage1D" => &self.TextureSubImage1D,
+            "TextureSubImage1DEXT" => &self.TextureSubImage1DEXT,
+            "TextureSubImage2D" => &self.TextureSubImage2D,
+            "TextureSubImage2DEXT" => &self.TextureSubImage2DEXT,
+            "TextureSubImage3D" => &self.TextureSubImage3D,
+            "TextureSubImage3DEXT" => &self.TextureSubImage3DEXT,
+            "TextureView" => &self.TextureView,
+            "TrackMatrixNV" => &self.TrackMatrixNV,
+            "TransformFeedbackAttribsNV" => &self.TransformFeedbackAttribsNV,
+            "TransformFeedbackBufferBase" => &self.TransformFeedbackBufferBase,
+            "TransformFeedbackBufferRange" => &self.TransformFeedbackBufferRange,
+            "TransformFeedbackStreamAttribsNV" => &self.TransformFeedbackStreamAttribsNV,
+            "TransformFeedbackVaryings" => &self.TransformFeedbackVaryings,
+            "TransformFeedbackVaryingsEXT" => &self.TransformFeedbackVaryingsEXT,
+            "TransformFeedbackVaryingsNV" => &self.TransformFeedbackVaryingsNV,
+            "TransformPathNV" => &self.TransformPathNV,
+            "TranslatexOES" => &self.TranslatexOES,
+            "Uniform1d" => &self.Uniform1d,
+            "Uniform1dv" => &self.Uniform1dv,
+            "Uniform1f" => &self.Uniform1f,
+            "Uniform1fARB" => &self.Uniform1fARB,
+            "Uniform1fv" => &self.Uniform1fv,
+            "Uniform1fvARB" => &self.Uniform1fvARB,
+            "Uniform1i" => &self.Uniform1i,
+            "Uniform1i64ARB" => &self.Uniform1i64ARB,
+            "Uniform1i64NV" => &self.Uniform1i64NV,
+            "Uniform1i64vARB" => &self.Uniform1i64vARB,
+            "Uniform1i64vNV" => &self.Uniform1i64vNV,
+            "Uniform1iARB" => &self.Uniform1iARB,
+            "Uniform1iv" => &self.Uniform1iv,
+            "Uniform1ivARB" => &self.Uniform1ivARB,
+            "Uniform1ui" => &self.Uniform1ui,
+            "Uniform1ui64ARB" => &self.Uniform1ui64ARB,
+            "Uniform1ui64NV" => &self.Uniform1ui64NV,
+            "Uniform1ui64vARB" => &self.Uniform1ui64vARB,
+            "Uniform1ui64vNV" => &self.Uniform1ui64vNV,
+            "Uniform1uiEXT" => &self.Uniform1uiEXT,
+            "Uniform1uiv" => &self.Uniform1uiv,
+            "Uniform1uivEXT" => &self.Uniform1uivEXT,
+            "Uniform2d" => &self.Uniform2d,
+            "Uniform2dv" => &self.Uniform2dv,
+            "Uniform2f" => &self.Uniform2f,
+            "Uniform2fARB" => &self.Uniform2fARB,
+            "Uniform2fv" => &self.Uniform2fv,
+            "Uniform2fvARB" => &self.Uniform2fvARB,
+            "Uniform2i" => &self.Uniform2i,
+            "Uniform2i64ARB" => &self.Uniform2i64ARB,
+            "Uniform2i64NV" => &self.Uniform2i64NV,
+            "Uniform2i64vARB" => &self.Uniform2i64vARB,
+            "Uniform2i64vNV" => &self.Uniform2i64vNV,
+            "Uniform2iARB" => &self.Uniform2iARB,
+            "Uniform2iv" => &self.Uniform2iv,
+            "Uniform2ivARB" => &self.Uniform2ivARB,
+            "Uniform2ui" => &self.Uniform2ui,
+            "Uniform2ui64ARB" => &self.Uniform2ui64ARB,
+            "Uniform2ui64NV" => &self.Uniform2ui64NV,
+            "Uniform2ui64vARB" => &self.Uniform2ui64vARB,
+            "Uniform2ui64vNV" => &self.Uniform2ui64vNV,
+            "Uniform2uiEXT" => &self.Uniform2uiEXT,
+            "Uniform2uiv" => &self.Uniform2uiv,
+            "Uniform2uivEXT" => &self.Uniform2uivEXT,
+            "Uniform3d" => &self.Uniform3d,
+            "Uniform3dv" => &self.Uniform3dv,
+            "Uniform3f" => &self.Uniform3f,
+            "Uniform3fARB" => &self.Uniform3fARB,
+            "Uniform3fv" => &self.Uniform3fv,
+            "Uniform3fvARB" => &self.Uniform3fvARB,
+            "Uniform3i" => &self.Uniform3i,
+            "Uniform3i64ARB" => &self.Uniform3i64ARB,
+            "Uniform3i64NV" => &self.Uniform3i64NV,
+            "Uniform3i64vARB" => &self.Uniform3i64vARB,
+            "Uniform3i64vNV" => &self.Uniform3i64vNV,
+            "Uniform3iARB" => &self.Uniform3iARB,
+            "Uniform3iv" => &self.Uniform3iv,
+            "Uniform3ivARB" => &self.Uniform3ivARB,
+            "Uniform3ui" => &self.Uniform3ui,
+            "Uniform3ui64ARB" => &self.Uniform3ui64ARB,
+            "Uniform3ui64NV" => &self.Uniform3ui64NV,
+            "Uniform3ui64vARB" => &self.Uniform3ui64vARB,
+            "Uniform3ui64vNV" => &self.Uniform3ui64vNV,
+            "Uniform3uiEXT" => &self.Uniform3uiEXT,
+            "Uniform3uiv" => &self.Uniform3uiv,
+            "Uniform3uivEXT" => &self.Uniform3uivEXT,
+            "Uniform4d" => &self.Uniform4d,
+            "Uniform4dv" => &self.Uniform4dv,
+            "Uniform4f" => &self.Uniform4f,
+            "Uniform4fARB" => &self.Uniform4fARB,
+            "Uniform4fv" => &self.Uniform4fv,
+            "Uniform4fvARB" => &self.Uniform4fvARB,
+            "Uniform4i" => &self.Uniform4i,
+            "Uniform4i64ARB" => &self.Uniform4i64ARB,
+            "Uniform4i64NV" => &self.Uniform4i64NV,
+            "Uniform4i64vARB" => &self.Uniform4i64vARB,
+            "Uniform4i64vNV" => &self.Uniform4i64vNV,
+            "Uniform4iARB" => &self.Uniform4iARB,
+            "Uniform4iv" => &self.Uniform4iv,
+            "Uniform4ivARB" => &self.Uniform4ivARB,
+            "Uniform4ui" => &self.Uniform4ui,
+            "Uniform4ui64ARB" => &self.Uniform4ui64ARB,
+            "Uniform4ui64NV" => &self.Uniform4ui64NV,
+            "Uniform4ui64vARB" => &self.Uniform4ui64vARB,
+            "Uniform4ui64vNV" => &self.Uniform4ui64vNV,
+            "Uniform4uiEXT" => &self.Uniform4uiEXT,
+            "Uniform4uiv" => &self.Uniform4uiv,
+            "Uniform4uivEXT" => &self.Uniform4uivEXT,
+            "UniformBlockBinding" => &self.UniformBlockBinding,
+            "UniformBufferEXT" => &self.UniformBufferEXT,
+            "UniformHandleui64ARB" => &self.UniformHandleui64ARB,
+            "UniformHandleui64NV" => &self.UniformHandleui64NV,
+            "UniformHandleui64vARB" => &self.UniformHandleui64vARB,
+            "UniformHandleui64vNV" => &self.UniformHandleui64vNV,
+            "UniformMatrix2dv" => &self.UniformMatrix2dv,
+            "UniformMatrix2fv" => &self.UniformMatrix2fv,
+            "UniformMatrix2fvARB" => &self.UniformMatrix2fvARB,
+            "UniformMatrix2x3dv" => &self.UniformMatrix2x3dv,
+            "UniformMatrix2x3fv" => &self.UniformMatrix2x3fv,
+            "UniformMatrix2x4dv" => &self.UniformMatrix2x4dv,
+            "UniformMatrix2x4fv" => &self.UniformMatrix2x4fv,
+            "UniformMatrix3dv" => &self.UniformMatrix3dv,
+            "UniformMatrix3fv" => &self.UniformMatrix3fv,
+            "UniformMatrix3fvARB" => &self.UniformMatrix3fvARB,
+            "UniformMatrix3x2dv" => &self.UniformMatrix3x2dv,
+            "UniformMatrix3x2fv" => &self.UniformMatrix3x2fv,
+            "UniformMatrix3x4dv" => &self.UniformMatrix3x4dv,
+            "UniformMatrix3x4fv" => &self.UniformMatrix3x4fv,
+            "UniformMatrix4dv" => &self.UniformMatrix4dv,
+            "UniformMatrix4fv" => &self.UniformMatrix4fv,
+            "UniformMatrix4fvARB" => &self.UniformMatrix4fvARB,
+            "UniformMatrix4x2dv" => &self.UniformMatrix4x2dv,
+            "UniformMatrix4x2fv" => &self.UniformMatrix4x2fv,
+            "UniformMatrix4x3dv" => &self.UniformMatrix4x3dv,
+            "UniformMatrix4x3fv" => &self.UniformMatrix4x3fv,
+            "UniformSubroutinesuiv" => &self.UniformSubroutinesuiv,
+            "Uniformui64NV" => &self.Uniformui64NV,
+            "Uniformui64vNV" => &self.Uniformui64vNV,
+            "UnlockArraysEXT" => &self.UnlockArraysEXT,
+            "UnmapBuffer" => &self.UnmapBuffer,
+            "UnmapBufferARB" => &self.UnmapBufferARB,
+            "UnmapNamedBuffer" => &self.UnmapNamedBuffer,
+            "UnmapNamedBufferEXT" => &self.UnmapNamedBufferEXT,
+            "UnmapObjectBufferATI" => &self.UnmapObjectBufferATI,
+            "UnmapTexture2DINTEL" => &self.UnmapTexture2DINTEL,
+            "UpdateObjectBufferATI" => &self.UpdateObjectBufferATI,
+            "UploadGpuMaskNVX" => &self.UploadGpuMaskNVX,
+            "UseProgram" => &self.UseProgram,
+            "UseProgramObjectARB" => &self.UseProgramObjectARB,
+            "UseProgramStages" => &self.UseProgramStages,
+            "UseShaderProgramEXT" => &self.UseShaderProgramEXT,
+            "VDPAUFiniNV" => &self.VDPAUFiniNV,
+            "VDPAUGetSurfaceivNV" => &self.VDPAUGetSurfaceivNV,
+            "VDPAUInitNV" => &self.VDPAUInitNV,
+            "VDPAUIsSurfaceNV" => &self.VDPAUIsSurfaceNV,
+            "VDPAUMapSurfacesNV" => &self.VDPAUMapSurfacesNV,
+            "VDPAURegisterOutputSurfaceNV" => &self.VDPAURegisterOutputSurfaceNV,
+            "VDPAURegisterVideoSurfaceNV" => &self.VDPAURegisterVideoSurfaceNV,
+            "VDPAURegisterVideoSurfaceWithPictureStructureNV" => {
+                &self.VDPAURegisterVideoSurfaceWithPictureStructureNV
+            }
+            "VDPAUSurfaceAccessNV" => &self.VDPAUSurfaceAccessNV,
+            "VDPAUUnmapSurfacesNV" => &self.VDPAUUnmapSurfacesNV,
+            "VDPAUUnregisterSurfaceNV" => &self.VDPAUUnregisterSurfaceNV,
+            "ValidateProgram" => &self.ValidateProgram,
+            "ValidateProgramARB" => &self.ValidateProgramARB,
+            "ValidateProgramPipeline" => &self.ValidateProgramPipeline,
+            "VariantArrayObjectATI" => &self.VariantArrayObjectATI,
+            "VariantPointerEXT" => &self.VariantPointerEXT,
+            "VariantbvEXT" => &self.VariantbvEXT,
+            "VariantdvEXT" => &self.VariantdvEXT,
+            "VariantfvEXT" => &self.VariantfvEXT,
+            "VariantivEXT" => &self.VariantivEXT,
+            "VariantsvEXT" => &self.VariantsvEXT,
+            "VariantubvEXT" => &self.VariantubvEXT,
+            "VariantuivEXT" => &self.VariantuivEXT,
+            "VariantusvEXT" => &self.VariantusvEXT,
+            "Vertex2bOES" => &self.Vertex2bOES,
+            "Vertex2bvOES" => &self.Vertex2bvOES,
+            "Vertex2hNV" => &self.Vertex2hNV,
+            "Vertex2hvNV" => &self.Vertex2hvNV,
+            "Vertex2xOES" => &self.Vertex2xOES,
+            "Vertex2xvOES" => &self.Vertex2xvOES,
+            "Vertex3bOES" => &self.Vertex3bOES,
+            "Vertex3bvOES" => &self.Vertex3bvOES,
+            "Vertex3hNV" => &self.Vertex3hNV,
+            "Vertex3hvNV" => &self.Vertex3hvNV,
+            "Vertex3xOES" => &self.Vertex3xOES,
+            "Vertex3xvOES" => &self.Vertex3xvOES,
+            "Vertex4bOES" => &self.Vertex4bOES,
+            "Vertex4bvOES" => &self.Vertex4bvOES,
+            "Vertex4hNV" => &self.Vertex4hNV,
+            "Vertex4hvNV" => &self.Vertex4hvNV,
+            "Vertex4xOES" => &self.Vertex4xOES,
+            "Vertex4xvOES" => &self.Vertex4xvOES,
+            "VertexArrayAttribBinding" => &self.VertexArrayAttribBinding,
+            "VertexArrayAttribFormat" => &self.VertexArrayAttribFormat,
+            "VertexArrayAttribIFormat" => &self.VertexArrayAttribIFormat,
+            "VertexArrayAttribLFormat" => &self.VertexArrayAttribLFormat,
+            "VertexArrayBindVertexBufferEXT" => &self.VertexArrayBindVertexBufferEXT,
+            "VertexArrayBindingDivisor" => &self.VertexArrayBindingDivisor,
+            "VertexArrayColorOffsetEXT" => &self.VertexArrayColorOffsetEXT,
+            "VertexArrayEdgeFlagOffsetEXT" => &self.VertexArrayEdgeFlagOffsetEXT,
+            "VertexArrayElementBuffer" => &self.VertexArrayElementBuffer,
+            "VertexArrayFogCoordOffsetEXT" => &self.VertexArrayFogCoordOffsetEXT,
+            "VertexArrayIndexOffsetEXT" => &self.VertexArrayIndexOffsetEXT,
+            "VertexArrayMultiTexCoordOffsetEXT" => &self.VertexArrayMultiTexCoordOffsetEXT,
+            "VertexArrayNormalOffsetEXT" => &self.VertexArrayNormalOffsetEXT,
+            "VertexArrayParameteriAPPLE" => &self.VertexArrayParameteriAPPLE,
+            "VertexArrayRangeAPPLE" => &self.VertexArrayRangeAPPLE,
+            "VertexArrayRangeNV" => &self.VertexArrayRangeNV,
+            "VertexArraySecondaryColorOffsetEXT" => &self.VertexArraySecondaryColorOffsetEXT,
+            "VertexArrayTexCoordOffsetEXT" => &self.VertexArrayTexCoordOffsetEXT,
+            "VertexArrayVertexAttribBindingEXT" => &self.VertexArrayVertexAttribBindingEXT,
+            "VertexArrayVertexAttribDivisorEXT" => &self.VertexArrayVertexAttribDivisorEXT,
+            "VertexArrayVertexAttribFormatEXT" => &self.VertexArrayVertexAttribFormatEXT,
+            "VertexArrayVertexAttribIFormatEXT" => &self.VertexArrayVertexAttribIFormatEXT,
+            "VertexArrayVertexAttribIOffsetEXT" => &self.VertexArrayVertexAttribIOffsetEXT,
+            "VertexArrayVertexAttribLFormatEXT" => &self.VertexArrayVertexAttribLFormatEXT,
+            "VertexArrayVertexAttribLOffsetEXT" => &self.VertexArrayVertexAttribLOffsetEXT,
+            "VertexArrayVertexAttribOffsetEXT" => &self.VertexArrayVertexAttribOffsetEXT,
+            "VertexArrayVertexBindingDivisorEXT" => &self.VertexArrayVertexBindingDivisorEXT,
+            "VertexArrayVertexBuffer" => &self.VertexArrayVertexBuffer,
+            "VertexArrayVertexBuffers" => &self.VertexArrayVertexBuffers,
+            "VertexArrayVertexOffsetEXT" => &self.VertexArrayVertexOffsetEXT,
+            "VertexAttrib1d" => &self.VertexAttrib1d,
+            "VertexAttrib1dARB" => &self.VertexAttrib1dARB,
+            "VertexAttrib1dNV" => &self.VertexAttrib1dNV,
+            "VertexAttrib1dv" => &self.VertexAttrib1dv,
+            "VertexAttrib1dvARB" => &self.VertexAttrib1dvARB,
+            "VertexAttrib1dvNV" => &self.VertexAttrib1dvNV,
+            "VertexAttrib1f" => &self.VertexAttrib1f,
+            "VertexAttrib1fARB" => &self.VertexAttrib1fARB,
+            "VertexAttrib1fNV" => &self.VertexAttrib1fNV,
+            "VertexAttrib1fv" => &self.VertexAttrib1fv,
+            "VertexAttrib1fvARB" => &self.VertexAttrib1fvARB,
+            "VertexAttrib1fvNV" => &self.VertexAttrib1fvNV,
+            "VertexAttrib1hNV" => &self.VertexAttrib1hNV,
+            "VertexAttrib1hvNV" => &self.VertexAttrib1hvNV,
+            "VertexAttrib1s" => &self.VertexAttrib1s,
+            "VertexAttrib1sARB" => &self.VertexAttrib1sARB,
+            "VertexAttrib1sNV" => &self.VertexAttrib1sNV,
+            "VertexAttrib1sv" => &self.VertexAttrib1sv,
+            "VertexAttrib1svARB" => &self.VertexAttrib1svARB,
+            "VertexAttrib1svNV" => &self.VertexAttrib1svNV,
+            "VertexAttrib2d" => &self.VertexAttrib2d,
+            "VertexAttrib2dARB" => &self.VertexAttrib2dARB,
+            "VertexAttrib2dNV" => &self.VertexAttrib2dNV,
+            "VertexAttrib2dv" => &self.VertexAttrib2dv,
+            "VertexAttrib2dvARB" => &self.VertexAttrib2dvARB,
+            "VertexAttrib2dvNV" => &self.VertexAttrib2dvNV,
+            "VertexAttrib2f" => &self.VertexAttrib2f,
+            "VertexAttrib2fARB" => &self.VertexAttrib2fARB,
+            "VertexAttrib2fNV" => &self.VertexAttrib2fNV,
+            "VertexAttrib2fv" => &self.VertexAttrib2fv,
+            "VertexAttrib2fvARB" => &self.VertexAttrib2fvARB,
+            "VertexAttrib2fvNV" => &self.VertexAttrib2fvNV,
+            "VertexAttrib2hNV" => &self.VertexAttrib2hNV,
+            "VertexAttrib2hvNV" => &self.VertexAttrib2hvNV,
+            "VertexAttrib2s" => &self.VertexAttrib2s,
+            "VertexAttrib2sARB" => &self.VertexAttrib2sARB,
+            "VertexAttrib2sNV" => &self.VertexAttrib2sNV,
+            "VertexAttrib2sv" => &self.VertexAttrib2sv,
+            "VertexAttrib2svARB" => &self.VertexAttrib2svARB,
+            "VertexAttrib2svNV" => &self.VertexAttrib2svNV,
+            "VertexAttrib3d" => &self.VertexAttrib3d,
+            "VertexAttrib3dARB" => &self.VertexAttrib3dARB,
+            "VertexAttrib3dNV" => &self.VertexAttrib3dNV,
+            "VertexAttrib3dv" => &self.VertexAttrib3dv,
+            "VertexAttrib3dvARB" => &self.VertexAttrib3dvARB,
+            "VertexAttrib3dvNV" => &self.VertexAttrib3dvNV,
+            "VertexAttrib3f" => &self.VertexAttrib3f,
+            "VertexAttrib3fARB" => &self.VertexAttrib3fARB,
+            "VertexAttrib3fNV" => &self.VertexAttrib3fNV,
+            "VertexAttrib3fv" => &self.VertexAttrib3fv,
+            "VertexAttrib3fvARB" => &self.VertexAttrib3fvARB,
+            "VertexAttrib3fvNV" => &self.VertexAttrib3fvNV,
+            "VertexAttrib3hNV" => &self.VertexAttrib3hNV,
+            "VertexAttrib3hvNV" => &self.VertexAttrib3hvNV,
+            "VertexAttrib3s" => &self.VertexAttrib3s,
+            "VertexAttrib3sARB" => &self.VertexAttrib3sARB,
+            "VertexAttrib3sNV" => &self.VertexAttrib3sNV,
+            "VertexAttrib3sv" => &self.VertexAttrib3sv,
+            "VertexAttrib3svARB" => &self.VertexAttrib3svARB,
+            "VertexAttrib3svNV" => &self.VertexAttrib3svNV,
+            "VertexAttrib4Nbv" => &self.VertexAttrib4Nbv,
+            "VertexAttrib4NbvARB" => &self.VertexAttrib4NbvARB,
+            "VertexAttrib4Niv" => &self.VertexAttrib4Niv,
+            "VertexAttrib4NivARB" => &self.VertexAttrib4NivARB,
+            "VertexAttrib4Nsv" => &self.VertexAttrib4Nsv,
+            "VertexAttrib4NsvARB" => &self.VertexAttrib4NsvARB,
+            "VertexAttrib4Nub" => &self.VertexAttrib4Nub,
+            "VertexAttrib4NubARB" => &self.VertexAttrib4NubARB,
+            "VertexAttrib4Nubv" => &self.VertexAttrib4Nubv,
+            "VertexAttrib4NubvARB" => &self.VertexAttrib4NubvARB,
+            "VertexAttrib4Nuiv" => &self.VertexAttrib4Nuiv,
+            "VertexAttrib4NuivARB" => &self.VertexAttrib4NuivARB,
+            "VertexAttrib4Nusv" => &self.VertexAttrib4Nusv,
+            "VertexAttrib4NusvARB" => &self.VertexAttrib4NusvARB,
+            "VertexAttrib4bv" => &self.VertexAttrib4bv,
+            "VertexAttrib4bvARB" => &self.VertexAttrib4bvARB,
+            "VertexAttrib4d" => &self.VertexAttrib4d,
+            "VertexAttrib4dARB" => &self.VertexAttrib4dARB,
+            "VertexAttrib4dNV" => &self.VertexAttrib4dNV,
+            "VertexAttrib4dv" => &self.VertexAttrib4dv,
+            "VertexAttrib4dvARB" => &self.VertexAttrib4dvARB,
+            "VertexAttrib4dvNV" => &self.VertexAttrib4dvNV,
+            "VertexAttrib4f" => &self.VertexAttrib4f,
+            "VertexAttrib4fARB" => &self.VertexAttrib4fARB,
+            "VertexAttrib4fNV" => &self.VertexAttrib4fNV,
+            "VertexAttrib4fv" => &self.VertexAttrib4fv,
+            "VertexAttrib4fvARB" => &self.VertexAttrib4fvARB,
+            "VertexAttrib4fvNV" => &self.VertexAttrib4fvNV,
+            "VertexAttrib4hNV" => &self.VertexAttrib4hNV,
+            "VertexAttrib4hvNV" => &self.VertexAttrib4hvNV,
+            "VertexAttrib4iv" => &self.VertexAttrib4iv,
+            "VertexAttrib4ivARB" => &self.VertexAttrib4ivARB,
+            "VertexAttrib4s" => &self.VertexAttrib4s,
+            "VertexAttrib4sARB" => &self.VertexAttrib4sARB,
+            "VertexAttrib4sNV" => &self.VertexAttrib4sNV,
+            "VertexAttrib4sv" => &self.VertexAttrib4sv,
+            "VertexAttrib4svARB" => &self.VertexAttrib4svARB,
+            "VertexAttrib4svNV" => &self.VertexAttrib4svNV,
+            "VertexAttrib4ubNV" => &self.VertexAttrib4ubNV,
+            "VertexAttrib4ubv" => &self.VertexAttrib4ubv,
+            "VertexAttrib4ubvARB" => &self.VertexAttrib4ubvARB,
+            "VertexAttrib4ubvNV" => &self.VertexAttrib4ubvNV,
+            "VertexAttrib4uiv" => &self.VertexAttrib4uiv,
+            "VertexAttrib4uivARB" => &self.VertexAttrib4uivARB,
+            "VertexAttrib4usv" => &self.VertexAttrib4usv,
+            "VertexAttrib4usvARB" => &self.VertexAttrib4usvARB,
+            "VertexAttribArrayObjectATI" => &self.VertexAttribArrayObjectATI,
+            "VertexAttribBinding" => &self.VertexAttribBinding,
+            "VertexAttribDivisor" => &self.VertexAttribDivisor,
+            "VertexAttribDivisorARB" => &self.VertexAttribDivisorARB,
+            "VertexAttribFormat" => &self.VertexAttribFormat,
+            "VertexAttribFormatNV" => &self.VertexAttribFormatNV,
+            "VertexAttribI1i" => &self.VertexAttribI1i,
+            "VertexAttribI1iEXT" => &self.VertexAttribI1iEXT,
+            "VertexAttribI1iv" => &self.VertexAttribI1iv,
+            "VertexAttribI1ivEXT" => &self.VertexAttribI1ivEXT,
+            "VertexAttribI1ui" => &self.VertexAttribI1ui,
+            "VertexAttribI1uiEXT" => &self.VertexAttribI1uiEXT,
+            "VertexAttribI1uiv" => &self.VertexAttribI1uiv,
+            "VertexAttribI1uivEXT" => &self.VertexAttribI1uivEXT,
+            "VertexAttribI2i" => &self.VertexAttribI2i,
+            "VertexAttribI2iEXT" => &self.VertexAttribI2iEXT,
+            "VertexAttribI2iv" => &self.VertexAttribI2iv,
+            "VertexAttribI2ivEXT" => &self.VertexAttribI2ivEXT,
+            "VertexAttribI2ui" => &self.VertexAttribI2ui,
+            "VertexAttribI2uiEXT" => &self.VertexAttribI2uiEXT,
+            "VertexAttribI2uiv" => &self.VertexAttribI2uiv,
+            "VertexAttribI2uivEXT" => &self.VertexAttribI2uivEXT,
+            "VertexAttribI3i" => &self.VertexAttribI3i,
+            "VertexAttribI3iEXT" => &self.VertexAttribI3iEXT,
+            "VertexAttribI3iv" => &self.VertexAttribI3iv,
+            "VertexAttribI3ivEXT" => &self.VertexAttribI3ivEXT,
+            "VertexAttribI3ui" => &self.VertexAttribI3ui,
+            "VertexAttribI3uiEXT" => &self.VertexAttribI3uiEXT,
+            "VertexAttribI3uiv" => &self.VertexAttribI3uiv,
+            "VertexAttribI3uivEXT" => &self.VertexAttribI3uivEXT,
+            "VertexAttribI4bv" => &self.VertexAttribI4bv,
+            "VertexAttribI4bvEXT" => &self.VertexAttribI4bvEXT,
+            "VertexAttribI4i" => &self.VertexAttribI4i,
+            "VertexAttribI4iEXT" => &self.VertexAttribI4iEXT,
+            "VertexAttribI4iv" => &self.VertexAttribI4iv,
+            "VertexAttribI4ivEXT" => &self.VertexAttribI4ivEXT,
+            "VertexAttribI4sv" => &self.VertexAttribI4sv,
+            "VertexAttribI4svEXT" => &self.VertexAttribI4svEXT,
+            "VertexAttribI4ubv" => &self.VertexAttribI4ubv,
+            "VertexAttribI4ubvEXT" => &self.VertexAttribI4ubvEXT,
+            "VertexAttribI4ui" => &self.VertexAttribI4ui,
+            "VertexAttribI4uiEXT" => &self.VertexAttribI4uiEXT,
+            "VertexAttribI4uiv" => &self.VertexAttribI4uiv,
+            "VertexAttribI4uivEXT" => &self.VertexAttribI4uivEXT,
+            "VertexAttribI4usv" => &self.VertexAttribI4usv,
+            "VertexAttribI4usvEXT" => &self.VertexAttribI4usvEXT,
+            "VertexAttribIFormat" => &self.VertexAttribIFormat,
+            "VertexAttribIFormatNV" => &self.VertexAttribIFormatNV,
+            "VertexAttribIPointer" => &self.VertexAttribIPointer,
+            "VertexAttribIPointerEXT" => &self.VertexAttribIPointerEXT,
+            "VertexAttribL1d" => &self.VertexAttribL1d,
+            "VertexAttribL1dEXT" => &self.VertexAttribL1dEXT,
+            "VertexAttribL1dv" => &self.VertexAttribL1dv,
+            "VertexAttribL1dvEXT" => &self.VertexAttribL1dvEXT,
+            "VertexAttribL1i64NV" => &self.VertexAttribL1i64NV,
+            "VertexAttribL1i64vNV" => &self.VertexAttribL1i64vNV,
+            "VertexAttribL1ui64ARB" => &self.VertexAttribL1ui64ARB,
+            "VertexAttribL1ui64NV" => &self.VertexAttribL1ui64NV,
+            "VertexAttribL1ui64vARB" => &self.VertexAttribL1ui64vARB,
+            "VertexAttribL1ui64vNV" => &self.VertexAttribL1ui64vNV,
+            "VertexAttribL2d" => &self.VertexAttribL2d,
+            "VertexAttribL2dEXT" => &self.VertexAttribL2dEXT,
+            "VertexAttribL2dv" => &self.VertexAttribL2dv,
+            "VertexAttribL2dvEXT" => &self.VertexAttribL2dvEXT,
+            "VertexAttribL2i64NV" => &self.VertexAttribL2i64NV,
+            "VertexAttribL2i64vNV" => &self.VertexAttribL2i64vNV,
+            "VertexAttribL2ui64NV" => &self.VertexAttribL2ui64NV,
+            "VertexAttribL2ui64vNV" => &self.VertexAttribL2ui64vNV,
+            "VertexAttribL3d" => &self.VertexAttribL3d,
+            "VertexAttribL3dEXT" => &self.VertexAttribL3dEXT,
+            "VertexAttribL3dv" => &self.VertexAttribL3dv,
+            "VertexAttribL3dvEXT" => &self.VertexAttribL3dvEXT,
+            "VertexAttribL3i64NV" => &self.VertexAttribL3i64NV,
+            "VertexAttribL3i64vNV" => &self.VertexAttribL3i64vNV,
+            "VertexAttribL3ui64NV" => &self.VertexAttribL3ui64NV,
+            "VertexAttribL3ui64vNV" => &self.VertexAttribL3ui64vNV,
+            "VertexAttribL4d" => &self.VertexAttribL4d,
+            "VertexAttribL4dEXT" => &self.VertexAttribL4dEXT,
+            "VertexAttribL4dv" => &self.VertexAttribL4dv,
+            "VertexAttribL4dvEXT" => &self.VertexAttribL4dvEXT,
+            "VertexAttribL4i64NV" => &self.VertexAttribL4i64NV,
+            "VertexAttribL4i64vNV" => &self.VertexAttribL4i64vNV,
+            "VertexAttribL4ui64NV" => &self.VertexAttribL4ui64NV,
+            "VertexAttribL4ui64vNV" => &self.VertexAttribL4ui64vNV,
+            "VertexAttribLFormat" => &self.VertexAttribLFormat,
+            "VertexAttribLFormatNV" => &self.VertexAttribLFormatNV,
+            "VertexAttribLPointer" => &self.VertexAttribLPointer,
+            "VertexAttribLPointerEXT" => &self.VertexAttribLPointerEXT,
+            "VertexAttribP1ui" => &self.VertexAttribP1ui,
+            "VertexAttribP1uiv" => &self.VertexAttribP1uiv,
+            "VertexAttribP2ui" => &self.VertexAttribP2ui,
+            "VertexAttribP2uiv" => &self.VertexAttribP2uiv,
+            "VertexAttribP3ui" => &self.VertexAttribP3ui,
+            "VertexAttribP3uiv" => &self.VertexAttribP3uiv,
+            "VertexAttribP4ui" => &self.VertexAttribP4ui,
+            "VertexAttribP4uiv" => &self.VertexAttribP4uiv,
+            "VertexAttribParameteriAMD" => &self.VertexAttribParameteriAMD,
+            "VertexAttribPointer" => &self.VertexAttribPointer,
+            "VertexAttribPointerARB" => &self.VertexAttribPointerARB,
+            "VertexAttribPointerNV" => &self.VertexAttribPointerNV,
+            "VertexAttribs1dvNV" => &self.VertexAttribs1dvNV,
+            "VertexAttribs1fvNV" => &self.VertexAttribs1fvNV,
+            "VertexAttribs1hvNV" => &self.VertexAttribs1hvNV,
+            "VertexAttribs1svNV" => &self.VertexAttribs1svNV,
+            "VertexAttribs2dvNV" => &self.VertexAttribs2dvNV,
+            "VertexAttribs2fvNV" => &self.VertexAttribs2fvNV,
+            "VertexAttribs2hvNV" => &self.VertexAttribs2hvNV,
+            "VertexAttribs2svNV" => &self.VertexAttribs2svNV,
+            "VertexAttribs3dvNV" => &self.VertexAttribs3dvNV,
+            "VertexAttribs3fvNV" => &self.VertexAttribs3fvNV,
+            "VertexAttribs3hvNV" => &self.VertexAttribs3hvNV,
+            "VertexAttribs3svNV" => &self.VertexAttribs3svNV,
+            "VertexAttribs4dvNV" => &self.VertexAttribs4dvNV,
+            "VertexAttribs4fvNV" => &self.VertexAttribs4fvNV,
+            "VertexAttribs4hvNV" => &self.VertexAttribs4hvNV,
+            "VertexAttribs4svNV" => &self.VertexAttribs4svNV,
+            "VertexAttribs4ubvNV" => &self.VertexAttribs4ubvNV,
+            "VertexBindingDivisor" => &self.VertexBindingDivisor,
+            "VertexBlendARB" => &self.VertexBlendARB,
+            "VertexBlendEnvfATI" => &self.VertexBlendEnvfATI,
+            "VertexBlendEnviATI" => &self.VertexBlendEnviATI,
+            "VertexFormatNV" => &self.VertexFormatNV,
+            "VertexPointerEXT" => &self.VertexPointerEXT,
+            "VertexPointerListIBM" => &self.VertexPointerListIBM,
+            "VertexPointervINTEL" => &self.VertexPointervINTEL,
+            "VertexStream1dATI" => &self.VertexStream1dATI,
+            "VertexStream1dvATI" => &self.VertexStream1dvATI,
+            "VertexStream1fATI" => &self.VertexStream1fATI,
+            "VertexStream1fvATI" => &self.VertexStream1fvATI,
+            "VertexStream1iATI" => &self.VertexStream1iATI,
+            "VertexStream1ivATI" => &self.VertexStream1ivATI,
+            "VertexStream1sATI" => &self.VertexStream1sATI,
+            "VertexStream1svATI" => &self.VertexStream1svATI,
+            "VertexStream2dATI" => &self.VertexStream2dATI,
+            "VertexStream2dvATI" => &self.VertexStream2dvATI,
+            "VertexStream2fATI" => &self.VertexStream2fATI,
+            "VertexStream2fvATI" => &self.VertexStream2fvATI,
+            "VertexStream2iATI" => &self.VertexStream2iATI,
+            "VertexStream2ivATI" => &self.VertexStream2ivATI,
+            "VertexStream2sATI" => &self.VertexStream2sATI,
+            "VertexStream2svATI" => &self.VertexStream2svATI,
+            "VertexStream3dATI" => &self.VertexStream3dATI,
+            "VertexStream3dvATI" => &self.VertexStream3dvATI,
+            "VertexStream3fATI" => &self.VertexStream3fATI,
+            "VertexStream3fvATI" => &self.VertexStream3fvATI,
+            "VertexStream3iATI" => &self.VertexStream3iATI,
+            "VertexStream3ivATI" => &self.VertexStream3ivATI,
+            "VertexStream3sATI" => &self.VertexStream3sATI,
+            "VertexStream3svATI" => &self.VertexStream3svATI,
+            "VertexStream4dATI" => &self.VertexStream4dATI,
+            "VertexStream4dvATI" => &self.VertexStream4dvATI,
+            "VertexStream4fATI" => &self.VertexStream4fATI,
+            "VertexStream4fvATI" => &self.VertexStream4fvATI,
+            "VertexStream4iATI" => &self.VertexStream4iATI,
+            "VertexStream4ivATI" => &self.VertexStream4ivATI,
+            "VertexStream4sATI" => &self.VertexStream4sATI,
+            "VertexStream4svATI" => &self.VertexStream4svATI,
+            "VertexWeightPointerEXT" => &self.VertexWeightPointerEXT,
+            "VertexWeightfEXT" => &self.VertexWeightfEXT,
+            "VertexWeightfvEXT" => &self.VertexWeightfvEXT,
+            "VertexWeighthNV" => &self.VertexWeighthNV,
+            "VertexWeighthvNV" => &self.VertexWeighthvNV,
+            "VideoCaptureNV" => &self.VideoCaptureNV,
+            "VideoCaptureStreamParameterdvNV" => &self.VideoCaptureStreamParameterdvNV,
+            "VideoCaptureStreamParameterfvNV" => &self.VideoCaptureStreamParameterfvNV,
+            "VideoCaptureStreamParameterivNV" => &self.VideoCaptureStreamParameterivNV,
+            "Viewport" => &self.Viewport,
+            "ViewportArrayv" => &self.ViewportArrayv,
+            "ViewportIndexedf" => &self.ViewportIndexedf,
+            "ViewportIndexedfv" => &self.ViewportIndexedfv,
+            "ViewportPositionWScaleNV" => &self.ViewportPositionWScaleNV,
+            "ViewportSwizzleNV" => &self.ViewportSwizzleNV,
+            "WaitSemaphoreEXT" => &self.WaitSemaphoreEXT,
+            "WaitSemaphoreui64NVX" => &self.WaitSemaphoreui64NVX,
+            "WaitSync" => &self.WaitSync,
+            "WaitVkSemaphoreNV" => &self.WaitVkSemaphoreNV,
+            "WeightPathsNV" => &self.WeightPathsNV,
+            "WeightPointerARB" => &self.WeightPointerARB,
+            "WeightbvARB" => &self.WeightbvARB,
+            "WeightdvARB" => &self.WeightdvARB,
+            "WeightfvARB" => &self.WeightfvARB,
+            "WeightivARB" => &self.WeightivARB,
+            "WeightsvARB" => &self.WeightsvARB,
+            "WeightubvARB" => &self.WeightubvARB,
+            "WeightuivARB" => &self.WeightuivARB,
+            "WeightusvARB" => &self.WeightusvARB,
+            "WindowPos2dARB" => &self.WindowPos2dARB,
+            "WindowPos2dMESA" => &self.WindowPos2dMESA,
+            "WindowPos2dvARB" => &self.WindowPos2dvARB,
+            "WindowPos2dvMESA" => &self.WindowPos2dvMESA,
+            "WindowPos2fARB" => &self.WindowPos2fARB,
+            "WindowPos2fMESA" => &self.WindowPos2fMESA,
+            "WindowPos2fvARB" => &self.WindowPos2fvARB,
+            "WindowPos2fvMESA" => &self.WindowPos2fvMESA,
+            "WindowPos2iARB" => &self.WindowPos2iARB,
+            "WindowPos2iMESA" => &self.WindowPos2iMESA,
+            "WindowPos2ivARB" => &self.WindowPos2ivARB,
+            "WindowPos2ivMESA" => &self.WindowPos2ivMESA,
+            "WindowPos2sARB" => &self.WindowPos2sARB,
+            "WindowPos2sMESA" => &self.WindowPos2sMESA,
+            "WindowPos2svARB" => &self.WindowPos2svARB,
+            "WindowPos2svMESA" => &self.WindowPos2svMESA,
+            "WindowPos3dARB" => &self.WindowPos3dARB,
+            "WindowPos3dMESA" => &self.WindowPos3dMESA,
+            "WindowPos3dvARB" => &self.WindowPos3dvARB,
+            "WindowPos3dvMESA" => &self.WindowPos3dvMESA,
+            "WindowPos3fARB" => &self.WindowPos3fARB,
+            "WindowPos3fMESA" => &self.WindowPos3fMESA,
+            "WindowPos3fvARB" => &self.WindowPos3fvARB,
+            "WindowPos3fvMESA" => &self.WindowPos3fvMESA,
+            "WindowPos3iARB" => &self.WindowPos3iARB,
+            "WindowPos3iMESA" => &self.WindowPos3iMESA,
+            "WindowPos3ivARB" => &self.WindowPos3ivARB,
+            "WindowPos3ivMESA" => &self.WindowPos3ivMESA,
+            "WindowPos3sARB" => &self.WindowPos3sARB,
+            "WindowPos3sMESA" => &self.WindowPos3sMESA,
+            "WindowPos3svARB" => &self.WindowPos3svARB,
+            "WindowPos3svMESA" => &self.WindowPos3svMESA,
+            "WindowPos4dMESA" => &self.WindowPos4dMESA,
+            "WindowPos4dvMESA" => &self.WindowPos4dvMESA,
+            "WindowPos4fMESA" => &self.WindowPos4fMESA,
+            "WindowPos4fvMESA" => &self.WindowPos4fvMESA,
+            "WindowPos4iMESA" => &self.WindowPos4iMESA,
+            "WindowPos4ivMESA" => &self.WindowPos4ivMESA,
+            "WindowPos4sMESA" => &self.WindowPos4sMESA,
+            "WindowPos4svMESA" => &self.WindowPos4svMESA,
+            "WindowRectanglesEXT" => &self.WindowRectanglesEXT,
+            "WriteMaskEXT" => &self.WriteMaskEXT,
+            _ => return None,
+        })
+    }
 }
@@ -1,4 +1,22 @@
+#[cfg(feature = "debug")]
+pub mod debug;
+#[cfg(feature = "slim")]
+#[allow(clippy::all)]
+pub mod gl;
+#[cfg(feature = "gl33")]
 #[allow(clippy::all)]
 pub mod gl33;
+#[cfg(feature = "gl46")]
 #[allow(clippy::all)]
 pub mod gl46;
+pub mod load_report;
+#[cfg(feature = "mock")]
+pub mod mock;
+#[cfg(any(feature = "mock", feature = "trace"))]
+mod pixels;
+#[cfg(feature = "trace")]
+pub mod replay;
+#[cfg(feature = "trace")]
+pub mod trace;
+
+pub use load_report::LoadReport;
@@ -0,0 +1,70 @@
+// What `load` found. Functions the driver didn't return panic with their name
+// when called, check here first to fail early or pick another code path.
+// Some loaders (Mesa's GLX and EGL) return a stub for any name, so a complete
+// report doesn't guarantee the context really has everything.
+
+use std::fmt;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LoadReport {
+    // core version the bindings were generated for, e.g. (3, 3), or the
+    // context's after `for_context`
+    pub version: (u32, u32),
+    pub loaded: usize,
+    // core functions of `version` the driver didn't return, without the gl prefix
+    // a context older than `version` or a broken driver
+    pub missing_core: Vec<&'static str>,
+    // extension functions the driver didn't return, usually most of them
+    pub missing_extensions: Vec<&'static str>,
+}
+
+impl LoadReport {
+    // every core function is there
+    pub fn is_complete(&self) -> bool {
+        self.missing_core.is_empty()
+    }
+
+    // only the missing core functions a context of `version` should have, the
+    // bindings can be newer than the context
+    pub fn for_context(mut self, version: (u32, u32)) -> LoadReport {
+        self.version = self.version.min(version);
+        let version = self.version;
+        self.missing_core
+            .retain(|name| core_version(name).is_some_and(|since| since <= version));
+        self
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/core_versions.rs"));
+
+// the GL version a core function was added in, None for extension functions
+// Example: core_version("DispatchCompute") == Some((4, 3))
+pub fn core_version(name: &str) -> Option<(u32, u32)> {
+    let name = name.strip_prefix("gl").unwrap_or(name);
+    CORE_VERSIONS
+        .binary_search_by_key(&name, |&(function, _)| function)
+        .ok()
+        .map(|i| CORE_VERSIONS[i].1)
+}
+
+impl fmt::Display for LoadReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "loaded {} GL functions, {} extension functions missing",
+            self.loaded,
+            self.missing_extensions.len()
+        )?;
+        if !self.is_complete() {
+            write!(
+                f,
+                ", {} GL {}.{} core functions missing: gl{}",
+                self.missing_core.len(),
+                self.version.0,
+                self.version.1,
+                self.missing_core.join(", gl")
+            )?;
+        }
+        Ok(())
+    }
+}
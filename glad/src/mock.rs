@@ -0,0 +1,1860 @@
+// A fake GL driver for tests on machines without one. `load` hands gl33::load
+// function pointers into a small in-memory state machine: object names,
+// bindings, buffer and texture contents, shader compile/link status and
+// uniform locations. Nothing is drawn, draw calls are only recorded.
+//
+//   let (mut gl, _) = glad::mock::load();
+//   let mesh = Mesh::new(&mut gl, &vertices, &[], &[3]);
+//   glad::mock::with_state(|state| assert_eq!(state.buffers[&mesh.vbo].data.len(), 36));
+//
+// The state is per thread, so tests running in parallel don't see each other.
+// Functions the mock doesn't implement aren't loaded and panic with their name.
+// It reports GL 3.3 until `set_version` says otherwise, the 4.x functions it
+// has are there whatever the version, like the ARB extensions on a driver.
+// Shaders compile when they have a `main` and don't contain `#error`, links
+// need a compiled vertex and fragment shader or a compute shader on its own.
+// Uniforms are found by scanning
+// the sources for `uniform <type> <name>;`, struct members aren't expanded.
+
+#![allow(non_snake_case)]
+
+use crate::{
+    gl33::{self as gl, types::*},
+    pixels::{pixel_size, row_size},
+    LoadReport,
+};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap, HashSet},
+    ffi::{CStr, CString},
+    os::raw::c_void,
+    ptr, slice,
+};
+
+// what the mock claims to be, see `GetString`, the versions until `set_version`
+pub const VENDOR: &CStr = c"glad";
+pub const RENDERER: &CStr = c"mock";
+pub const VERSION: &CStr = c"3.3 (Core Profile) glad mock";
+pub const SHADING_LANGUAGE_VERSION: &CStr = c"3.30";
+pub const EXTENSIONS: &[&CStr] = &[c"GL_KHR_debug"];
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Buffer {
+    pub data: Vec<u8>,
+    pub usage: GLenum,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Texture {
+    // 0 until first bound, like GL
+    pub target: GLenum,
+    pub width: GLsizei,
+    pub height: GLsizei,
+    pub internal_format: GLint,
+    pub format: GLenum,
+    pub type_: GLenum,
+    // level 0, rows tightly packed whatever the unpack alignment was
+    pub pixels: Vec<u8>,
+    pub parameters: HashMap<GLenum, GLint>,
+    pub mipmaps: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Shader {
+    pub type_: GLenum,
+    pub source: String,
+    pub compiled: bool,
+    pub info_log: String,
+    pub deleted: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Uniform {
+    Int(Vec<GLint>),
+    Float(Vec<GLfloat>),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Program {
+    pub shaders: Vec<GLuint>,
+    pub linked: bool,
+    pub info_log: String,
+    // active uniform names and their locations, set by LinkProgram
+    pub uniform_locations: BTreeMap<String, GLint>,
+    pub uniforms: HashMap<GLint, Uniform>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct VertexAttribute {
+    pub enabled: bool,
+    pub size: GLint,
+    pub type_: GLenum,
+    pub normalized: bool,
+    // the vertex buffer binding it reads from, VertexAttribPointer uses the
+    // attribute's own index like GL does
+    pub binding: GLuint,
+    pub relative_offset: usize,
+    // resolved through the binding: its buffer and stride, and the binding's
+    // offset plus the relative one
+    pub stride: GLsizei,
+    pub offset: usize,
+    pub buffer: GLuint,
+}
+
+// a vertex buffer binding of a vertex array, see VertexArrayVertexBuffer
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct VertexBuffer {
+    pub buffer: GLuint,
+    pub offset: usize,
+    pub stride: GLsizei,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VertexArray {
+    pub element_buffer: GLuint,
+    pub vertex_buffers: BTreeMap<GLuint, VertexBuffer>,
+    pub attributes: BTreeMap<GLuint, VertexAttribute>,
+}
+
+impl VertexArray {
+    // after a binding or an attribute format changed
+    fn resolve(&mut self) {
+        for attribute in self.attributes.values_mut() {
+            let binding = self
+                .vertex_buffers
+                .get(&attribute.binding)
+                .copied()
+                .unwrap_or_default();
+            attribute.buffer = binding.buffer;
+            attribute.stride = binding.stride;
+            attribute.offset = binding.offset + attribute.relative_offset;
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Attachment {
+    Texture(GLuint),
+    Renderbuffer(GLuint),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Framebuffer {
+    pub attachments: HashMap<GLenum, Attachment>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Renderbuffer {
+    pub internal_format: GLenum,
+    pub width: GLsizei,
+    pub height: GLsizei,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Draw {
+    pub mode: GLenum,
+    pub count: GLsizei,
+    pub indexed: bool,
+    pub program: GLuint,
+    pub vertex_array: GLuint,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Dispatch {
+    pub groups: [GLuint; 3],
+    pub program: GLuint,
+}
+
+#[derive(Debug)]
+pub struct State {
+    // (major, minor), see `set_version`
+    pub version: (GLint, GLint),
+    version_string: CString,
+    shading_language_version: CString,
+    // shared by every object kind, so mixing names up shows
+    next_name: GLuint,
+    pub buffers: HashMap<GLuint, Buffer>,
+    pub textures: HashMap<GLuint, Texture>,
+    pub shaders: HashMap<GLuint, Shader>,
+    pub programs: HashMap<GLuint, Program>,
+    pub vertex_arrays: HashMap<GLuint, VertexArray>,
+    pub framebuffers: HashMap<GLuint, Framebuffer>,
+    pub renderbuffers: HashMap<GLuint, Renderbuffer>,
+
+    // target -> buffer, except ELEMENT_ARRAY_BUFFER which is vertex array state
+    pub buffer_bindings: HashMap<GLenum, GLuint>,
+    // (target, index) -> buffer, BindBufferBase
+    pub indexed_buffer_bindings: HashMap<(GLenum, GLuint), GLuint>,
+    pub active_texture: GLuint,
+    // (unit, target) -> texture
+    pub texture_bindings: HashMap<(GLuint, GLenum), GLuint>,
+    pub current_program: GLuint,
+    pub vertex_array: GLuint,
+    pub draw_framebuffer: GLuint,
+    pub read_framebuffer: GLuint,
+    pub renderbuffer: GLuint,
+    pub enabled: HashSet<GLenum>,
+    pub viewport: [GLint; 4],
+    // (sfactor, dfactor)
+    pub blend_func: (GLenum, GLenum),
+    pub depth_func: GLenum,
+    pub depth_mask: bool,
+    pub cull_face: GLenum,
+    pub clear_color: [GLfloat; 4],
+    pub polygon_mode: GLenum,
+    pub pixel_store: HashMap<GLenum, GLint>,
+    pub labels: HashMap<(GLenum, GLuint), String>,
+    pub debug_groups: Vec<String>,
+
+    pub clears: Vec<GLbitfield>,
+    pub draws: Vec<Draw>,
+    pub dispatches: Vec<Dispatch>,
+    // oldest first, GetError takes them from the front
+    pub errors: Vec<GLenum>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State {
+            version: (3, 3),
+            version_string: VERSION.into(),
+            shading_language_version: SHADING_LANGUAGE_VERSION.into(),
+            next_name: 1,
+            buffers: HashMap::new(),
+            textures: HashMap::new(),
+            shaders: HashMap::new(),
+            programs: HashMap::new(),
+            vertex_arrays: HashMap::new(),
+            framebuffers: HashMap::new(),
+            renderbuffers: HashMap::new(),
+            buffer_bindings: HashMap::new(),
+            indexed_buffer_bindings: HashMap::new(),
+            active_texture: 0,
+            texture_bindings: HashMap::new(),
+            current_program: 0,
+            vertex_array: 0,
+            draw_framebuffer: 0,
+            read_framebuffer: 0,
+            renderbuffer: 0,
+            enabled: HashSet::new(),
+            viewport: [0; 4],
+            blend_func: (gl::ONE, gl::ZERO),
+            depth_func: gl::LESS,
+            depth_mask: true,
+            cull_face: gl::BACK,
+            clear_color: [0.0; 4],
+            polygon_mode: gl::FILL,
+            pixel_store: HashMap::from([(gl::UNPACK_ALIGNMENT, 4), (gl::PACK_ALIGNMENT, 4)]),
+            labels: HashMap::new(),
+            debug_groups: vec![],
+            clears: vec![],
+            draws: vec![],
+            dispatches: vec![],
+            errors: vec![],
+        }
+    }
+}
+
+impl State {
+    fn name(&mut self) -> GLuint {
+        let name = self.next_name;
+        self.next_name += 1;
+        name
+    }
+
+    fn error(&mut self, error: GLenum) {
+        self.errors.push(error);
+    }
+
+    // the texture bound to `target` on the active unit
+    pub fn bound_texture(&self, target: GLenum) -> GLuint {
+        let key = (self.active_texture, target);
+        self.texture_bindings.get(&key).copied().unwrap_or(0)
+    }
+
+    pub fn bound_buffer(&self, target: GLenum) -> GLuint {
+        match target {
+            gl::ELEMENT_ARRAY_BUFFER => self
+                .vertex_arrays
+                .get(&self.vertex_array)
+                .map_or(0, |vao| vao.element_buffer),
+            _ => self.buffer_bindings.get(&target).copied().unwrap_or(0),
+        }
+    }
+
+    // the value last set for `name` in `program`
+    pub fn uniform(&self, program: GLuint, name: &str) -> Option<&Uniform> {
+        let program = self.programs.get(&program)?;
+        program.uniforms.get(program.uniform_locations.get(name)?)
+    }
+
+    fn texture_mut(&mut self, target: GLenum) -> Option<&mut Texture> {
+        let id = self.bound_texture(target);
+        self.textures.get_mut(&id)
+    }
+
+    fn buffer_mut(&mut self, target: GLenum) -> Option<&mut Buffer> {
+        let id = self.bound_buffer(target);
+        self.buffers.get_mut(&id)
+    }
+}
+
+thread_local! {
+    static STATE: RefCell<State> = RefCell::new(State::default());
+}
+
+fn with<R>(f: impl FnOnce(&mut State) -> R) -> R {
+    STATE.with(|state| f(&mut state.borrow_mut()))
+}
+
+// look at what the calls so far did
+pub fn with_state<R>(f: impl FnOnce(&State) -> R) -> R {
+    STATE.with(|state| f(&state.borrow()))
+}
+
+// forget every object and binding, `load` does this too
+pub fn reset() {
+    with(|state| *state = State::default());
+}
+
+// pretend to be another version from now on, e.g. 4.5 for common's direct
+// state access paths, `load` goes back to 3.3
+pub fn set_version(major: GLint, minor: GLint) {
+    with(|state| {
+        let version = format!("{}.{} (Core Profile) glad mock", major, minor);
+        state.version = (major, minor);
+        state.version_string = CString::new(version).unwrap();
+        state.shading_language_version = CString::new(format!("{}.{}0", major, minor)).unwrap();
+    });
+}
+
+// a fresh context on this thread
+pub fn load() -> (gl::Gl, LoadReport) {
+    reset();
+    gl::load(get_proc_address)
+}
+
+// the same into other bindings, e.g. `mock::load_with(glad::gl46::load)`
+pub fn load_with<G>(
+    load: impl FnOnce(fn(&'static str) -> *const c_void) -> (G, LoadReport),
+) -> (G, LoadReport) {
+    reset();
+    load(get_proc_address)
+}
+
+// null for the functions the mock doesn't have
+pub fn get_proc_address(name: &str) -> *const c_void {
+    macro_rules! functions {
+        ($($function:ident),* $(,)?) => {
+            match name.strip_prefix("gl").unwrap_or("") {
+                $(stringify!($function) => $function as *const c_void,)*
+                _ => ptr::null(),
+            }
+        };
+    }
+    functions!(
+        ActiveTexture,
+        AttachShader,
+        BindBuffer,
+        BindBufferBase,
+        BindFramebuffer,
+        BindRenderbuffer,
+        BindTexture,
+        BindTextureUnit,
+        BindVertexArray,
+        BlendFunc,
+        BufferData,
+        BufferSubData,
+        CheckFramebufferStatus,
+        Clear,
+        ClearColor,
+        CompileShader,
+        CreateBuffers,
+        CreateProgram,
+        CreateShader,
+        CreateTextures,
+        CreateVertexArrays,
+        CullFace,
+        DebugMessageCallback,
+        DeleteBuffers,
+        DeleteFramebuffers,
+        DeleteProgram,
+        DeleteRenderbuffers,
+        DeleteShader,
+        DeleteTextures,
+        DeleteVertexArrays,
+        DepthFunc,
+        DepthMask,
+        Disable,
+        DisableVertexAttribArray,
+        DispatchCompute,
+        DrawArrays,
+        DrawElements,
+        Enable,
+        EnableVertexArrayAttrib,
+        EnableVertexAttribArray,
+        FramebufferRenderbuffer,
+        FramebufferTexture2D,
+        GenBuffers,
+        GenerateMipmap,
+        GenFramebuffers,
+        GenRenderbuffers,
+        GenTextures,
+        GenVertexArrays,
+        GetBufferSubData,
+        GetError,
+        GetFloatv,
+        GetIntegerv,
+        GetNamedBufferSubData,
+        GetProgramInfoLog,
+        GetProgramiv,
+        GetShaderInfoLog,
+        GetShaderiv,
+        GetString,
+        GetStringi,
+        GetUniformLocation,
+        IsEnabled,
+        LinkProgram,
+        MemoryBarrier,
+        NamedBufferData,
+        ObjectLabel,
+        PixelStorei,
+        PolygonMode,
+        PopDebugGroup,
+        PushDebugGroup,
+        ReadBuffer,
+        ReadPixels,
+        RenderbufferStorage,
+        ShaderSource,
+        TexImage2D,
+        TexParameteri,
+        TexSubImage2D,
+        TextureParameteri,
+        Uniform1f,
+        Uniform1fv,
+        Uniform1i,
+        Uniform1iv,
+        Uniform2f,
+        Uniform2fv,
+        Uniform3f,
+        Uniform3fv,
+        Uniform4f,
+        Uniform4fv,
+        UniformMatrix2fv,
+        UniformMatrix3fv,
+        UniformMatrix4fv,
+        UseProgram,
+        VertexArrayAttribBinding,
+        VertexArrayAttribFormat,
+        VertexArrayElementBuffer,
+        VertexArrayVertexBuffer,
+        VertexAttribPointer,
+        Viewport,
+    )
+}
+
+// object names
+
+unsafe fn generate(n: GLsizei, names: *mut GLuint, mut create: impl FnMut(&mut State, GLuint)) {
+    if n < 0 {
+        return with(|state| state.error(gl::INVALID_VALUE));
+    }
+    with(|state| {
+        for i in 0..n as usize {
+            let name = state.name();
+            create(state, name);
+            *names.add(i) = name;
+        }
+    });
+}
+
+unsafe fn delete(n: GLsizei, names: *const GLuint, mut remove: impl FnMut(&mut State, GLuint)) {
+    if n < 0 {
+        return with(|state| state.error(gl::INVALID_VALUE));
+    }
+    with(|state| {
+        for &name in slice::from_raw_parts(names, n as usize) {
+            // 0 and unknown names are silently ignored
+            if name != 0 {
+                remove(state, name);
+            }
+        }
+    });
+}
+
+unsafe extern "system" fn GenBuffers(n: GLsizei, buffers: *mut GLuint) {
+    generate(n, buffers, |state, name| {
+        state.buffers.insert(name, Buffer::default());
+    });
+}
+
+unsafe extern "system" fn DeleteBuffers(n: GLsizei, buffers: *const GLuint) {
+    delete(n, buffers, |state, name| {
+        state.buffers.remove(&name);
+        state.buffer_bindings.retain(|_, bound| *bound != name);
+        state
+            .indexed_buffer_bindings
+            .retain(|_, bound| *bound != name);
+        for vao in state.vertex_arrays.values_mut() {
+            if vao.element_buffer == name {
+                vao.element_buffer = 0;
+            }
+            vao.vertex_buffers
+                .retain(|_, binding| binding.buffer != name);
+            vao.resolve();
+        }
+    });
+}
+
+// the Create* functions of direct state access, Gen* already creates the
+// objects here, only textures get their target straight away
+
+unsafe extern "system" fn CreateBuffers(n: GLsizei, buffers: *mut GLuint) {
+    GenBuffers(n, buffers);
+}
+
+unsafe extern "system" fn CreateTextures(target: GLenum, n: GLsizei, textures: *mut GLuint) {
+    generate(n, textures, |state, name| {
+        let texture = Texture {
+            target,
+            ..Texture::default()
+        };
+        state.textures.insert(name, texture);
+    });
+}
+
+unsafe extern "system" fn CreateVertexArrays(n: GLsizei, arrays: *mut GLuint) {
+    GenVertexArrays(n, arrays);
+}
+
+unsafe extern "system" fn GenTextures(n: GLsizei, textures: *mut GLuint) {
+    generate(n, textures, |state, name| {
+        state.textures.insert(name, Texture::default());
+    });
+}
+
+unsafe extern "system" fn DeleteTextures(n: GLsizei, textures: *const GLuint) {
+    delete(n, textures, |state, name| {
+        state.textures.remove(&name);
+        state.texture_bindings.retain(|_, bound| *bound != name);
+    });
+}
+
+unsafe extern "system" fn GenVertexArrays(n: GLsizei, arrays: *mut GLuint) {
+    generate(n, arrays, |state, name| {
+        state.vertex_arrays.insert(name, VertexArray::default());
+    });
+}
+
+unsafe extern "system" fn DeleteVertexArrays(n: GLsizei, arrays: *const GLuint) {
+    delete(n, arrays, |state, name| {
+        state.vertex_arrays.remove(&name);
+        if state.vertex_array == name {
+            state.vertex_array = 0;
+        }
+    });
+}
+
+unsafe extern "system" fn GenFramebuffers(n: GLsizei, framebuffers: *mut GLuint) {
+    generate(n, framebuffers, |state, name| {
+        state.framebuffers.insert(name, Framebuffer::default());
+    });
+}
+
+unsafe extern "system" fn DeleteFramebuffers(n: GLsizei, framebuffers: *const GLuint) {
+    delete(n, framebuffers, |state, name| {
+        state.framebuffers.remove(&name);
+        if state.draw_framebuffer == name {
+            state.draw_framebuffer = 0;
+        }
+        if state.read_framebuffer == name {
+            state.read_framebuffer = 0;
+        }
+    });
+}
+
+unsafe extern "system" fn GenRenderbuffers(n: GLsizei, renderbuffers: *mut GLuint) {
+    generate(n, renderbuffers, |state, name| {
+        state.renderbuffers.insert(name, Renderbuffer::default());
+    });
+}
+
+unsafe extern "system" fn DeleteRenderbuffers(n: GLsizei, renderbuffers: *const GLuint) {
+    delete(n, renderbuffers, |state, name| {
+        state.renderbuffers.remove(&name);
+        if state.renderbuffer == name {
+            state.renderbuffer = 0;
+        }
+    });
+}
+
+// bindings
+
+extern "system" fn BindBuffer(target: GLenum, buffer: GLuint) {
+    with(|state| {
+        if buffer != 0 && !state.buffers.contains_key(&buffer) {
+            return state.error(gl::INVALID_OPERATION);
+        }
+        if target == gl::ELEMENT_ARRAY_BUFFER {
+            let vertex_array = state.vertex_array;
+            match state.vertex_arrays.get_mut(&vertex_array) {
+                Some(vao) => vao.element_buffer = buffer,
+                None => state.error(gl::INVALID_OPERATION),
+            }
+        } else {
+            state.buffer_bindings.insert(target, buffer);
+        }
+    });
+}
+
+// binds the generic `target` binding too, like GL
+extern "system" fn BindBufferBase(target: GLenum, index: GLuint, buffer: GLuint) {
+    with(|state| {
+        if buffer != 0 && !state.buffers.contains_key(&buffer) {
+            return state.error(gl::INVALID_OPERATION);
+        }
+        state.buffer_bindings.insert(target, buffer);
+        state
+            .indexed_buffer_bindings
+            .insert((target, index), buffer);
+    });
+}
+
+extern "system" fn BindVertexArray(array: GLuint) {
+    with(
+        |state| match array == 0 || state.vertex_arrays.contains_key(&array) {
+            true => state.vertex_array = array,
+            false => state.error(gl::INVALID_OPERATION),
+        },
+    );
+}
+
+extern "system" fn ActiveTexture(texture: GLenum) {
+    with(|state| match texture.checked_sub(gl::TEXTURE0) {
+        Some(unit) if unit < 32 => state.active_texture = unit,
+        _ => state.error(gl::INVALID_ENUM),
+    });
+}
+
+extern "system" fn BindTexture(target: GLenum, texture: GLuint) {
+    with(|state| {
+        if texture != 0 {
+            match state.textures.get_mut(&texture) {
+                Some(object) if object.target == 0 || object.target == target => {
+                    object.target = target
+                }
+                _ => return state.error(gl::INVALID_OPERATION),
+            }
+        }
+        let unit = state.active_texture;
+        state.texture_bindings.insert((unit, target), texture);
+    });
+}
+
+// to the texture's own target, 0 unbinds every target of the unit
+extern "system" fn BindTextureUnit(unit: GLuint, texture: GLuint) {
+    with(|state| {
+        if unit >= 32 {
+            return state.error(gl::INVALID_VALUE);
+        }
+        if texture == 0 {
+            return state
+                .texture_bindings
+                .retain(|&(bound, _), _| bound != unit);
+        }
+        match state.textures.get(&texture) {
+            Some(object) if object.target != 0 => {
+                let target = object.target;
+                state.texture_bindings.insert((unit, target), texture);
+            }
+            _ => state.error(gl::INVALID_OPERATION),
+        }
+    });
+}
+
+extern "system" fn BindFramebuffer(target: GLenum, framebuffer: GLuint) {
+    with(|state| {
+        if framebuffer != 0 && !state.framebuffers.contains_key(&framebuffer) {
+            return state.error(gl::INVALID_OPERATION);
+        }
+        match target {
+            gl::FRAMEBUFFER => {
+                state.draw_framebuffer = framebuffer;
+                state.read_framebuffer = framebuffer;
+            }
+            gl::DRAW_FRAMEBUFFER => state.draw_framebuffer = framebuffer,
+            gl::READ_FRAMEBUFFER => state.read_framebuffer = framebuffer,
+            _ => state.error(gl::INVALID_ENUM),
+        }
+    });
+}
+
+extern "system" fn BindRenderbuffer(_target: GLenum, renderbuffer: GLuint) {
+    with(
+        |state| match renderbuffer == 0 || state.renderbuffers.contains_key(&renderbuffer) {
+            true => state.renderbuffer = renderbuffer,
+            false => state.error(gl::INVALID_OPERATION),
+        },
+    );
+}
+
+extern "system" fn UseProgram(program: GLuint) {
+    with(|state| match program {
+        0 => state.current_program = 0,
+        _ => match state.programs.get(&program) {
+            Some(object) if object.linked => state.current_program = program,
+            _ => state.error(gl::INVALID_OPERATION),
+        },
+    });
+}
+
+// buffers
+
+unsafe fn buffer_data(buffer: GLuint, size: GLsizeiptr, data: *const c_void, usage: GLenum) {
+    if size < 0 {
+        return with(|state| state.error(gl::INVALID_VALUE));
+    }
+    with(|state| match state.buffers.get_mut(&buffer) {
+        Some(buffer) => {
+            buffer.data = match data.is_null() {
+                true => vec![0; size as usize],
+                false => slice::from_raw_parts(data as *const u8, size as usize).to_vec(),
+            };
+            buffer.usage = usage;
+        }
+        None => state.error(gl::INVALID_OPERATION),
+    });
+}
+
+unsafe extern "system" fn BufferData(
+    target: GLenum,
+    size: GLsizeiptr,
+    data: *const c_void,
+    usage: GLenum,
+) {
+    buffer_data(with(|state| state.bound_buffer(target)), size, data, usage);
+}
+
+unsafe extern "system" fn NamedBufferData(
+    buffer: GLuint,
+    size: GLsizeiptr,
+    data: *const c_void,
+    usage: GLenum,
+) {
+    buffer_data(buffer, size, data, usage);
+}
+
+unsafe extern "system" fn BufferSubData(
+    target: GLenum,
+    offset: GLintptr,
+    size: GLsizeiptr,
+    data: *const c_void,
+) {
+    with(|state| {
+        let Some(buffer) = state.buffer_mut(target) else {
+            return state.error(gl::INVALID_OPERATION);
+        };
+        let (offset, size) = (offset as usize, size as usize);
+        match buffer.data.get_mut(offset..offset + size) {
+            Some(range) => range.copy_from_slice(slice::from_raw_parts(data as *const u8, size)),
+            None => state.error(gl::INVALID_VALUE),
+        }
+    });
+}
+
+unsafe fn get_buffer_sub_data(
+    buffer: GLuint,
+    offset: GLintptr,
+    size: GLsizeiptr,
+    data: *mut c_void,
+) {
+    with(|state| {
+        let Some(buffer) = state.buffers.get(&buffer) else {
+            return state.error(gl::INVALID_OPERATION);
+        };
+        let (offset, size) = (offset as usize, size as usize);
+        match buffer.data.get(offset..offset + size) {
+            Some(range) => ptr::copy_nonoverlapping(range.as_ptr(), data as *mut u8, size),
+            None => state.error(gl::INVALID_VALUE),
+        }
+    });
+}
+
+unsafe extern "system" fn GetBufferSubData(
+    target: GLenum,
+    offset: GLintptr,
+    size: GLsizeiptr,
+    data: *mut c_void,
+) {
+    get_buffer_sub_data(with(|state| state.bound_buffer(target)), offset, size, data);
+}
+
+unsafe extern "system" fn GetNamedBufferSubData(
+    buffer: GLuint,
+    offset: GLintptr,
+    size: GLsizeiptr,
+    data: *mut c_void,
+) {
+    get_buffer_sub_data(buffer, offset, size, data);
+}
+
+// vertex arrays
+
+extern "system" fn VertexAttribPointer(
+    index: GLuint,
+    size: GLint,
+    type_: GLenum,
+    normalized: GLboolean,
+    stride: GLsizei,
+    pointer: *const c_void,
+) {
+    with(|state| {
+        let buffer = state.bound_buffer(gl::ARRAY_BUFFER);
+        let vertex_array = state.vertex_array;
+        // core profile: needs a vertex array and a buffer to point into
+        let Some(vao) = state.vertex_arrays.get_mut(&vertex_array) else {
+            return state.error(gl::INVALID_OPERATION);
+        };
+        if buffer == 0 {
+            return state.error(gl::INVALID_OPERATION);
+        }
+        let attribute = vao.attributes.entry(index).or_default();
+        attribute.size = size;
+        attribute.type_ = type_;
+        attribute.normalized = normalized != gl::FALSE;
+        attribute.binding = index;
+        attribute.relative_offset = 0;
+        let offset = pointer as usize;
+        vao.vertex_buffers.insert(
+            index,
+            VertexBuffer {
+                buffer,
+                offset,
+                stride,
+            },
+        );
+        vao.resolve();
+    });
+}
+
+fn set_attribute_enabled(index: GLuint, enabled: bool) {
+    with(|state| {
+        let vertex_array = state.vertex_array;
+        match state.vertex_arrays.get_mut(&vertex_array) {
+            Some(vao) => vao.attributes.entry(index).or_default().enabled = enabled,
+            None => state.error(gl::INVALID_OPERATION),
+        }
+    });
+}
+
+extern "system" fn EnableVertexAttribArray(index: GLuint) {
+    set_attribute_enabled(index, true);
+}
+
+extern "system" fn DisableVertexAttribArray(index: GLuint) {
+    set_attribute_enabled(index, false);
+}
+
+// the direct state access versions edit `vaobj` instead of the bound one
+fn edit_vertex_array(vaobj: GLuint, edit: impl FnOnce(&mut VertexArray)) {
+    with(|state| match state.vertex_arrays.get_mut(&vaobj) {
+        Some(vao) => {
+            edit(vao);
+            vao.resolve();
+        }
+        None => state.error(gl::INVALID_OPERATION),
+    });
+}
+
+fn is_buffer(buffer: GLuint) -> bool {
+    buffer == 0 || with(|state| state.buffers.contains_key(&buffer))
+}
+
+extern "system" fn VertexArrayVertexBuffer(
+    vaobj: GLuint,
+    bindingindex: GLuint,
+    buffer: GLuint,
+    offset: GLintptr,
+    stride: GLsizei,
+) {
+    if !is_buffer(buffer) {
+        return with(|state| state.error(gl::INVALID_OPERATION));
+    }
+    if offset < 0 || stride < 0 {
+        return with(|state| state.error(gl::INVALID_VALUE));
+    }
+    let binding = VertexBuffer {
+        buffer,
+        offset: offset as usize,
+        stride,
+    };
+    edit_vertex_array(vaobj, |vao| {
+        vao.vertex_buffers.insert(bindingindex, binding);
+    });
+}
+
+extern "system" fn VertexArrayElementBuffer(vaobj: GLuint, buffer: GLuint) {
+    if !is_buffer(buffer) {
+        return with(|state| state.error(gl::INVALID_OPERATION));
+    }
+    edit_vertex_array(vaobj, |vao| vao.element_buffer = buffer);
+}
+
+extern "system" fn VertexArrayAttribFormat(
+    vaobj: GLuint,
+    attribindex: GLuint,
+    size: GLint,
+    type_: GLenum,
+    normalized: GLboolean,
+    relativeoffset: GLuint,
+) {
+    edit_vertex_array(vaobj, |vao| {
+        let attribute = vao.attributes.entry(attribindex).or_default();
+        attribute.size = size;
+        attribute.type_ = type_;
+        attribute.normalized = normalized != gl::FALSE;
+        attribute.relative_offset = relativeoffset as usize;
+    });
+}
+
+extern "system" fn VertexArrayAttribBinding(
+    vaobj: GLuint,
+    attribindex: GLuint,
+    bindingindex: GLuint,
+) {
+    edit_vertex_array(vaobj, |vao| {
+        vao.attributes.entry(attribindex).or_default().binding = bindingindex;
+    });
+}
+
+extern "system" fn EnableVertexArrayAttrib(vaobj: GLuint, index: GLuint) {
+    edit_vertex_array(vaobj, |vao| {
+        vao.attributes.entry(index).or_default().enabled = true;
+    });
+}
+
+// textures
+
+// copy rows out of client memory laid out with `alignment`, into tight rows
+unsafe fn unpack(
+    pixels: *const c_void,
+    width: usize,
+    height: usize,
+    pixel_size: usize,
+    alignment: GLint,
+) -> Vec<u8> {
+    let tight = width * pixel_size;
+    if pixels.is_null() {
+        return vec![0; tight * height];
+    }
+    let stride = row_size(width, pixel_size, alignment);
+    let mut out = Vec::with_capacity(tight * height);
+    for row in 0..height {
+        let start = (pixels as *const u8).add(row * stride);
+        out.extend_from_slice(slice::from_raw_parts(start, tight));
+    }
+    out
+}
+
+unsafe extern "system" fn TexImage2D(
+    target: GLenum,
+    level: GLint,
+    internal_format: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    _border: GLint,
+    format: GLenum,
+    type_: GLenum,
+    pixels: *const c_void,
+) {
+    with(|state| {
+        let Some(pixel_size) = pixel_size(format, type_) else {
+            return state.error(gl::INVALID_ENUM);
+        };
+        if width < 0 || height < 0 || level < 0 {
+            return state.error(gl::INVALID_VALUE);
+        }
+        let alignment = state.pixel_store[&gl::UNPACK_ALIGNMENT];
+        let Some(texture) = state.texture_mut(target) else {
+            return state.error(gl::INVALID_OPERATION);
+        };
+        // only level 0 is kept
+        if level > 0 {
+            return;
+        }
+        let (w, h) = (width as usize, height as usize);
+        texture.pixels = unpack(pixels, w, h, pixel_size, alignment);
+        texture.width = width;
+        texture.height = height;
+        texture.internal_format = internal_format;
+        texture.format = format;
+        texture.type_ = type_;
+        texture.mipmaps = false;
+    });
+}
+
+unsafe extern "system" fn TexSubImage2D(
+    target: GLenum,
+    level: GLint,
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    format: GLenum,
+    type_: GLenum,
+    pixels: *const c_void,
+) {
+    with(|state| {
+        let alignment = state.pixel_store[&gl::UNPACK_ALIGNMENT];
+        let Some(texture) = state.texture_mut(target) else {
+            return state.error(gl::INVALID_OPERATION);
+        };
+        // formats are converted by real drivers, the mock only copies bytes
+        let Some(pixel_size) = pixel_size(format, type_)
+            .filter(|&size| Some(size) == pixel_size(texture.format, texture.type_))
+        else {
+            return state.error(gl::INVALID_OPERATION);
+        };
+        if x < 0 || y < 0 || x + width > texture.width || y + height > texture.height {
+            return state.error(gl::INVALID_VALUE);
+        }
+        if level > 0 {
+            return;
+        }
+        let (w, h) = (width as usize, height as usize);
+        let rows = unpack(pixels, w, h, pixel_size, alignment);
+        let stride = texture.width as usize * pixel_size;
+        for row in 0..h {
+            let start = (y as usize + row) * stride + x as usize * pixel_size;
+            texture.pixels[start..start + w * pixel_size]
+                .copy_from_slice(&rows[row * w * pixel_size..(row + 1) * w * pixel_size]);
+        }
+    });
+}
+
+extern "system" fn TexParameteri(target: GLenum, pname: GLenum, param: GLint) {
+    with(|state| match state.texture_mut(target) {
+        Some(texture) => {
+            texture.parameters.insert(pname, param);
+        }
+        None => state.error(gl::INVALID_OPERATION),
+    });
+}
+
+extern "system" fn TextureParameteri(texture: GLuint, pname: GLenum, param: GLint) {
+    with(|state| match state.textures.get_mut(&texture) {
+        Some(texture) => {
+            texture.parameters.insert(pname, param);
+        }
+        None => state.error(gl::INVALID_OPERATION),
+    });
+}
+
+extern "system" fn GenerateMipmap(target: GLenum) {
+    with(|state| match state.texture_mut(target) {
+        Some(texture) => texture.mipmaps = true,
+        None => state.error(gl::INVALID_OPERATION),
+    });
+}
+
+extern "system" fn PixelStorei(pname: GLenum, param: GLint) {
+    with(|state| match (pname, param) {
+        (gl::UNPACK_ALIGNMENT | gl::PACK_ALIGNMENT, 1 | 2 | 4 | 8) => {
+            state.pixel_store.insert(pname, param);
+        }
+        (gl::UNPACK_ALIGNMENT | gl::PACK_ALIGNMENT, _) => state.error(gl::INVALID_VALUE),
+        _ => {
+            state.pixel_store.insert(pname, param);
+        }
+    });
+}
+
+// framebuffers
+
+fn bound_framebuffer(state: &State, target: GLenum) -> GLuint {
+    match target {
+        gl::READ_FRAMEBUFFER => state.read_framebuffer,
+        _ => state.draw_framebuffer,
+    }
+}
+
+fn attach(target: GLenum, attachment: GLenum, object: Option<Attachment>) {
+    with(|state| {
+        let framebuffer = bound_framebuffer(state, target);
+        match state.framebuffers.get_mut(&framebuffer) {
+            Some(framebuffer) => {
+                match object {
+                    Some(object) => framebuffer.attachments.insert(attachment, object),
+                    None => framebuffer.attachments.remove(&attachment),
+                };
+            }
+            // the default framebuffer can't be attached to
+            None => state.error(gl::INVALID_OPERATION),
+        }
+    });
+}
+
+extern "system" fn FramebufferTexture2D(
+    target: GLenum,
+    attachment: GLenum,
+    _textarget: GLenum,
+    texture: GLuint,
+    _level: GLint,
+) {
+    attach(
+        target,
+        attachment,
+        (texture != 0).then_some(Attachment::Texture(texture)),
+    );
+}
+
+extern "system" fn FramebufferRenderbuffer(
+    target: GLenum,
+    attachment: GLenum,
+    _renderbuffertarget: GLenum,
+    renderbuffer: GLuint,
+) {
+    let object = (renderbuffer != 0).then_some(Attachment::Renderbuffer(renderbuffer));
+    attach(target, attachment, object);
+}
+
+extern "system" fn CheckFramebufferStatus(target: GLenum) -> GLenum {
+    with(|state| {
+        let framebuffer = bound_framebuffer(state, target);
+        match state.framebuffers.get(&framebuffer) {
+            None => gl::FRAMEBUFFER_COMPLETE,
+            Some(framebuffer) if framebuffer.attachments.is_empty() => {
+                gl::FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT
+            }
+            Some(_) => gl::FRAMEBUFFER_COMPLETE,
+        }
+    })
+}
+
+extern "system" fn RenderbufferStorage(
+    _target: GLenum,
+    internal_format: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+) {
+    with(|state| {
+        let renderbuffer = state.renderbuffer;
+        match state.renderbuffers.get_mut(&renderbuffer) {
+            Some(renderbuffer) => {
+                *renderbuffer = Renderbuffer {
+                    internal_format,
+                    width,
+                    height,
+                }
+            }
+            None => state.error(gl::INVALID_OPERATION),
+        }
+    });
+}
+
+extern "system" fn ReadBuffer(_src: GLenum) {}
+
+// every pixel is the last clear color, nothing else is ever drawn
+unsafe extern "system" fn ReadPixels(
+    _x: GLint,
+    _y: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    format: GLenum,
+    type_: GLenum,
+    pixels: *mut c_void,
+) {
+    with(|state| {
+        let Some(pixel_size) = pixel_size(format, type_) else {
+            return state.error(gl::INVALID_ENUM);
+        };
+        let color = state
+            .clear_color
+            .map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+        let pixel: &[u8] = match (format, type_) {
+            (gl::RGBA, gl::UNSIGNED_BYTE) => &color,
+            (gl::RGB, gl::UNSIGNED_BYTE) => &color[..3],
+            _ => &[],
+        };
+        let stride = row_size(
+            width as usize,
+            pixel_size,
+            state.pixel_store[&gl::PACK_ALIGNMENT],
+        );
+        for row in 0..height as usize {
+            let start = (pixels as *mut u8).add(row * stride);
+            let row = slice::from_raw_parts_mut(start, width as usize * pixel_size);
+            match pixel.is_empty() {
+                true => row.fill(0),
+                false => row
+                    .chunks_mut(pixel_size)
+                    .for_each(|out| out.copy_from_slice(pixel)),
+            }
+        }
+    });
+}
+
+// shaders
+
+extern "system" fn CreateShader(type_: GLenum) -> GLuint {
+    with(|state| match type_ {
+        gl::VERTEX_SHADER | gl::FRAGMENT_SHADER | gl::GEOMETRY_SHADER | gl::COMPUTE_SHADER => {
+            let name = state.name();
+            let shader = Shader {
+                type_,
+                ..Shader::default()
+            };
+            state.shaders.insert(name, shader);
+            name
+        }
+        _ => {
+            state.error(gl::INVALID_ENUM);
+            0
+        }
+    })
+}
+
+unsafe extern "system" fn ShaderSource(
+    shader: GLuint,
+    count: GLsizei,
+    string: *const *const GLchar,
+    length: *const GLint,
+) {
+    let mut source = String::new();
+    for i in 0..count.max(0) as usize {
+        let part = *string.add(i);
+        let len = match length.is_null() {
+            true => -1,
+            false => *length.add(i),
+        };
+        // a negative length means null terminated
+        let bytes = match len < 0 {
+            true => CStr::from_ptr(part).to_bytes(),
+            false => slice::from_raw_parts(part as *const u8, len as usize),
+        };
+        source += &String::from_utf8_lossy(bytes);
+    }
+    with(|state| match state.shaders.get_mut(&shader) {
+        Some(object) => object.source = source,
+        None => state.error(gl::INVALID_VALUE),
+    });
+}
+
+extern "system" fn CompileShader(shader: GLuint) {
+    with(|state| {
+        let Some(shader) = state.shaders.get_mut(&shader) else {
+            return state.error(gl::INVALID_VALUE);
+        };
+        let error = if !shader.source.contains("void main") {
+            Some(String::from("0:1(1): error: no main function"))
+        } else {
+            shader
+                .source
+                .lines()
+                .enumerate()
+                .find_map(|(number, line)| {
+                    let message = line.trim().strip_prefix("#error")?;
+                    Some(format!("0:{}(1): error: {}", number + 1, message.trim()))
+                })
+        };
+        shader.compiled = error.is_none();
+        shader.info_log = error.unwrap_or_default();
+    });
+}
+
+extern "system" fn DeleteShader(shader: GLuint) {
+    with(|state| {
+        if shader == 0 {
+            return;
+        }
+        let attached = state
+            .programs
+            .values()
+            .any(|program| program.shaders.contains(&shader));
+        match state.shaders.get_mut(&shader) {
+            // attached shaders live on until their program is deleted
+            Some(object) if attached => object.deleted = true,
+            Some(_) => {
+                state.shaders.remove(&shader);
+            }
+            None => state.error(gl::INVALID_VALUE),
+        }
+    });
+}
+
+extern "system" fn CreateProgram() -> GLuint {
+    with(|state| {
+        let name = state.name();
+        state.programs.insert(name, Program::default());
+        name
+    })
+}
+
+extern "system" fn AttachShader(program: GLuint, shader: GLuint) {
+    with(|state| {
+        if !state.shaders.contains_key(&shader) {
+            return state.error(gl::INVALID_VALUE);
+        }
+        match state.programs.get_mut(&program) {
+            Some(object) if object.shaders.contains(&shader) => state.error(gl::INVALID_OPERATION),
+            Some(object) => object.shaders.push(shader),
+            None => state.error(gl::INVALID_VALUE),
+        }
+    });
+}
+
+extern "system" fn LinkProgram(program: GLuint) {
+    with(|state| {
+        let Some(object) = state.programs.get(&program) else {
+            return state.error(gl::INVALID_VALUE);
+        };
+        let shaders: Vec<&Shader> = object
+            .shaders
+            .iter()
+            .filter_map(|shader| state.shaders.get(shader))
+            .collect();
+        let has = |type_| shaders.iter().any(|shader| shader.type_ == type_);
+        let error = if shaders.iter().any(|shader| !shader.compiled) {
+            Some("error: linking with uncompiled/unspecialized shader")
+        } else if has(gl::COMPUTE_SHADER) {
+            match shaders.len() {
+                1 => None,
+                _ => Some("error: a compute shader can't be linked with other stages"),
+            }
+        } else if !has(gl::VERTEX_SHADER) || !has(gl::FRAGMENT_SHADER) {
+            Some("error: a program needs a vertex and a fragment shader")
+        } else {
+            None
+        };
+
+        let mut locations = BTreeMap::new();
+        if error.is_none() {
+            for shader in &shaders {
+                for name in uniform_names(&shader.source) {
+                    let location = locations.len() as GLint;
+                    locations.entry(name).or_insert(location);
+                }
+            }
+        }
+
+        let object = state.programs.get_mut(&program).unwrap();
+        object.linked = error.is_none();
+        object.info_log = error.unwrap_or_default().to_string();
+        object.uniform_locations = locations;
+        object.uniforms.clear();
+    });
+}
+
+// `uniform [precision] <type> <name>[[size]];`, arrays get a name per element
+fn uniform_names(source: &str) -> Vec<String> {
+    let code: String = source
+        .lines()
+        .map(|line| line.split("//").next().unwrap_or(""))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let mut names = vec![];
+    for statement in code.split(';') {
+        let tokens: Vec<&str> = statement.split_whitespace().collect();
+        let Some(start) = tokens.iter().position(|&token| token == "uniform") else {
+            continue;
+        };
+        // uniform blocks aren't plain uniforms
+        if statement.contains('{') {
+            continue;
+        }
+        let rest: Vec<&str> = tokens[start + 1..]
+            .iter()
+            .copied()
+            .filter(|token| !matches!(*token, "lowp" | "mediump" | "highp"))
+            .collect();
+        let Some(declarator) = rest.get(1..) else {
+            continue;
+        };
+        // "a", "a[4]" or "a [4]", and several of them separated by commas
+        for name in declarator.join("").split(',') {
+            match name.split_once('[') {
+                Some((base, size)) => {
+                    let size: usize = size.trim_end_matches(']').parse().unwrap_or(1);
+                    names.push(base.to_string());
+                    names.extend((0..size).map(|i| format!("{}[{}]", base, i)));
+                }
+                None if !name.is_empty() => names.push(name.to_string()),
+                None => {}
+            }
+        }
+    }
+    names
+}
+
+extern "system" fn DeleteProgram(program: GLuint) {
+    with(|state| {
+        if program == 0 {
+            return;
+        }
+        let Some(object) = state.programs.remove(&program) else {
+            return state.error(gl::INVALID_VALUE);
+        };
+        for shader in object.shaders {
+            if state
+                .shaders
+                .get(&shader)
+                .is_some_and(|shader| shader.deleted)
+            {
+                state.shaders.remove(&shader);
+            }
+        }
+        if state.current_program == program {
+            state.current_program = 0;
+        }
+    });
+}
+
+unsafe extern "system" fn GetShaderiv(shader: GLuint, pname: GLenum, params: *mut GLint) {
+    with(|state| {
+        let Some(shader) = state.shaders.get(&shader) else {
+            return state.error(gl::INVALID_VALUE);
+        };
+        *params = match pname {
+            gl::SHADER_TYPE => shader.type_ as GLint,
+            gl::COMPILE_STATUS => shader.compiled as GLint,
+            gl::DELETE_STATUS => shader.deleted as GLint,
+            gl::INFO_LOG_LENGTH => log_length(&shader.info_log),
+            gl::SHADER_SOURCE_LENGTH => log_length(&shader.source),
+            _ => return state.error(gl::INVALID_ENUM),
+        };
+    });
+}
+
+unsafe extern "system" fn GetProgramiv(program: GLuint, pname: GLenum, params: *mut GLint) {
+    with(|state| {
+        let Some(program) = state.programs.get(&program) else {
+            return state.error(gl::INVALID_VALUE);
+        };
+        *params = match pname {
+            gl::LINK_STATUS => program.linked as GLint,
+            gl::INFO_LOG_LENGTH => log_length(&program.info_log),
+            gl::ATTACHED_SHADERS => program.shaders.len() as GLint,
+            gl::ACTIVE_UNIFORMS => program.uniform_locations.len() as GLint,
+            _ => return state.error(gl::INVALID_ENUM),
+        };
+    });
+}
+
+// with the null terminator, 0 for an empty log
+fn log_length(log: &str) -> GLint {
+    match log.len() {
+        0 => 0,
+        len => len as GLint + 1,
+    }
+}
+
+unsafe fn copy_log(log: &str, buf_size: GLsizei, length: *mut GLsizei, out: *mut GLchar) {
+    let len = log.len().min((buf_size.max(1) - 1) as usize);
+    if buf_size > 0 {
+        ptr::copy_nonoverlapping(log.as_ptr() as *const GLchar, out, len);
+        *out.add(len) = 0;
+    }
+    if !length.is_null() {
+        *length = len as GLsizei;
+    }
+}
+
+unsafe extern "system" fn GetShaderInfoLog(
+    shader: GLuint,
+    buf_size: GLsizei,
+    length: *mut GLsizei,
+    info_log: *mut GLchar,
+) {
+    let log = with(|state| {
+        state
+            .shaders
+            .get(&shader)
+            .map(|shader| shader.info_log.clone())
+    });
+    match log {
+        Some(log) => copy_log(&log, buf_size, length, info_log),
+        None => with(|state| state.error(gl::INVALID_VALUE)),
+    }
+}
+
+unsafe extern "system" fn GetProgramInfoLog(
+    program: GLuint,
+    buf_size: GLsizei,
+    length: *mut GLsizei,
+    info_log: *mut GLchar,
+) {
+    let log = with(|state| {
+        state
+            .programs
+            .get(&program)
+            .map(|program| program.info_log.clone())
+    });
+    match log {
+        Some(log) => copy_log(&log, buf_size, length, info_log),
+        None => with(|state| state.error(gl::INVALID_VALUE)),
+    }
+}
+
+// uniforms
+
+unsafe extern "system" fn GetUniformLocation(program: GLuint, name: *const GLchar) -> GLint {
+    let name = CStr::from_ptr(name).to_string_lossy();
+    with(|state| match state.programs.get(&program) {
+        Some(program) if program.linked => program
+            .uniform_locations
+            .get(name.as_ref())
+            .copied()
+            .unwrap_or(-1),
+        _ => {
+            state.error(gl::INVALID_OPERATION);
+            -1
+        }
+    })
+}
+
+fn set_uniform(location: GLint, value: Uniform) {
+    with(|state| {
+        // -1 is what GetUniformLocation returns for unused uniforms, ignored
+        if location == -1 {
+            return;
+        }
+        let current = state.current_program;
+        match state.programs.get_mut(&current) {
+            Some(program) if program.uniform_locations.values().any(|&l| l == location) => {
+                program.uniforms.insert(location, value);
+            }
+            _ => state.error(gl::INVALID_OPERATION),
+        }
+    });
+}
+
+unsafe fn floats(count: GLsizei, components: usize, value: *const GLfloat) -> Uniform {
+    let len = count.max(0) as usize * components;
+    Uniform::Float(slice::from_raw_parts(value, len).to_vec())
+}
+
+extern "system" fn Uniform1i(location: GLint, v0: GLint) {
+    set_uniform(location, Uniform::Int(vec![v0]));
+}
+
+unsafe extern "system" fn Uniform1iv(location: GLint, count: GLsizei, value: *const GLint) {
+    let values = slice::from_raw_parts(value, count.max(0) as usize).to_vec();
+    set_uniform(location, Uniform::Int(values));
+}
+
+extern "system" fn Uniform1f(location: GLint, v0: GLfloat) {
+    set_uniform(location, Uniform::Float(vec![v0]));
+}
+
+extern "system" fn Uniform2f(location: GLint, v0: GLfloat, v1: GLfloat) {
+    set_uniform(location, Uniform::Float(vec![v0, v1]));
+}
+
+extern "system" fn Uniform3f(location: GLint, v0: GLfloat, v1: GLfloat, v2: GLfloat) {
+    set_uniform(location, Uniform::Float(vec![v0, v1, v2]));
+}
+
+extern "system" fn Uniform4f(location: GLint, v0: GLfloat, v1: GLfloat, v2: GLfloat, v3: GLfloat) {
+    set_uniform(location, Uniform::Float(vec![v0, v1, v2, v3]));
+}
+
+unsafe extern "system" fn Uniform1fv(location: GLint, count: GLsizei, value: *const GLfloat) {
+    set_uniform(location, floats(count, 1, value));
+}
+
+unsafe extern "system" fn Uniform2fv(location: GLint, count: GLsizei, value: *const GLfloat) {
+    set_uniform(location, floats(count, 2, value));
+}
+
+unsafe extern "system" fn Uniform3fv(location: GLint, count: GLsizei, value: *const GLfloat) {
+    set_uniform(location, floats(count, 3, value));
+}
+
+unsafe extern "system" fn Uniform4fv(location: GLint, count: GLsizei, value: *const GLfloat) {
+    set_uniform(location, floats(count, 4, value));
+}
+
+// stored as passed, column major unless `transpose`
+unsafe extern "system" fn UniformMatrix2fv(
+    location: GLint,
+    count: GLsizei,
+    _transpose: GLboolean,
+    value: *const GLfloat,
+) {
+    set_uniform(location, floats(count, 4, value));
+}
+
+unsafe extern "system" fn UniformMatrix3fv(
+    location: GLint,
+    count: GLsizei,
+    _transpose: GLboolean,
+    value: *const GLfloat,
+) {
+    set_uniform(location, floats(count, 9, value));
+}
+
+unsafe extern "system" fn UniformMatrix4fv(
+    location: GLint,
+    count: GLsizei,
+    _transpose: GLboolean,
+    value: *const GLfloat,
+) {
+    set_uniform(location, floats(count, 16, value));
+}
+
+// drawing
+
+extern "system" fn Clear(mask: GLbitfield) {
+    with(|state| state.clears.push(mask));
+}
+
+extern "system" fn ClearColor(red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat) {
+    with(|state| state.clear_color = [red, green, blue, alpha]);
+}
+
+fn draw(mode: GLenum, count: GLsizei, indexed: bool) {
+    with(|state| {
+        if count < 0 {
+            return state.error(gl::INVALID_VALUE);
+        }
+        // core profile: drawing needs a vertex array and, indexed, an element buffer
+        let vertex_array = state.vertex_array;
+        let element_buffer = state.bound_buffer(gl::ELEMENT_ARRAY_BUFFER);
+        if vertex_array == 0 || (indexed && element_buffer == 0) {
+            return state.error(gl::INVALID_OPERATION);
+        }
+        let program = state.current_program;
+        state.draws.push(Draw {
+            mode,
+            count,
+            indexed,
+            program,
+            vertex_array,
+        });
+    });
+}
+
+extern "system" fn DrawArrays(mode: GLenum, _first: GLint, count: GLsizei) {
+    draw(mode, count, false);
+}
+
+extern "system" fn DrawElements(
+    mode: GLenum,
+    count: GLsizei,
+    _type: GLenum,
+    _indices: *const c_void,
+) {
+    draw(mode, count, true);
+}
+
+// recorded like draws, needs a program with a compute shader in use
+extern "system" fn DispatchCompute(
+    num_groups_x: GLuint,
+    num_groups_y: GLuint,
+    num_groups_z: GLuint,
+) {
+    with(|state| {
+        let program = state.current_program;
+        let compute = state.programs.get(&program).is_some_and(|object| {
+            object.shaders.iter().any(|shader| {
+                state
+                    .shaders
+                    .get(shader)
+                    .is_some_and(|shader| shader.type_ == gl::COMPUTE_SHADER)
+            })
+        });
+        if !compute {
+            return state.error(gl::INVALID_OPERATION);
+        }
+        state.dispatches.push(Dispatch {
+            groups: [num_groups_x, num_groups_y, num_groups_z],
+            program,
+        });
+    });
+}
+
+// nothing runs, so there's nothing to wait for
+extern "system" fn MemoryBarrier(_barriers: GLbitfield) {}
+
+// state
+
+extern "system" fn Enable(cap: GLenum) {
+    with(|state| state.enabled.insert(cap));
+}
+
+extern "system" fn Disable(cap: GLenum) {
+    with(|state| state.enabled.remove(&cap));
+}
+
+extern "system" fn IsEnabled(cap: GLenum) -> GLboolean {
+    with(|state| state.enabled.contains(&cap) as GLboolean)
+}
+
+extern "system" fn Viewport(x: GLint, y: GLint, width: GLsizei, height: GLsizei) {
+    with(|state| state.viewport = [x, y, width, height]);
+}
+
+extern "system" fn BlendFunc(sfactor: GLenum, dfactor: GLenum) {
+    with(|state| state.blend_func = (sfactor, dfactor));
+}
+
+extern "system" fn DepthFunc(func: GLenum) {
+    with(|state| state.depth_func = func);
+}
+
+extern "system" fn DepthMask(flag: GLboolean) {
+    with(|state| state.depth_mask = flag != gl::FALSE);
+}
+
+extern "system" fn CullFace(mode: GLenum) {
+    with(|state| state.cull_face = mode);
+}
+
+extern "system" fn PolygonMode(_face: GLenum, mode: GLenum) {
+    with(|state| state.polygon_mode = mode);
+}
+
+// limits, roughly what a small desktop GPU reports
+fn limit(pname: GLenum) -> Option<GLint> {
+    Some(match pname {
+        gl::MAX_TEXTURE_SIZE | gl::MAX_CUBE_MAP_TEXTURE_SIZE | gl::MAX_RENDERBUFFER_SIZE => 16384,
+        gl::MAX_3D_TEXTURE_SIZE | gl::MAX_ARRAY_TEXTURE_LAYERS => 2048,
+        gl::MAX_TEXTURE_IMAGE_UNITS => 16,
+        gl::MAX_COMBINED_TEXTURE_IMAGE_UNITS => 32,
+        gl::MAX_VERTEX_ATTRIBS => 16,
+        gl::MAX_VERTEX_UNIFORM_COMPONENTS | gl::MAX_FRAGMENT_UNIFORM_COMPONENTS => 4096,
+        gl::MAX_UNIFORM_BLOCK_SIZE => 65536,
+        gl::MAX_UNIFORM_BUFFER_BINDINGS => 36,
+        gl::MAX_SAMPLES => 4,
+        gl::MAX_COLOR_ATTACHMENTS | gl::MAX_DRAW_BUFFERS => 8,
+        _ => return None,
+    })
+}
+
+unsafe extern "system" fn GetIntegerv(pname: GLenum, data: *mut GLint) {
+    with(|state| {
+        let value = match pname {
+            gl::MAJOR_VERSION => state.version.0,
+            gl::MINOR_VERSION => state.version.1,
+            gl::CONTEXT_PROFILE_MASK => gl::CONTEXT_CORE_PROFILE_BIT as GLint,
+            gl::CONTEXT_FLAGS => 0,
+            gl::NUM_EXTENSIONS => EXTENSIONS.len() as GLint,
+            gl::ARRAY_BUFFER_BINDING => state.bound_buffer(gl::ARRAY_BUFFER) as GLint,
+            gl::ELEMENT_ARRAY_BUFFER_BINDING => {
+                state.bound_buffer(gl::ELEMENT_ARRAY_BUFFER) as GLint
+            }
+            gl::PIXEL_PACK_BUFFER_BINDING => state.bound_buffer(gl::PIXEL_PACK_BUFFER) as GLint,
+            gl::VERTEX_ARRAY_BINDING => state.vertex_array as GLint,
+            gl::CURRENT_PROGRAM => state.current_program as GLint,
+            gl::ACTIVE_TEXTURE => (gl::TEXTURE0 + state.active_texture) as GLint,
+            gl::TEXTURE_BINDING_2D => state.bound_texture(gl::TEXTURE_2D) as GLint,
+            // same as DRAW_FRAMEBUFFER_BINDING
+            gl::FRAMEBUFFER_BINDING => state.draw_framebuffer as GLint,
+            gl::READ_FRAMEBUFFER_BINDING => state.read_framebuffer as GLint,
+            gl::RENDERBUFFER_BINDING => state.renderbuffer as GLint,
+            gl::UNPACK_ALIGNMENT | gl::PACK_ALIGNMENT => state.pixel_store[&pname],
+            gl::VIEWPORT => {
+                ptr::copy_nonoverlapping(state.viewport.as_ptr(), data, 4);
+                return;
+            }
+            gl::MAX_VIEWPORT_DIMS => {
+                *data = 16384;
+                *data.add(1) = 16384;
+                return;
+            }
+            _ => match limit(pname) {
+                Some(value) => value,
+                None => return state.error(gl::INVALID_ENUM),
+            },
+        };
+        *data = value;
+    });
+}
+
+unsafe extern "system" fn GetFloatv(pname: GLenum, data: *mut GLfloat) {
+    match pname {
+        gl::COLOR_CLEAR_VALUE => with(|state| {
+            ptr::copy_nonoverlapping(state.clear_color.as_ptr(), data, 4);
+        }),
+        _ => match limit(pname) {
+            Some(value) => *data = value as GLfloat,
+            None => with(|state| state.error(gl::INVALID_ENUM)),
+        },
+    }
+}
+
+extern "system" fn GetString(name: GLenum) -> *const GLubyte {
+    // the version strings live in the state until the next `set_version`
+    let string = match name {
+        gl::VENDOR => VENDOR.as_ptr(),
+        gl::RENDERER => RENDERER.as_ptr(),
+        gl::VERSION => with(|state| state.version_string.as_ptr()),
+        gl::SHADING_LANGUAGE_VERSION => with(|state| state.shading_language_version.as_ptr()),
+        _ => {
+            with(|state| state.error(gl::INVALID_ENUM));
+            return ptr::null();
+        }
+    };
+    string as *const GLubyte
+}
+
+extern "system" fn GetStringi(name: GLenum, index: GLuint) -> *const GLubyte {
+    match (name, EXTENSIONS.get(index as usize)) {
+        (gl::EXTENSIONS, Some(extension)) => extension.as_ptr() as *const GLubyte,
+        _ => {
+            with(|state| state.error(gl::INVALID_VALUE));
+            ptr::null()
+        }
+    }
+}
+
+extern "system" fn GetError() -> GLenum {
+    with(|state| match state.errors.is_empty() {
+        true => gl::NO_ERROR,
+        false => state.errors.remove(0),
+    })
+}
+
+// debug output, nothing is ever reported
+
+extern "system" fn DebugMessageCallback(_callback: GLDEBUGPROC, _user_param: *const c_void) {}
+
+unsafe extern "system" fn ObjectLabel(
+    identifier: GLenum,
+    name: GLuint,
+    length: GLsizei,
+    label: *const GLchar,
+) {
+    let label = match length < 0 {
+        true => CStr::from_ptr(label).to_string_lossy().into_owned(),
+        false => {
+            String::from_utf8_lossy(slice::from_raw_parts(label as *const u8, length as usize))
+                .into_owned()
+        }
+    };
+    with(|state| state.labels.insert((identifier, name), label));
+}
+
+unsafe extern "system" fn PushDebugGroup(
+    _source: GLenum,
+    _id: GLuint,
+    length: GLsizei,
+    message: *const GLchar,
+) {
+    let message = match length < 0 {
+        true => CStr::from_ptr(message).to_string_lossy().into_owned(),
+        false => {
+            String::from_utf8_lossy(slice::from_raw_parts(message as *const u8, length as usize))
+                .into_owned()
+        }
+    };
+    with(|state| state.debug_groups.push(message));
+}
+
+extern "system" fn PopDebugGroup() {
+    with(|state| {
+        if state.debug_groups.pop().is_none() {
+            state.error(gl::STACK_UNDERFLOW);
+        }
+    });
+}
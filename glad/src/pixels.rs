@@ -0,0 +1,66 @@
+// Sizes of client-side pixel data, for the code that has to know how much
+// memory a glTexImage2D or glReadPixels pointer covers.
+
+use crate::gl33::{self as gl, types::*};
+
+// bytes per pixel, None for a format or type GL wouldn't take
+pub(crate) fn pixel_size(format: GLenum, type_: GLenum) -> Option<usize> {
+    // packed types hold a whole pixel
+    let packed = match type_ {
+        gl::UNSIGNED_BYTE_3_3_2 | gl::UNSIGNED_BYTE_2_3_3_REV => 1,
+        gl::UNSIGNED_SHORT_5_6_5
+        | gl::UNSIGNED_SHORT_5_6_5_REV
+        | gl::UNSIGNED_SHORT_4_4_4_4
+        | gl::UNSIGNED_SHORT_4_4_4_4_REV
+        | gl::UNSIGNED_SHORT_5_5_5_1
+        | gl::UNSIGNED_SHORT_1_5_5_5_REV => 2,
+        gl::UNSIGNED_INT_8_8_8_8
+        | gl::UNSIGNED_INT_8_8_8_8_REV
+        | gl::UNSIGNED_INT_10_10_10_2
+        | gl::UNSIGNED_INT_2_10_10_10_REV
+        | gl::UNSIGNED_INT_24_8
+        | gl::UNSIGNED_INT_10F_11F_11F_REV
+        | gl::UNSIGNED_INT_5_9_9_9_REV => 4,
+        gl::FLOAT_32_UNSIGNED_INT_24_8_REV => 8,
+        _ => 0,
+    };
+    if packed > 0 {
+        return Some(packed);
+    }
+
+    let components = match format {
+        gl::RED | gl::RED_INTEGER | gl::DEPTH_COMPONENT | gl::STENCIL_INDEX => 1,
+        gl::RG | gl::RG_INTEGER => 2,
+        gl::RGB | gl::BGR | gl::RGB_INTEGER | gl::BGR_INTEGER => 3,
+        gl::RGBA | gl::BGRA | gl::RGBA_INTEGER | gl::BGRA_INTEGER => 4,
+        _ => return None,
+    };
+    let size = match type_ {
+        gl::UNSIGNED_BYTE | gl::BYTE => 1,
+        gl::UNSIGNED_SHORT | gl::SHORT | gl::HALF_FLOAT => 2,
+        gl::UNSIGNED_INT | gl::INT | gl::FLOAT => 4,
+        _ => return None,
+    };
+    Some(components * size)
+}
+
+// bytes from one row to the next with GL_(UN)PACK_ALIGNMENT `alignment`
+pub(crate) fn row_size(width: usize, pixel_size: usize, alignment: GLint) -> usize {
+    let alignment = alignment.max(1) as usize;
+    (width * pixel_size).div_ceil(alignment) * alignment
+}
+
+// what GL reads for a width x height x depth image, the last row isn't padded
+pub(crate) fn image_size(
+    width: usize,
+    height: usize,
+    depth: usize,
+    pixel_size: usize,
+    alignment: GLint,
+) -> usize {
+    let rows = height * depth;
+    match rows {
+        0 => 0,
+        _ => row_size(width, pixel_size, alignment) * (rows - 1) + width * pixel_size,
+    }
+}
@@ -0,0 +1,369 @@
+// Plays a trace from `crate::trace` back on another context.
+//
+// Object names, uniform locations and syncs differ between the traced run and
+// the replay, so the replayer keeps a map of each: a traced name is replaced by
+// the one the replay got from the same glGen*/glCreate* call. Pointers to
+// client memory point at copies of the call's payloads, pointers GL writes to
+// get scratch memory. Calls that can't be replayed, because they read memory
+// the trace doesn't have or take a callback, are skipped and counted.
+// Replays go through gl33, which has every extension, so traces from gl46 or
+// slim bindings play back too, except the few 4.x functions without an
+// extension like glSpecializeShader.
+//
+//   let mut replayer = Replayer::new(0);
+//   for event in TraceReader::open("bug.gltrace")? {
+//       unsafe { replayer.replay(&gl, &event?) };
+//   }
+
+use crate::{
+    gl33::{self as gl, types::*, Gl},
+    pixels::{image_size, pixel_size},
+    trace::{shader_sources, Call, Event, Value},
+};
+use std::{
+    collections::{BTreeMap, HashMap},
+    ptr,
+};
+
+// a gl33 function as declared in src/gl33.rs, types as written there
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature {
+    // without the gl prefix
+    pub name: &'static str,
+    pub ret: &'static str,
+    // (name, type)
+    pub params: &'static [(&'static str, &'static str)],
+}
+
+// SIGNATURES and `dispatch`, generated by build.rs from the func! list
+include!(concat!(env!("OUT_DIR"), "/gl33_replay.rs"));
+
+pub fn signature(function: &str) -> Option<&'static Signature> {
+    SIGNATURES
+        .binary_search_by(|signature| signature.name.cmp(function))
+        .ok()
+        .map(|i| &SIGNATURES[i])
+}
+
+// a trace value as the parameter type `dispatch` passes it as
+pub(crate) trait Arg {
+    fn arg(value: Value) -> Self;
+}
+
+macro_rules! arg {
+    ($convert:ident: $($ty:ty),*) => {
+        $(impl Arg for $ty {
+            fn arg(value: Value) -> Self {
+                value.$convert() as $ty
+            }
+        })*
+    };
+}
+
+arg!(as_i64: i8, i16, i32, i64, isize);
+arg!(as_u64: u8, u16, u32, u64, usize);
+arg!(as_f64: f32, f64);
+
+impl<T> Arg for *const T {
+    fn arg(value: Value) -> Self {
+        value.as_u64() as usize as *const T
+    }
+}
+
+impl<T> Arg for *mut T {
+    fn arg(value: Value) -> Self {
+        value.as_u64() as usize as *mut T
+    }
+}
+
+// the kinds of object names, each has its own namespace
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Kind {
+    Buffer,
+    Texture,
+    // shaders and programs share their names
+    Program,
+    VertexArray,
+    Framebuffer,
+    Renderbuffer,
+    Sampler,
+    Query,
+    TransformFeedback,
+    ProgramPipeline,
+}
+
+// (kind, count argument, names argument) of a function generating names
+pub(crate) fn generated_names(function: &str) -> Option<(Kind, usize, usize)> {
+    let kind = match function {
+        "GenBuffers" | "CreateBuffers" => Kind::Buffer,
+        "GenTextures" | "CreateTextures" => Kind::Texture,
+        "GenVertexArrays" | "CreateVertexArrays" => Kind::VertexArray,
+        "GenFramebuffers" | "CreateFramebuffers" => Kind::Framebuffer,
+        "GenRenderbuffers" | "CreateRenderbuffers" => Kind::Renderbuffer,
+        "GenSamplers" | "CreateSamplers" => Kind::Sampler,
+        "GenQueries" | "CreateQueries" => Kind::Query,
+        "GenTransformFeedbacks" | "CreateTransformFeedbacks" => Kind::TransformFeedback,
+        "GenProgramPipelines" | "CreateProgramPipelines" => Kind::ProgramPipeline,
+        _ => return None,
+    };
+    // glCreateTextures and glCreateQueries take a target first
+    match function {
+        "CreateTextures" | "CreateQueries" => Some((kind, 1, 2)),
+        _ => Some((kind, 0, 1)),
+    }
+}
+
+// the kind of name a GLuint or *const GLuint parameter takes, by its name
+pub(crate) fn kind(function: &str, param: &str, ty: &str, args: &[Value]) -> Option<Kind> {
+    if ty != "GLuint" && ty != "*const GLuint" {
+        return None;
+    }
+    Some(match param {
+        "buffer" | "buffers" => Kind::Buffer,
+        "texture" | "textures" => Kind::Texture,
+        "shader" | "shaders" | "program" => Kind::Program,
+        "array" | "arrays" | "vaobj" => Kind::VertexArray,
+        "framebuffer" | "framebuffers" => Kind::Framebuffer,
+        "renderbuffer" | "renderbuffers" => Kind::Renderbuffer,
+        "sampler" | "samplers" => Kind::Sampler,
+        "pipeline" | "pipelines" => Kind::ProgramPipeline,
+        "xfb" => Kind::TransformFeedback,
+        // message ids of the debug functions aren't names
+        "id" | "ids" if function.contains("TransformFeedback") => Kind::TransformFeedback,
+        "id" | "ids" if function.contains("Quer") || function == "BeginConditionalRender" => {
+            Kind::Query
+        }
+        // glObjectLabel and glGetObjectLabel
+        "name" => match args[0].as_u64() as GLenum {
+            gl::BUFFER => Kind::Buffer,
+            gl::TEXTURE => Kind::Texture,
+            gl::SHADER | gl::PROGRAM => Kind::Program,
+            gl::VERTEX_ARRAY => Kind::VertexArray,
+            gl::FRAMEBUFFER => Kind::Framebuffer,
+            gl::RENDERBUFFER => Kind::Renderbuffer,
+            gl::SAMPLER => Kind::Sampler,
+            gl::QUERY => Kind::Query,
+            gl::TRANSFORM_FEEDBACK => Kind::TransformFeedback,
+            gl::PROGRAM_PIPELINE => Kind::ProgramPipeline,
+            _ => return None,
+        },
+        _ => return None,
+    })
+}
+
+// memory for GL to write to when the call doesn't say how much it writes
+const SCRATCH: usize = 64 * 1024;
+
+pub struct Replayer {
+    // traced name -> replayed name
+    names: HashMap<(Kind, GLuint), GLuint>,
+    // (traced program, traced location) -> replayed location
+    locations: HashMap<(GLuint, GLint), GLint>,
+    // traced address -> replayed sync
+    syncs: HashMap<usize, GLsync>,
+    // the traced program of glUseProgram, for glUniform* locations
+    program: GLuint,
+    // mapped buffers by target, the payload of glUnmapBuffer goes there
+    mapped: HashMap<GLenum, (usize, usize)>,
+    default_framebuffer: GLuint,
+    // calls replayed
+    pub calls: usize,
+    // calls skipped, by function
+    pub skipped: BTreeMap<String, usize>,
+}
+
+// unsafe like the Gl functions, a trace from another program can draw anything
+#[allow(clippy::missing_safety_doc)]
+impl Replayer {
+    // `default_framebuffer` is what the traced program's window becomes
+    pub fn new(default_framebuffer: GLuint) -> Self {
+        Replayer {
+            names: HashMap::from([((Kind::Framebuffer, 0), default_framebuffer)]),
+            locations: HashMap::new(),
+            syncs: HashMap::new(),
+            program: 0,
+            mapped: HashMap::new(),
+            default_framebuffer,
+            calls: 0,
+            skipped: BTreeMap::new(),
+        }
+    }
+
+    // frames are up to the caller, they're where it swaps buffers
+    pub unsafe fn replay(&mut self, gl: &Gl, event: &Event) {
+        match event {
+            Event::Call(call) => {
+                self.call(gl, call);
+            }
+            Event::DefaultFramebuffer { framebuffer, .. } => {
+                self.names
+                    .insert((Kind::Framebuffer, *framebuffer), self.default_framebuffer);
+            }
+            Event::Frame => {}
+        }
+    }
+
+    // false if the call was skipped
+    pub unsafe fn call(&mut self, gl: &Gl, call: &Call) -> bool {
+        let replayed = match signature(&call.function) {
+            Some(signature) if signature.params.len() == call.args.len() => {
+                self.dispatch(gl, signature, call)
+            }
+            _ => false,
+        };
+        match replayed {
+            true => self.calls += 1,
+            false => *self.skipped.entry(call.function.clone()).or_default() += 1,
+        }
+        replayed
+    }
+
+    unsafe fn dispatch(&mut self, gl: &Gl, signature: &Signature, call: &Call) -> bool {
+        let function = call.function.as_str();
+        let mut args = call.args.clone();
+        // the copies and scratch memory pointer arguments point to, u64 for alignment
+        let mut memory: Vec<Vec<u64>> = vec![];
+        let mut point_at = |args: &mut Vec<Value>, arg: usize, mut block: Vec<u64>| {
+            args[arg] = Value::Pointer(block.as_mut_ptr() as usize);
+            memory.push(block);
+        };
+
+        for (arg, &(param, ty)) in signature.params.iter().enumerate() {
+            let value = call.args[arg];
+            if let Some(kind) = kind(function, param, ty, &call.args) {
+                if ty == "GLuint" {
+                    args[arg] = Value::UInt(self.name(kind, value.as_u64() as GLuint) as u64);
+                    continue;
+                }
+            }
+            if param == "location" && ty == "GLint" {
+                let program = match function.starts_with("ProgramUniform") {
+                    true => call.args[0].as_u64() as GLuint,
+                    false => self.program,
+                };
+                let location = value.as_i64() as GLint;
+                let location = self
+                    .locations
+                    .get(&(program, location))
+                    .unwrap_or(&location);
+                args[arg] = Value::Int(*location as i64);
+                continue;
+            }
+            if ty == "GLsync" {
+                let sync = self.syncs.get(&(value.as_u64() as usize));
+                args[arg] = Value::Pointer(sync.map_or(0, |sync| *sync as usize));
+                continue;
+            }
+
+            let Value::Pointer(address) = value else {
+                continue;
+            };
+            if function == "ShaderSource" && arg == 2 {
+                // filled in below with the lengths
+            } else if let Some(data) = call.payload(arg) {
+                let mut block = copy(data);
+                if let Some(kind) = kind(function, param, ty, &call.args) {
+                    let names = block.as_mut_ptr() as *mut GLuint;
+                    for i in 0..data.len() / 4 {
+                        *names.add(i) = self.name(kind, *names.add(i));
+                    }
+                }
+                point_at(&mut args, arg, block);
+            } else if address == 0 {
+            } else if ty.starts_with("*mut") {
+                let len = match function {
+                    "ReadPixels" => {
+                        let size = pixel_size(args[4].as_u64() as _, args[5].as_u64() as _);
+                        let (width, height) = (args[2].as_u64(), args[3].as_u64());
+                        // 8 is the largest alignment there is
+                        image_size(width as _, height as _, 1, size.unwrap_or(16), 8)
+                    }
+                    "GetBufferSubData" => args[2].as_u64() as usize,
+                    _ => SCRATCH,
+                };
+                point_at(&mut args, arg, vec![0; len.div_ceil(8)]);
+            } else {
+                return false;
+            }
+        }
+
+        if function == "ShaderSource" {
+            let Some(Ok(sources)) = call.payload(2).map(shader_sources) else {
+                return false;
+            };
+            let pointers: Vec<*const u8> = sources.iter().map(|source| source.as_ptr()).collect();
+            let lengths: Vec<GLint> = sources.iter().map(|source| source.len() as GLint).collect();
+            args[1] = Value::Int(sources.len() as i64);
+            args[2] = Value::Pointer(pointers.as_ptr() as usize);
+            args[3] = Value::Pointer(lengths.as_ptr() as usize);
+            return self.finish(gl, call, &args);
+        }
+        if function == "UnmapBuffer" {
+            let target = call.args[0].as_u64() as GLenum;
+            if let (Some(data), Some(&(address, len))) = (call.payload(0), self.mapped.get(&target))
+            {
+                ptr::copy_nonoverlapping(data.as_ptr(), address as *mut u8, data.len().min(len));
+            }
+        }
+        self.finish(gl, call, &args)
+    }
+
+    // makes the call and notes the names, locations and pointers it returned
+    unsafe fn finish(&mut self, gl: &Gl, call: &Call, args: &[Value]) -> bool {
+        let function = call.function.as_str();
+        let Some(result) = dispatch(gl, function, args) else {
+            return false;
+        };
+
+        if let Some((kind, count, names)) = generated_names(function) {
+            if let Some(traced) = call.payload(names) {
+                let replayed = args[names].as_u64() as usize as *const GLuint;
+                let count = (args[count].as_u64() as usize).min(traced.len() / 4);
+                for i in 0..count {
+                    let traced = u32::from_ne_bytes(traced[4 * i..4 * i + 4].try_into().unwrap());
+                    self.names.insert((kind, traced), *replayed.add(i));
+                }
+            }
+        }
+        let traced = call.result;
+        match function {
+            "CreateShader" | "CreateProgram" | "CreateShaderProgramv" => {
+                let name = result.as_u64() as GLuint;
+                self.names
+                    .insert((Kind::Program, traced.as_u64() as GLuint), name);
+            }
+            "GetUniformLocation" => {
+                let program = call.args[0].as_u64() as GLuint;
+                let location = (program, traced.as_i64() as GLint);
+                self.locations.insert(location, result.as_i64() as GLint);
+            }
+            "FenceSync" => {
+                self.syncs
+                    .insert(traced.as_u64() as usize, result.as_u64() as GLsync);
+            }
+            "UseProgram" => self.program = call.args[0].as_u64() as GLuint,
+            "MapBufferRange" => {
+                let target = call.args[0].as_u64() as GLenum;
+                let len = call.args[2].as_u64() as usize;
+                self.mapped.insert(target, (result.as_u64() as usize, len));
+            }
+            "UnmapBuffer" => {
+                self.mapped.remove(&(call.args[0].as_u64() as GLenum));
+            }
+            _ => {}
+        }
+        true
+    }
+
+    // names the trace didn't generate, e.g. 0, are kept
+    fn name(&self, kind: Kind, traced: GLuint) -> GLuint {
+        *self.names.get(&(kind, traced)).unwrap_or(&traced)
+    }
+}
+
+// a payload in memory aligned for any type
+fn copy(data: &[u8]) -> Vec<u64> {
+    let mut block = vec![0u64; data.len().div_ceil(8)];
+    unsafe { ptr::copy_nonoverlapping(data.as_ptr(), block.as_mut_ptr() as *mut u8, data.len()) };
+    block
+}
@@ -0,0 +1,940 @@
+// Call tracing for the `trace` feature: while a trace runs, every call through
+// the gl33, gl46 or slim bindings is written to a file with its arguments, its
+// result and the client memory it reads (buffer data, pixels, shader sources,
+// uniform values...), so it can be played back somewhere else without the
+// program or its assets, see `replay`.
+//
+//   glad::trace::start("bug.gltrace")?;
+//   ...                      // GL calls
+//   glad::trace::frame();    // after each swap
+//   let calls = glad::trace::stop()?;
+//
+// Memory is captured for the functions in `Recorder::regions` and friends,
+// other pointers are kept as plain addresses. A call reading client memory the
+// trace doesn't have is reported once while recording and skipped on replay.
+// glGetError isn't traced.
+//
+// The file is MAGIC and FORMAT_VERSION followed by records, a tag byte and its
+// fields each. Integers are LEB128 varints, zigzag encoded when signed, floats
+// are little endian. A function's name is written once, calls refer to it by
+// index.
+
+use crate::{
+    gl33::{self as gl, types::*},
+    pixels::{image_size, pixel_size},
+    replay::{self, generated_names},
+};
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    ffi::CStr,
+    fmt,
+    fs::File,
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::Path,
+    slice,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+};
+
+pub const MAGIC: &[u8; 8] = b"GLTRACE\0";
+pub const FORMAT_VERSION: u16 = 1;
+
+// record tags
+const FUNCTION: u8 = 0;
+const CALL: u8 = 1;
+const FRAME: u8 = 2;
+const DEFAULT_FRAMEBUFFER: u8 = 3;
+
+// value tags
+const VOID: u8 = 0;
+const INT: u8 = 1;
+const UINT: u8 = 2;
+const FLOAT: u8 = 3;
+const DOUBLE: u8 = 4;
+const POINTER: u8 = 5;
+const OFFSET: u8 = 6;
+
+// an argument or return value
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Value {
+    Void,
+    Int(i64),
+    UInt(u64),
+    Float(f32),
+    Double(f64),
+    // an address in the traced process, the call's payload for it if it has one
+    Pointer(usize),
+    // a pointer argument GL takes as an offset into a bound buffer, e.g. the
+    // last argument of glVertexAttribPointer
+    Offset(usize),
+}
+
+macro_rules! from_number {
+    ($variant:ident, $as:ty: $($ty:ty),*) => {
+        $(impl From<$ty> for Value {
+            fn from(value: $ty) -> Self {
+                Value::$variant(value as $as)
+            }
+        })*
+    };
+}
+
+from_number!(Int, i64: i8, i16, i32, i64, isize);
+from_number!(UInt, u64: u8, u16, u32, u64, usize);
+from_number!(Float, f32: f32);
+from_number!(Double, f64: f64);
+
+impl From<()> for Value {
+    fn from(_: ()) -> Self {
+        Value::Void
+    }
+}
+
+impl<T> From<*const T> for Value {
+    fn from(pointer: *const T) -> Self {
+        Value::Pointer(pointer as usize)
+    }
+}
+
+impl<T> From<*mut T> for Value {
+    fn from(pointer: *mut T) -> Self {
+        Value::Pointer(pointer as usize)
+    }
+}
+
+// callbacks are only addresses, a replay can't call them
+impl From<GLDEBUGPROC> for Value {
+    fn from(callback: GLDEBUGPROC) -> Self {
+        Value::Pointer(callback as usize)
+    }
+}
+
+impl From<GLDEBUGPROCAMD> for Value {
+    fn from(callback: GLDEBUGPROCAMD) -> Self {
+        Value::Pointer(callback as usize)
+    }
+}
+
+impl From<GLVULKANPROCNV> for Value {
+    fn from(callback: GLVULKANPROCNV) -> Self {
+        Value::Pointer(callback as usize)
+    }
+}
+
+impl Value {
+    // as an integer whatever it was, pointers by their address
+    pub fn as_i64(self) -> i64 {
+        match self {
+            Value::Void => 0,
+            Value::Int(value) => value,
+            Value::UInt(value) => value as i64,
+            Value::Float(value) => value as i64,
+            Value::Double(value) => value as i64,
+            Value::Pointer(address) | Value::Offset(address) => address as i64,
+        }
+    }
+
+    pub fn as_u64(self) -> u64 {
+        self.as_i64() as u64
+    }
+
+    pub fn as_f64(self) -> f64 {
+        match self {
+            Value::Float(value) => value as f64,
+            Value::Double(value) => value,
+            value => value.as_i64() as f64,
+        }
+    }
+
+    // a non-negative integer argument, e.g. a size or count
+    fn as_usize(self) -> usize {
+        self.as_i64().max(0) as usize
+    }
+}
+
+// client memory a pointer argument covered at the time of the call
+#[derive(Clone, Debug, PartialEq)]
+pub struct Payload {
+    pub arg: usize,
+    pub data: Vec<u8>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Call {
+    // without the gl prefix, like the Gl methods
+    pub function: String,
+    pub args: Vec<Value>,
+    pub result: Value,
+    pub payloads: Vec<Payload>,
+}
+
+impl Call {
+    pub fn payload(&self, arg: usize) -> Option<&[u8]> {
+        self.payloads
+            .iter()
+            .find(|payload| payload.arg == arg)
+            .map(|payload| payload.data.as_slice())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Event {
+    Call(Call),
+    // a frame ended, the traced program swapped buffers
+    Frame,
+    // what the traced program draws to as its window, 0 or the offscreen
+    // framebuffer of a headless run, and its size
+    DefaultFramebuffer {
+        framebuffer: GLuint,
+        width: i32,
+        height: i32,
+    },
+}
+
+#[derive(Debug)]
+pub enum TraceError {
+    Io(io::Error),
+    NotATrace,
+    // written by a newer glad
+    Version(u16),
+    // a record that doesn't parse, e.g. a trace cut off by a crash
+    Corrupt(&'static str),
+}
+
+impl fmt::Display for TraceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TraceError::Io(err) => write!(f, "{}", err),
+            TraceError::NotATrace => write!(f, "not a GL trace"),
+            TraceError::Version(version) => write!(
+                f,
+                "trace format {} is newer than this build's {}",
+                version, FORMAT_VERSION
+            ),
+            TraceError::Corrupt(what) => write!(f, "corrupt trace: {}", what),
+        }
+    }
+}
+
+impl Error for TraceError {}
+
+impl From<io::Error> for TraceError {
+    fn from(err: io::Error) -> Self {
+        match err.kind() {
+            io::ErrorKind::UnexpectedEof => TraceError::Corrupt("cut off in a record"),
+            _ => TraceError::Io(err),
+        }
+    }
+}
+
+// recording
+
+struct Recorder {
+    out: BufWriter<File>,
+    // index of each function name written so far
+    functions: HashMap<&'static str, u64>,
+    calls: usize,
+    // client state the captured sizes depend on
+    unpack_alignment: GLint,
+    pixel_unpack_buffer: GLuint,
+    pixel_pack_buffer: GLuint,
+    // write-mapped ranges by target, captured when they're unmapped
+    mapped: HashMap<GLenum, (usize, usize)>,
+    // functions already reported for memory the trace doesn't capture
+    warned: HashSet<&'static str>,
+    // the first write error, `stop` returns it
+    error: Option<io::Error>,
+    record: Vec<u8>,
+}
+
+static RECORDER: Mutex<Option<Recorder>> = Mutex::new(None);
+
+// checked by every call before taking the lock
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+// start writing calls to `path`, replacing a trace already running
+pub fn start<P: AsRef<Path>>(path: P) -> io::Result<()> {
+    let mut out = BufWriter::new(File::create(path)?);
+    out.write_all(MAGIC)?;
+    out.write_all(&FORMAT_VERSION.to_le_bytes())?;
+    *RECORDER.lock().unwrap() = Some(Recorder {
+        out,
+        functions: HashMap::new(),
+        calls: 0,
+        unpack_alignment: 4,
+        pixel_unpack_buffer: 0,
+        pixel_pack_buffer: 0,
+        mapped: HashMap::new(),
+        warned: HashSet::new(),
+        error: None,
+        record: vec![],
+    });
+    ACTIVE.store(true, Ordering::Release);
+    Ok(())
+}
+
+pub fn is_active() -> bool {
+    ACTIVE.load(Ordering::Acquire)
+}
+
+// mark the end of a frame, the trace is flushed so a crash loses little
+pub fn frame() {
+    with_recorder(|recorder| {
+        recorder.record.clear();
+        recorder.record.push(FRAME);
+        recorder.flush_record();
+        if let Err(err) = recorder.out.flush() {
+            recorder.error.get_or_insert(err);
+        }
+    });
+}
+
+// tell the replay what the window is, at the start and after every resize
+pub fn default_framebuffer(framebuffer: GLuint, width: i32, height: i32) {
+    with_recorder(|recorder| {
+        let record = &mut recorder.record;
+        record.clear();
+        record.push(DEFAULT_FRAMEBUFFER);
+        write_varint(record, framebuffer as u64);
+        write_varint(record, zigzag(width as i64));
+        write_varint(record, zigzag(height as i64));
+        recorder.flush_record();
+    });
+}
+
+// finish the trace, returns how many calls it has
+pub fn stop() -> io::Result<usize> {
+    ACTIVE.store(false, Ordering::Release);
+    let Some(mut recorder) = RECORDER.lock().unwrap().take() else {
+        return Ok(0);
+    };
+    recorder.out.flush()?;
+    match recorder.error {
+        Some(err) => Err(err),
+        None => Ok(recorder.calls),
+    }
+}
+
+fn with_recorder(f: impl FnOnce(&mut Recorder)) {
+    if !is_active() {
+        return;
+    }
+    // a panic while recording poisons the lock, stop tracing then
+    if let Ok(mut recorder) = RECORDER.lock() {
+        if let Some(recorder) = recorder.as_mut() {
+            f(recorder);
+        }
+    }
+}
+
+// a call on its way through, see the func! macro in gl33.rs
+pub(crate) struct Pending {
+    function: &'static str,
+    args: Vec<Value>,
+    payloads: Vec<Payload>,
+}
+
+// before the call, while the memory it reads is certainly valid
+// `args` only runs while a trace is running
+pub(crate) fn begin<A>(function: &'static str, args: A) -> Option<Pending>
+where
+    A: FnOnce() -> Vec<Value>,
+{
+    if !is_active() {
+        return None;
+    }
+    let mut pending = Pending {
+        function,
+        args: args(),
+        payloads: vec![],
+    };
+    with_recorder(|recorder| unsafe { recorder.inputs(&mut pending) });
+    Some(pending)
+}
+
+// after the call, with what it returned and wrote
+pub(crate) fn end(pending: Option<Pending>, result: Value) {
+    if let Some(mut pending) = pending {
+        with_recorder(|recorder| {
+            unsafe { recorder.outputs(&mut pending, result) };
+            recorder.write_call(&pending, result);
+        });
+    }
+}
+
+impl Recorder {
+    unsafe fn inputs(&mut self, call: &mut Pending) {
+        let args = &call.args;
+        let pointer = |arg: usize| match args.get(arg) {
+            Some(Value::Pointer(address)) => *address,
+            _ => 0,
+        };
+
+        for (arg, len) in self.regions(call.function, args) {
+            if pointer(arg) != 0 && len > 0 {
+                let data = slice::from_raw_parts(pointer(arg) as *const u8, len).to_vec();
+                call.payloads.push(Payload { arg, data });
+            }
+        }
+        if let Some((arg, length)) = string_argument(call.function) {
+            if pointer(arg) != 0 {
+                let length = length.map_or(-1, |length| args[length].as_i64());
+                let data = string(pointer(arg), length);
+                call.payloads.push(Payload { arg, data });
+            }
+        }
+        match call.function {
+            // count strings, each with an optional length, packed into one payload
+            "ShaderSource" if pointer(2) != 0 => {
+                let (count, strings, lengths) = (args[1].as_usize(), pointer(2), pointer(3));
+                let mut data = vec![];
+                for i in 0..count {
+                    let string = *(strings as *const *const GLchar).add(i);
+                    let length = match lengths {
+                        0 => -1,
+                        lengths => *(lengths as *const GLint).add(i) as i64,
+                    };
+                    let mut source = self::string(string as usize, length);
+                    // the terminator is added back on replay
+                    if length < 0 {
+                        source.pop();
+                    }
+                    write_varint(&mut data, source.len() as u64);
+                    data.extend_from_slice(&source);
+                }
+                call.payloads.push(Payload { arg: 2, data });
+            }
+            // the mapped memory, written before unmapping, goes with the target
+            "UnmapBuffer" => {
+                if let Some(&(address, len)) = self.mapped.get(&(args[0].as_u64() as GLenum)) {
+                    let data = slice::from_raw_parts(address as *const u8, len).to_vec();
+                    call.payloads.push(Payload { arg: 0, data });
+                }
+            }
+            _ => {}
+        }
+
+        if self.takes_offsets(call.function) {
+            for arg in call.args.iter_mut() {
+                if let Value::Pointer(address) = *arg {
+                    *arg = Value::Offset(address);
+                }
+            }
+        }
+        self.check_captured(call);
+    }
+
+    // (argument, bytes) of the client memory each function reads
+    fn regions(&self, function: &str, args: &[Value]) -> Vec<(usize, usize)> {
+        let arg = |i: usize| args[i].as_usize();
+        // 2D images have no depth argument
+        let image = |width, height, depth: Option<usize>, format: usize, type_: usize| {
+            let format = args[format].as_u64() as GLenum;
+            let size = pixel_size(format, args[type_].as_u64() as GLenum).unwrap_or(0);
+            let (width, height, depth) = (arg(width), arg(height), depth.map_or(1, arg));
+            image_size(width, height, depth, size, self.unpack_alignment)
+        };
+        let unpacking = self.pixel_unpack_buffer == 0;
+        match function {
+            "BufferData" | "NamedBufferData" | "BufferStorage" | "NamedBufferStorage" => {
+                vec![(2, arg(1))]
+            }
+            "BufferSubData" | "NamedBufferSubData" => vec![(3, arg(2))],
+            "TexImage2D" if unpacking => vec![(8, image(3, 4, None, 6, 7))],
+            "TexSubImage2D" if unpacking => vec![(8, image(4, 5, None, 6, 7))],
+            "TexImage3D" if unpacking => vec![(9, image(3, 4, Some(5), 7, 8))],
+            "TexSubImage3D" if unpacking => vec![(10, image(5, 6, Some(7), 8, 9))],
+            "CompressedTexImage2D" if unpacking => vec![(7, arg(6))],
+            "CompressedTexSubImage2D" if unpacking => vec![(8, arg(7))],
+            "DeleteBuffers"
+            | "DeleteTextures"
+            | "DeleteVertexArrays"
+            | "DeleteFramebuffers"
+            | "DeleteRenderbuffers"
+            | "DeleteSamplers"
+            | "DeleteQueries"
+            | "DeleteTransformFeedbacks"
+            | "DeleteProgramPipelines"
+            | "DrawBuffers" => {
+                vec![(1, 4 * arg(0))]
+            }
+            "InvalidateFramebuffer" => vec![(2, 4 * arg(1))],
+            "DebugMessageControl" => vec![(4, 4 * arg(3))],
+            "TexParameterfv"
+            | "TexParameteriv"
+            | "TexParameterIiv"
+            | "TexParameterIuiv"
+            | "SamplerParameterfv"
+            | "SamplerParameteriv"
+            | "SamplerParameterIiv"
+            | "SamplerParameterIuiv" => {
+                let values = match args[1].as_u64() as GLenum {
+                    gl::TEXTURE_BORDER_COLOR | gl::TEXTURE_SWIZZLE_RGBA => 4,
+                    _ => 1,
+                };
+                vec![(2, 4 * values)]
+            }
+            "ClearBufferfv" | "ClearBufferiv" | "ClearBufferuiv" => {
+                let values = match args[0].as_u64() as GLenum {
+                    gl::COLOR => 4,
+                    _ => 1,
+                };
+                vec![(2, 4 * values)]
+            }
+            function => uniform_region(function, args).into_iter().collect(),
+        }
+    }
+
+    // whether the function's pointers are offsets into a bound buffer
+    fn takes_offsets(&self, function: &str) -> bool {
+        match function {
+            "VertexAttribPointer"
+            | "VertexAttribIPointer"
+            | "VertexAttribLPointer"
+            | "DrawElements"
+            | "DrawElementsBaseVertex"
+            | "DrawElementsInstanced"
+            | "DrawElementsInstancedBaseVertex"
+            | "DrawElementsInstancedBaseInstance"
+            | "DrawElementsInstancedBaseVertexBaseInstance"
+            | "DrawRangeElements"
+            | "DrawRangeElementsBaseVertex"
+            | "DrawArraysIndirect"
+            | "DrawElementsIndirect"
+            | "MultiDrawArraysIndirect"
+            | "MultiDrawElementsIndirect" => true,
+            "TexImage2D"
+            | "TexSubImage2D"
+            | "TexImage3D"
+            | "TexSubImage3D"
+            | "CompressedTexImage2D"
+            | "CompressedTexSubImage2D" => self.pixel_unpack_buffer != 0,
+            "ReadPixels" => self.pixel_pack_buffer != 0,
+            _ => false,
+        }
+    }
+
+    // const pointers left without a payload can't be replayed, say so once
+    fn check_captured(&mut self, call: &Pending) {
+        let Some(signature) = replay::signature(call.function) else {
+            return;
+        };
+        let missing = signature.params.iter().enumerate().any(|(arg, (_, ty))| {
+            let uncaptured =
+                matches!(call.args.get(arg), Some(Value::Pointer(address)) if *address != 0);
+            uncaptured
+                && ty.starts_with("*const")
+                && call.payloads.iter().all(|payload| payload.arg != arg)
+        });
+        if missing && self.warned.insert(call.function) {
+            eprintln!(
+                "gl trace: gl{} reads memory the trace doesn't capture, replays skip it",
+                call.function
+            );
+        }
+    }
+
+    unsafe fn outputs(&mut self, call: &mut Pending, result: Value) {
+        let args = &call.args;
+        // the names a Gen or Create function wrote, the replay maps them
+        if let Some((_, count, names)) = generated_names(call.function) {
+            if let Value::Pointer(address) = args[names] {
+                let len = 4 * args[count].as_usize();
+                let data = slice::from_raw_parts(address as *const u8, len).to_vec();
+                call.payloads.push(Payload { arg: names, data });
+            }
+        }
+
+        let (first, second) = (args.first().copied(), args.get(1).copied());
+        let enum_arg = |value: Option<Value>| value.map_or(0, |value| value.as_u64() as GLenum);
+        match call.function {
+            "PixelStorei" if enum_arg(first) == gl::UNPACK_ALIGNMENT => {
+                self.unpack_alignment = second.map_or(4, |value| value.as_i64() as GLint);
+            }
+            "BindBuffer" => {
+                let buffer = enum_arg(second);
+                match enum_arg(first) {
+                    gl::PIXEL_UNPACK_BUFFER => self.pixel_unpack_buffer = buffer,
+                    gl::PIXEL_PACK_BUFFER => self.pixel_pack_buffer = buffer,
+                    _ => {}
+                }
+            }
+            "MapBufferRange" if enum_arg(args.get(3).copied()) & gl::MAP_WRITE_BIT != 0 => {
+                if let Value::Pointer(address) = result {
+                    let len = args[2].as_usize();
+                    self.mapped.insert(enum_arg(first), (address, len));
+                }
+            }
+            "UnmapBuffer" => {
+                self.mapped.remove(&enum_arg(first));
+            }
+            _ => {}
+        }
+    }
+
+    fn write_call(&mut self, call: &Pending, result: Value) {
+        self.record.clear();
+        let index = match self.functions.get(call.function) {
+            Some(&index) => index,
+            None => {
+                let index = self.functions.len() as u64;
+                self.functions.insert(call.function, index);
+                self.record.push(FUNCTION);
+                write_varint(&mut self.record, index);
+                write_varint(&mut self.record, call.function.len() as u64);
+                self.record.extend_from_slice(call.function.as_bytes());
+                index
+            }
+        };
+
+        let record = &mut self.record;
+        record.push(CALL);
+        write_varint(record, index);
+        write_varint(record, call.args.len() as u64);
+        for &arg in &call.args {
+            write_value(record, arg);
+        }
+        write_value(record, result);
+        write_varint(record, call.payloads.len() as u64);
+        for payload in &call.payloads {
+            write_varint(record, payload.arg as u64);
+            write_varint(record, payload.data.len() as u64);
+            record.extend_from_slice(&payload.data);
+        }
+        self.calls += 1;
+        self.flush_record();
+    }
+
+    fn flush_record(&mut self) {
+        if self.error.is_some() {
+            return;
+        }
+        if let Err(err) = self.out.write_all(&self.record) {
+            self.error = Some(err);
+        }
+    }
+}
+
+// (argument, length argument) of functions taking a string, without a length
+// argument it's null terminated
+fn string_argument(function: &str) -> Option<(usize, Option<usize>)> {
+    Some(match function {
+        "GetUniformLocation"
+        | "GetAttribLocation"
+        | "GetUniformBlockIndex"
+        | "GetFragDataLocation"
+        | "GetFragDataIndex" => (1, None),
+        "BindAttribLocation" | "BindFragDataLocation" => (2, None),
+        "ObjectLabel" | "PushDebugGroup" => (3, Some(2)),
+        "DebugMessageInsert" => (5, Some(4)),
+        _ => return None,
+    })
+}
+
+// a negative length is null terminated, the terminator is kept
+unsafe fn string(address: usize, length: i64) -> Vec<u8> {
+    match length {
+        length if length < 0 => CStr::from_ptr(address as *const GLchar)
+            .to_bytes_with_nul()
+            .to_vec(),
+        length => slice::from_raw_parts(address as *const u8, length as usize).to_vec(),
+    }
+}
+
+// glUniform{1,2,3,4}{f,i,ui,d}v and glUniformMatrix{2,3,4,2x3,...}{f,d}v
+fn uniform_region(function: &str, args: &[Value]) -> Option<(usize, usize)> {
+    let name = function.strip_prefix("Uniform")?.strip_suffix('v')?;
+    let (matrix, name) = match name.strip_prefix("Matrix") {
+        Some(name) => (true, name),
+        None => (false, name),
+    };
+    let split = name.find(|c: char| !c.is_ascii_digit() && c != 'x')?;
+    let (shape, ty) = name.split_at(split);
+    let components: usize = match shape.split_once('x') {
+        Some((columns, rows)) => columns.parse::<usize>().ok()? * rows.parse::<usize>().ok()?,
+        None if matrix => shape.parse::<usize>().ok()?.pow(2),
+        None => shape.parse().ok()?,
+    };
+    let size = match ty {
+        "f" | "i" | "ui" => 4,
+        "d" => 8,
+        _ => return None,
+    };
+    // glUniformMatrix has a transpose flag before the values
+    let value = if matrix { 3 } else { 2 };
+    Some((value, args[1].as_usize() * components * size))
+}
+
+// reading
+
+pub struct TraceReader<R> {
+    input: R,
+    functions: Vec<String>,
+}
+
+impl TraceReader<BufReader<File>> {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, TraceError> {
+        TraceReader::new(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<R: Read> TraceReader<R> {
+    pub fn new(mut input: R) -> Result<Self, TraceError> {
+        let mut magic = [0; 8];
+        input
+            .read_exact(&mut magic)
+            .map_err(|_| TraceError::NotATrace)?;
+        if &magic != MAGIC {
+            return Err(TraceError::NotATrace);
+        }
+        let mut version = [0; 2];
+        input.read_exact(&mut version)?;
+        match u16::from_le_bytes(version) {
+            FORMAT_VERSION => Ok(TraceReader {
+                input,
+                functions: vec![],
+            }),
+            version => Err(TraceError::Version(version)),
+        }
+    }
+
+    // None at the end of the trace
+    pub fn next_event(&mut self) -> Result<Option<Event>, TraceError> {
+        loop {
+            let mut tag = [0];
+            if self.input.read(&mut tag)? == 0 {
+                return Ok(None);
+            }
+            match tag[0] {
+                FUNCTION => {
+                    let index = self.varint()?;
+                    if index != self.functions.len() as u64 {
+                        return Err(TraceError::Corrupt("functions out of order"));
+                    }
+                    let name = self.bytes()?;
+                    let name = String::from_utf8(name)
+                        .map_err(|_| TraceError::Corrupt("function name isn't UTF-8"))?;
+                    self.functions.push(name);
+                }
+                CALL => return self.call().map(|call| Some(Event::Call(call))),
+                FRAME => return Ok(Some(Event::Frame)),
+                DEFAULT_FRAMEBUFFER => {
+                    return Ok(Some(Event::DefaultFramebuffer {
+                        framebuffer: self.varint()? as GLuint,
+                        width: unzigzag(self.varint()?) as i32,
+                        height: unzigzag(self.varint()?) as i32,
+                    }))
+                }
+                _ => return Err(TraceError::Corrupt("unknown record")),
+            }
+        }
+    }
+
+    fn call(&mut self) -> Result<Call, TraceError> {
+        let index = self.varint()? as usize;
+        let function = self
+            .functions
+            .get(index)
+            .ok_or(TraceError::Corrupt("call to an undefined function"))?
+            .clone();
+        let count = self.varint()?;
+        let args = (0..count).map(|_| self.value()).collect::<Result<_, _>>()?;
+        let result = self.value()?;
+        let count = self.varint()?;
+        let payloads = (0..count)
+            .map(|_| {
+                let arg = self.varint()? as usize;
+                let data = self.bytes()?;
+                Ok(Payload { arg, data })
+            })
+            .collect::<Result<_, TraceError>>()?;
+        Ok(Call {
+            function,
+            args,
+            result,
+            payloads,
+        })
+    }
+
+    fn value(&mut self) -> Result<Value, TraceError> {
+        let mut tag = [0];
+        self.input.read_exact(&mut tag)?;
+        Ok(match tag[0] {
+            VOID => Value::Void,
+            INT => Value::Int(unzigzag(self.varint()?)),
+            UINT => Value::UInt(self.varint()?),
+            FLOAT => {
+                let mut bytes = [0; 4];
+                self.input.read_exact(&mut bytes)?;
+                Value::Float(f32::from_le_bytes(bytes))
+            }
+            DOUBLE => {
+                let mut bytes = [0; 8];
+                self.input.read_exact(&mut bytes)?;
+                Value::Double(f64::from_le_bytes(bytes))
+            }
+            POINTER => Value::Pointer(self.varint()? as usize),
+            OFFSET => Value::Offset(self.varint()? as usize),
+            _ => return Err(TraceError::Corrupt("unknown value")),
+        })
+    }
+
+    fn varint(&mut self) -> Result<u64, TraceError> {
+        read_varint(&mut self.input)
+    }
+
+    fn bytes(&mut self) -> Result<Vec<u8>, TraceError> {
+        let len = self.varint()? as usize;
+        let mut data = vec![];
+        // a corrupt length can't allocate more than the file has
+        (&mut self.input).take(len as u64).read_to_end(&mut data)?;
+        match data.len() == len {
+            true => Ok(data),
+            false => Err(TraceError::Corrupt("cut off in a payload")),
+        }
+    }
+}
+
+impl<R: Read> Iterator for TraceReader<R> {
+    type Item = Result<Event, TraceError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_event().transpose()
+    }
+}
+
+// the strings of a glShaderSource payload
+pub fn shader_sources(payload: &[u8]) -> Result<Vec<&[u8]>, TraceError> {
+    let mut input = payload;
+    let mut sources = vec![];
+    while !input.is_empty() {
+        let len = read_varint(&mut input)? as usize;
+        let source = input
+            .get(..len)
+            .ok_or(TraceError::Corrupt("cut off in a shader source"))?;
+        sources.push(source);
+        input = &input[len..];
+    }
+    Ok(sources)
+}
+
+// glBufferData(target: 0x8892, size: 144, data: [144 bytes], usage: 0x88e4)
+impl fmt::Display for Call {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let signature = replay::signature(&self.function);
+        let params = signature.map_or(&[][..], |signature| signature.params);
+        write!(f, "gl{}(", self.function)?;
+        for (arg, &value) in self.args.iter().enumerate() {
+            if arg > 0 {
+                write!(f, ", ")?;
+            }
+            let (name, ty) = params.get(arg).copied().unwrap_or(("?", ""));
+            write!(f, "{}: ", name)?;
+            match self.payload(arg) {
+                Some(data) if ty == "*const GLchar" => {
+                    let text = data.strip_suffix(&[0]).unwrap_or(data);
+                    write!(f, "{:?}", String::from_utf8_lossy(text))?
+                }
+                Some(data) if self.function == "ShaderSource" && arg == 2 => {
+                    let sources = shader_sources(data).map_or(0, |sources| sources.len());
+                    write!(f, "[{} strings, {} bytes]", sources, data.len())?
+                }
+                Some(data) => write!(f, "[{} bytes]", data.len())?,
+                None => write_readable(f, value, ty)?,
+            }
+        }
+        write!(f, ")")?;
+        if self.result != Value::Void {
+            write!(f, " = ")?;
+            write_readable(
+                f,
+                self.result,
+                signature.map_or("", |signature| signature.ret),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn write_readable(f: &mut fmt::Formatter<'_>, value: Value, ty: &str) -> fmt::Result {
+    match value {
+        Value::UInt(value) if ty == "GLenum" || ty == "GLbitfield" => write!(f, "0x{:x}", value),
+        Value::Void => write!(f, "()"),
+        Value::Int(value) => write!(f, "{}", value),
+        Value::UInt(value) => write!(f, "{}", value),
+        Value::Float(value) => write!(f, "{}", value),
+        Value::Double(value) => write!(f, "{}", value),
+        Value::Pointer(0) => write!(f, "null"),
+        Value::Pointer(address) => write!(f, "0x{:x}", address),
+        Value::Offset(offset) => write!(f, "offset {}", offset),
+    }
+}
+
+// encoding
+
+fn write_value(out: &mut Vec<u8>, value: Value) {
+    match value {
+        Value::Void => out.push(VOID),
+        Value::Int(value) => {
+            out.push(INT);
+            write_varint(out, zigzag(value));
+        }
+        Value::UInt(value) => {
+            out.push(UINT);
+            write_varint(out, value);
+        }
+        Value::Float(value) => {
+            out.push(FLOAT);
+            out.extend_from_slice(&value.to_le_bytes());
+        }
+        Value::Double(value) => {
+            out.push(DOUBLE);
+            out.extend_from_slice(&value.to_le_bytes());
+        }
+        Value::Pointer(address) => {
+            out.push(POINTER);
+            write_varint(out, address as u64);
+        }
+        Value::Offset(offset) => {
+            out.push(OFFSET);
+            write_varint(out, offset as u64);
+        }
+    }
+}
+
+pub(crate) fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+fn read_varint<R: Read>(input: &mut R) -> Result<u64, TraceError> {
+    let mut value = 0;
+    for shift in (0..64).step_by(7) {
+        let mut byte = [0];
+        input.read_exact(&mut byte)?;
+        value |= ((byte[0] & 0x7f) as u64) << shift;
+        if byte[0] & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(TraceError::Corrupt("varint too long"))
+}
+
+// small negative numbers stay short
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+fn unzigzag(value: u64) -> i64 {
+    (value >> 1) as i64 ^ -((value & 1) as i64)
+}
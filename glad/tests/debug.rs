@@ -0,0 +1,37 @@
+// glGetError checking on the mock driver, run with
+//   cargo test -p glad --features debug,mock
+#![cfg(all(feature = "debug", feature = "mock"))]
+
+use glad::{
+    debug::{self, ErrorMode},
+    gl33 as gl, mock,
+};
+use std::panic::{self, AssertUnwindSafe};
+
+#[test]
+fn errors_panic_with_function_and_caller() {
+    debug::set_error_mode(ErrorMode::Panic);
+    let (gl, _) = mock::load();
+    unsafe { gl.ActiveTexture(gl::TEXTURE0 + 1) };
+
+    let line = line!() + 1;
+    let result = panic::catch_unwind(AssertUnwindSafe(|| unsafe { gl.ActiveTexture(0) }));
+    let payload = result.expect_err("an invalid enum didn't panic");
+    let message = payload
+        .downcast_ref::<String>()
+        .expect("panic message isn't a String");
+
+    assert!(
+        message.starts_with("glActiveTexture(texture: 0)"),
+        "{}",
+        message
+    );
+    assert!(
+        message.contains(&format!("{}:{}:", file!(), line)),
+        "{}",
+        message
+    );
+    assert!(message.ends_with("GL_INVALID_ENUM (0x0500)"), "{}", message);
+    // the error was drained, the next call is clean
+    unsafe { gl.ActiveTexture(gl::TEXTURE0) };
+}
@@ -0,0 +1,107 @@
+// `load` with fake loaders, no context needed since nothing gets called
+// all of them run with
+//   cargo test -p glad --features gl33,gl46
+#![cfg(feature = "gl33")]
+
+#[cfg(feature = "gl46")]
+use glad::gl46;
+use glad::{gl33, load_report::core_version};
+use std::{os::raw::c_void, panic, ptr};
+
+// never called, any non-null pointer counts as loaded
+fn dummy() -> *const c_void {
+    ptr::dangling()
+}
+
+#[test]
+fn nothing_loaded() {
+    let (gl, report) = gl33::load(|_| ptr::null());
+    assert_eq!(report.version, (3, 3));
+    assert_eq!(report.loaded, 0);
+    assert_eq!(report.missing_core, gl33::CORE_FUNCTIONS);
+    assert!(!report.is_complete());
+    assert!(!gl.is_loaded("glClear"));
+    assert!(!gl.is_loaded("Clear"));
+}
+
+#[test]
+#[cfg(feature = "gl46")]
+fn core_only() {
+    let (gl, report) = gl46::load(
+        |symbol| match gl46::CORE_FUNCTIONS.contains(&&symbol[2..]) {
+            true => dummy(),
+            false => ptr::null(),
+        },
+    );
+    assert!(report.is_complete(), "{}", report);
+    // extension names aliasing core functions load with them
+    assert!(report.loaded > gl46::CORE_FUNCTIONS.len());
+    assert!(gl.is_loaded("glActiveTextureARB"));
+    assert!(report.missing_extensions.contains(&"AccumxOES"));
+    assert!(gl.is_loaded("glDebugMessageCallback"));
+    assert!(!gl.is_loaded("glAccumxOES"));
+    // not in the bindings at all
+    assert!(!gl.is_loaded("glNotAFunction"));
+}
+
+#[test]
+fn core_versions_come_from_gl_xml() {
+    assert_eq!(core_version("Clear"), Some((1, 0)));
+    assert_eq!(core_version("glBufferData"), Some((1, 5)));
+    assert_eq!(core_version("DispatchCompute"), Some((4, 3)));
+    assert_eq!(core_version("CreateBuffers"), Some((4, 5)));
+    assert_eq!(core_version("ActiveTextureARB"), None);
+    assert_eq!(core_version("NotAFunction"), None);
+}
+
+#[test]
+#[cfg(feature = "gl46")]
+fn older_contexts_only_miss_their_own_functions() {
+    // a 3.3 driver without the 4.x functions
+    let (_, report) = gl46::load(|symbol| match core_version(symbol) {
+        Some(version) if version <= (3, 3) => dummy(),
+        _ => ptr::null(),
+    });
+    assert!(report.missing_core.contains(&"DispatchCompute"));
+    let report = report.for_context((3, 3));
+    assert!(report.is_complete(), "{}", report);
+    assert_eq!(report.version, (3, 3));
+
+    // and one that doesn't even have those
+    let (_, report) = gl46::load(|symbol| match symbol {
+        "glClear" => ptr::null(),
+        _ => dummy(),
+    });
+    let report = report.for_context((4, 5));
+    assert_eq!(report.missing_core, ["Clear"]);
+    assert!(report
+        .to_string()
+        .contains("GL 4.5 core functions missing: glClear"));
+}
+
+#[test]
+fn newer_contexts_keep_the_bindings_version() {
+    let (_, report) = gl33::load(|_| ptr::null());
+    let report = report.for_context((4, 6));
+    assert_eq!(report.version, (3, 3));
+    assert_eq!(report.missing_core, gl33::CORE_FUNCTIONS);
+}
+
+#[test]
+fn aliases_fill_in_core_functions() {
+    // an old driver that only has the ARB name
+    let (gl, report) = gl33::load(|symbol| match symbol {
+        "glActiveTextureARB" => dummy(),
+        _ => ptr::null(),
+    });
+    assert!(gl.is_loaded("glActiveTexture"));
+    assert!(!report.missing_core.contains(&"ActiveTexture"));
+}
+
+#[test]
+fn missing_function_panics_with_its_name() {
+    let (gl, _) = gl33::load(|_| ptr::null());
+    let panic = panic::catch_unwind(|| unsafe { gl.Clear(gl33::COLOR_BUFFER_BIT) }).unwrap_err();
+    let message = panic.downcast_ref::<String>().unwrap();
+    assert!(message.starts_with("glClear is not loaded"), "{}", message);
+}
@@ -0,0 +1,32 @@
+// the generated bindings against the checked-in ones, run with
+//   cargo test -p glad --features slim,gl33,gl46
+#![cfg(all(feature = "slim", feature = "gl33", feature = "gl46"))]
+
+use glad::{gl, gl33, gl46};
+use std::ptr;
+
+#[test]
+fn same_core_as_the_full_bindings() {
+    let full = match gl::CORE_VERSION {
+        (3, 3) => gl33::CORE_FUNCTIONS,
+        (4, 6) => gl46::CORE_FUNCTIONS,
+        _ => return,
+    };
+    // compatibility contexts have the removed functions on top
+    assert!(full.iter().all(|name| gl::CORE_FUNCTIONS.contains(name)));
+}
+
+#[test]
+fn only_core_and_chosen_extensions() {
+    assert!(gl::FUNCTIONS.len() < gl33::FUNCTIONS.len() / 2);
+    assert!(!gl::FUNCTIONS.contains(&"AccumxOES"));
+    assert_eq!(gl::CLEAR, gl33::CLEAR);
+}
+
+#[test]
+fn loads_like_the_full_bindings() {
+    let (gl, report) = gl::load(|_| ptr::null());
+    assert_eq!(report.version, gl::CORE_VERSION);
+    assert_eq!(report.missing_core.len(), gl::CORE_FUNCTIONS.len());
+    assert!(!gl.is_loaded("glClear"));
+}